@@ -9,3 +9,11 @@ pub fn nape_cli_config_file_path() -> Result<PathBuf, Error> {
     let path = os_home_directory::retrieve()?;
     Ok(path.join(CONFIG_DIRECTORY).join(CONFIG_FILE_NAME))
 }
+
+const ORGANISATION_NIDS_FILE_NAME: &str = "organisation_nids.yaml";
+
+/// The file listing the organisation-defined NIDs which a Subject NRN may use, in addition to those built into NAPE.
+pub fn organisation_nids_file_path() -> Result<PathBuf, Error> {
+    let path = os_home_directory::retrieve()?;
+    Ok(path.join(CONFIG_DIRECTORY).join(ORGANISATION_NIDS_FILE_NAME))
+}
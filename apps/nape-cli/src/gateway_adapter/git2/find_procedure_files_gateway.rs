@@ -0,0 +1,77 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use git2::build::RepoBuilder;
+use git2::{ObjectType, Repository, TreeWalkMode, TreeWalkResult};
+use nape_domain::procedure_management::usecases::list_procedures::gateway_boundary::{ProcedureFile, PROCEDURE_FILE_NAME};
+use nape_kernel::error::{Error, Kind};
+
+/// # Overview
+///
+/// The [`git2`] implementation of the [`FindProcedureFilesGateway`] which walks the tree of the HEAD commit of a procedure repository and returns every assurance procedure file within it.
+///
+/// # Arguments
+///
+/// * `repository` - The URL of the git repository.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`ProcedureFile`]s, where each directory is relative to the root of the repository, or an [`Error`].
+///
+/// # Design Decision
+///
+/// * The repository is cloned bare into a temporary directory which is always removed, since only the committed files are needed.
+///
+/// # Errors
+///
+/// - All [`Error`] returned are for the [`Audience::System`] with the [`Kind::GatewayError`] and a message indicating the issue that is bubbled up from the git2 library.
+///
+pub fn procedure_files_from_git(repository: &str) -> Result<Vec<ProcedureFile>, Error> {
+
+    let clone_directory = temporary_clone_directory();
+    let result = clone_and_find(repository, &clone_directory);
+    let _ = fs::remove_dir_all(&clone_directory);
+    result
+}
+
+fn clone_and_find(repository: &str, clone_directory: &Path) -> Result<Vec<ProcedureFile>, Error> {
+
+    let git_repo: Repository = RepoBuilder::new()
+        .bare(true)
+        .clone(repository, clone_directory)
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not clone the git repository '{}'. {}", repository, e)))?;
+
+    let tree = git_repo.head()
+        .and_then(|head| head.peel_to_tree())
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not get the tree of the HEAD commit for the git repository '{}'. {}", repository, e)))?;
+
+    let mut procedure_files = Vec::new();
+    let mut read_error: Option<Error> = None;
+    let walked = tree.walk(TreeWalkMode::PreOrder, |directory, entry| {
+        if entry.kind() != Some(ObjectType::Blob) || entry.name() != Some(PROCEDURE_FILE_NAME) {
+            return TreeWalkResult::Ok;
+        }
+        match git_repo.find_blob(entry.id()) {
+            Ok(blob) => procedure_files.push(ProcedureFile {
+                directory: directory.trim_end_matches('/').to_string(),
+                content: String::from_utf8_lossy(blob.content()).to_string(),
+            }),
+            Err(e) => {
+                read_error = Some(Error::for_system(Kind::GatewayError, format!("Could not read the file '{}{}' from the git repository '{}'. {}", directory, PROCEDURE_FILE_NAME, repository, e)));
+                return TreeWalkResult::Abort;
+            }
+        }
+        TreeWalkResult::Ok
+    });
+
+    if let Some(error) = read_error {
+        return Err(error);
+    }
+    walked.map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not walk the tree of the git repository '{}'. {}", repository, e)))?;
+    Ok(procedure_files)
+}
+
+fn temporary_clone_directory() -> PathBuf {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos()).unwrap_or_default();
+    std::env::temp_dir().join(format!("nape-procedure-list-{}-{}", std::process::id(), nanos))
+}
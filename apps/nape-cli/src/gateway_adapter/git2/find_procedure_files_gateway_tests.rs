@@ -0,0 +1,52 @@
+use git2::{Repository, Signature};
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::git2::find_procedure_files_gateway::procedure_files_from_git;
+
+/// Commits every file in the working directory of the repository.
+fn commit_all(repository: &Repository) {
+    let mut index = repository.index().unwrap();
+    index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None).unwrap();
+    index.write().unwrap();
+    let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("NAPE", "nape@example.com").unwrap();
+    repository.commit(Some("HEAD"), &signature, &signature, "Add the procedures.", &tree, &[]).unwrap();
+}
+
+#[test]
+fn success() {
+
+    // Assemble
+    remove!("find_procedure_files_from_git_success");
+    create_file!("find_procedure_files_from_git_success/procedures/rust-ci/sourcecode-integration/assurance_procedure.yaml", "kind: rust");
+    create_file!("find_procedure_files_from_git_success/procedures/rust-ci/sourcecode-integration/activity/peer-review.rego", "package x");
+    create_file!("find_procedure_files_from_git_success/procedures/assurance_procedure.yaml", "kind: root");
+    let repository_path = canonical_path!(create!("find_procedure_files_from_git_success/procedures"));
+    let repository = Repository::init(&repository_path).unwrap();
+    commit_all(&repository);
+    create_file!("find_procedure_files_from_git_success/procedures/uncommitted/assurance_procedure.yaml", "kind: uncommitted");
+
+    // Act
+    let result = procedure_files_from_git(&repository_path);
+
+    // Assert
+    let procedure_files = is_ok!(result);
+    assert_eq!(procedure_files.len(), 2);
+    assert_eq!(procedure_files[0].directory, "");
+    assert_eq!(procedure_files[0].content, "kind: root");
+    assert_eq!(procedure_files[1].directory, "rust-ci/sourcecode-integration");
+    assert_eq!(procedure_files[1].content, "kind: rust");
+
+    // Clean up
+    remove!("find_procedure_files_from_git_success");
+}
+
+#[test]
+fn clone_error() {
+
+    let result = procedure_files_from_git("find_procedure_files_from_git_missing/procedures");
+
+    kernel_error_starts_with!(result, Kind::GatewayError, Audience::System,
+        "Could not clone the git repository 'find_procedure_files_from_git_missing/procedures'. ");
+}
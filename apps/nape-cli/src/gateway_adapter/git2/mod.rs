@@ -1,5 +1,9 @@
+pub mod find_procedure_files_gateway;
 pub mod process_retrieval_gateway;
+pub mod publish_collection_gateway;
 
 
 // #[cfg(large_tests)]
+#[cfg(test)] mod find_procedure_files_gateway_tests;
 #[cfg(test)] mod process_retrieval_gateway_tests;
+#[cfg(test)] mod publish_collection_gateway_tests;
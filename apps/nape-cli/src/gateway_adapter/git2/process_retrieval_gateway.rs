@@ -6,6 +6,7 @@ use git2::{Commit, ObjectType, Reference, Repository, Tree, TreeEntry};
 use nape_kernel::error::{Error, Kind};
 use nape_kernel::values::directory::directory_list::DirectoryList;
 use nape_kernel::values::specification::repository_link::RepositoryLink;
+use nape_kernel::values::specification::assurance_procedure::include::Source;
 
 /// # Overview
 /// The [`git2`] implementation of the Gateway [`domain::evidence_collection::usecase::for_process::start_collection_process::ProcessRetrievalGateway`] which retrieves the NAPE procedure data from a git repository and writes the procedure files to disk.
@@ -30,13 +31,38 @@ use nape_kernel::values::specification::repository_link::RepositoryLink;
 ///  - All [`Error`] returned are for the [`Audience::System`] with the [`Kind::GatewayError`] and a message indicating the issues that is either bubbled up from the git2 library or a custom message describing the issue.
 ///
 pub fn retrieve_procedure_from_git(repo_link: &RepositoryLink, procedure_directory: &str, download_directory: &str) -> Result<DirectoryList, Error> {
+    retrieve_revision_from_git(&repo_link.value, procedure_directory, None, download_directory)
+}
+
+/// # Overview
+/// The [`git2`] implementation of the Gateway [`domain::evidence_collection::usecases::start_collection::gateway::IncludeRetrievalGateway`] which retrieves a procedure that activities are included from, at the revision of the [`Source`], and writes the procedure files to disk.
+///
+/// # Arguments
+///
+/// * `source` - The [`Source`] of the included procedure.  When it has no revision, the HEAD of the default branch is used.
+/// * `download_directory` - A string representing the directory where the procedure files are written to.
+///
+/// # Returns
+///
+/// The same [`DirectoryList`] as [`retrieve_procedure_from_git`], or an [`Error`] for the [`Audience::System`] with the [`Kind::GatewayError`], e.g. if the revision does not exist in the repository.
+///
+pub fn retrieve_include_from_git(source: &Source, download_directory: &str) -> Result<DirectoryList, Error> {
+    retrieve_revision_from_git(&source.repository, &source.directory, source.revision.as_deref(), download_directory)
+}
+
+pub(crate) fn retrieve_revision_from_git(repository: &str, procedure_directory: &str, revision: Option<&str>, download_directory: &str) -> Result<DirectoryList, Error> {
 
     let dir_to_clone_to = format!("{}/{}", &download_directory, "clone");
-    let git_repo =  clone_repo(repo_link, &dir_to_clone_to)?;
-    let repo_head = get_head_from_repo(&git_repo, &repo_link.value)?;
-    let head_commit = get_commit_from_head(&repo_head, &repo_link.value)?;
-    let commit_tree = get_tree_from_commit(&head_commit, &repo_link.value)?;
-    let process_directory_tree = get_tree_for_process_directory_only(&commit_tree, &git_repo, procedure_directory, &repo_link.value)?;
+    let git_repo =  clone_repo(repository, &dir_to_clone_to, revision.is_none())?;
+    let commit = match revision {
+        Some(revision) => get_commit_for_revision(&git_repo, revision, repository)?,
+        None => {
+            let repo_head = get_head_from_repo(&git_repo, repository)?;
+            get_commit_from_head(&repo_head, repository)?
+        }
+    };
+    let commit_tree = get_tree_from_commit(&commit, repository)?;
+    let process_directory_tree = get_tree_for_process_directory_only(&commit_tree, &git_repo, procedure_directory, repository)?;
     write_process_directory_tree_files_to_disk(&process_directory_tree, &git_repo, &download_directory)?;
     remove_clone_directory(&dir_to_clone_to)?;
     let directory_list = build_directory_list(&download_directory)?;
@@ -44,22 +70,33 @@ pub fn retrieve_procedure_from_git(repo_link: &RepositoryLink, procedure_directo
 }
 
 
-fn clone_repo(repo_link: &RepositoryLink, clone_directory: &str) -> Result<Repository, Error> {
+/// A shallow clone is only possible when the HEAD is used, as a revision may point to any commit in the history.
+fn clone_repo(repository: &str, clone_directory: &str, shallow: bool) -> Result<Repository, Error> {
     let mut fetch_options = git2::FetchOptions::new();
-    fetch_options.depth(1);
+    if shallow {
+        fetch_options.depth(1);
+    }
 
     let mut builder = RepoBuilder::new();
     builder.bare(true);
     builder.fetch_options(fetch_options);
-    let repo_link_value = repo_link.value.as_str();
-     match builder.clone(repo_link_value, &Path::new(&clone_directory)) {
+     match builder.clone(repository, &Path::new(&clone_directory)) {
         Ok(repo) =>  Ok(repo),
         Err(error) => Err(Error::for_system(Kind::GatewayError,
-                                                       format!("Could not clone the git repository '{}'. {}", repo_link_value, error)))
+                                                       format!("Could not clone the git repository '{}'. {}", repository, error)))
 
     }
 }
 
+/// Resolves the revision as a tag, commit, or local branch, then as a remote branch of the clone.
+fn get_commit_for_revision<'a>(repo: &'a Repository, revision: &str, repo_link: &str) -> Result<Commit<'a>, Error> {
+    repo.revparse_single(revision)
+        .or_else(|_| repo.revparse_single(&format!("origin/{}", revision)))
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("Could not find the revision '{}' in the git repository '{}'. {}", revision, repo_link, error)))
+}
+
 fn get_head_from_repo<'a>(repo: &'a Repository, repo_link: &'a str) -> Result<Reference<'a>, Error> {
     match repo.head() {
         Ok(head) => Ok(head),
@@ -68,7 +105,7 @@ fn get_head_from_repo<'a>(repo: &'a Repository, repo_link: &'a str) -> Result<Re
     }
 }
 
-fn get_commit_from_head<'a>(head: &'a Reference<'a>, repo_link: &'a str) -> Result<Commit<'a>, Error> {
+fn get_commit_from_head<'a>(head: &Reference<'a>, repo_link: &str) -> Result<Commit<'a>, Error> {
     match head.peel_to_commit() {
         Ok(commit) => Ok(commit),
         Err(error) => Err(Error::for_system(Kind::GatewayError,
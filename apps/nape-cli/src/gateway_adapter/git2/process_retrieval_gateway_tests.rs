@@ -1,7 +1,11 @@
 use std::fs;
 use std::path::Path;
+use git2::{Repository, Signature};
+use nape_kernel::error::{Audience, Kind};
 use nape_kernel::values::specification::repository_link::RepositoryLink;
-use crate::gateway_adapter::git2::process_retrieval_gateway::retrieve_procedure_from_git;
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::git2::process_retrieval_gateway::{retrieve_procedure_from_git, retrieve_revision_from_git};
 
 
 #[test]
@@ -33,3 +37,67 @@ fn retrieve_procedure_from_git_success() {
     fs::remove_dir_all("retrieve_process_from_git_success")
         .expect("Filed to remove testing directory 'retrieve_process_from_git_success'.")
 }
+
+/// Commits every file in the working directory of the repository on top of the HEAD, if there is one.
+fn commit_all(repository: &Repository, message: &str) {
+    let mut index = repository.index().unwrap();
+    index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None).unwrap();
+    index.write().unwrap();
+    let tree = repository.find_tree(index.write_tree().unwrap()).unwrap();
+    let signature = Signature::now("NAPE", "nape@example.com").unwrap();
+    let parent = repository.head().ok().map(|head| head.peel_to_commit().unwrap());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+    repository.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents).unwrap();
+}
+
+#[test]
+fn retrieve_revision_from_git_success() {
+
+    // Assemble
+    remove!("retrieve_revision_from_git_success");
+    create_file!("retrieve_revision_from_git_success/procedures/shared/assurance_procedure.yaml", "kind: v1");
+    create_file!("retrieve_revision_from_git_success/procedures/shared/activity/branch/protected.py", "print('v1')");
+    let repository_path = canonical_path!(create!("retrieve_revision_from_git_success/procedures"));
+    let repository = Repository::init(&repository_path).unwrap();
+    commit_all(&repository, "The first version.");
+    let first_commit = repository.head().unwrap().peel_to_commit().unwrap();
+    repository.tag_lightweight("v1", first_commit.as_object(), false).unwrap();
+    create_file!("retrieve_revision_from_git_success/procedures/shared/assurance_procedure.yaml", "kind: v2");
+    commit_all(&repository, "The second version.");
+    let download_directory = canonical_path!(create!("retrieve_revision_from_git_success/download"));
+
+    // Act
+    let result = retrieve_revision_from_git(&repository_path, "shared", Some("v1"), &download_directory);
+
+    // Assert
+    let directory_list = is_ok!(result);
+    let procedure_file = directory_list.try_get("assurance-procedure-file").unwrap();
+    assert_eq!(fs::read_to_string(procedure_file).unwrap(), "kind: v1");
+    assert!(Path::new(&format!("{}/activity/branch/protected.py", download_directory)).exists());
+    assert!(!Path::new(&format!("{}/clone", download_directory)).exists());
+
+    // Clean up
+    remove!("retrieve_revision_from_git_success");
+}
+
+#[test]
+fn retrieve_revision_from_git_missing_revision_error() {
+
+    // Assemble
+    remove!("retrieve_revision_from_git_missing_revision");
+    create_file!("retrieve_revision_from_git_missing_revision/procedures/shared/assurance_procedure.yaml", "kind: v1");
+    let repository_path = canonical_path!(create!("retrieve_revision_from_git_missing_revision/procedures"));
+    let repository = Repository::init(&repository_path).unwrap();
+    commit_all(&repository, "The first version.");
+    let download_directory = canonical_path!(create!("retrieve_revision_from_git_missing_revision/download"));
+
+    // Act
+    let result = retrieve_revision_from_git(&repository_path, "shared", Some("v9"), &download_directory);
+
+    // Assert
+    kernel_error_starts_with!(result, Kind::GatewayError, Audience::System,
+        &format!("Could not find the revision 'v9' in the git repository '{}'. ", repository_path));
+
+    // Clean up
+    remove!("retrieve_revision_from_git_missing_revision");
+}
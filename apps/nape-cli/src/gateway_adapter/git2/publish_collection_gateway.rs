@@ -0,0 +1,148 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+use git2::build::RepoBuilder;
+use git2::{Config, Cred, CredentialType, FetchOptions, Oid, PushOptions, RemoteCallbacks, Repository, Signature};
+use nape_domain::evidence_collection::usecases::publish_collection::gateway_boundary::CollectionPackage;
+use nape_kernel::error::{Error, Kind};
+use crate::gateway_adapter::std_env::retrieve_user::current_user;
+
+/// The email used for the commit when git is not configured with a user, such as on a build server.
+const DEFAULT_COMMITTER_EMAIL: &str = "nape@localhost";
+
+/// # Overview
+/// The [`git2`] implementation of the [`PublishCollectionGateway`] which commits the collection to a git evidence repository, and pushes the commit to the branch the repository's HEAD refers to.
+///
+/// # Arguments
+///
+/// * `package` - The [`CollectionPackage`] to publish.
+/// * `location` - The URL, or local path, of the git evidence repository.  The repository may be empty.
+///
+/// # Returns
+///
+/// The location of the repository followed by the id of the commit, such as 'https://github.com/example/evidence.git@1f0e3c...', or an [`Error`].
+///
+/// # Design Decision
+///
+/// * The repository is cloned into a temporary directory which is always removed, so nothing is left behind when the push fails.
+/// * Credentials are taken from the SSH agent, or the git credential helper, in the same way as the git command line.
+///
+pub fn publish_to_git(package: &CollectionPackage, location: &str) -> Result<String, Error> {
+
+    let clone_directory = temporary_clone_directory();
+    let result = commit_and_push(package, location, &clone_directory);
+    let _ = fs::remove_dir_all(&clone_directory);
+    result
+}
+
+fn commit_and_push(package: &CollectionPackage, location: &str, clone_directory: &Path) -> Result<String, Error> {
+
+    let mut fetch_options = FetchOptions::new();
+    fetch_options.remote_callbacks(credential_callbacks());
+    let repository = RepoBuilder::new()
+        .fetch_options(fetch_options)
+        .clone(location, clone_directory)
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not clone the git repository '{}'. {}", location, e)))?;
+
+    let workdir = repository.workdir()
+        .ok_or_else(|| Error::for_system(Kind::GatewayError, format!("The clone of the git repository '{}' does not have a working directory.", location)))?
+        .to_path_buf();
+
+    let mut index = repository.index()
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not open the index of the git repository '{}'. {}", location, e)))?;
+
+    for file in &package.files {
+        let relative_path = format!("{}/{}", package.destination, file.relative_path);
+        let target_file = workdir.join(&relative_path);
+        if let Some(parent) = target_file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not create the directory '{}': {}", parent.display(), e)))?;
+        }
+        fs::copy(&file.source, &target_file)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not copy the file '{}' to '{}': {}", file.source, target_file.display(), e)))?;
+        index.add_path(Path::new(&relative_path))
+            .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not add the file '{}' to the git index. {}", relative_path, e)))?;
+    }
+
+    index.write()
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not write the git index. {}", e)))?;
+
+    let commit_id = commit(&repository, &mut index, &package.message)?;
+    let branch = head_branch(&repository)?;
+    push(&repository, &branch, location)?;
+
+    Ok(format!("{}@{}", location, commit_id))
+}
+
+fn commit(repository: &Repository, index: &mut git2::Index, message: &str) -> Result<Oid, Error> {
+
+    let tree_id = index.write_tree()
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not write the git tree. {}", e)))?;
+    let tree = repository.find_tree(tree_id)
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not find the git tree. {}", e)))?;
+
+    let signature = repository.signature()
+        .or_else(|_| Signature::now(&current_user(), DEFAULT_COMMITTER_EMAIL))
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not create the git commit signature. {}", e)))?;
+
+    // The HEAD of an empty repository does not refer to a commit yet, so the first commit has no parent.
+    let parent = repository.head().ok().and_then(|head| head.peel_to_commit().ok());
+    let parents: Vec<&git2::Commit> = parent.iter().collect();
+
+    repository.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents)
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not commit the collection. {}", e)))
+}
+
+fn head_branch(repository: &Repository) -> Result<String, Error> {
+    repository.find_reference("HEAD")
+        .ok()
+        .and_then(|head| head.symbolic_target().map(str::to_string))
+        .ok_or_else(|| Error::for_system(Kind::GatewayError, String::from("The HEAD of the git repository does not refer to a branch.")))
+}
+
+fn push(repository: &Repository, branch: &str, location: &str) -> Result<(), Error> {
+
+    let mut remote = repository.find_remote("origin")
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not find the remote for the git repository '{}'. {}", location, e)))?;
+
+    let mut rejection: Option<String> = None;
+    let mut callbacks = credential_callbacks();
+    callbacks.push_update_reference(|reference, status| {
+        if let Some(status) = status {
+            rejection = Some(format!("The push to '{}' was rejected. {}", reference, status));
+        }
+        Ok(())
+    });
+
+    let mut push_options = PushOptions::new();
+    push_options.remote_callbacks(callbacks);
+
+    remote.push(&[format!("{}:{}", branch, branch)], Some(&mut push_options))
+        .map_err(|e| Error::for_system(Kind::GatewayError, format!("Could not push the collection to the git repository '{}'. {}", location, e)))?;
+    drop(push_options);
+
+    match rejection {
+        Some(message) => Err(Error::for_system(Kind::GatewayError, message)),
+        None => Ok(())
+    }
+}
+
+fn credential_callbacks<'a>() -> RemoteCallbacks<'a> {
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(|url, username, allowed| {
+        if allowed.contains(CredentialType::SSH_KEY) {
+            return Cred::ssh_key_from_agent(username.unwrap_or("git"));
+        }
+        if allowed.contains(CredentialType::USER_PASS_PLAINTEXT) {
+            let config = Config::open_default()?;
+            return Cred::credential_helper(&config, url, username);
+        }
+        Cred::default()
+    });
+    callbacks
+}
+
+fn temporary_clone_directory() -> PathBuf {
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_nanos()).unwrap_or_default();
+    std::env::temp_dir().join(format!("nape-publish-{}-{}", std::process::id(), nanos))
+}
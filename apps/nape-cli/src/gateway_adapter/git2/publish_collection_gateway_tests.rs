@@ -0,0 +1,67 @@
+use std::path::Path;
+use git2::Repository;
+use nape_domain::evidence_collection::usecases::publish_collection::gateway_boundary::CollectionPackage;
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::gateways::file_selection::SelectedFile;
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::git2::publish_collection_gateway::publish_to_git;
+
+fn package(collection_directory: &str, start_time: &str) -> CollectionPackage {
+    let files = ["assurance_report.yaml", "evidence/peer-review/review.json"].iter()
+        .map(|relative_path| SelectedFile { source: format!("{}/{}", collection_directory, relative_path), relative_path: relative_path.to_string() })
+        .collect();
+    CollectionPackage {
+        destination: format!("nrn_sourcecode_nape_project_-_nape-cli/{}", start_time),
+        files,
+        message: format!("Publish the assurance collection '{}'.", start_time),
+    }
+}
+
+fn blob_content(repository: &Repository, path: &str) -> String {
+    let tree = repository.head().unwrap().peel_to_tree().unwrap();
+    let entry = tree.get_path(Path::new(path)).unwrap();
+    let blob = repository.find_blob(entry.id()).unwrap();
+    String::from_utf8(blob.content().to_vec()).unwrap()
+}
+
+#[test]
+fn success() {
+
+    // Assemble
+    remove!("publish_to_git_success");
+    create_file!("publish_to_git_success/collection/assurance_report.yaml", "outcome: PASS");
+    let review = create_file!("publish_to_git_success/collection/evidence/peer-review/review.json", "{}");
+    let collection_directory = canonical_path!(review.ancestors().nth(3).unwrap().to_path_buf());
+    let repository_path = canonical_path!(create!("publish_to_git_success/repository"));
+    Repository::init_bare(&repository_path).unwrap();
+
+    // Act
+    let first = publish_to_git(&package(&collection_directory, "1719326666123"), &repository_path);
+    let second = publish_to_git(&package(&collection_directory, "1719326999999"), &repository_path);
+
+    // Assert
+    let first_location = is_ok!(first);
+    let second_location = is_ok!(second);
+    assert!(first_location.starts_with(&format!("{}@", repository_path)));
+
+    let repository = Repository::open_bare(&repository_path).unwrap();
+    let head = repository.head().unwrap().peel_to_commit().unwrap();
+    assert_eq!(second_location, format!("{}@{}", repository_path, head.id()));
+    assert_eq!(head.message(), Some("Publish the assurance collection '1719326999999'."));
+    assert_eq!(head.parent_count(), 1);
+    assert_eq!(blob_content(&repository, "nrn_sourcecode_nape_project_-_nape-cli/1719326666123/assurance_report.yaml"), "outcome: PASS");
+    assert_eq!(blob_content(&repository, "nrn_sourcecode_nape_project_-_nape-cli/1719326999999/evidence/peer-review/review.json"), "{}");
+
+    // Clean up
+    remove!("publish_to_git_success");
+}
+
+#[test]
+fn repository_does_not_exist_error() {
+
+    let result = publish_to_git(&package("collection", "1719326666123"), "publish_to_git_repository_does_not_exist_error/repository.git");
+
+    kernel_error_starts_with!(result, Kind::GatewayError, Audience::System,
+        "Could not clone the git repository 'publish_to_git_repository_does_not_exist_error/repository.git'. ");
+}
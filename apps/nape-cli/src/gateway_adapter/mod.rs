@@ -1,6 +1,10 @@
 pub mod git2;
 pub mod nape_evaluator;
+pub mod reqwest;
 pub mod serde;
 pub mod state_management;
+pub mod std_env;
 pub mod std_fs;
+pub mod std_process;
+pub mod tar;
 pub mod sha2;
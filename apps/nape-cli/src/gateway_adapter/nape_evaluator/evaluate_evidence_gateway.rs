@@ -1,22 +1,119 @@
 use std::process::Command;
 use serde::Deserialize;
+use serde_json::{Map, Value};
 use nape_domain::evidence_collection::usecases::evaluate_evidence::gateway_boundary::request::EvaluationFiles;
 use nape_domain::evidence_collection::usecases::evaluate_evidence::gateway_boundary::response::{EvaluationResults, TestResult};
 use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_procedure::evidence_input::EvidenceInput;
+use nape_kernel::values::specification::assurance_procedure::parameters::Parameters;
+use nape_kernel::values::specification::assurance_report::finding::{EvidenceLocation, Finding, LineRange};
+use nape_kernel::values::specification::assurance_report::observations::Observations;
+use nape_kernel::values::specification::description::Description;
 use nape_kernel::values::specification::file_path::FilePath;
+use nape_kernel::values::specification::outcome::Outcome;
 
+/// The prefix of the environment variables which pass the procedure parameters to the test, e.g. `${meta.environment}` is passed as `NAPE_META_ENVIRONMENT`.
+const PARAMETER_PREFIX: &str = "NAPE_";
+
+/// The versions of the evaluator protocol which the 'nape-eval' output can use.  Version 1 reports the outcome and reason, and version 2 adds findings and observations.
+const SUPPORTED_PROTOCOL_VERSIONS: &[u32] = &[1, 2];
+
+/// The output of the 'nape-eval' CLI.  Output without a `version` uses version 1 of the evaluator protocol, where the `findings` and `observations` are not defined, so they are ignored.
 #[derive(Deserialize)]
 struct NapeEvalOutput {
+    #[serde(default = "protocol_v1")]
+    version: u32,
     outcome: String,
     reason: String,
+    #[serde(default)]
+    findings: Vec<NapeEvalFinding>,
+    #[serde(default)]
+    observations: Map<String, Value>,
+}
+
+#[derive(Deserialize)]
+struct NapeEvalFinding {
+    message: String,
+    severity: String,
+    #[serde(default)]
+    evidence: Option<NapeEvalEvidenceLocation>,
+}
+
+#[derive(Deserialize)]
+struct NapeEvalEvidenceLocation {
+    path: String,
+    #[serde(default)]
+    lines: Option<NapeEvalLineRange>,
+}
+
+#[derive(Deserialize)]
+struct NapeEvalLineRange {
+    start: u32,
+    end: u32,
+}
+
+fn protocol_v1() -> u32 {
+    1
 }
 
 impl NapeEvalOutput {
     fn try_to_test_result(&self) -> Result<TestResult, Error> {
-        TestResult::try_from(&self.outcome, &self.reason)
+        self.try_to_versioned_test_result()
             .map_err(|e| Error::for_system(Kind::ProcessingFailure,
                                            format!("Failed to convert nape-eval output to a TestResult. {}", e)))
     }
+
+    fn try_to_versioned_test_result(&self) -> Result<TestResult, Error> {
+        if !SUPPORTED_PROTOCOL_VERSIONS.contains(&self.version) {
+            return Err(Error::for_system(Kind::InvalidInput,
+                                         format!("The version '{}' of the evaluator protocol is not supported. Must be one of: [{}].",
+                                                 self.version, SUPPORTED_PROTOCOL_VERSIONS.iter().map(|version| version.to_string()).collect::<Vec<String>>().join(", "))));
+        }
+
+        let test_result = TestResult::try_from(&self.outcome, &self.reason)?;
+        if self.version == 1 {
+            return Ok(test_result);
+        }
+
+        let observations = match self.try_to_observations() {
+            Ok(observations) => observations,
+            Err(e) => return Ok(TestResult::new(&Outcome::ERROR, &Description::try_from(
+                &format!("The test reported an observation which could not be recorded, so its outcome '{}' was not used. {}", self.outcome, e.message))?))
+        };
+        let mut test_result = test_result.with_observations(&observations);
+        for finding in &self.findings {
+            test_result = test_result.with_finding(&finding.try_to()?);
+        }
+        Ok(test_result)
+    }
+
+    /// Text values are recorded as they are, and any other value, such as a number, is recorded as its JSON text.  The observations are recorded in the order the test reported them, which `serde_json` keeps with its `preserve_order` feature.
+    fn try_to_observations(&self) -> Result<Observations, Error> {
+        let mut observations = Observations::default();
+        for (key, value) in &self.observations {
+            match value {
+                Value::String(text) => observations.add(key, text)?,
+                other => observations.add(key, &other.to_string())?
+            }
+        }
+        Ok(observations)
+    }
+}
+
+impl NapeEvalFinding {
+    fn try_to(&self) -> Result<Finding, Error> {
+        let finding = Finding::try_new(&self.message, &self.severity)?;
+        match &self.evidence {
+            Some(evidence) => {
+                let mut location = EvidenceLocation::try_new(&evidence.path)?;
+                if let Some(lines) = &evidence.lines {
+                    location = location.with_lines(&LineRange::try_new(lines.start, lines.end)?);
+                }
+                Ok(finding.with_location(&location))
+            }
+            None => Ok(finding)
+        }
+    }
 }
 
 pub fn nape_evidence_evaluator(files: &EvaluationFiles) -> Result<EvaluationResults, Error> {
@@ -27,7 +124,7 @@ pub fn nape_evidence_evaluator(files: &EvaluationFiles) -> Result<EvaluationResu
 
     for (evidence_file, test_files) in files.list() {
         for test_file in test_files {
-            let cli_result = invoke_cli(evidence_file, test_file)?;
+            let cli_result = invoke_cli(evidence_file, test_file, files.inputs_for(evidence_file, test_file), &files.parameters)?;
             let test_result = deserialize_cli_result_into_test_result(&cli_result)?;
             results = results.add_result(&evidence_file, &test_file, test_result);
         }
@@ -51,11 +148,34 @@ fn verify_cli_install() -> Result<(), Error> {
 
 }
 
-fn invoke_cli(evidence_file: &FilePath, test_file: &FilePath) -> Result<String, Error> {
+/// Builds the 'nape-eval' command for the evidence and test file.  Each [`EvidenceInput`] is passed to the test as an '--input name=path' argument, and each of the [`Parameters`] as an environment variable.
+pub(crate) fn nape_eval_command(evidence_file: &FilePath, test_file: &FilePath, inputs: &[EvidenceInput], parameters: &Parameters) -> Command {
+
+    let mut command = Command::new("nape-eval");
+    command.arg("--evidence").arg(evidence_file.as_str())
+        .arg("--test").arg(test_file.as_str());
+
+    for input in inputs {
+        command.arg("--input").arg(format!("{}={}", input.name.value, input.evidence.as_str()));
+    }
+
+    for (name, value) in parameters.list() {
+        command.env(parameter_variable(name), value);
+    }
+
+    command
+}
+
+fn parameter_variable(name: &str) -> String {
+    let variable: String = name.chars()
+        .map(|character| if character.is_ascii_alphanumeric() { character.to_ascii_uppercase() } else { '_' })
+        .collect();
+    format!("{}{}", PARAMETER_PREFIX, variable)
+}
+
+fn invoke_cli(evidence_file: &FilePath, test_file: &FilePath, inputs: &[EvidenceInput], parameters: &Parameters) -> Result<String, Error> {
 
-    let output = Command::new("nape-eval")
-        .arg("--evidence").arg(evidence_file.as_str())
-        .arg("--test").arg(test_file.as_str())
+    let output = nape_eval_command(evidence_file, test_file, inputs, parameters)
         .output().map_err(|e| Error::for_system(Kind::ProcessingFailure,
                                                 format!("Failed to execute 'nape-eval' cli command. {}", e)))?;
 
@@ -72,7 +192,8 @@ fn invoke_cli(evidence_file: &FilePath, test_file: &FilePath) -> Result<String,
     Ok(output_str)
 }
 
-fn deserialize_cli_result_into_test_result(cli_result: &str) -> Result<TestResult, Error> {
+/// Deserializes the output of the 'nape-eval' CLI into a [`TestResult`], using the version of the evaluator protocol the output declares.
+pub(crate) fn deserialize_cli_result_into_test_result(cli_result: &str) -> Result<TestResult, Error> {
     let eval_output: NapeEvalOutput = serde_json::from_str(&cli_result)
         .map_err(|e| Error::for_system(Kind::ProcessingFailure,
                                        format!("Failed to deserialize nape-eval output: {}", e)))?;
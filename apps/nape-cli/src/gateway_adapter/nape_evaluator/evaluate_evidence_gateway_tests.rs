@@ -1,8 +1,16 @@
 use nape_domain::evidence_collection::usecases::evaluate_evidence::gateway_boundary::{EvidenceFilePath, TestFilePath};
 use nape_domain::evidence_collection::usecases::evaluate_evidence::gateway_boundary::request::EvaluationFiles;
 use nape_kernel::values::specification::outcome::Outcome;
-use nape_testing_assertions::is_ok;
-use crate::gateway_adapter::nape_evaluator::evaluate_evidence_gateway::{nape_evidence_evaluator};
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::assurance_report::finding::LineRange;
+use nape_kernel::values::specification::severity::Severity;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::gateway_adapter::nape_evaluator::evaluate_evidence_gateway::{deserialize_cli_result_into_test_result, nape_eval_command, nape_evidence_evaluator};
+use nape_kernel::values::specification::assurance_procedure::evidence_input::EvidenceInput;
+use nape_kernel::values::specification::assurance_procedure::parameters::Parameters;
+use nape_kernel::values::specification::metadata::MetaData;
+use nape_kernel::values::specification::subject::Subject;
+use std::ffi::OsStr;
 use nape_testing_filesystem::{canonical_path, create_file};
 
 #[test]
@@ -33,6 +41,141 @@ fn success() {
 
 }
 
+#[test]
+fn nape_eval_command_passes_parameters() {
+    let mut metadata = MetaData::default();
+    metadata.add("environment", "staging").unwrap();
+    metadata.add("utc-start", "2024-05-02").unwrap();
+    let parameters = Parameters::from(&metadata, &Subject::try_new("nrn:sourcecode:example", "1234").unwrap());
+
+    let command = nape_eval_command(&EvidenceFilePath::from("evidence.json"), &TestFilePath::from("test.py"), &[], &parameters);
+
+    let envs: Vec<(&OsStr, Option<&OsStr>)> = command.get_envs().collect();
+    assert!(envs.contains(&(OsStr::new("NAPE_META_ENVIRONMENT"), Some(OsStr::new("staging")))));
+    assert!(envs.contains(&(OsStr::new("NAPE_META_UTC_START"), Some(OsStr::new("2024-05-02")))));
+    assert!(envs.contains(&(OsStr::new("NAPE_SUBJECT_NRN"), Some(OsStr::new("nrn:sourcecode:example")))));
+    assert!(envs.contains(&(OsStr::new("NAPE_SUBJECT_ID"), Some(OsStr::new("1234")))));
+
+    let args: Vec<&OsStr> = command.get_args().collect();
+    assert_eq!(args, vec!["--evidence", "evidence.json", "--test", "test.py"]);
+}
+
+#[test]
+fn nape_eval_command_passes_inputs() {
+    let inputs = vec![
+        EvidenceInput::try_new("runtime", "/home/evidence/runtime.json").unwrap(),
+        EvidenceInput::try_new("config", "/home/evidence/config.yaml").unwrap()];
+
+    let command = nape_eval_command(&EvidenceFilePath::from("evidence.json"), &TestFilePath::from("test.py"), &inputs, &Parameters::default());
+
+    let args: Vec<&OsStr> = command.get_args().collect();
+    assert_eq!(args, vec!["--evidence", "evidence.json", "--test", "test.py",
+                          "--input", "runtime=/home/evidence/runtime.json",
+                          "--input", "config=/home/evidence/config.yaml"]);
+}
+
+#[test]
+fn deserialize_v1_output_success() {
+    let result = deserialize_cli_result_into_test_result(r#"{"outcome": "pass", "reason": "The author has achieved the status of complete."}"#);
+
+    let test_result = is_ok!(result);
+    assert_eq!(test_result.outcome, Outcome::PASS);
+    assert_eq!(test_result.reason(), "The author has achieved the status of complete.");
+    assert!(test_result.findings.is_empty());
+    assert!(test_result.observations.is_empty());
+}
+
+#[test]
+fn deserialize_v1_output_ignores_findings_success() {
+    let result = deserialize_cli_result_into_test_result(r#"{"version": 1, "outcome": "pass", "reason": "Passed.", "findings": [{"message": "Unused.", "severity": "low"}]}"#);
+
+    let test_result = is_ok!(result);
+    assert!(test_result.findings.is_empty());
+}
+
+#[test]
+fn deserialize_v2_output_success() {
+    let result = deserialize_cli_result_into_test_result(r#"
+{
+  "version": 2,
+  "outcome": "fail",
+  "reason": "The deployment is not hardened.",
+  "findings": [
+    { "message": "The container runs as root.", "severity": "critical", "evidence": { "path": "manifests/deploy.yaml", "lines": { "start": 12, "end": 14 } } },
+    { "message": "The container has no readiness probe.", "severity": "low", "evidence": { "path": "manifests/deploy.yaml" } },
+    { "message": "The namespace has no network policy.", "severity": "medium" }
+  ],
+  "observations": { "replicas": 2, "image-tag": "1.4.2", "privileged": true }
+}
+    "#);
+
+    let test_result = is_ok!(result);
+    assert_eq!(test_result.outcome, Outcome::FAIL);
+    assert_eq!(test_result.findings.len(), 3);
+    assert_eq!(test_result.findings[0].message.value, "The container runs as root.");
+    assert_eq!(test_result.findings[0].severity, Severity::CRITICAL);
+    let location = test_result.findings[0].location.as_ref().unwrap();
+    assert_eq!(location.file.as_str(), "manifests/deploy.yaml");
+    assert_eq!(location.lines, Some(LineRange { start: 12, end: 14 }));
+    assert_eq!(test_result.findings[1].location.as_ref().unwrap().lines, None);
+    assert_eq!(test_result.findings[2].location, None);
+    assert_eq!(test_result.observations.get("replicas"), Some("2".to_string()));
+    assert_eq!(test_result.observations.get("image-tag"), Some("1.4.2".to_string()));
+    assert_eq!(test_result.observations.get("privileged"), Some("true".to_string()));
+    let keys: Vec<&str> = test_result.observations.data.iter().map(|(key, _)| key.value.as_str()).collect();
+    assert_eq!(keys, vec!["replicas", "image-tag", "privileged"]);
+}
+
+#[test]
+fn deserialize_v2_invalid_observation_key_error_outcome() {
+    let result = deserialize_cli_result_into_test_result(r#"{"version": 2, "outcome": "pass", "reason": "Passed.", "observations": { "replica count": 2 }}"#);
+
+    let test_result = is_ok!(result);
+    assert_eq!(test_result.outcome, Outcome::ERROR);
+    assert!(test_result.reason.value.starts_with("The test reported an observation which could not be recorded, so its outcome 'pass' was not used. The Observations could not be recorded. There is an issue with the observation key 'replica count'. "),
+            "{}", test_result.reason.value);
+}
+
+#[test]
+fn deserialize_waived_outcome_error() {
+    let result = deserialize_cli_result_into_test_result(r#"{"outcome": "waived", "reason": "The test waived itself."}"#);
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System,
+        "Failed to convert nape-eval output to a TestResult. 'waived' is not an Outcome a test can report. Must be one of: [pass, fail, inconclusive, error].");
+}
+
+#[test]
+fn deserialize_not_applicable_outcome_error() {
+    let result = deserialize_cli_result_into_test_result(r#"{"outcome": "not_applicable", "reason": "The test decided it does not apply."}"#);
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System,
+        "Failed to convert nape-eval output to a TestResult. 'not_applicable' is not an Outcome a test can report. Must be one of: [pass, fail, inconclusive, error].");
+}
+
+#[test]
+fn deserialize_skipped_outcome_error() {
+    let result = deserialize_cli_result_into_test_result(r#"{"outcome": "skipped", "reason": "The test hid its failure."}"#);
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System,
+        "Failed to convert nape-eval output to a TestResult. 'skipped' is not an Outcome a test can report. Must be one of: [pass, fail, inconclusive, error].");
+}
+
+#[test]
+fn deserialize_unsupported_version_error() {
+    let result = deserialize_cli_result_into_test_result(r#"{"version": 3, "outcome": "pass", "reason": "Passed."}"#);
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System,
+        "Failed to convert nape-eval output to a TestResult. The version '3' of the evaluator protocol is not supported. Must be one of: [1, 2].");
+}
+
+#[test]
+fn deserialize_v2_invalid_line_range_error() {
+    let result = deserialize_cli_result_into_test_result(r#"{"version": 2, "outcome": "fail", "reason": "Failed.", "findings": [{"message": "Bad.", "severity": "high", "evidence": {"path": "a.yaml", "lines": {"start": 5, "end": 2}}}]}"#);
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System,
+        "Failed to convert nape-eval output to a TestResult. ");
+}
+
 fn generate_author_evidence_file() -> String {
     r#"
 {
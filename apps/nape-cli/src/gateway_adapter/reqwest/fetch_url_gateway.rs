@@ -0,0 +1,50 @@
+use std::time::Duration;
+use reqwest::blocking::Client;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION};
+use nape_domain::evidence_collection::usecases::capture_evidence::gateway_boundary::{HttpRequest, HttpResponse};
+use nape_kernel::error::{Error, Kind};
+
+/// The maximum time to wait for an endpoint to respond.
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Implementation of the [`FetchUrlGateway`] which sends the GET request with a blocking `reqwest` client.
+pub fn fetch_url_with_reqwest(request: &HttpRequest) -> Result<HttpResponse, Error> {
+
+    let client = Client::builder()
+        .timeout(TIMEOUT)
+        .build()
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not create the HTTP client. {}", e)))?;
+
+    let response = client.get(&request.url)
+        .headers(request_headers(request)?)
+        .send()
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not send the request. {}", e)))?;
+
+    let status = response.status().as_u16();
+    let headers = response.headers().iter()
+        .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).to_string()))
+        .collect();
+    let body = response.bytes()
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the response body. {}", e)))?
+        .to_vec();
+
+    Ok(HttpResponse { status, headers, body })
+}
+
+fn request_headers(request: &HttpRequest) -> Result<HeaderMap, Error> {
+    let mut headers = HeaderMap::new();
+    for (name, value) in &request.headers {
+        let header_name = HeaderName::from_bytes(name.as_bytes())
+            .map_err(|e| Error::for_system(Kind::InvalidInput, format!("The header name '{}' is not valid. {}", name, e)))?;
+        let header_value = HeaderValue::from_str(value)
+            .map_err(|e| Error::for_system(Kind::InvalidInput, format!("The value of the header '{}' is not valid. {}", name, e)))?;
+        headers.append(header_name, header_value);
+    }
+    if let Some(token) = &request.bearer_token {
+        let mut authorization = HeaderValue::from_str(&format!("Bearer {}", token))
+            .map_err(|_| Error::for_system(Kind::InvalidInput, String::from("The bearer token is not a valid header value.")))?;
+        authorization.set_sensitive(true);
+        headers.insert(AUTHORIZATION, authorization);
+    }
+    Ok(headers)
+}
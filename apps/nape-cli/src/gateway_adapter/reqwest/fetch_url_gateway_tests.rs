@@ -0,0 +1,79 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::thread::JoinHandle;
+use nape_domain::evidence_collection::usecases::capture_evidence::gateway_boundary::HttpRequest;
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use crate::gateway_adapter::reqwest::fetch_url_gateway::fetch_url_with_reqwest;
+
+/// Starts a stand-in HTTP server on a random local port which answers a single request with the given response, and returns the URL of the server and a handle which yields the head of the request it received.
+fn stand_in_server(response: &'static str) -> (String, JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind the stand-in server.");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().expect("The stand-in server did not receive a request.");
+        let request_head: Vec<String> = BufReader::new(stream.try_clone().unwrap()).lines()
+            .map(|line| line.unwrap())
+            .take_while(|line| !line.is_empty())
+            .collect();
+        stream.write_all(response.as_bytes()).unwrap();
+        request_head
+    });
+    (url, handle)
+}
+
+#[test]
+fn success() {
+
+    let (url, server) = stand_in_server("HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nX-Service: billing\r\nContent-Length: 16\r\nConnection: close\r\n\r\n{\"status\": \"up\"}");
+
+    let request = HttpRequest {
+        url: format!("{}/health", url),
+        headers: vec![("X-Request-Source".to_string(), "nape".to_string())],
+        bearer_token: Some("the-token".to_string()),
+    };
+
+    let result = fetch_url_with_reqwest(&request);
+
+    let response = is_ok!(result);
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, b"{\"status\": \"up\"}".to_vec());
+    assert!(response.headers.contains(&("content-type".to_string(), "application/json".to_string())));
+    assert!(response.headers.contains(&("x-service".to_string(), "billing".to_string())));
+
+    let request_head = server.join().unwrap();
+    assert_eq!(request_head[0], "GET /health HTTP/1.1");
+    assert!(request_head.iter().any(|line| line.eq_ignore_ascii_case("x-request-source: nape")));
+    assert!(request_head.iter().any(|line| line.eq_ignore_ascii_case("authorization: Bearer the-token")));
+}
+
+#[test]
+fn unsuccessful_status_success() {
+
+    let (url, server) = stand_in_server("HTTP/1.1 503 Service Unavailable\r\nContent-Length: 4\r\nConnection: close\r\n\r\ndown");
+
+    let request = HttpRequest { url, headers: Vec::new(), bearer_token: None };
+
+    let result = fetch_url_with_reqwest(&request);
+
+    let response = is_ok!(result);
+    assert_eq!(response.status, 503);
+    assert_eq!(response.body, b"down".to_vec());
+
+    let request_head = server.join().unwrap();
+    assert!(!request_head.iter().any(|line| line.to_ascii_lowercase().starts_with("authorization")));
+}
+
+#[test]
+fn connection_refused_error() {
+
+    // Bind, then drop, a listener to find a local port nothing is listening on.
+    let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+
+    let request = HttpRequest { url: format!("http://127.0.0.1:{}/health", port), headers: Vec::new(), bearer_token: None };
+
+    let result = fetch_url_with_reqwest(&request);
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not send the request. ");
+}
@@ -0,0 +1,4 @@
+pub mod fetch_url_gateway;
+pub mod publish_s3_gateway;
+#[cfg(test)] mod fetch_url_gateway_tests;
+#[cfg(test)] mod publish_s3_gateway_tests;
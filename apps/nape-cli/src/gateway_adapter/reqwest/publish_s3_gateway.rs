@@ -0,0 +1,145 @@
+use std::env;
+use std::fs;
+use std::time::Duration;
+use hmac::{Hmac, Mac};
+use reqwest::blocking::Client;
+use sha2::{Digest, Sha256};
+use nape_domain::evidence_collection::usecases::publish_collection::gateway_boundary::CollectionPackage;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::time::start_time::StartTime;
+
+/// The maximum time to wait for the store to accept a file.
+const TIMEOUT: Duration = Duration::from_secs(120);
+
+/// The region used to sign requests when none is configured, which S3 compatible stores such as MinIO accept by default.
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// The [`S3Credentials`] used to sign the requests to an S3 compatible store.
+#[derive(Clone, Debug)]
+pub struct S3Credentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: Option<String>,
+    pub region: String,
+}
+
+impl S3Credentials {
+
+    /// Reads the credentials from the standard 'AWS_ACCESS_KEY_ID', 'AWS_SECRET_ACCESS_KEY', and optional 'AWS_SESSION_TOKEN' environment variables.  The region is read from 'AWS_REGION', or 'AWS_DEFAULT_REGION', and is 'us-east-1' when neither is set.
+    pub fn from_environment() -> Result<S3Credentials, Error> {
+        let required = |name: &str| env::var(name)
+            .map_err(|_| Error::for_user(Kind::InvalidInput,
+                                         format!("The environment variable '{}' is required to publish to an S3 compatible store.", name)));
+        Ok(S3Credentials {
+            access_key_id: required("AWS_ACCESS_KEY_ID")?,
+            secret_access_key: required("AWS_SECRET_ACCESS_KEY")?,
+            session_token: env::var("AWS_SESSION_TOKEN").ok(),
+            region: env::var("AWS_REGION").or_else(|_| env::var("AWS_DEFAULT_REGION")).unwrap_or(DEFAULT_REGION.to_string()),
+        })
+    }
+}
+
+/// Implementation of the [`PublishCollectionGateway`] which uploads the collection to a bucket in an S3 compatible store, such as AWS S3 or MinIO, using the [`S3Credentials`] from the environment.
+///
+/// The `location` is the path-style URL of the bucket, such as 'http://localhost:9000/evidence'.  Each file is uploaded with a signed PUT request to the key made of the destination of the package followed by the relative path of the file.
+pub fn publish_to_s3(package: &CollectionPackage, location: &str) -> Result<String, Error> {
+    let credentials = S3Credentials::from_environment()?;
+    upload_collection(package, location, &credentials)
+}
+
+/// Uploads each file of the collection to the bucket at the `location`, signing each request with the `credentials`, and returns the URL of the collection in the bucket.
+pub fn upload_collection(package: &CollectionPackage, location: &str, credentials: &S3Credentials) -> Result<String, Error> {
+
+    let bucket_url = location.trim_end_matches('/');
+    let (scheme, authority_and_path) = bucket_url.split_once("://")
+        .filter(|(scheme, _)| *scheme == "http" || *scheme == "https")
+        .ok_or_else(|| Error::for_user(Kind::InvalidInput,
+                                       format!("The bucket URL '{}' is not valid. A bucket URL must start with 'http://' or 'https://', such as 'https://s3.us-east-1.amazonaws.com/evidence'.", location)))?;
+    let (host, bucket_path) = match authority_and_path.split_once('/') {
+        Some((host, bucket_path)) => (host, format!("/{}", bucket_path)),
+        None => (authority_and_path, String::new())
+    };
+
+    let client = Client::builder()
+        .timeout(TIMEOUT)
+        .build()
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not create the HTTP client. {}", e)))?;
+
+    for file in &package.files {
+        let key = format!("{}/{}", package.destination, file.relative_path);
+        let path = format!("{}/{}", bucket_path, encode_key(&key));
+        let body = fs::read(&file.source)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the file '{}': {}", file.source, e)))?;
+
+        let headers = signed_headers(credentials, host, &path, &body, &StartTime::now());
+        let mut request = client.put(format!("{}://{}{}", scheme, host, path)).body(body);
+        for (name, value) in headers {
+            request = request.header(name, value);
+        }
+
+        let response = request.send()
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not upload the file '{}'. {}", key, e)))?;
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let reason = response.text().unwrap_or_default();
+            return Err(Error::for_system(Kind::GatewayError,
+                                         format!("The store rejected the file '{}' with the status {}. {}", key, status, reason)));
+        }
+    }
+
+    Ok(format!("{}/{}", bucket_url, package.destination))
+}
+
+/// Creates the headers, including the AWS Signature Version 4 'Authorization' header, for a PUT request of the `body` to the `path` on the `host`.
+fn signed_headers(credentials: &S3Credentials, host: &str, path: &str, body: &[u8], time: &StartTime) -> Vec<(String, String)> {
+
+    let amz_date = time.to_utc_string().replace(['-', ':'], "");
+    let date = &amz_date[..8];
+    let payload_hash = hex::encode(Sha256::digest(body));
+
+    let mut headers = vec![
+        ("host".to_string(), host.to_string()),
+        ("x-amz-content-sha256".to_string(), payload_hash.clone()),
+        ("x-amz-date".to_string(), amz_date.clone()),
+    ];
+    if let Some(token) = &credentials.session_token {
+        headers.push(("x-amz-security-token".to_string(), token.clone()));
+    }
+
+    let canonical_headers: String = headers.iter().map(|(name, value)| format!("{}:{}\n", name, value.trim())).collect();
+    let signed_header_names = headers.iter().map(|(name, _)| name.as_str()).collect::<Vec<&str>>().join(";");
+    let canonical_request = format!("PUT\n{}\n\n{}\n{}\n{}", path, canonical_headers, signed_header_names, payload_hash);
+
+    let scope = format!("{}/{}/s3/aws4_request", date, credentials.region);
+    let string_to_sign = format!("AWS4-HMAC-SHA256\n{}\n{}\n{}", amz_date, scope, hex::encode(Sha256::digest(canonical_request.as_bytes())));
+    let signature = hex::encode(hmac_sha256(&signing_key(&credentials.secret_access_key, date, &credentials.region, "s3"), string_to_sign.as_bytes()));
+
+    headers.retain(|(name, _)| name != "host");
+    headers.push(("authorization".to_string(),
+                  format!("AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}", credentials.access_key_id, scope, signed_header_names, signature)));
+    headers
+}
+
+/// Derives the AWS Signature Version 4 signing key for the `date`, in the format 'YYYYMMDD', the `region`, and the `service`.
+pub fn signing_key(secret_access_key: &str, date: &str, region: &str, service: &str) -> Vec<u8> {
+    let date_key = hmac_sha256(format!("AWS4{}", secret_access_key).as_bytes(), date.as_bytes());
+    let region_key = hmac_sha256(&date_key, region.as_bytes());
+    let service_key = hmac_sha256(&region_key, service.as_bytes());
+    hmac_sha256(&service_key, b"aws4_request")
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC accepts a key of any length.");
+    mac.update(data);
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Percent encodes each segment of an object key, keeping the '/' which separate the segments.
+fn encode_key(key: &str) -> String {
+    key.bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte)
+        })
+        .collect()
+}
@@ -0,0 +1,132 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+use std::thread::JoinHandle;
+use nape_domain::evidence_collection::usecases::publish_collection::gateway_boundary::CollectionPackage;
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::gateways::file_selection::SelectedFile;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create_file, remove};
+use crate::gateway_adapter::reqwest::publish_s3_gateway::{signing_key, upload_collection, S3Credentials};
+
+/// A request received by the stand-in store, as its head lines and body.
+struct ReceivedRequest {
+    head: Vec<String>,
+    body: String,
+}
+
+/// Starts a stand-in S3 compatible store on a random local port which answers the given number of requests with the given status line, and returns the URL of the store and a handle which yields the requests it received.
+fn stand_in_store(requests: usize, status_line: &'static str) -> (String, JoinHandle<Vec<ReceivedRequest>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Could not bind the stand-in store.");
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        (0..requests).map(|_| {
+            let (mut stream, _) = listener.accept().expect("The stand-in store did not receive a request.");
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let head: Vec<String> = reader.by_ref().lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect();
+            let content_length = head.iter()
+                .find_map(|line| line.to_ascii_lowercase().strip_prefix("content-length: ").map(|length| length.parse::<usize>().unwrap()))
+                .unwrap_or(0);
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            stream.write_all(format!("{}\r\nContent-Length: 6\r\nConnection: close\r\n\r\ndenied", status_line).as_bytes()).unwrap();
+            ReceivedRequest { head, body: String::from_utf8(body).unwrap() }
+        }).collect()
+    });
+    (url, handle)
+}
+
+fn credentials() -> S3Credentials {
+    S3Credentials {
+        access_key_id: "minioadmin".to_string(),
+        secret_access_key: "minio-secret".to_string(),
+        session_token: None,
+        region: "us-east-1".to_string(),
+    }
+}
+
+fn package(collection_directory: &str) -> CollectionPackage {
+    let files = ["assurance_report.yaml", "evidence/peer review/review.json"].iter()
+        .map(|relative_path| SelectedFile { source: format!("{}/{}", collection_directory, relative_path), relative_path: relative_path.to_string() })
+        .collect();
+    CollectionPackage {
+        destination: "nrn_sourcecode_nape_project_-_nape-cli/1719326666123".to_string(),
+        files,
+        message: "Publish the collection.".to_string(),
+    }
+}
+
+#[test]
+fn success() {
+
+    // Assemble
+    remove!("publish_to_s3_success");
+    create_file!("publish_to_s3_success/assurance_report.yaml", "outcome: PASS");
+    let review = create_file!("publish_to_s3_success/evidence/peer review/review.json", "{}");
+    let collection_directory = canonical_path!(review.ancestors().nth(3).unwrap().to_path_buf());
+    let (url, store) = stand_in_store(2, "HTTP/1.1 200 OK");
+
+    // Act
+    let result = upload_collection(&package(&collection_directory), &format!("{}/evidence/", url), &credentials());
+
+    // Assert
+    let location = is_ok!(result);
+    assert_eq!(location, format!("{}/evidence/nrn_sourcecode_nape_project_-_nape-cli/1719326666123", url));
+
+    let requests = store.join().unwrap();
+    assert_eq!(requests[0].head[0], "PUT /evidence/nrn_sourcecode_nape_project_-_nape-cli/1719326666123/assurance_report.yaml HTTP/1.1");
+    assert_eq!(requests[0].body, "outcome: PASS");
+    assert_eq!(requests[1].head[0], "PUT /evidence/nrn_sourcecode_nape_project_-_nape-cli/1719326666123/evidence/peer%20review/review.json HTTP/1.1");
+    assert_eq!(requests[1].body, "{}");
+
+    let authorization = requests[0].head.iter()
+        .find(|line| line.to_ascii_lowercase().starts_with("authorization: "))
+        .expect("The request was not signed.");
+    assert!(authorization.contains("AWS4-HMAC-SHA256 Credential=minioadmin/"));
+    assert!(authorization.contains("/us-east-1/s3/aws4_request, SignedHeaders=host;x-amz-content-sha256;x-amz-date, Signature="));
+    assert!(requests[0].head.iter().any(|line| line.to_ascii_lowercase().starts_with("x-amz-content-sha256: ")));
+
+    // Clean up
+    remove!("publish_to_s3_success");
+}
+
+#[test]
+fn signing_key_success() {
+
+    // The signing key derivation example from the AWS Signature Version 4 documentation.
+    let key = signing_key("wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY", "20120215", "us-east-1", "iam");
+
+    assert_eq!(hex::encode(key), "f4780e2d9f65fa895f9c67b32ce1baf0b0d8a43505a000a1a9e090d414db404d");
+}
+
+#[test]
+fn rejected_error() {
+
+    // Assemble
+    remove!("publish_to_s3_rejected_error");
+    let report = create_file!("publish_to_s3_rejected_error/assurance_report.yaml", "outcome: PASS");
+    let collection_directory = canonical_path!(report.parent().unwrap().to_path_buf());
+    let (url, store) = stand_in_store(1, "HTTP/1.1 403 Forbidden");
+
+    // Act
+    let result = upload_collection(&package(&collection_directory), &format!("{}/evidence", url), &credentials());
+
+    // Assert
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "The store rejected the file 'nrn_sourcecode_nape_project_-_nape-cli/1719326666123/assurance_report.yaml' with the status 403. denied");
+    store.join().unwrap();
+
+    // Clean up
+    remove!("publish_to_s3_rejected_error");
+}
+
+#[test]
+fn invalid_bucket_url_error() {
+
+    let result = upload_collection(&package("collection"), "s3://evidence", &credentials());
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The bucket URL 's3://evidence' is not valid. ");
+}
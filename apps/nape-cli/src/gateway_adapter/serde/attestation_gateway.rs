@@ -0,0 +1,94 @@
+use std::fs;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use nape_kernel::algorithms::signature_algorithm::Signature;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_report::attestation::Attestation;
+use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use nape_kernel::values::time::start_time::StartTime;
+
+/// The [`AttestationFile`] struct is a representation of an [`Attestation`] serialized as the YAML statement of an attestation action.
+///
+/// * `attested_at` - The time the action was attested in milliseconds since the epoch, which is how the custody ledger also records time.
+/// * `attested_at_utc` - The time the action was attested as a UTC timestamp, so the statement can be read by a person.
+///
+#[derive(Serialize, Deserialize)]
+pub struct AttestationFile {
+    pub action: String,
+    pub outcome: String,
+    pub rationale: String,
+    pub attested_by: String,
+    pub attested_at: u128,
+    pub attested_at_utc: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub supporting_files: Vec<AttestationSupportingFile>,
+}
+
+/// The [`AttestationSupportingFile`] struct is a representation of a signed supporting file of an [`Attestation`].
+#[derive(Serialize, Deserialize)]
+pub struct AttestationSupportingFile {
+    pub file: String,
+    pub signature: String,
+}
+
+impl From<&Attestation> for AttestationFile {
+    fn from(attestation: &Attestation) -> AttestationFile {
+        AttestationFile {
+            action: attestation.action.value.clone(),
+            outcome: attestation.outcome.to_string(),
+            rationale: attestation.rationale.value.clone(),
+            attested_by: attestation.attested_by.value.clone(),
+            attested_at: attestation.attested_at.time,
+            attested_at_utc: attestation.attested_at.to_utc_string(),
+            supporting_files: attestation.supporting_files.iter()
+                .map(|file| AttestationSupportingFile { file: file.file().to_string(), signature: file.signature().structure_signature() })
+                .collect(),
+        }
+    }
+}
+
+impl AttestationFile {
+    pub fn try_to(&self) -> Result<Attestation, Error> {
+        let mut attestation = Attestation::try_new(&self.action, &self.outcome, &self.rationale, &self.attested_by, &StartTime::from(self.attested_at))?;
+        for supporting_file in &self.supporting_files {
+            let signature = Signature::try_from(&supporting_file.signature)?;
+            attestation = attestation.with_supporting_file(&SignedFile::new(&supporting_file.file, &signature)?);
+        }
+        Ok(attestation)
+    }
+}
+
+/// Implementation of the [`PersistAttestationGateway`] which saves the [`Attestation`] as a YAML statement at the file path, creating its directory if it does not exist.
+pub fn save_attestation_as_yaml(attestation: &Attestation, file_path: &str) -> Result<String, Error> {
+
+    let yaml = serde_yaml::to_string(&AttestationFile::from(attestation))
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not serialize the attestation: {}", e)))?;
+
+    let path = Path::new(file_path);
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not create the directory '{}' for the attestation: {}", directory.display(), e)))?;
+    }
+
+    fs::write(path, yaml)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not write the attestation '{}': {}", file_path, e)))?;
+
+    Ok(file_path.to_string())
+}
+
+/// Implementation of the [`RetrieveAttestationGateway`] which reads the [`Attestation`] from the YAML statement at the file path.  When the file does not exist, the action has not been attested.
+pub fn attestation_from_yaml_file(file_path: &str) -> Result<Option<Attestation>, Error> {
+
+    let path = Path::new(file_path);
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let content = fs::read_to_string(path)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the attestation '{}': {}", file_path, e)))?;
+
+    let attestation_file: AttestationFile = serde_yaml::from_str(&content)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not deserialize the attestation '{}': {}", file_path, e)))?;
+
+    attestation_file.try_to().map(Some)
+}
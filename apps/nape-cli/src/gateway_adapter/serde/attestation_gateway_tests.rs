@@ -0,0 +1,75 @@
+use std::fs;
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureType};
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::assurance_report::attestation::Attestation;
+use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use nape_kernel::values::time::start_time::StartTime;
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::serde::attestation_gateway::{attestation_from_yaml_file, save_attestation_as_yaml};
+
+#[test]
+fn save_and_retrieve_success() {
+
+    // Assemble
+    remove!("attestation_gateway_save_and_retrieve_success");
+    let directory = create!("attestation_gateway_save_and_retrieve_success");
+    let file_path = format!("{}/evidence/access-review/attestation.yaml", canonical_path!(&directory));
+    let attestation = generate_attestation();
+
+    // Act
+    let saved = save_attestation_as_yaml(&attestation, &file_path);
+    let result = attestation_from_yaml_file(&file_path);
+
+    // Assert
+    assert_eq!(is_ok!(saved), file_path);
+    assert_eq!(is_ok!(result), Some(attestation));
+    let content = fs::read_to_string(&file_path).unwrap();
+    assert_eq!(content, r#"action: access-review
+outcome: pass
+rationale: All accounts were reviewed.
+attested_by: jane
+attested_at: 1719326666000
+attested_at_utc: 2024-06-25T14:44:26Z
+supporting_files:
+- file: evidence/access-review/minutes.pdf
+  signature: SHA256[theminutessig]
+"#);
+
+    // Clean up
+    remove!("attestation_gateway_save_and_retrieve_success");
+}
+
+#[test]
+fn retrieve_not_attested_success() {
+
+    remove!("attestation_gateway_retrieve_not_attested_success");
+    let directory = create!("attestation_gateway_retrieve_not_attested_success");
+    let file_path = format!("{}/attestation.yaml", canonical_path!(&directory));
+
+    let result = attestation_from_yaml_file(&file_path);
+
+    assert_eq!(is_ok!(result), None);
+
+    remove!("attestation_gateway_retrieve_not_attested_success");
+}
+
+#[test]
+fn retrieve_invalid_attestation_error() {
+
+    remove!("attestation_gateway_retrieve_invalid_attestation_error");
+    let file_path_buff = create_file!("attestation_gateway_retrieve_invalid_attestation_error/attestation.yaml", "action: access-review");
+    let file_path = canonical_path!(&file_path_buff);
+
+    let result = attestation_from_yaml_file(&file_path);
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not deserialize the attestation '");
+
+    remove!("attestation_gateway_retrieve_invalid_attestation_error");
+}
+
+fn generate_attestation() -> Attestation {
+    let supporting_file = SignedFile::new("evidence/access-review/minutes.pdf", &Signature::try_new(SignatureType::SHA256, "theminutessig").unwrap()).unwrap();
+    Attestation::try_new("access-review", "pass", "All accounts were reviewed.", "jane", &StartTime::from(1719326666000)).unwrap()
+        .with_supporting_file(&supporting_file)
+}
@@ -0,0 +1,92 @@
+use std::fs;
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use nape_domain::evidence_collection::usecases::collect_evidence::gateway_boundary::CustodyEntry;
+use nape_kernel::algorithms::signature_algorithm::Signature;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::name::Name;
+use nape_kernel::values::time::start_time::StartTime;
+
+/// The name of the custody ledger file in the collection's home directory.
+pub const CUSTODY_LEDGER_FILE: &str = "custody-ledger.jsonl";
+
+/// The [`CustodyLedgerLine`] struct is a representation of a [`CustodyEntry`] serialized as a single line of JSON in the custody ledger.
+///
+/// * `collected` - The time the evidence was collected in milliseconds since the epoch, which is how the collection start time is also recorded.
+/// * `collected_utc` - The time the evidence was collected as a UTC timestamp, so the ledger can be read by a person.
+///
+#[derive(Serialize, Deserialize)]
+pub struct CustodyLedgerLine {
+    pub action: String,
+    pub source: String,
+    pub target: String,
+    pub signature: String,
+    pub collected: u128,
+    pub collected_utc: String,
+    pub user: String,
+}
+
+impl From<&CustodyEntry> for CustodyLedgerLine {
+    fn from(entry: &CustodyEntry) -> CustodyLedgerLine {
+        CustodyLedgerLine {
+            action: entry.action.value.clone(),
+            source: entry.source.clone(),
+            target: entry.target.clone(),
+            signature: entry.signature.structure_signature(),
+            collected: entry.collected.time,
+            collected_utc: entry.collected.to_utc_string(),
+            user: entry.user.clone(),
+        }
+    }
+}
+
+impl CustodyLedgerLine {
+    pub fn try_to(&self) -> Result<CustodyEntry, Error> {
+        Ok(CustodyEntry {
+            action: Name::try_from(self.action.as_str())?,
+            source: self.source.clone(),
+            target: self.target.clone(),
+            signature: Signature::try_from(&self.signature)?,
+            collected: StartTime::from(self.collected),
+            user: self.user.clone(),
+        })
+    }
+}
+
+/// Implementation of the [`AppendCustodyEntryGateway`] which appends the [`CustodyEntry`] as a line of JSON to the 'custody-ledger.jsonl' file in the home directory.  The file is only ever opened for appending, so existing entries are never rewritten.
+pub fn append_custody_entry_as_json_line(entry: &CustodyEntry, home_directory: &str) -> Result<(), Error> {
+
+    let mut line = serde_json::to_string(&CustodyLedgerLine::from(entry))
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not serialize the custody ledger entry: {}", e)))?;
+    line.push('\n');
+
+    let file_path = Path::new(home_directory).join(CUSTODY_LEDGER_FILE);
+
+    OpenOptions::new().create(true).append(true).open(&file_path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not append to the custody ledger '{}': {}", file_path.display(), e)))
+}
+
+/// Implementation of the [`RetrieveCustodyLedgerGateway`] which reads the entries from the 'custody-ledger.jsonl' file in the home directory.  When the file does not exist, no evidence has been collected and the list is empty.
+pub fn custody_ledger_from_json_lines(home_directory: &str) -> Result<Vec<CustodyEntry>, Error> {
+
+    let file_path = Path::new(home_directory).join(CUSTODY_LEDGER_FILE);
+    if !file_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = fs::read_to_string(&file_path)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the custody ledger '{}': {}", file_path.display(), e)))?;
+
+    content.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| serde_json::from_str::<CustodyLedgerLine>(line)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, e.to_string()))
+            .and_then(|ledger_line| ledger_line.try_to())
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure,
+                                           format!("Could not read line {} of the custody ledger '{}': {}", index + 1, file_path.display(), e.message))))
+        .collect()
+}
@@ -0,0 +1,71 @@
+use std::fs;
+use nape_domain::evidence_collection::usecases::collect_evidence::gateway_boundary::CustodyEntry;
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureType};
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::name::Name;
+use nape_kernel::values::time::start_time::StartTime;
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::serde::custody_ledger_gateway::{append_custody_entry_as_json_line, custody_ledger_from_json_lines};
+
+#[test]
+fn append_and_retrieve_success() {
+
+    // Assemble
+    remove!("custody_ledger_append_and_retrieve_success");
+    let directory = create!("custody_ledger_append_and_retrieve_success");
+    let home_directory = canonical_path!(&directory);
+
+    // Act
+    let first = append_custody_entry_as_json_line(&custody_entry("first-signature"), &home_directory);
+    let second = append_custody_entry_as_json_line(&custody_entry("second-signature"), &home_directory);
+    let result = custody_ledger_from_json_lines(&home_directory);
+
+    // Assert
+    is_ok!(first);
+    is_ok!(second);
+    let content = fs::read_to_string(format!("{}/custody-ledger.jsonl", home_directory)).unwrap();
+    assert_eq!(content.lines().next().unwrap(), r#"{"action":"peer-review","source":"./reports/review.json","target":"evidence/peer-review/review.json","signature":"SHA256[first-signature]","collected":1719326666000,"collected_utc":"2024-06-25T14:44:26Z","user":"jane"}"#);
+    let ledger = is_ok!(result);
+    assert_eq!(ledger, vec![custody_entry("first-signature"), custody_entry("second-signature")]);
+
+    // Clean up
+    remove!("custody_ledger_append_and_retrieve_success");
+}
+
+#[test]
+fn retrieve_no_ledger_success() {
+
+    remove!("custody_ledger_retrieve_no_ledger_success");
+    let directory = create!("custody_ledger_retrieve_no_ledger_success");
+    let home_directory = canonical_path!(&directory);
+
+    let result = custody_ledger_from_json_lines(&home_directory);
+
+    assert!(is_ok!(result).is_empty());
+    remove!("custody_ledger_retrieve_no_ledger_success");
+}
+
+#[test]
+fn retrieve_invalid_line_error() {
+
+    remove!("custody_ledger_retrieve_invalid_line_error");
+    let file_path_buff = create_file!("custody_ledger_retrieve_invalid_line_error/custody-ledger.jsonl", "not json\n");
+    let home_directory = canonical_path!(file_path_buff.parent().unwrap().to_path_buf());
+
+    let result = custody_ledger_from_json_lines(&home_directory);
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not read line 1 of the custody ledger ");
+    remove!("custody_ledger_retrieve_invalid_line_error");
+}
+
+fn custody_entry(signature: &str) -> CustodyEntry {
+    CustodyEntry {
+        action: Name::try_from("peer-review").unwrap(),
+        source: "./reports/review.json".to_string(),
+        target: "evidence/peer-review/review.json".to_string(),
+        signature: Signature::try_new(SignatureType::SHA256, signature).unwrap(),
+        collected: StartTime::from(1719326666000),
+        user: "jane".to_string(),
+    }
+}
@@ -1,3 +1,19 @@
+pub mod attestation_gateway;
+#[cfg(test)] mod attestation_gateway_tests;
+pub mod custody_ledger_gateway;
+#[cfg(test)] mod custody_ledger_gateway_tests;
+pub mod persist_procedure_gateway;
+#[cfg(test)] mod persist_procedure_gateway_tests;
 pub mod persist_report_gateway;
 #[cfg(test)] mod persist_report_gateway_tests;
-pub mod specification_serializer;
\ No newline at end of file
+pub mod persist_sidecar_gateway;
+#[cfg(test)] mod persist_sidecar_gateway_tests;
+pub mod report_signatures_gateway;
+#[cfg(test)] mod report_signatures_gateway_tests;
+pub mod specification_serializer;
+pub mod tailoring_file;
+#[cfg(test)] mod tailoring_file_tests;
+pub mod tailoring_gateway;
+#[cfg(test)] mod tailoring_gateway_tests;
+pub mod waiver_file;
+#[cfg(test)] mod waiver_file_tests;
@@ -0,0 +1,13 @@
+use std::fs;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::factory;
+
+/// Implementation of the [`PersistProcedureGateway`] which writes an [`AssuranceProcedure`] as a YAML file, replacing the content of the file if it already exists.  The file is written with the schema of the procedure's apiVersion.
+pub fn save_procedure_as_yaml(procedure: &AssuranceProcedure, file_path: &str) -> Result<(), Error> {
+    let yaml = factory::to_yaml(procedure)?;
+
+    fs::write(file_path, yaml)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure,
+                                       format!("Could not write the assurance procedure to '{}'. {}", file_path, e)))
+}
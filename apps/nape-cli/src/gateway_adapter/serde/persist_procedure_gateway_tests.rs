@@ -0,0 +1,58 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+use nape_kernel::values::specification::assurance_procedure::include::Source;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, remove};
+use crate::gateway_adapter::serde::persist_procedure_gateway::save_procedure_as_yaml;
+use crate::gateway_adapter::std_fs::retrieve_assurance_procedure::from_yaml_on_filesystem;
+
+#[test]
+fn success() {
+
+    // Assemble
+    remove!("save_procedure_as_yaml_success");
+    let directory = canonical_path!(create!("save_procedure_as_yaml_success"));
+    let file_path = format!("{}/assurance_procedure.yaml", directory);
+    let procedure = generate_procedure();
+
+    // Act
+    let result = save_procedure_as_yaml(&procedure, &file_path);
+
+    // Assert
+    is_ok!(result);
+    let read_back = is_ok!(from_yaml_on_filesystem(&file_path));
+    assert_eq!(read_back.api_version, procedure.api_version);
+    assert_eq!(read_back.activities, procedure.activities);
+
+    // Clean up
+    remove!("save_procedure_as_yaml_success");
+}
+
+#[test]
+fn missing_directory_error() {
+    let result = save_procedure_as_yaml(&generate_procedure(), "save_procedure_as_yaml_missing/assurance_procedure.yaml");
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System,
+        "Could not write the assurance procedure to 'save_procedure_as_yaml_missing/assurance_procedure.yaml'. ");
+}
+
+fn generate_procedure() -> AssuranceProcedure {
+    let source = Source::try_new("https://github.com/example/procedures.git", "shared", Some("v1.0.0")).unwrap();
+    let action = Action::builder().name("protected-main")
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path("./activity/branch/protected_main.py")
+        .evidence_file_path("./evidence/branch/protected_main.json")
+        .try_build().unwrap();
+    let activity = Activity::new("branch-protection", "Short Desc", "Long Desc").unwrap()
+        .add(action)
+        .included_from(&source);
+
+    AssuranceProcedure::builder()
+        .api_version("1.2.0")
+        .procedure_info("nrn:procedure:example", "A Short Desc.", "This is an example procedure")
+        .add_activity(&activity)
+        .try_build().unwrap()
+}
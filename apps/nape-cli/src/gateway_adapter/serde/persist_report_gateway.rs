@@ -8,7 +8,7 @@ use nape_kernel::values::specification::traits::AssuranceReport;
 use crate::gateway_adapter::serde::specification_serializer::assurance_report;
 
 
-const FILE_NAME: &str = "assurance_report.yaml";
+pub const FILE_NAME: &str = "assurance_report.yaml";
 
 /// Implementation of the [`PersistReportGateway`] trait that saves an assurance report as a YAML file.
 pub fn save_report_as_yaml(report: &dyn AssuranceReport, report_directory: &str) -> Result<FilePath, Error> {
@@ -91,6 +91,16 @@ summary:
   pass: 3
   fail: 2
   inconclusive: 2
+  waived: 0
+  not_applicable: 0
+  skipped: 0
+  failures_by_severity:
+    critical: 0
+    high: 0
+    medium: 0
+    low: 0
+    unrated: 2
+  outcome_policy: any
   outcome: inconclusive
 activity:
 - name: activity-1
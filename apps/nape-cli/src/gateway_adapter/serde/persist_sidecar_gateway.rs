@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use nape_domain::evidence_collection::usecases::capture_evidence::gateway_boundary::{EvidenceSidecar, EvidenceSource};
+use nape_domain::evidence_collection::usecases::collect_evidence::redaction::Redaction;
+use nape_kernel::error::{Error, Kind};
+
+/// The suffix added to the evidence file name to create the name of its sidecar file.
+pub const SIDECAR_SUFFIX: &str = ".sidecar.yaml";
+
+/// The [`SidecarFile`] struct is a representation of an [`EvidenceSidecar`] serialized as a YAML file next to the evidence file it describes.
+#[derive(Serialize, Deserialize)]
+pub struct SidecarFile {
+    pub action: String,
+    pub evidence_file: String,
+    pub captured: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<SidecarCommand>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<SidecarUrl>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<SidecarSourceFile>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redactions: Vec<SidecarRedaction>,
+}
+
+/// The [`SidecarCommand`] struct is a representation of an [`EvidenceSource::Command`].
+#[derive(Serialize, Deserialize)]
+pub struct SidecarCommand {
+    pub command_line: String,
+    pub exit_code: Option<i32>,
+}
+
+/// The [`SidecarUrl`] struct is a representation of an [`EvidenceSource::Url`].  Response headers with the same name are combined into a single comma separated value.
+#[derive(Serialize, Deserialize)]
+pub struct SidecarUrl {
+    pub url: String,
+    pub status: u16,
+    pub headers: BTreeMap<String, String>,
+}
+
+/// The [`SidecarSourceFile`] struct is a representation of an [`EvidenceSource::File`].
+#[derive(Serialize, Deserialize)]
+pub struct SidecarSourceFile {
+    pub path: String,
+}
+
+/// The [`SidecarRedaction`] struct is a representation of a [`Redaction`].
+#[derive(Serialize, Deserialize)]
+pub struct SidecarRedaction {
+    pub file: String,
+    pub rule: String,
+    pub count: usize,
+}
+
+impl From<&Redaction> for SidecarRedaction {
+    fn from(redaction: &Redaction) -> SidecarRedaction {
+        SidecarRedaction { file: redaction.file.clone(), rule: redaction.rule.clone(), count: redaction.count }
+    }
+}
+
+impl From<&EvidenceSidecar> for SidecarFile {
+    fn from(sidecar: &EvidenceSidecar) -> SidecarFile {
+        let mut file = SidecarFile {
+            action: sidecar.action.value.clone(),
+            evidence_file: sidecar.evidence_file.clone(),
+            captured: sidecar.captured.to_utc_string(),
+            command: None,
+            url: None,
+            file: None,
+            redactions: sidecar.redactions.iter().map(SidecarRedaction::from).collect(),
+        };
+        match &sidecar.source {
+            EvidenceSource::Command { command_line, exit_code } => {
+                file.command = Some(SidecarCommand { command_line: command_line.clone(), exit_code: *exit_code });
+            }
+            EvidenceSource::Url { url, status, headers } => {
+                let mut combined_headers: BTreeMap<String, String> = BTreeMap::new();
+                for (name, value) in headers {
+                    combined_headers.entry(name.to_lowercase())
+                        .and_modify(|combined| { combined.push_str(", "); combined.push_str(value); })
+                        .or_insert_with(|| value.clone());
+                }
+                file.url = Some(SidecarUrl { url: url.clone(), status: *status, headers: combined_headers });
+            }
+            EvidenceSource::File { path } => {
+                file.file = Some(SidecarSourceFile { path: path.clone() });
+            }
+        }
+        file
+    }
+}
+
+/// Implementation of the [`PersistSidecarGateway`] which saves the [`EvidenceSidecar`] as a YAML file named after the evidence file, such as 'pods.json.sidecar.yaml'.
+pub fn save_sidecar_as_yaml(sidecar: &EvidenceSidecar, target_directory: &str) -> Result<String, Error> {
+
+    let yaml = serde_yaml::to_string(&SidecarFile::from(sidecar))
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not serialize the sidecar: {}", e)))?;
+
+    let file_path = Path::new(target_directory).join(format!("{}{}", sidecar.evidence_file, SIDECAR_SUFFIX));
+
+    fs::write(&file_path, yaml)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not write the sidecar file '{}': {}", file_path.display(), e)))?;
+
+    Ok(file_path.to_str().unwrap_or("").to_string())
+}
@@ -0,0 +1,138 @@
+use std::fs;
+use nape_domain::evidence_collection::usecases::capture_evidence::gateway_boundary::{EvidenceSidecar, EvidenceSource};
+use nape_domain::evidence_collection::usecases::collect_evidence::redaction::Redaction;
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::name::Name;
+use nape_kernel::values::time::start_time::StartTime;
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, remove};
+use crate::gateway_adapter::serde::persist_sidecar_gateway::save_sidecar_as_yaml;
+
+#[test]
+fn success() {
+
+    // Assemble
+    remove!("persist_sidecar_gateway_success");
+    let directory = create!("persist_sidecar_gateway_success");
+    let directory_path = canonical_path!(&directory);
+
+    // Act
+    let result = save_sidecar_as_yaml(&command_sidecar(), &directory_path);
+
+    // Assert
+    let sidecar_path = is_ok!(result);
+    assert!(sidecar_path.ends_with("persist_sidecar_gateway_success/pods.json.sidecar.yaml"));
+    let content = fs::read_to_string(&sidecar_path).unwrap();
+    assert_eq!(content, r#"action: pod-inventory
+evidence_file: pods.json
+captured: 2024-06-25T14:44:26Z
+command:
+  command_line: kubectl get pods -o json
+  exit_code: 0
+"#);
+
+    // Clean up
+    remove!("persist_sidecar_gateway_success");
+}
+
+#[test]
+fn url_success() {
+
+    // Assemble
+    remove!("persist_sidecar_gateway_url_success");
+    let directory = create!("persist_sidecar_gateway_url_success");
+    let directory_path = canonical_path!(&directory);
+    let sidecar = EvidenceSidecar {
+        action: Name::try_from("service-health").unwrap(),
+        evidence_file: "health.json".to_string(),
+        source: EvidenceSource::Url {
+            url: "https://internal.example.com/health".to_string(),
+            status: 200,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("set-cookie".to_string(), "a=1".to_string()),
+                ("set-cookie".to_string(), "b=2".to_string())] },
+        captured: StartTime::from(1719326666123),
+        redactions: Vec::new(),
+    };
+
+    // Act
+    let result = save_sidecar_as_yaml(&sidecar, &directory_path);
+
+    // Assert
+    let sidecar_path = is_ok!(result);
+    let content = fs::read_to_string(&sidecar_path).unwrap();
+    assert_eq!(content, r#"action: service-health
+evidence_file: health.json
+captured: 2024-06-25T14:44:26Z
+url:
+  url: https://internal.example.com/health
+  status: 200
+  headers:
+    content-type: application/json
+    set-cookie: a=1, b=2
+"#);
+
+    // Clean up
+    remove!("persist_sidecar_gateway_url_success");
+}
+
+#[test]
+fn file_with_redactions_success() {
+
+    // Assemble
+    remove!("persist_sidecar_gateway_file_with_redactions_success");
+    let directory = create!("persist_sidecar_gateway_file_with_redactions_success");
+    let directory_path = canonical_path!(&directory);
+    let sidecar = EvidenceSidecar {
+        action: Name::try_from("manifest-review").unwrap(),
+        evidence_file: "manifests".to_string(),
+        source: EvidenceSource::File { path: "./k8s".to_string() },
+        captured: StartTime::from(1719326666123),
+        redactions: vec![
+            Redaction { file: "app/deploy.yaml".to_string(), rule: "password".to_string(), count: 2 },
+            Redaction { file: "app/secret.yaml".to_string(), rule: "custom-1".to_string(), count: 1 }],
+    };
+
+    // Act
+    let result = save_sidecar_as_yaml(&sidecar, &directory_path);
+
+    // Assert
+    let sidecar_path = is_ok!(result);
+    assert!(sidecar_path.ends_with("manifests.sidecar.yaml"));
+    let content = fs::read_to_string(&sidecar_path).unwrap();
+    assert_eq!(content, r#"action: manifest-review
+evidence_file: manifests
+captured: 2024-06-25T14:44:26Z
+file:
+  path: ./k8s
+redactions:
+- file: app/deploy.yaml
+  rule: password
+  count: 2
+- file: app/secret.yaml
+  rule: custom-1
+  count: 1
+"#);
+
+    // Clean up
+    remove!("persist_sidecar_gateway_file_with_redactions_success");
+}
+
+#[test]
+fn directory_does_not_exist_error() {
+
+    let result = save_sidecar_as_yaml(&command_sidecar(), "persist_sidecar_gateway_directory_does_not_exist");
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not write the sidecar file ");
+}
+
+fn command_sidecar() -> EvidenceSidecar {
+    EvidenceSidecar {
+        action: Name::try_from("pod-inventory").unwrap(),
+        evidence_file: "pods.json".to_string(),
+        source: EvidenceSource::Command { command_line: "kubectl get pods -o json".to_string(), exit_code: Some(0) },
+        captured: StartTime::from(1719326666123),
+        redactions: Vec::new(),
+    }
+}
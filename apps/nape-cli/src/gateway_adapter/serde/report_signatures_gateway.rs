@@ -0,0 +1,42 @@
+use nape_domain::evidence_collection::usecases::bundle_collection::gateway_boundary::ReportSignatures;
+use nape_kernel::algorithms::signature_algorithm::Signature;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use crate::gateway_adapter::serde::specification_serializer::assurance_report::v1_0_0::{AssuranceReportFileV1, ReportFileSignedFile};
+
+/// Implementation of the [`ReportSignaturesGateway`] which reads the subject NRN, and the signed test, evidence, evidence input, and attestation supporting files from an assurance report in YAML.
+pub fn signed_files_from_yaml_report(report_data: &[u8]) -> Result<ReportSignatures, Error> {
+
+    let report: AssuranceReportFileV1 = serde_yaml::from_slice(report_data)
+        .map_err(|e| Error::for_user(Kind::InvalidInput, format!("The assurance report is not valid YAML. {}", e)))?;
+
+    let mut signed_files = Vec::new();
+    for action in report.activities.iter().flat_map(|activity| activity.actions.iter()) {
+        if let Some(test_file) = &action.test_file {
+            signed_files.push(to_signed_file(test_file)?);
+        }
+        if let Some(evidence_file) = &action.evidence_file {
+            signed_files.push(to_signed_file(evidence_file)?);
+        }
+        for evidence_file in action.evidence_files.iter().flatten() {
+            signed_files.push(to_signed_file(evidence_file)?);
+        }
+        for input in action.inputs.iter().flatten() {
+            signed_files.push(to_signed_file(&input.evidence_file)?);
+            for evidence_file in input.evidence_files.iter().flatten() {
+                signed_files.push(to_signed_file(evidence_file)?);
+            }
+        }
+        for supporting_file in action.attestation.iter().flat_map(|attestation| attestation.supporting_files.iter().flatten()) {
+            signed_files.push(to_signed_file(supporting_file)?);
+        }
+    }
+    Ok(ReportSignatures { subject_nrn: report.subject.urn, signed_files })
+}
+
+fn to_signed_file(file: &ReportFileSignedFile) -> Result<SignedFile, Error> {
+    let signature = Signature::try_from(&file.signature)
+        .map_err(|error| Error::for_user(Kind::InvalidInput,
+                                         format!("The signature of the file '{}' in the assurance report is not valid. {}", file.file, error.message)))?;
+    SignedFile::new(&file.file, &signature)
+}
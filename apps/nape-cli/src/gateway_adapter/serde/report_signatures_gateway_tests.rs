@@ -0,0 +1,83 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use crate::gateway_adapter::serde::report_signatures_gateway::signed_files_from_yaml_report;
+
+const REPORT: &str = r#"
+apiVersion: 1.0.0
+kind: AssuranceReport
+subject:
+  urn: nrn:sourcecode:nape:nape-cli
+  id: 9f3f183a
+procedure:
+  repository: git://github.com/nape/processes
+  directory: rust_ci
+summary:
+  activity_count: 1
+  action_count: 3
+  actions_run: 3
+  pass: 3
+  fail: 0
+  inconclusive: 0
+  waived: 0
+  failures_by_severity: { critical: 0, high: 0, medium: 0, low: 0, unrated: 0 }
+  outcome_policy: any
+  outcome: pass
+activity:
+- name: review
+  action:
+  - name: peer-review
+    outcome: pass
+    reason: Approved
+    test_file: { file: activity/review/peer-review.rego, signature: "SHA256[test1]" }
+    evidence_file: { file: evidence/review/review.json, signature: "SHA256[evidence1]" }
+    inputs:
+    - name: approvals
+      evidence_file: { file: evidence/review/approvals.json, signature: "SHA256[approvals1]" }
+  - name: access-review
+    outcome: pass
+    reason: All accounts were reviewed.
+    evidence_file: { file: evidence/review/attestation.yaml, signature: "SHA256[statement1]" }
+    attestation:
+      marker: ATTESTED
+      outcome: pass
+      attested_by: jane
+      attested_at: 2024-06-25T14:44:26Z
+      supporting_files:
+      - { file: evidence/review/minutes.pdf, signature: "SHA256[minutes1]" }
+  - name: manifests
+    outcome: pass
+    reason: Valid
+    test_file: { file: activity/review/manifests.rego, signature: "SHA256[test2]" }
+    evidence_file: { file: evidence/review/manifests, signature: "SHA256[directory2]" }
+    evidence_files:
+    - { file: evidence/review/manifests/deploy.yaml, signature: "SHA256[deploy2]" }
+"#;
+
+#[test]
+fn success() {
+
+    let result = signed_files_from_yaml_report(REPORT.as_bytes());
+
+    let report_signatures = is_ok!(result);
+    assert_eq!(report_signatures.subject_nrn, "nrn:sourcecode:nape:nape-cli");
+    let files: Vec<(&str, String)> = report_signatures.signed_files.iter().map(|file| (file.file().as_str(), file.signature().structure_signature())).collect();
+    assert_eq!(files, vec![
+        ("activity/review/peer-review.rego", "SHA256[test1]".to_string()),
+        ("evidence/review/review.json", "SHA256[evidence1]".to_string()),
+        ("evidence/review/approvals.json", "SHA256[approvals1]".to_string()),
+        ("evidence/review/attestation.yaml", "SHA256[statement1]".to_string()),
+        ("evidence/review/minutes.pdf", "SHA256[minutes1]".to_string()),
+        ("activity/review/manifests.rego", "SHA256[test2]".to_string()),
+        ("evidence/review/manifests", "SHA256[directory2]".to_string()),
+        ("evidence/review/manifests/deploy.yaml", "SHA256[deploy2]".to_string()),
+    ]);
+}
+
+#[test]
+fn invalid_signature_error() {
+
+    let result = signed_files_from_yaml_report(REPORT.replace("SHA256[test1]", "test1").as_bytes());
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User,
+        "The signature of the file 'activity/review/peer-review.rego' in the assurance report is not valid. ");
+}
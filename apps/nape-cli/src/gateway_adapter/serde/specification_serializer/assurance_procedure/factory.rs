@@ -0,0 +1,88 @@
+use serde::Deserialize;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::api_version::APIVersion;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::{v1_0_0, v1};
+
+/// The [`SUPPORTED_API_VERSIONS`] contains the list of Assurance Procedure schema versions which can be read.
+const SUPPORTED_API_VERSIONS: &[&str] = &["1.0.0", "1.1.0", "1.2.0", "1.3.0", "1.4.0", "1.5.0", "1.6.0"];
+
+/// The [`VersionHeader`] is the minimal part of an Assurance Procedure file which is read first to determine which schema version the rest of the file conforms to.
+#[derive(Deserialize)]
+struct VersionHeader {
+    #[serde(rename = "apiVersion")]
+    api_version: String
+}
+
+/// # Overview
+///
+/// The [`from_yaml`] function is a factory function that reads the `apiVersion` of a YAML Assurance Procedure, then deserializes the content with the schema of its major version and upgrades it to the in-memory [`AssuranceProcedure`] model.
+///
+/// # Arguments
+///
+/// * `content` - The YAML content of the Assurance Procedure file.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`AssuranceProcedure`] or an [`Error`].
+///
+/// ## Errors
+///
+/// An [`Error`] for [`Audience::System`] with [`Kind::ProcessingFailure`] is returned if:
+///
+/// * the content does not contain an `apiVersion`, or it is not a valid version,
+/// * the `apiVersion` is not one of the supported schema versions, or
+/// * the content does not conform to the schema of its `apiVersion`.
+///
+pub fn from_yaml(content: &str) -> Result<AssuranceProcedure, Error> {
+
+    let header: VersionHeader = serde_yaml::from_str(content)
+        .map_err(|e| custom_error(&format!("Could not read the apiVersion of the file. {}", e)))?;
+
+    let api_version = APIVersion::from_str(&header.api_version)
+        .map_err(|e| custom_error(&e.message))?;
+
+    match api_version {
+        v if v == APIVersion::new(1, 0, 0) => {
+            let file: v1_0_0::AssuranceProcedureFile = serde_yaml::from_str(content)
+                .map_err(|e| custom_error(&format!("The file does not conform to the v1.0.0 schema. {}", e)))?;
+            file.try_to()
+        },
+        v if SUPPORTED_API_VERSIONS.contains(&v.as_string().as_str()) => {
+            let file: v1::AssuranceProcedureFile = serde_yaml::from_str(content)
+                .map_err(|e| custom_error(&format!("The file does not conform to the v{} schema. {}", header.api_version, e)))?;
+            file.try_to()
+        },
+        _ => Err(custom_error(&format!("The apiVersion '{}' is not supported. Must be one of: [{}].", header.api_version, SUPPORTED_API_VERSIONS.join(", "))))
+    }
+}
+
+/// # Overview
+///
+/// The [`to_yaml`] function writes an [`AssuranceProcedure`] as the YAML content of an Assurance Procedure file, using the schema of its `apiVersion`.
+///
+/// # Returns
+///
+/// A [`Result`] of either the YAML content, or an [`Error`].
+///
+/// ## Errors
+///
+/// * An [`Error`] for [`Audience::User`] with [`Kind::InvalidInput`] is returned if the `apiVersion` is not one of the supported schema versions, or the [`AssuranceProcedure`] uses a field which was added after its `apiVersion`.
+/// * An [`Error`] for [`Audience::System`] with [`Kind::ProcessingFailure`] is returned if it could not be serialized.
+///
+pub fn to_yaml(procedure: &AssuranceProcedure) -> Result<String, Error> {
+    let api_version = procedure.api_version.as_string();
+    let yaml = match &procedure.api_version {
+        v if *v == APIVersion::new(1, 0, 0) => serde_yaml::to_string(&v1_0_0::AssuranceProcedureFile::try_from(procedure)?),
+        _ if SUPPORTED_API_VERSIONS.contains(&api_version.as_str()) => serde_yaml::to_string(&v1::AssuranceProcedureFile::try_from(procedure)?),
+        _ => return Err(Error::for_user(Kind::InvalidInput,
+                                        format!("The apiVersion '{}' can not be written. Must be one of: [{}].", api_version, SUPPORTED_API_VERSIONS.join(", "))))
+    };
+    yaml.map_err(|e| Error::for_system(Kind::ProcessingFailure,
+                                       format!("Could not write the AssuranceProcedure as YAML. {}", e)))
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_system(Kind::ProcessingFailure,
+                      format!("Could not create the AssuranceProcedure from the file. {}", message))
+}
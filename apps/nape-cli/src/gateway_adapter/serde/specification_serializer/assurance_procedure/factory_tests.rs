@@ -0,0 +1,206 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::api_version::APIVersion;
+use nape_kernel::values::specification::severity::Severity;
+use nape_testing_assertions::{is_ok, kernel_error_starts_with, kernel_error_contains};
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::factory::{from_yaml, to_yaml};
+
+#[test]
+fn from_yaml_v1_0_0_success() {
+    let result = from_yaml(&procedure_yaml("1.0.0", ""));
+
+    let procedure = is_ok!(result);
+    assert_eq!(procedure.api_version.as_string(), "1.0.0");
+    assert_eq!(procedure.activities.list[0].actions[0].severity, None);
+    assert!(procedure.activities.list[0].actions[0].tags.is_empty());
+    assert_eq!(procedure.activities.list[0].actions[0].owner, None);
+}
+
+#[test]
+fn from_yaml_v1_1_0_success() {
+    let optional_fields = r#"
+        severity: critical
+        tags:
+          - security
+        owner: "Platform Team""#;
+    let result = from_yaml(&procedure_yaml("1.1.0", optional_fields));
+
+    let procedure = is_ok!(result);
+    let action = &procedure.activities.list[0].actions[0];
+    assert_eq!(procedure.api_version.as_string(), "1.1.0");
+    assert_eq!(action.severity, Some(Severity::CRITICAL));
+    assert_eq!(action.tags[0].value, "security");
+    assert_eq!(action.owner.as_ref().unwrap().value, "Platform Team");
+}
+
+#[test]
+fn from_yaml_v1_1_0_without_optional_fields_success() {
+    let result = from_yaml(&procedure_yaml("1.1.0", ""));
+
+    let procedure = is_ok!(result);
+    assert_eq!(procedure.activities.list[0].actions[0].severity, None);
+}
+
+#[test]
+fn from_yaml_v1_2_0_with_include_success() {
+    let content = format!("{}include:\n  - repository: \"https://github.com/example/procedures.git\"\n    directory: shared/branch-protection\n    revision: v1.0.0\n    activities:\n      - branch-protection\n", procedure_yaml("1.2.0", ""));
+    let result = from_yaml(&content);
+
+    let procedure = is_ok!(result);
+    assert_eq!(procedure.api_version.as_string(), "1.2.0");
+    assert_eq!(procedure.includes.len(), 1);
+    assert_eq!(procedure.includes[0].source.directory, "shared/branch-protection");
+    assert_eq!(procedure.includes[0].activities[0].value, "branch-protection");
+}
+
+#[test]
+fn from_yaml_v1_3_0_with_when_success() {
+    let result = from_yaml(&procedure_yaml("1.3.0", "\n        when: \"meta.environment == 'prod'\""));
+
+    let procedure = is_ok!(result);
+    assert_eq!(procedure.api_version.as_string(), "1.3.0");
+    assert_eq!(procedure.activities.list[0].actions[0].when.as_ref().unwrap().as_str(), "meta.environment == 'prod'");
+}
+
+#[test]
+fn from_yaml_v1_4_0_with_depends_on_success() {
+    let content = procedure_yaml("1.4.0", "\n        depends_on:\n          - reviewers-configured")
+        .replace("    action:\n", "    action:\n      - name: reviewers-configured\n        short: \"Reviewers Configured\"\n        description: \"The reviewers are configured.\"\n        test: \"./activity/peer_review/reviewers_configured.py\"\n        evidence: \"./evidence/peer_review/reviewers.json\"\n");
+    let result = from_yaml(&content);
+
+    let procedure = is_ok!(result);
+    assert_eq!(procedure.api_version.as_string(), "1.4.0");
+    assert_eq!(procedure.activities.list[0].actions[1].depends_on[0].value, "reviewers-configured");
+}
+
+#[test]
+fn from_yaml_v1_4_0_dependency_cycle_error() {
+    let result = from_yaml(&procedure_yaml("1.4.0", "\n        depends_on:\n          - at-least-two-reviewers"));
+
+    kernel_error_contains!(result, Kind::ProcessingFailure, Audience::System, "The dependencies of the actions are not valid. The dependencies form a cycle: at-least-two-reviewers -> at-least-two-reviewers.");
+}
+
+#[test]
+fn from_yaml_v1_5_0_with_inputs_success() {
+    let result = from_yaml(&procedure_yaml("1.5.0", "\n        inputs:\n          - name: approvals\n            evidence: \"./evidence/peer_review/approvals.json\""));
+
+    let procedure = is_ok!(result);
+    assert_eq!(procedure.api_version.as_string(), "1.5.0");
+    assert_eq!(procedure.activities.list[0].actions[0].inputs[0].name.value, "approvals");
+    assert_eq!(procedure.activities.list[0].actions[0].inputs[0].evidence.as_str(), "./evidence/peer_review/approvals.json");
+}
+
+#[test]
+fn from_yaml_v1_5_0_reserved_input_name_error() {
+    let result = from_yaml(&procedure_yaml("1.5.0", "\n        inputs:\n          - name: evidence\n            evidence: \"./evidence/peer_review/approvals.json\""));
+
+    kernel_error_contains!(result, Kind::ProcessingFailure, Audience::System, "There is an issue with an Action. ");
+}
+
+#[test]
+fn from_yaml_v1_6_0_attestation_success() {
+    let result = from_yaml(&attestation_procedure_yaml());
+
+    let procedure = is_ok!(result);
+    assert_eq!(procedure.api_version.as_string(), "1.6.0");
+    assert!(procedure.activities.list[0].actions[0].is_attestation());
+    assert_eq!(procedure.activities.list[0].actions[0].test, None);
+}
+
+#[test]
+fn to_yaml_attestation_round_trip_success() {
+    let procedure = from_yaml(&attestation_procedure_yaml()).unwrap();
+
+    let result = to_yaml(&procedure);
+
+    let content = is_ok!(result);
+    assert!(content.contains("type: attestation\n"));
+    let read_back = is_ok!(from_yaml(&content));
+    assert_eq!(read_back.activities.list[0].actions, procedure.activities.list[0].actions);
+}
+
+#[test]
+fn to_yaml_round_trip_success() {
+    let procedure = from_yaml(&procedure_yaml("1.0.0", "")).unwrap();
+
+    let result = to_yaml(&procedure);
+
+    let content = is_ok!(result);
+    assert!(content.starts_with("apiVersion: 1.0.0\n"));
+    let read_back = is_ok!(from_yaml(&content));
+    assert_eq!(read_back.procedure, procedure.procedure);
+    assert_eq!(read_back.activities.list[0].actions, procedure.activities.list[0].actions);
+}
+
+#[test]
+fn to_yaml_keeps_api_version_success() {
+    let procedure = from_yaml(&procedure_yaml("1.1.0", "")).unwrap();
+
+    let result = to_yaml(&procedure);
+
+    let content = is_ok!(result);
+    assert!(content.starts_with("apiVersion: 1.1.0\n"));
+}
+
+#[test]
+fn to_yaml_v1_0_0_attestation_error() {
+    let mut procedure = from_yaml(&attestation_procedure_yaml()).unwrap();
+    procedure.api_version = APIVersion::new(1, 0, 0);
+
+    let result = to_yaml(&procedure);
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User,
+        "The procedure 'nrn:procedure:nape/software-procedures:rust-ci/sourcecode-integration' can not be written with the v1.0.0 schema, because it uses the fields [type]");
+}
+
+#[test]
+fn from_yaml_unsupported_version_error() {
+    let result = from_yaml(&procedure_yaml("2.0.0", ""));
+
+    kernel_error_contains!(result, Kind::ProcessingFailure, Audience::System, "The apiVersion '2.0.0' is not supported. Must be one of: [1.0.0, 1.1.0, 1.2.0, 1.3.0, 1.4.0, 1.5.0, 1.6.0].");
+}
+
+#[test]
+fn from_yaml_missing_api_version_error() {
+    let result = from_yaml("kind: AssuranceProcedure");
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not create the AssuranceProcedure from the file. Could not read the apiVersion of the file. ");
+}
+
+#[test]
+fn from_yaml_invalid_api_version_error() {
+    let result = from_yaml(&procedure_yaml("one", ""));
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not create the AssuranceProcedure from the file. Version string 'one' is not in the format 'major.minor.patch'.");
+}
+
+#[test]
+fn from_yaml_schema_mismatch_error() {
+    let result = from_yaml("apiVersion: 1.0.0\nkind: AssuranceProcedure");
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not create the AssuranceProcedure from the file. The file does not conform to the v1.0.0 schema. ");
+}
+
+fn procedure_yaml(api_version: &str, optional_action_fields: &str) -> String {
+    format!(r#"---
+apiVersion: {}
+kind: AssuranceProcedure
+procedure:
+  nrn: "nrn:procedure:nape/software-procedures:rust-ci/sourcecode-integration"
+  short: "Rust CI Assurance Procedure"
+  description: "The procedure to integrate a source code change."
+activity:
+  - name: peer-review
+    short: "Peer Review"
+    description: "Verifies the peer review."
+    action:
+      - name: at-least-two-reviewers
+        short: "Two (2) Reviewer Approval"
+        description: "There are at least two (2) peer reviews who approved the merge request."
+        test: "./activity/peer_review/at_least_two_reviewers.py"
+        evidence: "./evidence/peer_review/peer_review.json"{}
+"#, api_version, optional_action_fields)
+}
+
+fn attestation_procedure_yaml() -> String {
+    procedure_yaml("1.6.0", "").replace("        test: \"./activity/peer_review/at_least_two_reviewers.py\"\n", "        type: attestation\n")
+}
@@ -1,3 +1,7 @@
 pub mod v1_0_0;
+pub mod v1;
+pub(crate) mod factory;
 
-#[cfg(test)]  mod v1_0_0_tests;
\ No newline at end of file
+#[cfg(test)]  mod v1_0_0_tests;
+#[cfg(test)]  mod v1_tests;
+#[cfg(test)]  mod factory_tests;
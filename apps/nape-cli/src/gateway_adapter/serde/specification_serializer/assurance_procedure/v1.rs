@@ -0,0 +1,327 @@
+use serde::{Deserialize, Serialize};
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::api_version::APIVersion;
+use nape_kernel::values::specification::assurance_procedure;
+use nape_kernel::values::specification::assurance_procedure::condition::Condition;
+use nape_kernel::values::specification::assurance_procedure::include::Source;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+
+/// The [`ATTESTATION_TYPE`] is the `type` of an action which is attested by a person rather than evaluated by a test.
+const ATTESTATION_TYPE: &str = "attestation";
+
+/// The [`FIELD_API_VERSIONS`] are the optional fields added to the v1 schema after v1.1.0, with the `apiVersion` each was added in.
+const FIELD_API_VERSIONS: &[(&str, (u8, u8, u8))] = &[
+    ("include", (1, 2, 0)),
+    ("included_from", (1, 2, 0)),
+    ("when", (1, 3, 0)),
+    ("depends_on", (1, 4, 0)),
+    ("inputs", (1, 5, 0)),
+    ("type", (1, 6, 0))
+];
+
+/// The [`AssuranceProcedureFile`] struct is a representation of the v1 schema of an [`AssuranceProcedure`] file, from v1.1.0 to v1.6.0.  Each minor version only added optional fields, so one model reads all of them, and a field is only accepted when the `apiVersion` of the file is at least the version it was added in:
+///
+/// * v1.1.0 - the `severity`, `tags`, and `owner` of each action.
+/// * v1.2.0 - the `include` section, and the `included_from` of each activity.
+/// * v1.3.0 - the `when` condition of each activity and action.
+/// * v1.4.0 - the `depends_on` of each action.
+/// * v1.5.0 - the `inputs` of each action.
+/// * v1.6.0 - the `type` of each action, which is `attestation` for an action attested by a person rather than evaluated by a test.  An attestation action does not have a `test`.
+#[derive(Serialize, Deserialize)]
+pub struct AssuranceProcedureFile {
+    #[serde(rename = "apiVersion")]
+    pub api_version: String,
+    pub kind: String,
+    pub procedure: Procedure,
+    #[serde(rename = "include", default, skip_serializing_if = "Vec::is_empty")]
+    pub includes: Vec<Include>,
+    #[serde(rename = "activity")]
+    pub activities: Vec<Activity>
+}
+
+/// The [`Procedure`] struct is a representation of the procedure section of an [`AssuranceProcedure`].
+#[derive(Serialize, Deserialize)]
+pub struct Procedure {
+    pub nrn: String,
+    pub short: String,
+    pub description: String
+}
+
+/// The [`Include`] struct is a representation of an entry in the include section of an [`AssuranceProcedure`].
+#[derive(Serialize, Deserialize)]
+pub struct Include {
+    pub repository: String,
+    pub directory: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+    pub activities: Vec<String>
+}
+
+/// The [`IncludedFrom`] struct is a representation of the procedure an activity was included from.
+#[derive(Serialize, Deserialize)]
+pub struct IncludedFrom {
+    pub repository: String,
+    pub directory: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>
+}
+
+/// The [`Activity`] struct is a representation of the activity section of an [`AssuranceProcedure`].
+#[derive(Serialize, Deserialize)]
+pub struct Activity {
+    pub name: String,
+    pub short: String,
+    pub description: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub included_from: Option<IncludedFrom>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+    #[serde(rename = "action")]
+    pub actions: Vec<Action>
+}
+
+/// The [`Action`] struct is a representation of the action section of an [`AssuranceProcedure`].
+#[derive(Serialize, Deserialize)]
+pub struct Action {
+    pub name: String,
+    pub short: String,
+    pub description: String,
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub action_type: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test: Option<String>,
+    pub evidence: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub when: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<Input>
+}
+
+/// The [`Input`] struct is a representation of a named evidence input of an [`Action`].
+#[derive(Serialize, Deserialize)]
+pub struct Input {
+    pub name: String,
+    pub evidence: String
+}
+
+impl AssuranceProcedureFile {
+
+    /// Attempt to create a new instance of the [`AssuranceProcedureFile`] from an existing instance of an [`AssuranceProcedure`].
+    ///
+    /// This assumes the [`AssuranceProcedure`] is valid and will not perform any validation, other than checking the fields it uses were part of the schema at its `apiVersion`.  The file is written as the `apiVersion` of the [`AssuranceProcedure`].
+    ///
+    /// # Returns
+    ///
+    /// An [`Error`] for [`Audience::User`] with [`Kind::InvalidInput`] is returned if the [`AssuranceProcedure`] uses a field which was added after its `apiVersion`, so the field is never silently dropped.
+    ///
+    pub fn try_from(procedure_definition: &AssuranceProcedure) -> Result<Self, Error> {
+
+        let mut activities = Vec::new();
+
+        for activity in procedure_definition.activities.list.iter() {
+
+            let mut actions = Vec::new();
+            for action in activity.actions.iter() {
+                actions.push(Action {
+                    name: action.name.value.clone(),
+                    short: action.short.value.clone(),
+                    description: action.description.value.clone(),
+                    action_type: action.is_attestation().then(|| ATTESTATION_TYPE.to_string()),
+                    test: action.test.as_ref().map(|test| test.to_string()),
+                    evidence: action.evidence.to_string(),
+                    severity: action.severity.as_ref().map(|severity| severity.to_string()),
+                    tags: action.tags.iter().map(|tag| tag.value.clone()).collect(),
+                    owner: action.owner.as_ref().map(|owner| owner.value.clone()),
+                    when: action.when.as_ref().map(|condition| condition.to_string()),
+                    depends_on: action.depends_on.iter().map(|dependency| dependency.value.clone()).collect(),
+                    inputs: action.inputs.iter().map(|input| Input { name: input.name.value.clone(), evidence: input.evidence.to_string() }).collect()
+                });
+            }
+
+            activities.push(Activity {
+                name: activity.name.value.clone(),
+                short: activity.short.value.clone(),
+                description: activity.description.value.clone(),
+                included_from: activity.source.as_ref().map(|source| IncludedFrom {
+                    repository: source.repository.clone(),
+                    directory: source.directory.clone(),
+                    revision: source.revision.clone()
+                }),
+                when: activity.when.as_ref().map(|condition| condition.to_string()),
+                actions
+            });
+        }
+
+        let includes = procedure_definition.includes.iter()
+            .map(|include| Include {
+                repository: include.source.repository.clone(),
+                directory: include.source.directory.clone(),
+                revision: include.source.revision.clone(),
+                activities: include.activities.iter().map(|activity| activity.value.clone()).collect()
+            })
+            .collect();
+
+        let file = AssuranceProcedureFile {
+            api_version: procedure_definition.api_version.as_string(),
+            kind: procedure_definition.kind.to_string(),
+            procedure: Procedure {
+                nrn: procedure_definition.procedure.nrn.to_string(),
+                short: procedure_definition.procedure.short.value.clone(),
+                description: procedure_definition.procedure.description.value.clone()
+            },
+            includes,
+            activities
+        };
+
+        match file.field_added_later(&procedure_definition.api_version) {
+            Some((field, added_in)) => Err(Error::for_user(Kind::InvalidInput,
+                                                           format!("The procedure '{}' can not be written with the v{} schema, because the field '{}' requires an apiVersion of {} or later.",
+                                                                   file.procedure.nrn, file.api_version, field, added_in.as_string()))),
+            None => Ok(file)
+        }
+    }
+
+    /// # Overview
+    ///
+    /// Attempt to convert the [`AssuranceProcedureFile`] to an [`AssuranceProcedure`].
+    ///
+    ///  # Returns
+    ///
+    /// This will attempt to convert the [`AssuranceProcedureFile`] to an [`AssuranceProcedure`].  If the conversion fails, an [`Error`] will be returned for [`Audience::System`] with [`Kind::ProcessingFailure`].
+    ///
+    pub fn try_to(&self) -> Result<AssuranceProcedure, Error> {
+
+        self.check_field_versions()?;
+
+        let mut builder = AssuranceProcedure::builder()
+            .api_version(&self.api_version)
+            .procedure_info(&self.procedure.nrn, &self.procedure.short, &self.procedure.description);
+
+        for include in &self.includes {
+            let source = Source::try_new(&include.repository, &include.directory, include.revision.as_deref())
+                .map_err(|e| self.custom_error(&format!("There is an issue with an Include. {}", &e.message)))?;
+            let valid_include = assurance_procedure::include::Include::try_new(&source, &include.activities)
+                .map_err(|e| self.custom_error(&format!("There is an issue with an Include. {}", &e.message)))?;
+            builder = builder.add_include(&valid_include);
+        }
+
+        for activity in &self.activities {
+            let mut valid_activity = assurance_procedure::activity::Activity::new(&activity.name, &activity.short, &activity.description)
+                .map_err(|e| self.custom_error(&format!("There is an issue with an Activity. {}", &e.message)))?;
+
+            if let Some(included_from) = &activity.included_from {
+                let source = Source::try_new(&included_from.repository, &included_from.directory, included_from.revision.as_deref())
+                    .map_err(|e| self.custom_error(&format!("There is an issue with an Activity. {}", &e.message)))?;
+                valid_activity = valid_activity.included_from(&source);
+            }
+
+            if let Some(when) = &activity.when {
+                let condition = Condition::try_from(when)
+                    .map_err(|e| self.custom_error(&format!("There is an issue with an Activity. {}", &e.message)))?;
+                valid_activity = valid_activity.when(&condition);
+            }
+
+            for action in &activity.actions {
+                let mut action_builder = assurance_procedure::action::Action::builder()
+                    .name(&action.name)
+                    .short_description(&action.short)
+                    .long_description(&action.description)
+                    .evidence_file_path(&action.evidence);
+
+                match action.action_type.as_deref() {
+                    Some(ATTESTATION_TYPE) => action_builder = action_builder.attestation(),
+                    Some(action_type) => return Err(self.custom_error(&format!("There is an issue with an Action. The type '{}' of the action '{}' is not supported. Must be one of: [{}].", action_type, action.name, ATTESTATION_TYPE))),
+                    None => {}
+                }
+                if let Some(test) = &action.test {
+                    action_builder = action_builder.test_file_path(test);
+                }
+
+                if let Some(severity) = &action.severity {
+                    action_builder = action_builder.severity(severity);
+                }
+                for tag in &action.tags {
+                    action_builder = action_builder.add_tag(tag);
+                }
+                if let Some(owner) = &action.owner {
+                    action_builder = action_builder.owner(owner);
+                }
+                if let Some(when) = &action.when {
+                    action_builder = action_builder.when(when);
+                }
+                for dependency in &action.depends_on {
+                    action_builder = action_builder.add_dependency(dependency);
+                }
+                for input in &action.inputs {
+                    action_builder = action_builder.add_input(&input.name, &input.evidence);
+                }
+
+                let valid_action = action_builder.try_build()
+                    .map_err(|e| self.custom_error(&format!("There is an issue with an Action. {}", &e.message)))?;
+                valid_activity = valid_activity.add(valid_action);
+            }
+
+           builder = builder.add_activity(&valid_activity)
+        }
+
+        builder.try_build().map_err(|e| self.custom_error(&e.message))
+
+    }
+
+    /// Check each optional field used in the file was part of the schema at the `apiVersion` of the file.
+    fn check_field_versions(&self) -> Result<(), Error> {
+        let api_version = APIVersion::from_str(&self.api_version)
+            .map_err(|e| self.custom_error(&e.message))?;
+        match self.field_added_later(&api_version) {
+            Some((field, added_in)) => Err(self.custom_error(&format!("The field '{}' requires an apiVersion of {} or later.", field, added_in.as_string()))),
+            None => Ok(())
+        }
+    }
+
+    /// The first optional field used in the file which was added to the schema after the `api_version`, with the `apiVersion` it was added in.
+    fn field_added_later(&self, api_version: &APIVersion) -> Option<(&'static str, APIVersion)> {
+        let used_fields = self.used_fields();
+        FIELD_API_VERSIONS.iter()
+            .map(|(field, (major, minor, patch))| (*field, APIVersion::new(*major, *minor, *patch)))
+            .find(|(field, added_in)| used_fields.contains(field) && api_version < added_in)
+    }
+
+    /// The optional fields of the [`FIELD_API_VERSIONS`] which are used in the file.
+    fn used_fields(&self) -> Vec<&'static str> {
+        let actions = || self.activities.iter().flat_map(|activity| activity.actions.iter());
+        let mut used_fields = Vec::new();
+        if !self.includes.is_empty() {
+            used_fields.push("include");
+        }
+        if self.activities.iter().any(|activity| activity.included_from.is_some()) {
+            used_fields.push("included_from");
+        }
+        if self.activities.iter().any(|activity| activity.when.is_some()) || actions().any(|action| action.when.is_some()) {
+            used_fields.push("when");
+        }
+        if actions().any(|action| !action.depends_on.is_empty()) {
+            used_fields.push("depends_on");
+        }
+        if actions().any(|action| !action.inputs.is_empty()) {
+            used_fields.push("inputs");
+        }
+        if actions().any(|action| action.action_type.is_some()) {
+            used_fields.push("type");
+        }
+        used_fields
+    }
+
+    fn custom_error(&self, message: &str) -> Error {
+        Error::for_system(Kind::ProcessingFailure,
+                          format!("Failed to extract the data from the v{} Assurance Procedure File. {}", self.api_version, message))
+    }
+
+}
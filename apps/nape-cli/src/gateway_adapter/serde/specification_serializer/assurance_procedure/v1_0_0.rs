@@ -44,11 +44,22 @@ pub struct Action {
 
 impl AssuranceProcedureFile {
 
-    /// Create a new instance of the [`AssuranceProcedureFile`] from an existing instance of an [`AssuranceProcedure`].
+    /// Attempt to create a new instance of the [`AssuranceProcedureFile`] from an existing instance of an [`AssuranceProcedure`].
     ///
-    /// This assumes the [`AssuranceProcedure`] is valid and will not perform any validation.
+    /// This assumes the [`AssuranceProcedure`] is valid and will not perform any validation, other than checking it only uses the fields of the v1.0.0 schema.
     ///
-    pub fn from(procedure_definition: &AssuranceProcedure) -> Self {
+    /// # Returns
+    ///
+    /// An [`Error`] for [`Audience::User`] with [`Kind::InvalidInput`] is returned if the [`AssuranceProcedure`] uses a field which was added after v1.0.0, such as an attestation action, so the field is never silently dropped.
+    ///
+    pub fn try_from(procedure_definition: &AssuranceProcedure) -> Result<Self, Error> {
+
+        let later_fields = later_fields(procedure_definition);
+        if !later_fields.is_empty() {
+            return Err(Error::for_user(Kind::InvalidInput,
+                                       format!("The procedure '{}' can not be written with the v1.0.0 schema, because it uses the fields [{}], which were added in a later apiVersion.",
+                                               procedure_definition.procedure.nrn, later_fields.join(", "))));
+        }
 
         let mut activities = Vec::new();
 
@@ -56,11 +67,14 @@ impl AssuranceProcedureFile {
 
             let mut actions = Vec::new();
             for action in activity.actions.iter() {
+                let test = action.test.as_ref()
+                    .ok_or_else(|| Error::for_user(Kind::InvalidInput,
+                                                   format!("The action '{}' can not be written with the v1.0.0 schema, because it does not have a test.", action.name.value)))?;
                 actions.push(Action {
                     name: action.name.value.clone(),
                     short: action.short.value.clone(),
                     description: action.description.value.clone(),
-                    test: action.test.to_string(),
+                    test: test.to_string(),
                     evidence: action.evidence.to_string()
                 });
             }
@@ -73,16 +87,16 @@ impl AssuranceProcedureFile {
             });
         }
 
-        AssuranceProcedureFile {
+        Ok(AssuranceProcedureFile {
             api_version: procedure_definition.api_version.as_string(),
             kind: procedure_definition.kind.to_string(),
             procedure: Procedure {
-                nrn: procedure_definition.procedure.nrn.value.clone(),
+                nrn: procedure_definition.procedure.nrn.to_string(),
                 short: procedure_definition.procedure.short.value.clone(),
                 description: procedure_definition.procedure.description.value.clone()
             },
             activities
-        }
+        })
     }
 
     /// # Overview
@@ -129,6 +143,24 @@ impl AssuranceProcedureFile {
 
 }
 
+/// The fields added to the schema after v1.0.0 which the [`AssuranceProcedure`] uses, named as they are in the later schema.
+fn later_fields(procedure_definition: &AssuranceProcedure) -> Vec<&'static str> {
+    let activities = || procedure_definition.activities.list.iter();
+    let actions = || activities().flat_map(|activity| activity.actions.iter());
+    let used_fields = [
+        ("severity", actions().any(|action| action.severity.is_some())),
+        ("tags", actions().any(|action| !action.tags.is_empty())),
+        ("owner", actions().any(|action| action.owner.is_some())),
+        ("include", !procedure_definition.includes.is_empty()),
+        ("included_from", activities().any(|activity| activity.source.is_some())),
+        ("when", activities().any(|activity| activity.when.is_some()) || actions().any(|action| action.when.is_some())),
+        ("depends_on", actions().any(|action| !action.depends_on.is_empty())),
+        ("inputs", actions().any(|action| !action.inputs.is_empty())),
+        ("type", actions().any(|action| action.is_attestation())),
+    ];
+    used_fields.iter().filter(|(_, used)| *used).map(|(field, _)| *field).collect()
+}
+
 fn custom_error(message: &str) -> Error {
     Error::for_system(Kind::ProcessingFailure,
                       format!("Failed to extract the data from the Assurance Procedure File. {}", message))
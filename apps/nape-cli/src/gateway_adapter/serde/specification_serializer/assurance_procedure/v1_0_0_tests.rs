@@ -2,7 +2,7 @@ use nape_kernel::error::{Audience, Kind};
 use nape_kernel::values::specification::assurance_procedure::action::Action;
 use nape_kernel::values::specification::assurance_procedure::activity::Activity;
 use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
-use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
 use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::v1_0_0::{AssuranceProcedureFile, Procedure};
 use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::v1_0_0::Action as FileAction;
 use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::v1_0_0::Activity as FileActivity;
@@ -10,7 +10,7 @@ use crate::gateway_adapter::serde::specification_serializer::assurance_procedure
 #[test]
 fn from_success() {
     let procedure = generate_valid_assurance_procedure();
-    let file = AssuranceProcedureFile::from(&procedure);
+    let file = is_ok!(AssuranceProcedureFile::try_from(&procedure));
 
     assert_eq!(file.api_version, "1.0.0");
     assert_eq!(file.kind, "AssuranceProcedure");
@@ -95,7 +95,7 @@ fn try_to_success() {
     let action_1 = action_1.unwrap();
     assert_eq!(action_1.short.value, "Short Desc - A1".to_string());
     assert_eq!(action_1.description.value, "Long Desc - Action 1".to_string());
-    assert_eq!(action_1.test.as_str(), "test/for/action_1.txt".to_string());
+    assert_eq!(action_1.test.as_ref().unwrap().as_str(), "test/for/action_1.txt".to_string());
     assert_eq!(action_1.evidence.as_str(), "evidence/for/action_1.txt".to_string());
 
     let action_2 = activity_1.actions.iter().find(|&action| action.name.value == "action-2");
@@ -103,7 +103,7 @@ fn try_to_success() {
     let action_2 = action_2.unwrap();
     assert_eq!(action_2.short.value, "Short Desc - A2".to_string());
     assert_eq!(action_2.description.value, "Long Desc - Action 2".to_string());
-    assert_eq!(action_2.test.as_str(), "test/for/action_2.txt".to_string());
+    assert_eq!(action_2.test.as_ref().unwrap().as_str(), "test/for/action_2.txt".to_string());
     assert_eq!(action_2.evidence.as_str(), "evidence/for/action_2.txt".to_string());
 
     let activity_2 = result.activities.list.iter().find(|&activity| activity.name.value == "activity-2");
@@ -118,7 +118,7 @@ fn try_to_success() {
     let action_3 = action_3.unwrap();
     assert_eq!(action_3.short.value, "Short Desc - A3".to_string());
     assert_eq!(action_3.description.value, "Long Desc - Action 3".to_string());
-    assert_eq!(action_3.test.as_str(), "test/for/action_3.txt".to_string());
+    assert_eq!(action_3.test.as_ref().unwrap().as_str(), "test/for/action_3.txt".to_string());
     assert_eq!(action_3.evidence.as_str(), "evidence/for/action_3.txt".to_string());
 
     let action_4 = activity_2.actions.iter().find(|&action| action.name.value == "action-4");
@@ -126,10 +126,38 @@ fn try_to_success() {
     let action_4 = action_4.unwrap();
     assert_eq!(action_4.short.value, "Short Desc - A4".to_string());
     assert_eq!(action_4.description.value, "Long Desc - Action 4".to_string());
-    assert_eq!(action_4.test.as_str(), "test/for/action_4.txt".to_string());
+    assert_eq!(action_4.test.as_ref().unwrap().as_str(), "test/for/action_4.txt".to_string());
     assert_eq!(action_4.evidence.as_str(), "evidence/for/action_4.txt".to_string());
 }
 
+#[test]
+fn try_from_later_fields_error() {
+    let activity = Activity::new("activity-1", "Short Desc - A1", "Long Desc - Activity 1").unwrap()
+        .add(Action::builder().name("action-1")
+            .short_description("Short Desc - A1")
+            .long_description("Long Desc - Action 1")
+            .evidence_file_path("evidence/for/action_1.txt")
+            .attestation()
+            .try_build().unwrap())
+        .add(Action::builder().name("action-2")
+            .short_description("Short Desc - A2")
+            .long_description("Long Desc - Action 2")
+            .test_file_path("test/for/action_2.txt")
+            .evidence_file_path("evidence/for/action_2.txt")
+            .severity("high")
+            .try_build().unwrap());
+    let procedure = AssuranceProcedure::builder()
+        .api_version("1.0.0")
+        .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+        .add_activity(&activity)
+        .try_build().unwrap();
+
+    let result = AssuranceProcedureFile::try_from(&procedure).map(|_| ());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The procedure 'nrn:sourcecode::example' can not be written with the v1.0.0 schema, because it uses the fields [severity, type], which were added in a later apiVersion.");
+}
+
 #[test]
 fn try_to_handles_invalid_api_version_error() {
     let file = generate_invalid_assurance_procedure_file_bad_api_version();
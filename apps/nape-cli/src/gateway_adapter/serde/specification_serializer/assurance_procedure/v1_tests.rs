@@ -0,0 +1,225 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::api_version::APIVersion;
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::v1::{AssuranceProcedureFile, Include as FileInclude, Input as FileInput, Procedure};
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::v1::Action as FileAction;
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::v1::Activity as FileActivity;
+
+#[test]
+fn from_success() {
+    let procedure = generate_valid_assurance_procedure();
+    let file = is_ok!(AssuranceProcedureFile::try_from(&procedure));
+
+    assert_eq!(file.api_version, "1.6.0");
+    assert_eq!(file.activities[0].actions[0].action_type, None);
+    assert_eq!(file.activities[0].actions[0].test, Some("./activity/peer-review.py".to_string()));
+    assert_eq!(file.activities[0].actions[1].action_type, Some("attestation".to_string()));
+    assert_eq!(file.activities[0].actions[1].test, None);
+    assert_eq!(file.activities[0].actions[1].evidence, "./evidence/access-review/attestation.yaml");
+}
+
+#[test]
+fn from_omits_empty_optional_fields_success() {
+    let procedure = generate_valid_assurance_procedure();
+    let file = is_ok!(AssuranceProcedureFile::try_from(&procedure));
+
+    let yaml = serde_yaml::to_string(&file).unwrap();
+
+    assert_eq!(yaml.matches("type:").count(), 1);
+    assert_eq!(yaml.matches("test:").count(), 1);
+}
+
+#[test]
+fn try_from_writes_api_version_success() {
+    let mut procedure = generate_valid_assurance_procedure();
+    procedure.activities.list[0].actions.retain(|action| !action.is_attestation());
+    procedure.api_version = APIVersion::new(1, 1, 0);
+
+    let file = is_ok!(AssuranceProcedureFile::try_from(&procedure));
+
+    assert_eq!(file.api_version, "1.1.0");
+}
+
+#[test]
+fn try_from_field_added_later_error() {
+    let mut procedure = generate_valid_assurance_procedure();
+    procedure.api_version = APIVersion::new(1, 5, 0);
+
+    let result = AssuranceProcedureFile::try_from(&procedure).map(|_| ());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The procedure 'nrn:procedure:example' can not be written with the v1.5.0 schema, because the field 'type' requires an apiVersion of 1.6.0 or later.");
+}
+
+#[test]
+fn try_to_success() {
+    let file = generate_valid_assurance_procedure_file(Some("attestation"), None);
+    let result = file.try_to();
+
+    let result = is_ok!(result);
+
+    assert_eq!(result.api_version.as_string(), "1.6.0".to_string());
+    assert!(!result.activities.list[0].actions[0].is_attestation());
+    assert!(result.activities.list[0].actions[1].is_attestation());
+    assert_eq!(result.activities.list[0].actions[1].test, None);
+}
+
+#[test]
+fn try_to_handles_unsupported_type_error() {
+    let file = generate_valid_assurance_procedure_file(Some("manual"), None);
+    let result = file.try_to();
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.6.0 Assurance Procedure File. There is an issue with an Action. The type 'manual' of the action 'access-review' is not supported. Must be one of: [attestation]." );
+}
+
+#[test]
+fn try_to_handles_attestation_with_test_error() {
+    let file = generate_valid_assurance_procedure_file(Some("attestation"), Some("test/for/access_review.py"));
+    let result = file.try_to();
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.6.0 Assurance Procedure File. There is an issue with an Action. " );
+}
+
+#[test]
+fn try_to_handles_missing_test_error() {
+    let file = generate_valid_assurance_procedure_file(None, None);
+    let result = file.try_to();
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.6.0 Assurance Procedure File. There is an issue with an Action. " );
+}
+
+#[test]
+fn try_to_reads_earlier_minor_version_success() {
+    let mut file = generate_valid_assurance_procedure_file(None, Some("test/for/access_review.py"));
+    file.api_version = "1.1.0".to_string();
+    file.activities[0].actions[0].severity = Some("high".to_string());
+    file.activities[0].actions[0].tags = vec!["security".to_string()];
+    let result = file.try_to();
+
+    let result = is_ok!(result);
+
+    assert_eq!(result.api_version.as_string(), "1.1.0".to_string());
+    assert_eq!(result.activities.list[0].actions[0].tags[0].value, "security");
+}
+
+#[test]
+fn try_to_reads_fields_from_their_version_success() {
+    let mut file = generate_valid_assurance_procedure_file(None, Some("test/for/access_review.py"));
+    file.api_version = "1.5.0".to_string();
+    file.activities[0].when = Some("meta.pci == true".to_string());
+    file.activities[0].actions[1].depends_on = vec!["peer-review".to_string()];
+    file.activities[0].actions[1].inputs = vec![FileInput { name: "runtime".to_string(), evidence: "evidence/for/runtime.json".to_string() }];
+    let result = file.try_to();
+
+    let result = is_ok!(result);
+
+    assert!(result.activities.list[0].when.is_some());
+    assert_eq!(result.activities.list[0].actions[1].depends_on[0].value, "peer-review");
+    assert_eq!(result.activities.list[0].actions[1].inputs[0].name.value, "runtime");
+}
+
+#[test]
+fn try_to_handles_include_before_its_version_error() {
+    let mut file = generate_valid_assurance_procedure_file(None, Some("test/for/access_review.py"));
+    file.api_version = "1.1.0".to_string();
+    file.includes = vec![FileInclude { repository: "https://github.com/example/procedures.git".to_string(), directory: "shared".to_string(), revision: None, activities: vec!["branch-protection".to_string()] }];
+    let result = file.try_to();
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.1.0 Assurance Procedure File. The field 'include' requires an apiVersion of 1.2.0 or later." );
+}
+
+#[test]
+fn try_to_handles_when_before_its_version_error() {
+    let mut file = generate_valid_assurance_procedure_file(None, Some("test/for/access_review.py"));
+    file.api_version = "1.2.0".to_string();
+    file.activities[0].actions[0].when = Some("subject.nid == sourcecode".to_string());
+    let result = file.try_to();
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.2.0 Assurance Procedure File. The field 'when' requires an apiVersion of 1.3.0 or later." );
+}
+
+#[test]
+fn try_to_handles_depends_on_before_its_version_error() {
+    let mut file = generate_valid_assurance_procedure_file(None, Some("test/for/access_review.py"));
+    file.api_version = "1.3.0".to_string();
+    file.activities[0].actions[1].depends_on = vec!["peer-review".to_string()];
+    let result = file.try_to();
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.3.0 Assurance Procedure File. The field 'depends_on' requires an apiVersion of 1.4.0 or later." );
+}
+
+#[test]
+fn try_to_handles_inputs_before_its_version_error() {
+    let mut file = generate_valid_assurance_procedure_file(None, Some("test/for/access_review.py"));
+    file.api_version = "1.4.0".to_string();
+    file.activities[0].actions[1].inputs = vec![FileInput { name: "runtime".to_string(), evidence: "evidence/for/runtime.json".to_string() }];
+    let result = file.try_to();
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.4.0 Assurance Procedure File. The field 'inputs' requires an apiVersion of 1.5.0 or later." );
+}
+
+#[test]
+fn try_to_handles_type_before_its_version_error() {
+    let mut file = generate_valid_assurance_procedure_file(Some("attestation"), None);
+    file.api_version = "1.5.0".to_string();
+    let result = file.try_to();
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.5.0 Assurance Procedure File. The field 'type' requires an apiVersion of 1.6.0 or later." );
+}
+
+#[test]
+fn try_to_handles_unknown_dependency_error() {
+    let mut file = generate_valid_assurance_procedure_file(None, Some("test/for/access_review.py"));
+    file.activities[0].actions[1].depends_on = vec!["unknown-action".to_string()];
+    let result = file.try_to();
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.6.0 Assurance Procedure File. The AssuranceProcedure could not be created: The dependencies of the actions are not valid. " );
+}
+
+#[test]
+fn try_to_handles_invalid_activity_condition_error() {
+    let mut file = generate_valid_assurance_procedure_file(None, Some("test/for/access_review.py"));
+    file.activities[0].when = Some("pci == true".to_string());
+    let result = file.try_to();
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Failed to extract the data from the v1.6.0 Assurance Procedure File. There is an issue with an Activity. There is an issue with the condition 'pci == true'. " );
+}
+
+fn generate_valid_assurance_procedure() -> AssuranceProcedure {
+    let peer_review = Action::builder().name("peer-review")
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path("./activity/peer-review.py")
+        .evidence_file_path("./evidence/peer-review.json")
+        .try_build().unwrap();
+    let access_review = Action::builder().name("access-review")
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .evidence_file_path("./evidence/access-review/attestation.yaml")
+        .attestation()
+        .try_build().unwrap();
+    let activity = Activity::new("review", "Short Desc - A1", "Long Desc - Activity 1").unwrap()
+        .add(peer_review)
+        .add(access_review);
+
+    AssuranceProcedure::builder()
+        .api_version("1.6.0")
+        .procedure_info("nrn:procedure:example", "A Short Desc.", "This is an example procedure")
+        .add_activity(&activity)
+        .try_build().unwrap()
+}
+
+fn generate_valid_assurance_procedure_file(access_review_type: Option<&str>, access_review_test: Option<&str>) -> AssuranceProcedureFile {
+
+    let action1 = FileAction {  name: "peer-review".to_string(),  short: "Short Desc - A1".to_string(),  description: "Long Desc - Action 1".to_string(), action_type: None, test: Some("test/for/peer_review.py".to_string()),  evidence: "evidence/for/peer_review.json".to_string(), severity: None, tags: vec![], owner: None, when: None, depends_on: vec![], inputs: vec![] };
+    let action2 = FileAction {  name: "access-review".to_string(),  short: "Short Desc - A2".to_string(),  description: "Long Desc - Action 2".to_string(), action_type: access_review_type.map(str::to_string), test: access_review_test.map(str::to_string),  evidence: "evidence/for/access_review.yaml".to_string(), severity: None, tags: vec![], owner: None, when: None, depends_on: vec![], inputs: vec![] };
+    let activity1 = FileActivity {  name: "review".to_string(),  short: "Short Desc - A1".to_string(),  description: "Long Desc - Activity 1".to_string(), included_from: None, when: None, actions: vec![action1, action2] };
+
+    let procedure = Procedure { nrn: "nrn:procedure:example".to_string(),  short: "A Short Desc.".to_string(),  description: "This is an example procedure".to_string() };
+
+    AssuranceProcedureFile {  api_version: "1.6.0".to_string(),  kind: "AssuranceProcedure".to_string(),  procedure, includes: vec![], activities: vec![activity1] }
+
+}
@@ -1,7 +1,11 @@
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use nape_kernel::values::specification::assurance_report::action::Action;
+use nape_kernel::values::specification::assurance_report::attestation::{Attestation, ATTESTED};
+use nape_kernel::values::specification::assurance_report::finding::Finding;
+use nape_kernel::values::specification::assurance_report::outcome_policy::OutcomePolicy;
 use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use nape_kernel::values::specification::assurance_report::signed_input::SignedInput;
 use nape_kernel::values::specification::traits::{AssuranceReport};
 use nape_kernel::values::specification::v1_0_0::assurance_report::AssuranceReportV1;
 
@@ -43,23 +47,133 @@ pub struct ReportFileSummary {
     pub pass: u32,
     pub fail: u32,
     pub inconclusive: u32,
+    #[serde(default)]
+    pub waived: u32,
+    #[serde(default)]
+    pub not_applicable: u32,
+    #[serde(default)]
+    pub skipped: u32,
+    #[serde(default)]
+    pub failures_by_severity: ReportFileFailuresBySeverity,
+    #[serde(default = "default_outcome_policy")]
+    pub outcome_policy: String,
     pub outcome: String,
 }
 
+/// Reports written before outcome policies were introduced failed on any failed action, which is the default [`OutcomePolicy`].
+fn default_outcome_policy() -> String {
+    OutcomePolicy::default().to_string()
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ReportFileFailuresBySeverity {
+    pub critical: u32,
+    pub high: u32,
+    pub medium: u32,
+    pub low: u32,
+    pub unrated: u32,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ReportFileActivity {
     pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub included_from: Option<ReportFileIncludedFrom>,
     #[serde(rename = "action")]
     pub actions: Vec<ReportFileAction>,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct ReportFileIncludedFrom {
+    pub repository: String,
+    pub directory: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub revision: Option<String>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct ReportFileAction {
     pub name: String,
     pub outcome: String,
     pub reason: String,
-    pub test_file: ReportFileSignedFile,
-    pub evidence_file: ReportFileSignedFile
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub waiver: Option<ReportFileWaiver>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exclusion: Option<ReportFileExclusion>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_file: Option<ReportFileSignedFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attestation: Option<ReportFileAttestation>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence_file: Option<ReportFileSignedFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence_files: Option<Vec<ReportFileSignedFile>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inputs: Option<Vec<ReportFileInput>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub findings: Option<Vec<ReportFileFinding>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub observations: Option<Vec<ReportFileObservation>>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReportFileInput {
+    pub name: String,
+    pub evidence_file: ReportFileSignedFile,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence_files: Option<Vec<ReportFileSignedFile>>
+}
+
+/// The [`ReportFileAttestation`] is recorded in place of the test file of an action whose outcome was attested by a person, and is marked as [`ATTESTED`].
+#[derive(Serialize, Deserialize)]
+pub struct ReportFileAttestation {
+    pub marker: String,
+    pub outcome: String,
+    pub attested_by: String,
+    pub attested_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub supporting_files: Option<Vec<ReportFileSignedFile>>
+}
+
+/// The [`ReportFileFinding`] is a finding the test reported in the evidence of an action, and where in the evidence it was found.
+#[derive(Serialize, Deserialize)]
+pub struct ReportFileFinding {
+    pub message: String,
+    pub severity: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub evidence: Option<ReportFileEvidenceLocation>
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReportFileEvidenceLocation {
+    pub file: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<u32>
+}
+
+/// The [`ReportFileObservation`] is a value the test measured in the evidence of an action.  Observations are a list, rather than a map, so they are printed in the order the test reported them.
+#[derive(Serialize, Deserialize)]
+pub struct ReportFileObservation {
+    pub name: String,
+    pub value: String
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReportFileWaiver {
+    pub justification: String,
+    pub approver: String,
+    pub expires: String
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReportFileExclusion {
+    pub justification: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>
 }
 
 #[derive(Serialize, Deserialize)]
@@ -99,7 +213,7 @@ fn extract_metadata(report: &AssuranceReportV1) -> Option<HashMap<String, String
 
 fn extract_subject(report: &AssuranceReportV1) -> ReportFileSubject {
     ReportFileSubject {
-        urn: report.subject().nrn.value.clone(),
+        urn: report.subject().nrn.to_string(),
         id: report.subject().id.value.clone()
     }
 }
@@ -119,6 +233,17 @@ fn extract_summary(report: &AssuranceReportV1) -> ReportFileSummary {
         pass: report.summary().pass.clone(),
         fail: report.summary().fail.clone(),
         inconclusive: report.summary().inconclusive.clone(),
+        waived: report.summary().waived,
+        not_applicable: report.summary().not_applicable,
+        skipped: report.summary().skipped,
+        failures_by_severity: ReportFileFailuresBySeverity {
+            critical: report.summary().failures_by_severity.critical,
+            high: report.summary().failures_by_severity.high,
+            medium: report.summary().failures_by_severity.medium,
+            low: report.summary().failures_by_severity.low,
+            unrated: report.summary().failures_by_severity.unrated
+        },
+        outcome_policy: report.summary().outcome_policy.to_string(),
         outcome: report.summary().outcome.to_string()
     }
 }
@@ -128,6 +253,11 @@ fn extract_activities(report: &AssuranceReportV1) -> Vec<ReportFileActivity> {
     for activity in report.activities().list() {
         let extracted_activity =     ReportFileActivity {
             name: activity.name.value.clone(),
+            included_from: activity.source().map(|source| ReportFileIncludedFrom {
+                repository: source.repository.clone(),
+                directory: source.directory.clone(),
+                revision: source.revision.clone()
+            }),
             actions: extract_actions(&activity.actions)
         };
         activities.push(extracted_activity);
@@ -142,14 +272,79 @@ fn extract_actions(actions: &Vec<Action>) -> Vec<ReportFileAction> {
             name: action.name().value.clone(),
             outcome: action.outcome().to_string(),
             reason: action.reason().value.clone(),
-            test_file: extract_signed_file(&action.test_file()),
-            evidence_file: extract_signed_file(&action.evidence_file())
+            severity: action.severity().map(|severity| severity.to_string()),
+            waiver: action.waiver().map(|waiver| ReportFileWaiver {
+                justification: waiver.justification.value.clone(),
+                approver: waiver.approver.value.clone(),
+                expires: waiver.expires.to_string()
+            }),
+            exclusion: action.exclusion().map(|exclusion| ReportFileExclusion {
+                justification: exclusion.justification.value.clone(),
+                condition: exclusion.condition.as_ref().map(|condition| condition.to_string())
+            }),
+            test_file: action.test_file().map(extract_signed_file),
+            attestation: action.attestation().map(extract_attestation),
+            evidence_file: action.evidence_file().map(extract_signed_file),
+            evidence_files: match action.evidence_files().is_empty() {
+                true => None,
+                false => Some(action.evidence_files().iter().map(extract_signed_file).collect())
+            },
+            inputs: match action.inputs().is_empty() {
+                true => None,
+                false => Some(action.inputs().iter().map(extract_input).collect())
+            },
+            findings: match action.findings().is_empty() {
+                true => None,
+                false => Some(action.findings().iter().map(extract_finding).collect())
+            },
+            observations: match action.observations().is_empty() {
+                true => None,
+                false => Some(action.observations().data.iter()
+                    .map(|(name, value)| ReportFileObservation { name: name.value.clone(), value: value.value.clone() })
+                    .collect())
+            }
         };
         report_actions.push(report_action);
     }
     report_actions
 }
 
+fn extract_input(input: &SignedInput) -> ReportFileInput {
+    ReportFileInput {
+        name: input.name.value.clone(),
+        evidence_file: extract_signed_file(&input.evidence_file),
+        evidence_files: match input.evidence_files.is_empty() {
+            true => None,
+            false => Some(input.evidence_files.iter().map(extract_signed_file).collect())
+        }
+    }
+}
+
+fn extract_attestation(attestation: &Attestation) -> ReportFileAttestation {
+    ReportFileAttestation {
+        marker: ATTESTED.to_string(),
+        outcome: attestation.outcome.to_string(),
+        attested_by: attestation.attested_by.value.clone(),
+        attested_at: attestation.attested_at.to_utc_string(),
+        supporting_files: match attestation.supporting_files.is_empty() {
+            true => None,
+            false => Some(attestation.supporting_files.iter().map(extract_signed_file).collect())
+        }
+    }
+}
+
+fn extract_finding(finding: &Finding) -> ReportFileFinding {
+    ReportFileFinding {
+        message: finding.message.value.clone(),
+        severity: finding.severity.to_string(),
+        evidence: finding.location.as_ref().map(|location| ReportFileEvidenceLocation {
+            file: location.file.to_string(),
+            start_line: location.lines.as_ref().map(|lines| lines.start),
+            end_line: location.lines.as_ref().map(|lines| lines.end)
+        })
+    }
+}
+
 fn extract_signed_file(file: &SignedFile) -> ReportFileSignedFile {
     ReportFileSignedFile {
         file: file.file().to_string(),
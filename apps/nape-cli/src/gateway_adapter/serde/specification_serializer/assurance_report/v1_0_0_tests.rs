@@ -1,6 +1,18 @@
 use nape_kernel::values::specification::assurance_report::action::Action;
 use nape_kernel::values::specification::assurance_report::activity::Activity;
+use nape_kernel::values::specification::assurance_report::attestation::Attestation;
+use nape_kernel::values::specification::assurance_report::exclusion::Exclusion;
+use nape_kernel::values::specification::assurance_report::finding::{EvidenceLocation, Finding, LineRange};
+use nape_kernel::values::specification::assurance_report::observations::Observations;
+use nape_kernel::values::specification::assurance_procedure::condition::Condition;
+use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use nape_kernel::values::specification::assurance_report::signed_input::SignedInput;
+use nape_kernel::values::specification::name::Name;
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureType};
+use nape_kernel::values::specification::assurance_report::waiver::Waiver;
+use nape_kernel::values::specification::assurance_procedure::include::Source;
 use nape_kernel::values::specification::v1_0_0::assurance_report::AssuranceReportV1;
+use nape_kernel::values::time::start_time::StartTime;
 use crate::gateway_adapter::serde::specification_serializer::assurance_report::v1_0_0::AssuranceReportFileV1;
 
 
@@ -8,12 +20,20 @@ use crate::gateway_adapter::serde::specification_serializer::assurance_report::v
 fn success() {
 
     let action1 = Action::builder().name("action-1").outcome("pass").reason("Test passed").test_file_path("test_file.txt").test_file_signature("SHA256[theaction1testsig]").evidence_file_path("evidence_file.txt").evidence_file_signature("SHA256[theaction1evidencesig]").try_build().unwrap();
-    let action2 = Action::builder().name("action-2").outcome("fail").reason("Test failed").test_file_path("test_file.txt").test_file_signature("SHA256[theaction2testsig]").evidence_file_path("evidence_file.txt").evidence_file_signature("SHA256[theaction2evidencesig]").try_build().unwrap();
-    let action3 = Action::builder().name("action-3").outcome("inconclusive").reason("Test inconclusive").test_file_path("test_file.txt").test_file_signature("SHA256[theaction3testsig]").evidence_file_path("evidence_file.txt").evidence_file_signature("SHA256[theaction3evidencesig]").try_build().unwrap();
-    let action4 = Action::builder().name("action-4").outcome("pass").reason("Test passed").test_file_path("test_file.txt").test_file_signature("SHA256[theaction4testsig]").evidence_file_path("evidence_file.txt").evidence_file_signature("SHA256[theaction4evidencesig]").try_build().unwrap();
+    let action2 = Action::builder().name("action-2").outcome("fail").reason("Test failed").test_file_path("test_file.txt").test_file_signature("SHA256[theaction2testsig]").evidence_file_path("evidence_file.txt").evidence_file_signature("SHA256[theaction2evidencesig]").severity("high").try_build().unwrap();
+    let evidence_file = SignedFile::new("evidence_directory/app/deploy.yaml", &Signature::try_new(SignatureType::SHA256, "theaction3deploysig").unwrap()).unwrap();
+    let input_file = SignedFile::new("evidence_directory/runtime.json", &Signature::try_new(SignatureType::SHA256, "theaction3runtimesig").unwrap()).unwrap();
+    let input = SignedInput::new(&Name::try_from("runtime").unwrap(), &input_file, &[]);
+    let action3 = Action::builder().name("action-3").outcome("inconclusive").reason("Test inconclusive").test_file_path("test_file.txt").test_file_signature("SHA256[theaction3testsig]").evidence_file_path("evidence_directory").evidence_file_signature("SHA256[theaction3evidencesig]").add_evidence_file(&evidence_file).add_input(&input).try_build().unwrap();
+    let waiver = Waiver::try_new("action-4", "A compensating control is in place.", "Jane Doe", "2024-06-30").unwrap();
+    let action4 = Action::builder().name("action-4").outcome("waived").reason("Test failed").test_file_path("test_file.txt").test_file_signature("SHA256[theaction4testsig]").evidence_file_path("evidence_file.txt").evidence_file_signature("SHA256[theaction4evidencesig]").use_waiver(&waiver).try_build().unwrap();
+    let exclusion = Exclusion::try_new("action-5", "The subject has no deployments.").unwrap()
+        .with_condition(&Condition::try_from("meta.deploys == true").unwrap());
+    let action5 = Action::builder().name("action-5").outcome("not_applicable").reason("The subject has no deployments.").test_file_path("test_file.txt").test_file_signature("SHA256[theaction5testsig]").use_exclusion(&exclusion).try_build().unwrap();
 
-    let activity1 = Activity::builder().name("activity-1").add(&action1).add(&action2).try_build().unwrap();
-    let activity2 = Activity::builder().name("activity-2").add(&action3).add(&action4).try_build().unwrap();
+    let activity1 = Activity::builder().name("activity-1").add(&action1).add(&action2).add(&action5).try_build().unwrap();
+    let source = Source::try_new("https://github.com/nape/shared-procedures.git", "branch_protection", Some("v1.0.0")).unwrap();
+    let activity2 = Activity::builder().name("activity-2").add(&action3).add(&action4).use_source(&source).try_build().unwrap();
 
     // Assemble
     let report = AssuranceReportV1::builder()
@@ -23,6 +43,7 @@ fn success() {
         .procedure_repository("github.com/nape/processes")
         .procedure_directory("rust_ci/sourcecode_integration")
         .add_activity(&activity1).add_activity(&activity2)
+        .outcome_policy("critical,high")
         // tod - add additinoal info
         .try_build()
         .unwrap();
@@ -41,52 +62,211 @@ fn success() {
     assert_eq!(report_file.procedure.directory, "rust_ci/sourcecode_integration");
 
     assert_eq!(report_file.summary.activity_count, 2);
-    assert_eq!(report_file.summary.action_count, 4);
+    assert_eq!(report_file.summary.action_count, 5);
     assert_eq!(report_file.summary.actions_run, 4);
-    assert_eq!(report_file.summary.pass, 2);
+    assert_eq!(report_file.summary.pass, 1);
     assert_eq!(report_file.summary.fail, 1);
     assert_eq!(report_file.summary.inconclusive, 1);
+    assert_eq!(report_file.summary.waived, 1);
+    assert_eq!(report_file.summary.not_applicable, 1);
+    assert_eq!(report_file.summary.skipped, 0);
+    assert_eq!(report_file.summary.failures_by_severity.critical, 0);
+    assert_eq!(report_file.summary.failures_by_severity.high, 1);
+    assert_eq!(report_file.summary.failures_by_severity.medium, 0);
+    assert_eq!(report_file.summary.failures_by_severity.low, 0);
+    assert_eq!(report_file.summary.failures_by_severity.unrated, 0);
+    assert_eq!(report_file.summary.outcome_policy, "critical,high");
     assert_eq!(report_file.summary.outcome, "inconclusive");
 
     let actual_activity1 = report_file.activities.get(0).unwrap();
     let actual_activity2 = report_file.activities.get(1).unwrap();
     assert_eq!(actual_activity1.name, "activity-1");
     assert_eq!(actual_activity2.name, "activity-2");
+    assert!(actual_activity1.included_from.is_none());
+    let included_from = actual_activity2.included_from.as_ref().unwrap();
+    assert_eq!(included_from.repository, "https://github.com/nape/shared-procedures.git");
+    assert_eq!(included_from.directory, "branch_protection");
+    assert_eq!(included_from.revision, Some("v1.0.0".to_string()));
 
     let actual_action1 = actual_activity1.actions.get(0).unwrap();
     assert_eq!(actual_action1.name, "action-1");
     assert_eq!(actual_action1.outcome, "pass");
     assert_eq!(actual_action1.reason, "Test passed");
-    assert_eq!(actual_action1.test_file.file, "test_file.txt");
-    assert_eq!(actual_action1.test_file.signature, "SHA256[theaction1testsig]");
-    assert_eq!(actual_action1.evidence_file.file, "evidence_file.txt");
-    assert_eq!(actual_action1.evidence_file.signature, "SHA256[theaction1evidencesig]");
+    assert_eq!(actual_action1.severity, None);
+    assert!(actual_action1.waiver.is_none());
+    assert_eq!(actual_action1.test_file.as_ref().unwrap().file, "test_file.txt");
+    assert_eq!(actual_action1.test_file.as_ref().unwrap().signature, "SHA256[theaction1testsig]");
+    assert_eq!(actual_action1.evidence_file.as_ref().unwrap().file, "evidence_file.txt");
+    assert_eq!(actual_action1.evidence_file.as_ref().unwrap().signature, "SHA256[theaction1evidencesig]");
+    assert!(actual_action1.evidence_files.is_none());
+    assert!(actual_action1.inputs.is_none());
+    assert!(actual_action1.findings.is_none());
+    assert!(actual_action1.observations.is_none());
 
     let actual_action2 = actual_activity1.actions.get(1).unwrap();
     assert_eq!(actual_action2.name, "action-2");
     assert_eq!(actual_action2.outcome, "fail");
     assert_eq!(actual_action2.reason, "Test failed");
-    assert_eq!(actual_action2.test_file.file, "test_file.txt");
-    assert_eq!(actual_action2.test_file.signature, "SHA256[theaction2testsig]");
-    assert_eq!(actual_action2.evidence_file.file, "evidence_file.txt");
-    assert_eq!(actual_action2.evidence_file.signature, "SHA256[theaction2evidencesig]");
+    assert_eq!(actual_action2.severity, Some("high".to_string()));
+    assert_eq!(actual_action2.test_file.as_ref().unwrap().file, "test_file.txt");
+    assert_eq!(actual_action2.test_file.as_ref().unwrap().signature, "SHA256[theaction2testsig]");
+    assert_eq!(actual_action2.evidence_file.as_ref().unwrap().file, "evidence_file.txt");
+    assert_eq!(actual_action2.evidence_file.as_ref().unwrap().signature, "SHA256[theaction2evidencesig]");
 
     let actual_action3 = actual_activity2.actions.get(0).unwrap();
     assert_eq!(actual_action3.name, "action-3");
     assert_eq!(actual_action3.outcome, "inconclusive");
     assert_eq!(actual_action3.reason, "Test inconclusive");
-    assert_eq!(actual_action3.test_file.file, "test_file.txt");
-    assert_eq!(actual_action3.test_file.signature, "SHA256[theaction3testsig]");
-    assert_eq!(actual_action3.evidence_file.file, "evidence_file.txt");
-    assert_eq!(actual_action3.evidence_file.signature, "SHA256[theaction3evidencesig]");
+    assert_eq!(actual_action3.test_file.as_ref().unwrap().file, "test_file.txt");
+    assert_eq!(actual_action3.test_file.as_ref().unwrap().signature, "SHA256[theaction3testsig]");
+    assert_eq!(actual_action3.evidence_file.as_ref().unwrap().file, "evidence_directory");
+    assert_eq!(actual_action3.evidence_file.as_ref().unwrap().signature, "SHA256[theaction3evidencesig]");
+    let actual_action3_files = actual_action3.evidence_files.as_ref().unwrap();
+    assert_eq!(actual_action3_files.len(), 1);
+    assert_eq!(actual_action3_files[0].file, "evidence_directory/app/deploy.yaml");
+    assert_eq!(actual_action3_files[0].signature, "SHA256[theaction3deploysig]");
+    let actual_action3_inputs = actual_action3.inputs.as_ref().unwrap();
+    assert_eq!(actual_action3_inputs.len(), 1);
+    assert_eq!(actual_action3_inputs[0].name, "runtime");
+    assert_eq!(actual_action3_inputs[0].evidence_file.file, "evidence_directory/runtime.json");
+    assert_eq!(actual_action3_inputs[0].evidence_file.signature, "SHA256[theaction3runtimesig]");
+    assert!(actual_action3_inputs[0].evidence_files.is_none());
 
     let actual_action4 = actual_activity2.actions.get(1).unwrap();
     assert_eq!(actual_action4.name, "action-4");
-    assert_eq!(actual_action4.outcome, "pass");
-    assert_eq!(actual_action4.reason, "Test passed");
-    assert_eq!(actual_action4.test_file.file, "test_file.txt");
-    assert_eq!(actual_action4.test_file.signature, "SHA256[theaction4testsig]");
-    assert_eq!(actual_action4.evidence_file.file, "evidence_file.txt");
-    assert_eq!(actual_action4.evidence_file.signature, "SHA256[theaction4evidencesig]");
+    assert_eq!(actual_action4.outcome, "waived");
+    assert_eq!(actual_action4.reason, "Test failed");
+    assert_eq!(actual_action4.waiver.as_ref().unwrap().justification, "A compensating control is in place.");
+    assert_eq!(actual_action4.waiver.as_ref().unwrap().approver, "Jane Doe");
+    assert_eq!(actual_action4.waiver.as_ref().unwrap().expires, "2024-06-30");
+    assert_eq!(actual_action4.test_file.as_ref().unwrap().file, "test_file.txt");
+    assert_eq!(actual_action4.test_file.as_ref().unwrap().signature, "SHA256[theaction4testsig]");
+    assert_eq!(actual_action4.evidence_file.as_ref().unwrap().file, "evidence_file.txt");
+    assert_eq!(actual_action4.evidence_file.as_ref().unwrap().signature, "SHA256[theaction4evidencesig]");
+
+    let actual_action5 = actual_activity1.actions.get(2).unwrap();
+    assert_eq!(actual_action5.outcome, "not_applicable");
+    assert_eq!(actual_action5.exclusion.as_ref().unwrap().justification, "The subject has no deployments.");
+    assert_eq!(actual_action5.exclusion.as_ref().unwrap().condition, Some("meta.deploys == true".to_string()));
+    assert_eq!(actual_action5.test_file.as_ref().unwrap().signature, "SHA256[theaction5testsig]");
+    assert!(actual_action5.evidence_file.is_none());
+}
+
+#[test]
+fn success_with_attestation() {
+
+    let supporting_file = SignedFile::new("evidence/access-review/minutes.pdf", &Signature::try_new(SignatureType::SHA256, "theminutessig").unwrap()).unwrap();
+    let attestation = Attestation::try_new("access-review", "pass", "All accounts were reviewed.", "jane", &StartTime::from(1719326666000)).unwrap()
+        .with_supporting_file(&supporting_file);
+    let action = Action::builder().name("access-review").outcome("pass").reason("All accounts were reviewed.").evidence_file_path("evidence/access-review/attestation.yaml").evidence_file_signature("SHA256[thestatementsig]").use_attestation(&attestation).try_build().unwrap();
+    let activity = Activity::builder().name("review").add(&action).try_build().unwrap();
+    let report = AssuranceReportV1::builder()
+        .subject_nrn("nrn:sourcecode:nape:nape-cli")
+        .subject_id("9f3f183a300501b53e2fa04f48acb4bd478d6414")
+        .procedure_repository("github.com/nape/processes")
+        .procedure_directory("rust_ci/sourcecode_integration")
+        .add_activity(&activity)
+        .try_build()
+        .unwrap();
+
+    let report_file = AssuranceReportFileV1::from(&report);
+
+    let actual_action = &report_file.activities[0].actions[0];
+    assert!(actual_action.test_file.is_none());
+    assert_eq!(actual_action.evidence_file.as_ref().unwrap().file, "evidence/access-review/attestation.yaml");
+    let actual_attestation = actual_action.attestation.as_ref().unwrap();
+    assert_eq!(actual_attestation.marker, "ATTESTED");
+    assert_eq!(actual_attestation.outcome, "pass");
+    assert_eq!(actual_attestation.attested_by, "jane");
+    assert_eq!(actual_attestation.attested_at, "2024-06-25T14:44:26Z");
+    assert_eq!(actual_attestation.supporting_files.as_ref().unwrap()[0].file, "evidence/access-review/minutes.pdf");
+    assert_eq!(actual_attestation.supporting_files.as_ref().unwrap()[0].signature, "SHA256[theminutessig]");
+
+    let yaml = serde_yaml::to_string(&report_file).unwrap();
+    assert!(!yaml.contains("test_file:"));
+    assert!(yaml.contains("marker: ATTESTED"));
 }
 
+#[test]
+fn success_with_findings_and_observations() {
+
+    let root_finding = Finding::try_new("The container runs as root.", "critical").unwrap()
+        .with_location(&EvidenceLocation::try_new("manifests/deploy.yaml").unwrap().with_lines(&LineRange::try_new(12, 14).unwrap()));
+    let probe_finding = Finding::try_new("The container has no readiness probe.", "low").unwrap()
+        .with_location(&EvidenceLocation::try_new("manifests/deploy.yaml").unwrap());
+    let mut observations = Observations::default();
+    observations.add("containers", "3").unwrap();
+    observations.add("replicas", "2").unwrap();
+    let action = Action::builder().name("hardened-deployment").outcome("fail").reason("The deployment is not hardened.")
+        .test_file_path("tests/hardened-deployment.rego").test_file_signature("SHA256[thetestsig]")
+        .evidence_file_path("evidence/manifests").evidence_file_signature("SHA256[theevidencesig]")
+        .add_finding(&root_finding).add_finding(&probe_finding).use_observations(&observations)
+        .try_build().unwrap();
+    let activity = Activity::builder().name("deploy").add(&action).try_build().unwrap();
+    let report = AssuranceReportV1::builder()
+        .subject_nrn("nrn:sourcecode:nape:nape-cli")
+        .subject_id("9f3f183a300501b53e2fa04f48acb4bd478d6414")
+        .procedure_repository("github.com/nape/processes")
+        .procedure_directory("rust_ci/sourcecode_integration")
+        .add_activity(&activity)
+        .try_build()
+        .unwrap();
+
+    let report_file = AssuranceReportFileV1::from(&report);
+
+    let actual_action = &report_file.activities[0].actions[0];
+    let findings = actual_action.findings.as_ref().unwrap();
+    assert_eq!(findings.len(), 2);
+    assert_eq!(findings[0].message, "The container runs as root.");
+    assert_eq!(findings[0].severity, "critical");
+    let location = findings[0].evidence.as_ref().unwrap();
+    assert_eq!(location.file, "manifests/deploy.yaml");
+    assert_eq!(location.start_line, Some(12));
+    assert_eq!(location.end_line, Some(14));
+    assert_eq!(findings[1].evidence.as_ref().unwrap().start_line, None);
+    let observations: Vec<(&str, &str)> = actual_action.observations.as_ref().unwrap().iter()
+        .map(|observation| (observation.name.as_str(), observation.value.as_str()))
+        .collect();
+    assert_eq!(observations, vec![("containers", "3"), ("replicas", "2")]);
+
+    let yaml = serde_yaml::to_string(&report_file).unwrap();
+    assert!(yaml.contains("start_line: 12"));
+    assert_eq!(yaml.matches("start_line:").count(), 1);
+}
+
+#[test]
+fn deserialize_baseline_report_success() {
+    let yaml = r#"
+apiVersion: 1.0.0
+kind: AssuranceReport
+subject:
+  urn: nrn:sourcecode:nape:nape-cli
+  id: 9f3f183a
+procedure:
+  repository: git://github.com/nape/processes
+  directory: rust_ci
+summary:
+  activity_count: 1
+  action_count: 1
+  actions_run: 1
+  pass: 1
+  fail: 0
+  inconclusive: 0
+  outcome: pass
+activity:
+- name: review
+  action:
+  - name: peer-review
+    outcome: pass
+    reason: Approved
+    test_file: { file: activity/review/peer-review.rego, signature: "SHA256[test1]" }
+    evidence_file: { file: evidence/review/review.json, signature: "SHA256[evidence1]" }
+"#;
+
+    let report_file: AssuranceReportFileV1 = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!(report_file.summary.waived, 0);
+    assert_eq!(report_file.summary.failures_by_severity.critical, 0);
+    assert_eq!(report_file.summary.outcome_policy, "any");
+    assert_eq!(report_file.activities[0].actions[0].test_file.as_ref().unwrap().file, "activity/review/peer-review.rego");
+}
@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_procedure::condition::Condition;
+use nape_kernel::values::specification::assurance_report::exclusion::Exclusion;
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+
+/// The [`TailoringFile`] struct is a representation of a tailoring file, which lists the actions that do not apply to the subject of a collection.
+#[derive(Serialize, Deserialize)]
+pub struct TailoringFile {
+    #[serde(rename = "exclude")]
+    pub exclusions: Vec<ExclusionEntry>
+}
+
+/// The [`ExclusionEntry`] struct is a representation of a single excluded action within a [`TailoringFile`].  The `condition` is only present when the action was excluded because a `when` condition of the procedure was not met.
+#[derive(Serialize, Deserialize)]
+pub struct ExclusionEntry {
+    pub action: String,
+    pub justification: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>
+}
+
+impl From<&Tailoring> for TailoringFile {
+    fn from(tailoring: &Tailoring) -> TailoringFile {
+        TailoringFile {
+            exclusions: tailoring.list().iter()
+                .map(|exclusion| ExclusionEntry {
+                    action: exclusion.action.value.clone(),
+                    justification: exclusion.justification.value.clone(),
+                    condition: exclusion.condition.as_ref().map(|condition| condition.to_string())
+                })
+                .collect()
+        }
+    }
+}
+
+impl TailoringFile {
+
+    /// Convert the [`TailoringFile`] into a [`Tailoring`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] for [`Audience::System`] with [`Kind::ProcessingFailure`] is returned if an exclusion is invalid, or the same action is excluded more than once.
+    ///
+    pub fn try_to(&self) -> Result<Tailoring, Error> {
+        self.exclusions.iter().try_fold(Tailoring::default(), |tailoring, entry| {
+            let mut exclusion = Exclusion::try_new(&entry.action, &entry.justification)
+                .map_err(|e| custom_error(&e.message))?;
+            if let Some(condition) = &entry.condition {
+                let condition = Condition::try_from(condition)
+                    .map_err(|e| custom_error(&e.message))?;
+                exclusion = exclusion.with_condition(&condition);
+            }
+            tailoring.try_add(exclusion)
+                .map_err(|e| custom_error(&e.message))
+        })
+    }
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_system(Kind::ProcessingFailure,
+                      format!("Failed to extract the data from the Tailoring File. {}", message))
+}
@@ -0,0 +1,54 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::name::Name;
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::gateway_adapter::serde::tailoring_file::TailoringFile;
+
+#[test]
+fn try_to_success() {
+
+    let file: TailoringFile = serde_yaml::from_str(&tailoring_file_yaml("requester-not-a-reviewer")).unwrap();
+
+    let tailoring = is_ok!(file.try_to());
+
+    assert_eq!(tailoring.list().len(), 3);
+    let exclusion = tailoring.for_action(&Name::try_from("at-least-two-reviewers").unwrap()).unwrap();
+    assert_eq!(exclusion.justification.value, "The repository does not accept pull requests.");
+    assert_eq!(exclusion.condition, None);
+    let conditional = tailoring.for_action(&Name::try_from("card-data-encrypted").unwrap()).unwrap();
+    assert_eq!(conditional.condition.as_ref().unwrap().as_str(), "meta.pci == true");
+}
+
+#[test]
+fn from_success() {
+
+    let file: TailoringFile = serde_yaml::from_str(&tailoring_file_yaml("requester-not-a-reviewer")).unwrap();
+    let tailoring = file.try_to().unwrap();
+
+    let round_trip = TailoringFile::from(&tailoring).try_to().unwrap();
+
+    assert_eq!(round_trip, tailoring);
+}
+
+#[test]
+fn try_to_duplicate_action_error() {
+
+    let file: TailoringFile = serde_yaml::from_str(&tailoring_file_yaml("at-least-two-reviewers")).unwrap();
+
+    let result = file.try_to();
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System,
+        "Failed to extract the data from the Tailoring File. The action 'at-least-two-reviewers' is excluded more than once. Each action can only be excluded once.");
+}
+
+fn tailoring_file_yaml(second_action: &str) -> String {
+    format!(r#"---
+exclude:
+  - action: at-least-two-reviewers
+    justification: "The repository does not accept pull requests."
+  - action: {}
+    justification: "The repository has a single maintainer."
+  - action: card-data-encrypted
+    justification: "The condition 'meta.pci == true' is not met."
+    condition: "meta.pci == true"
+"#, second_action)
+}
@@ -0,0 +1,36 @@
+use std::fs;
+use std::path::Path;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+use crate::gateway_adapter::serde::tailoring_file::TailoringFile;
+
+/// The name of the tailoring file in the collection's home directory.
+pub const TAILORING_FILE: &str = "tailoring.yaml";
+
+/// Implementation of the [`PersistTailoringGateway`] which writes the [`Tailoring`] as YAML to the 'tailoring.yaml' file in the home directory.
+pub fn save_tailoring_as_yaml(tailoring: &Tailoring, home_directory: &str) -> Result<(), Error> {
+
+    let yaml = serde_yaml::to_string(&TailoringFile::from(tailoring))
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not serialize the tailoring: {}", e)))?;
+
+    let file_path = Path::new(home_directory).join(TAILORING_FILE);
+    fs::write(&file_path, yaml)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not write the tailoring to '{}': {}", file_path.display(), e)))
+}
+
+/// Implementation of the [`RetrieveTailoring`] gateway which reads the [`Tailoring`] from the 'tailoring.yaml' file in the home directory.  When the file does not exist, the collection was not tailored and no actions are excluded.
+pub fn tailoring_from_collection_home(home_directory: &str) -> Result<Tailoring, Error> {
+
+    let file_path = Path::new(home_directory).join(TAILORING_FILE);
+    if !file_path.exists() {
+        return Ok(Tailoring::default());
+    }
+
+    let content = fs::read_to_string(&file_path)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the tailoring '{}': {}", file_path.display(), e)))?;
+
+    let tailoring_file: TailoringFile = serde_yaml::from_str(&content)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not deserialize the tailoring '{}': {}", file_path.display(), e)))?;
+
+    tailoring_file.try_to()
+}
@@ -0,0 +1,56 @@
+use nape_kernel::values::specification::assurance_report::exclusion::Exclusion;
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::serde::tailoring_gateway::{save_tailoring_as_yaml, tailoring_from_collection_home};
+
+#[test]
+fn save_and_retrieve_success() {
+
+    // Assemble
+    remove!("tailoring_gateway_save_and_retrieve_success");
+    let directory = create!("tailoring_gateway_save_and_retrieve_success");
+    let home_directory = canonical_path!(&directory);
+    let tailoring = Tailoring::default()
+        .try_add(Exclusion::try_new("at-least-two-reviewers", "The repository does not accept pull requests.").unwrap()).unwrap();
+
+    // Act
+    let saved = save_tailoring_as_yaml(&tailoring, &home_directory);
+    let result = tailoring_from_collection_home(&home_directory);
+
+    // Assert
+    is_ok!(saved);
+    assert_eq!(is_ok!(result), tailoring);
+
+    // Clean up
+    remove!("tailoring_gateway_save_and_retrieve_success");
+}
+
+#[test]
+fn retrieve_no_tailoring_success() {
+
+    remove!("tailoring_gateway_retrieve_no_tailoring_success");
+    let directory = create!("tailoring_gateway_retrieve_no_tailoring_success");
+    let home_directory = canonical_path!(&directory);
+
+    let result = tailoring_from_collection_home(&home_directory);
+
+    assert!(is_ok!(result).is_empty());
+
+    remove!("tailoring_gateway_retrieve_no_tailoring_success");
+}
+
+#[test]
+fn retrieve_invalid_tailoring_error() {
+
+    remove!("tailoring_gateway_retrieve_invalid_tailoring_error");
+    let file_path_buff = create_file!("tailoring_gateway_retrieve_invalid_tailoring_error/tailoring.yaml", "exclude: not-a-list");
+    let home_directory = canonical_path!(file_path_buff.parent().unwrap());
+
+    let result = tailoring_from_collection_home(&home_directory);
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not deserialize the tailoring '");
+
+    remove!("tailoring_gateway_retrieve_invalid_tailoring_error");
+}
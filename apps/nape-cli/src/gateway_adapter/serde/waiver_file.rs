@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_report::waiver::Waiver;
+use nape_kernel::values::specification::assurance_report::waivers::Waivers;
+
+/// The [`WaiverFile`] struct is a representation of a waiver file, which lists the approved exceptions for actions that are known to fail.
+#[derive(Serialize, Deserialize)]
+pub struct WaiverFile {
+    #[serde(rename = "waiver")]
+    pub waivers: Vec<WaiverEntry>
+}
+
+/// The [`WaiverEntry`] struct is a representation of a single waiver within a [`WaiverFile`].
+#[derive(Serialize, Deserialize)]
+pub struct WaiverEntry {
+    pub action: String,
+    pub justification: String,
+    pub approver: String,
+    pub expires: String
+}
+
+impl WaiverFile {
+
+    /// Convert the [`WaiverFile`] into [`Waivers`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] for [`Audience::System`] with [`Kind::ProcessingFailure`] is returned if a waiver is invalid, or there is more than one waiver for the same action.
+    ///
+    pub fn try_to(&self) -> Result<Waivers, Error> {
+        self.waivers.iter().try_fold(Waivers::default(), |waivers, entry| {
+            let waiver = Waiver::try_new(&entry.action, &entry.justification, &entry.approver, &entry.expires)
+                .map_err(|e| custom_error(&e.message))?;
+            waivers.try_add(waiver)
+                .map_err(|e| custom_error(&e.message))
+        })
+    }
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_system(Kind::ProcessingFailure,
+                      format!("Failed to extract the data from the Waiver File. {}", message))
+}
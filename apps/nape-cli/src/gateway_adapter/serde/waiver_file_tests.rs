@@ -0,0 +1,43 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::values::specification::name::Name;
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::gateway_adapter::serde::waiver_file::WaiverFile;
+
+#[test]
+fn try_to_success() {
+
+    let file: WaiverFile = serde_yaml::from_str(&waiver_file_yaml("2024-06-30")).unwrap();
+
+    let waivers = is_ok!(file.try_to());
+
+    assert_eq!(waivers.list().len(), 2);
+    let waiver = waivers.for_action(&Name::try_from("at-least-two-reviewers").unwrap()).unwrap();
+    assert_eq!(waiver.justification.value, "The team only has one reviewer until the new hire starts.");
+    assert_eq!(waiver.approver.value, "Jane Doe");
+    assert_eq!(waiver.expires.to_string(), "2024-06-30");
+}
+
+#[test]
+fn try_to_invalid_expiry_error() {
+
+    let file: WaiverFile = serde_yaml::from_str(&waiver_file_yaml("30/06/2024")).unwrap();
+
+    let result = file.try_to();
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System,
+        "Failed to extract the data from the Waiver File. The Waiver could not be created. There is an issue with the expiry for the action 'at-least-two-reviewers'. '30/06/2024' is not a valid date. A date must be in the format 'YYYY-MM-DD'.");
+}
+
+fn waiver_file_yaml(expires: &str) -> String {
+    format!(r#"---
+waiver:
+  - action: at-least-two-reviewers
+    justification: "The team only has one reviewer until the new hire starts."
+    approver: "Jane Doe"
+    expires: "{}"
+  - action: requester-not-a-reviewer
+    justification: "The requester is the only maintainer of the repository."
+    approver: "John Doe"
+    expires: "2024-12-31"
+"#, expires)
+}
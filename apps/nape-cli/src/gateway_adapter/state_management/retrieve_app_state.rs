@@ -1,9 +1,18 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 use nape_kernel::error::{Error, Kind};
 use crate::filesystem_state_configuration::nape_cli_config_file_path;
+use crate::gateway_adapter::serde::persist_report_gateway::FILE_NAME as REPORT_FILE_NAME;
+use crate::gateway_adapter::serde::specification_serializer::assurance_report::v1_0_0::AssuranceReportFileV1;
+use crate::gateway_adapter::state_management::retrieve_directory_path::directory_path_from_home;
 use crate::state_management::cli_app_state::CLIAppState;
 use crate::state_management::retrieve_state_file::retrieve_from_filesystem;
 use crate::state_management::yaml_serializer::deserialize_from_yaml;
 
+/// The directories of a collection, which are recorded in the application state by 'nape collect start'.
+const COLLECTION_DIRECTORIES: [&str; 5] = ["home", "evidence", "activity-test", "temp", "assurance-procedure-file"];
+
 /// Retrieve the application state from the NAPE configuration file.
 pub fn app_state_from_nape_config() -> Result<CLIAppState, Error> {
     let local_state_file = nape_cli_config_file_path()
@@ -13,4 +22,34 @@ pub fn app_state_from_nape_config() -> Result<CLIAppState, Error> {
         .map_err(|error| Error::for_system(Kind::ProcessingFailure,
                                            format!("Failed to deserialize the local NAPE state configuration file. {}", error.message)))?;
     Ok(app_state)
-}
\ No newline at end of file
+}
+/// Retrieve the application state for the collection in the home directory, such as an archived collection, rather than the collection recorded in the NAPE configuration file.  The subject, procedure, and metadata are read from the assurance report in the home directory.
+pub fn app_state_from_collection(home_directory: &str) -> Result<CLIAppState, Error> {
+
+    if !Path::new(home_directory).is_dir() {
+        return Err(Error::for_user(Kind::NotFound,
+                                   format!("The collection directory '{}' does not exist.", home_directory)));
+    }
+
+    let report_file = format!("{}/{}", home_directory.trim_end_matches('/'), REPORT_FILE_NAME);
+    let report_data = fs::read(&report_file)
+        .map_err(|_| Error::for_user(Kind::NotFound,
+                                     format!("The collection '{}' does not have the assurance report '{}', which records its subject and procedure.", home_directory, REPORT_FILE_NAME)))?;
+    let report: AssuranceReportFileV1 = serde_yaml::from_slice(&report_data)
+        .map_err(|e| Error::for_user(Kind::InvalidInput,
+                                     format!("The assurance report '{}' is not valid. {}", report_file, e)))?;
+
+    let mut directories = HashMap::new();
+    for directory_key in COLLECTION_DIRECTORIES {
+        directories.insert(directory_key.to_string(), directory_path_from_home(home_directory, directory_key)?);
+    }
+
+    Ok(CLIAppState {
+        subject_nrn: report.subject.urn,
+        subject_id: report.subject.id,
+        procedure_repository: report.procedure.repository,
+        procedure_directory: report.procedure.directory,
+        metadata: report.metadata.unwrap_or_default(),
+        directories,
+    })
+}
@@ -1 +1,81 @@
-// TODO - Figure out how to test the app_state_from_nape_config without it being a flaky test.
\ No newline at end of file
+// TODO - Figure out how to test the app_state_from_nape_config without it being a flaky test.
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::state_management::retrieve_app_state::app_state_from_collection;
+
+const REPORT: &str = r#"
+apiVersion: 1.0.0
+kind: AssuranceReport
+metadata:
+  build-id: "42"
+subject:
+  urn: nrn:sourcecode:nape:nape-cli
+  id: 9f3f183a
+procedure:
+  repository: https://github.com/nape-dev/catalog.git
+  directory: rust_ci
+summary:
+  activity_count: 0
+  action_count: 0
+  actions_run: 0
+  pass: 0
+  fail: 0
+  inconclusive: 0
+  waived: 0
+  failures_by_severity: { critical: 0, high: 0, medium: 0, low: 0, unrated: 0 }
+  outcome_policy: any
+  outcome: pass
+activity: []
+"#;
+
+#[test]
+fn app_state_from_collection_success() {
+
+    // Assemble
+    remove!("app_state_from_collection_success");
+    let report = create_file!("app_state_from_collection_success/1719326666123/assurance_report.yaml", REPORT);
+    let home = canonical_path!(report.parent().unwrap().to_path_buf());
+
+    // Act
+    let result = app_state_from_collection(&home);
+
+    // Assert
+    let app_state = is_ok!(result);
+    assert_eq!(app_state.subject_nrn, "nrn:sourcecode:nape:nape-cli");
+    assert_eq!(app_state.subject_id, "9f3f183a");
+    assert_eq!(app_state.procedure_repository, "https://github.com/nape-dev/catalog.git");
+    assert_eq!(app_state.procedure_directory, "rust_ci");
+    assert_eq!(app_state.metadata.get("build-id").unwrap(), "42");
+    assert_eq!(app_state.directories.get("home").unwrap(), &home);
+    assert_eq!(app_state.directories.get("assurance-procedure-file").unwrap(), &format!("{}/assurance_procedure.yaml", home));
+
+    // Clean up
+    remove!("app_state_from_collection_success");
+}
+
+#[test]
+fn app_state_from_collection_without_report_error() {
+
+    // Assemble
+    remove!("app_state_from_collection_without_report_error");
+    let home = canonical_path!(create!("app_state_from_collection_without_report_error/1719326666123"));
+
+    // Act
+    let result = app_state_from_collection(&home);
+
+    // Assert
+    kernel_error_eq!(result, Kind::NotFound, Audience::User,
+        format!("The collection '{}' does not have the assurance report 'assurance_report.yaml', which records its subject and procedure.", home));
+
+    // Clean up
+    remove!("app_state_from_collection_without_report_error");
+}
+
+#[test]
+fn app_state_from_collection_missing_directory_error() {
+
+    let result = app_state_from_collection("app_state_from_collection_missing_directory_error/1719326666123");
+
+    kernel_error_starts_with!(result, Kind::NotFound, Audience::User, "The collection directory ");
+}
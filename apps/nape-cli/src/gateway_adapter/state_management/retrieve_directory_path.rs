@@ -8,4 +8,18 @@ pub fn directory_path_from_app_state(path_name: &str) -> Result<String, Error> {
         .try_get(path_name)
         .ok_or(Error::for_system(Kind::InvalidInput,
                                  format!("The '{}' directory was not found in the NAPE state configuration file", path_name)))
-}
\ No newline at end of file
+}
+
+/// Resolves the directory path for the directory key within the home directory of a collection, such as an archived collection, using the layout that 'nape collect start' creates.
+pub fn directory_path_from_home(home_directory: &str, path_name: &str) -> Result<String, Error> {
+    let home = home_directory.trim_end_matches('/');
+    match path_name {
+        "home" => Ok(home.to_string()),
+        "evidence" => Ok(format!("{}/evidence", home)),
+        "activity-test" => Ok(format!("{}/activity", home)),
+        "temp" => Ok(format!("{}/temp", home)),
+        "assurance-procedure-file" => Ok(format!("{}/assurance_procedure.yaml", home)),
+        _ => Err(Error::for_system(Kind::InvalidInput,
+                                   format!("The '{}' directory is not part of the home directory of a collection.", path_name)))
+    }
+}
@@ -1 +1,20 @@
-// TODO - Figure out how to test the directory_path_from_app_state without it being a flaky test.
\ No newline at end of file
+// TODO - Figure out how to test the directory_path_from_app_state without it being a flaky test.
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::gateway_adapter::state_management::retrieve_directory_path::directory_path_from_home;
+
+#[test]
+fn directory_path_from_home_success() {
+    let home = "archive/nrn_sourcecode_nape_project_-_nape-cli/1719326666123/";
+
+    assert_eq!(is_ok!(directory_path_from_home(home, "home")), "archive/nrn_sourcecode_nape_project_-_nape-cli/1719326666123");
+    assert_eq!(is_ok!(directory_path_from_home(home, "activity-test")), "archive/nrn_sourcecode_nape_project_-_nape-cli/1719326666123/activity");
+    assert_eq!(is_ok!(directory_path_from_home(home, "assurance-procedure-file")), "archive/nrn_sourcecode_nape_project_-_nape-cli/1719326666123/assurance_procedure.yaml");
+}
+
+#[test]
+fn directory_path_from_home_unknown_key_error() {
+    let result = directory_path_from_home("archive/collection", "downloads");
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::System, "The 'downloads' directory is not part of the home directory of a collection.");
+}
@@ -0,0 +1,4 @@
+pub mod retrieve_provenance;
+#[cfg(test)] mod retrieve_provenance_tests;
+pub mod retrieve_user;
+#[cfg(test)] mod retrieve_user_tests;
@@ -0,0 +1,61 @@
+use std::env;
+use std::process::Command;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_report::provenance::Provenance;
+use nape_kernel::values::specification::short_description::ShortDescription;
+use crate::gateway_adapter::std_env::retrieve_user::current_user;
+
+/// The value recorded when a piece of the provenance cannot be determined.
+const UNKNOWN: &str = "unknown";
+
+/// # Overview
+///
+/// An implementation of the [`RetrieveProvenance`] gateway which retrieves the [`Provenance`] from the running process, its environment, and the `nape-eval --version` and `hostname` commands.
+///
+/// # Design Decision
+///
+/// * A missing or unusable evaluator version, hostname, or user is recorded as 'unknown' rather than failing the report, because the provenance describes the report and should not prevent it from being generated.
+///
+pub fn provenance_from_environment() -> Result<Provenance, Error> {
+    Provenance::try_new(env!("CARGO_PKG_VERSION"),
+                        &evaluator_version(),
+                        env::consts::OS,
+                        env::consts::ARCH,
+                        &hostname(),
+                        &current_user())
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure,
+                                       format!("Failed to create the provenance from the environment. {}", e.message)))
+}
+
+fn evaluator_version() -> String {
+    command_output("nape-eval", "--version")
+        .map(|version| short_value_or_unknown(&version))
+        .unwrap_or_else(|| UNKNOWN.to_string())
+}
+
+fn hostname() -> String {
+    env::var("HOSTNAME")
+        .or_else(|_| env::var("COMPUTERNAME"))
+        .ok()
+        .or_else(|| command_output("hostname", ""))
+        .map(|hostname| short_value_or_unknown(&hostname))
+        .unwrap_or_else(|| UNKNOWN.to_string())
+}
+
+fn command_output(program: &str, argument: &str) -> Option<String> {
+    let mut command = Command::new(program);
+    if !argument.is_empty() {
+        command.arg(argument);
+    }
+    command.output().ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+}
+
+/// Returns the first line of the `value` when it is a valid [`ShortDescription`], otherwise 'unknown'.
+pub(crate) fn short_value_or_unknown(value: &str) -> String {
+    let first_line = value.lines().map(str::trim).find(|line| !line.is_empty()).unwrap_or_default();
+    ShortDescription::try_from(first_line)
+        .map(|description| description.value)
+        .unwrap_or_else(|_| UNKNOWN.to_string())
+}
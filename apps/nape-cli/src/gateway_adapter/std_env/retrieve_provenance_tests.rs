@@ -0,0 +1,41 @@
+use std::env;
+use nape_testing_assertions::is_ok;
+use crate::gateway_adapter::std_env::retrieve_provenance::{provenance_from_environment, short_value_or_unknown};
+
+#[test]
+fn success() {
+
+    let result = provenance_from_environment();
+
+    let provenance = is_ok!(result);
+    assert_eq!(provenance.nape_version.value, env!("CARGO_PKG_VERSION"));
+    assert_eq!(provenance.os.value, env::consts::OS);
+    assert_eq!(provenance.arch.value, env::consts::ARCH);
+    assert!(!provenance.evaluator_version.value.is_empty());
+    assert!(!provenance.hostname.value.is_empty());
+    assert!(!provenance.user.value.is_empty());
+}
+
+#[test]
+fn short_value_first_line_success() {
+
+    let value = short_value_or_unknown("\nnape-eval 0.3.1\nCopyright (c) the nape authors\n");
+
+    assert_eq!(value, "nape-eval 0.3.1");
+}
+
+#[test]
+fn short_value_too_long_unknown() {
+
+    let value = short_value_or_unknown(&"x".repeat(256));
+
+    assert_eq!(value, "unknown");
+}
+
+#[test]
+fn short_value_empty_unknown() {
+
+    let value = short_value_or_unknown(" \n \n");
+
+    assert_eq!(value, "unknown");
+}
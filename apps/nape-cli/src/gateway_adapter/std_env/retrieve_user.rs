@@ -0,0 +1,26 @@
+use std::env;
+use nape_kernel::error::Error;
+
+/// The value recorded when the user cannot be determined.
+pub const UNKNOWN_USER: &str = "unknown";
+
+/// # Overview
+///
+/// An implementation of the [`RetrieveUserGateway`] which retrieves the user from the `USER`, or on Windows the `USERNAME`, environment variable.
+///
+/// # Design Decision
+///
+/// * A missing user is recorded as 'unknown' rather than failing, because the user describes the evidence and should not prevent it from being collected.
+///
+pub fn user_from_environment() -> Result<String, Error> {
+    Ok(current_user())
+}
+
+/// Returns the user from the environment, or 'unknown'.
+pub fn current_user() -> String {
+    env::var("USER")
+        .or_else(|_| env::var("USERNAME"))
+        .ok()
+        .filter(|user| !user.trim().is_empty())
+        .unwrap_or_else(|| UNKNOWN_USER.to_string())
+}
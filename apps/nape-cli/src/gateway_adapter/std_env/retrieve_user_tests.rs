@@ -0,0 +1,11 @@
+use nape_testing_assertions::is_ok;
+use crate::gateway_adapter::std_env::retrieve_user::user_from_environment;
+
+#[test]
+fn success() {
+
+    let result = user_from_environment();
+
+    let user = is_ok!(result);
+    assert!(!user.trim().is_empty());
+}
@@ -0,0 +1,59 @@
+use std::fs;
+use std::path::Path;
+use nape_domain::procedure_management::usecases::list_procedures::gateway_boundary::{ProcedureFile, PROCEDURE_FILE_NAME};
+use nape_kernel::error::{Error, Kind};
+
+/// The directory of git metadata, which is never searched for procedures.
+const GIT_DIRECTORY: &str = ".git";
+
+/// # Overview
+///
+/// An implementation of the [`FindProcedureFilesGateway`] which walks a procedure repository checked out on the machine's filesystem and returns every assurance procedure file within it.
+///
+/// # Arguments
+///
+/// * `repository` - The directory of the procedure repository.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`ProcedureFile`]s, where each directory is relative to the `repository`, or an [`Error`].
+///
+/// # Errors
+///
+/// * An [`Error`] for [`Audience::User`] with [`Kind::NotFound`] is returned if the `repository` is not a directory.
+/// * An [`Error`] for [`Audience::System`] with [`Kind::ProcessingFailure`] is returned if a directory or file could not be read.
+///
+pub fn procedure_files_on_filesystem(repository: &str) -> Result<Vec<ProcedureFile>, Error> {
+
+    let root = Path::new(repository);
+    if !root.is_dir() {
+        return Err(Error::for_user(Kind::NotFound, format!("The procedure repository '{}' is not a directory.", repository)));
+    }
+
+    let mut procedure_files = Vec::new();
+    find_in_directory(root, root, &mut procedure_files)?;
+    Ok(procedure_files)
+}
+
+fn find_in_directory(root: &Path, directory: &Path, procedure_files: &mut Vec<ProcedureFile>) -> Result<(), Error> {
+
+    let entries = fs::read_dir(directory)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the directory '{}': {}", directory.display(), e)))?;
+
+    for entry in entries {
+        let path = entry
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read an entry of the directory '{}': {}", directory.display(), e)))?
+            .path();
+        if path.is_dir() {
+            if path.file_name().is_some_and(|name| name != GIT_DIRECTORY) {
+                find_in_directory(root, &path, procedure_files)?;
+            }
+        } else if path.file_name().is_some_and(|name| name == PROCEDURE_FILE_NAME) {
+            let content = fs::read_to_string(&path)
+                .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the file '{}': {}", path.display(), e)))?;
+            let relative_directory = directory.strip_prefix(root).unwrap_or(directory);
+            procedure_files.push(ProcedureFile { directory: relative_directory.to_string_lossy().to_string(), content });
+        }
+    }
+    Ok(())
+}
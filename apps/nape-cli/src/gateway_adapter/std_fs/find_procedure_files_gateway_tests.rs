@@ -0,0 +1,41 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use nape_testing_filesystem::{canonical_path, create_file, remove};
+use crate::gateway_adapter::std_fs::find_procedure_files_gateway::procedure_files_on_filesystem;
+
+#[test]
+fn success() {
+
+    // Clean up space if any previous test failed
+    remove!("find_procedure_files_success");
+
+    // Assemble
+    create_file!("find_procedure_files_success/rust-ci/sourcecode-integration/assurance_procedure.yaml", "kind: rust");
+    create_file!("find_procedure_files_success/rust-ci/sourcecode-integration/activity/peer-review.rego", "package x");
+    create_file!("find_procedure_files_success/service/assurance_procedure.yaml", "kind: service");
+    create_file!("find_procedure_files_success/.git/assurance_procedure.yaml", "kind: git");
+    let readme = create_file!("find_procedure_files_success/README.md", "# Procedures");
+    let repository = canonical_path!(readme.parent().unwrap().to_path_buf());
+
+    // Act
+    let result = procedure_files_on_filesystem(&repository);
+
+    // Assert
+    let mut procedure_files = is_ok!(result);
+    procedure_files.sort_by(|a, b| a.directory.cmp(&b.directory));
+    assert_eq!(procedure_files.len(), 2);
+    assert_eq!(procedure_files[0].directory, "rust-ci/sourcecode-integration");
+    assert_eq!(procedure_files[0].content, "kind: rust");
+    assert_eq!(procedure_files[1].directory, "service");
+
+    // Clean up
+    remove!("find_procedure_files_success");
+}
+
+#[test]
+fn not_a_directory_error() {
+
+    let result = procedure_files_on_filesystem("find_procedure_files_not_a_directory");
+
+    kernel_error_eq!(result, Kind::NotFound, Audience::User, "The procedure repository 'find_procedure_files_not_a_directory' is not a directory.");
+}
@@ -3,15 +3,29 @@ pub mod directory_creation_gateway;
 pub mod file_data_gateway;
 pub mod file_delete_gateway;
 pub mod file_move_gateway;
+pub mod find_procedure_files_gateway;
+pub mod publish_directory_gateway;
 
 pub mod retrieve_assurance_procedure;
 pub mod retrieve_file_data_gateway;
+pub mod retrieve_organisation_nids;
+pub mod retrieve_tailoring;
+pub mod retrieve_waivers;
+pub mod select_files_gateway;
+pub mod write_scaffold_gateway;
 
 #[cfg(test)] mod copy_file_gateway_tests;
 #[cfg(test)] mod directory_creation_gateway_tests;
 #[cfg(test)]mod file_data_gateway_tests;
 #[cfg(test)] mod file_delete_gateway_tests;
 #[cfg(test)] mod file_move_gateway_tests;
+#[cfg(test)] mod find_procedure_files_gateway_tests;
+#[cfg(test)] mod publish_directory_gateway_tests;
 
 #[cfg(test)] mod retrieve_assurance_procedure_tests;
 #[cfg(test)] mod retrieve_file_data_gateway_tests;
+#[cfg(test)] mod retrieve_organisation_nids_tests;
+#[cfg(test)] mod retrieve_tailoring_tests;
+#[cfg(test)] mod retrieve_waivers_tests;
+#[cfg(test)] mod select_files_gateway_tests;
+#[cfg(test)] mod write_scaffold_gateway_tests;
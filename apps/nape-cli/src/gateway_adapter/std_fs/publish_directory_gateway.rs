@@ -0,0 +1,32 @@
+use std::fs;
+use std::path::Path;
+use nape_domain::evidence_collection::usecases::publish_collection::gateway_boundary::CollectionPackage;
+use nape_kernel::error::{Error, Kind};
+
+/// Implementation of the [`PublishCollectionGateway`] which copies the collection into a directory, such as a mounted network share.  A collection is never published over an earlier one, so an error is returned if its destination already exists.
+pub fn publish_to_directory(package: &CollectionPackage, location: &str) -> Result<String, Error> {
+
+    let repository = Path::new(location);
+    if !repository.is_dir() {
+        return Err(Error::for_user(Kind::NotFound,
+                                   format!("The evidence repository directory '{}' does not exist.", location)));
+    }
+
+    let target_directory = repository.join(&package.destination);
+    if target_directory.exists() {
+        return Err(Error::for_user(Kind::InvalidInput,
+                                   format!("The collection has already been published to '{}'.", target_directory.display())));
+    }
+
+    for file in &package.files {
+        let target_file = target_directory.join(&file.relative_path);
+        if let Some(parent) = target_file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not create the directory '{}': {}", parent.display(), e)))?;
+        }
+        fs::copy(&file.source, &target_file)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not copy the file '{}' to '{}': {}", file.source, target_file.display(), e)))?;
+    }
+
+    Ok(target_directory.to_str().unwrap_or("").to_string())
+}
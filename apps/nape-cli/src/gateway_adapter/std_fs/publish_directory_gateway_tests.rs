@@ -0,0 +1,71 @@
+use std::fs;
+use nape_domain::evidence_collection::usecases::publish_collection::gateway_boundary::CollectionPackage;
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::gateways::file_selection::SelectedFile;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::std_fs::publish_directory_gateway::publish_to_directory;
+
+fn package(collection_directory: &str) -> CollectionPackage {
+    let files = ["assurance_report.yaml", "evidence/peer-review/review.json"].iter()
+        .map(|relative_path| SelectedFile { source: format!("{}/{}", collection_directory, relative_path), relative_path: relative_path.to_string() })
+        .collect();
+    CollectionPackage {
+        destination: "nrn_sourcecode_nape_project_-_nape-cli/1719326666123".to_string(),
+        files,
+        message: "Publish the collection.".to_string(),
+    }
+}
+
+#[test]
+fn success() {
+
+    // Assemble
+    remove!("publish_to_directory_success");
+    create_file!("publish_to_directory_success/collection/assurance_report.yaml", "outcome: PASS");
+    let review = create_file!("publish_to_directory_success/collection/evidence/peer-review/review.json", "{}");
+    let collection_directory = canonical_path!(review.ancestors().nth(3).unwrap().to_path_buf());
+    let repository = canonical_path!(create!("publish_to_directory_success/repository"));
+
+    // Act
+    let result = publish_to_directory(&package(&collection_directory), &repository);
+
+    // Assert
+    let location = is_ok!(result);
+    assert_eq!(location, format!("{}/nrn_sourcecode_nape_project_-_nape-cli/1719326666123", repository));
+    assert_eq!(fs::read_to_string(format!("{}/assurance_report.yaml", location)).unwrap(), "outcome: PASS");
+    assert_eq!(fs::read_to_string(format!("{}/evidence/peer-review/review.json", location)).unwrap(), "{}");
+
+    // Clean up
+    remove!("publish_to_directory_success");
+}
+
+#[test]
+fn already_published_error() {
+
+    // Assemble
+    remove!("publish_to_directory_already_published_error");
+    let review = create_file!("publish_to_directory_already_published_error/collection/evidence/peer-review/review.json", "{}");
+    create_file!("publish_to_directory_already_published_error/collection/assurance_report.yaml", "outcome: PASS");
+    let collection_directory = canonical_path!(review.ancestors().nth(3).unwrap().to_path_buf());
+    let repository = canonical_path!(create!("publish_to_directory_already_published_error/repository"));
+    is_ok!(publish_to_directory(&package(&collection_directory), &repository));
+
+    // Act
+    let result = publish_to_directory(&package(&collection_directory), &repository);
+
+    // Assert
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        format!("The collection has already been published to '{}/nrn_sourcecode_nape_project_-_nape-cli/1719326666123'.", repository));
+
+    // Clean up
+    remove!("publish_to_directory_already_published_error");
+}
+
+#[test]
+fn repository_does_not_exist_error() {
+
+    let result = publish_to_directory(&package("collection"), "publish_to_directory_repository_does_not_exist_error");
+
+    kernel_error_starts_with!(result, Kind::NotFound, Audience::User, "The evidence repository directory ");
+}
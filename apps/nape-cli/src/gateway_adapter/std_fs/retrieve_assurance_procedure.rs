@@ -2,13 +2,15 @@ use std::fs::File;
 use std::io::Read;
 use nape_kernel::error::{Error, Kind};
 use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
-use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::v1_0_0::AssuranceProcedureFile;
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::factory;
 
 
 /// # Overview
 ///
 /// An implementation of the [`RetrieveAssuranceProcedure`] gateway which retrieves the [`AssuranceProcedure`] from an assurance procedure file serialized as a YAML on the machine's filesystem.
 ///
+/// The `apiVersion` of the file determines which schema version is used to read it.  Older schema versions are upgraded to the current in-memory [`AssuranceProcedure`] model.
+///
 /// # Returns
 ///
 /// A [`RetrieveAssuranceProcedure`] function which will attempt to retrieve an [`AssuranceProcedure`] from a file on the filesystem.
@@ -30,10 +32,7 @@ pub fn from_yaml_on_filesystem(file_path: &str) -> Result<AssuranceProcedure, Er
         file.read_to_string(&mut file_content)
             .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read file: {}", e)))?;
 
-        let assurance_procedure_file: AssuranceProcedureFile = serde_yaml::from_str(&file_content)
-            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not deserialize file content: {}", e)))?;
-
-        let assurance_procedure = assurance_procedure_file.try_to()
+        let assurance_procedure = factory::from_yaml(&file_content)
             .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Failed to convert assurance procedure file content to AssuranceProcedure: {}", e)))?;
 
         Ok(assurance_procedure)
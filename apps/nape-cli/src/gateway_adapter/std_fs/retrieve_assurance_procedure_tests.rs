@@ -35,12 +35,12 @@ fn success() {
     assert_eq!(assurance_procedure.activities.list[0].actions[0].name.value, "at-least-two-reviewers");
     assert_eq!(assurance_procedure.activities.list[0].actions[0].short.value, "Two (2) Reviewer Approval");
     assert_eq!(assurance_procedure.activities.list[0].actions[0].description.value, "There are at least two (2) peer reviews who approved the merge request.");
-    assert_eq!(assurance_procedure.activities.list[0].actions[0].test.as_str(), "./activity/peer_review/at_least_two_reviewers.py");
+    assert_eq!(assurance_procedure.activities.list[0].actions[0].test.as_ref().unwrap().as_str(), "./activity/peer_review/at_least_two_reviewers.py");
     assert_eq!(assurance_procedure.activities.list[0].actions[0].evidence.as_str(), "./evidence/peer_review/peer_review.json");
     assert_eq!(assurance_procedure.activities.list[0].actions[1].name.value, "requester-not-a-reviewer");
     assert_eq!(assurance_procedure.activities.list[0].actions[1].short.value, "Requester is not Approver");
     assert_eq!(assurance_procedure.activities.list[0].actions[1].description.value, "The person who initiated the merge request is not one of the people who approved the merge request.");
-    assert_eq!(assurance_procedure.activities.list[0].actions[1].test.as_str(), "./activity/peer_review/requester_not_a_reviewer.py");
+    assert_eq!(assurance_procedure.activities.list[0].actions[1].test.as_ref().unwrap().as_str(), "./activity/peer_review/requester_not_a_reviewer.py");
     assert_eq!(assurance_procedure.activities.list[0].actions[1].evidence.as_str(), "./evidence/peer_review/peer_review.json");
 
     // Clean up
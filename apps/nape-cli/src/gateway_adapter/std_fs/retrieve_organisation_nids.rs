@@ -0,0 +1,53 @@
+use std::fs;
+use std::path::Path;
+use serde::Deserialize;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::nrn::nrn::NID;
+use crate::filesystem_state_configuration::organisation_nids_file_path;
+
+/// The [`OrganisationNIDsFile`] struct is a representation of the file listing the organisation-defined NIDs.
+#[derive(Deserialize)]
+struct OrganisationNIDsFile {
+    nids: Vec<String>
+}
+
+/// Retrieve the organisation-defined NIDs from the NAPE configuration directory. No NIDs are returned if the organisation has not defined any.
+pub fn organisation_nids_from_nape_config() -> Result<Vec<String>, Error> {
+    let file_path = organisation_nids_file_path()
+        .map_err(|e| Error::for_system(Kind::NotFound,
+                                       format!("Failed to retrieve the organisation NIDs file. {}", e.message)))?;
+    organisation_nids_from_yaml_on_filesystem(&file_path.to_string_lossy())
+}
+
+/// # Overview
+///
+/// Retrieves the organisation-defined NIDs from a YAML file on the machine's filesystem, in the format:
+///
+/// ```yaml
+/// nids:
+///   - bank-product
+/// ```
+///
+/// # Returns
+///
+/// The list of NIDs, which is empty if the file does not exist. An [`Error`] of [`Kind::InvalidInput`] is returned if the file can not be deserialized, or one of the NIDs is not valid.
+///
+pub fn organisation_nids_from_yaml_on_filesystem(file_path: &str) -> Result<Vec<String>, Error> {
+
+    if !Path::new(file_path).exists() {
+        return Ok(Vec::new());
+    }
+
+    let file_content = fs::read_to_string(file_path)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the organisation NIDs file '{}': {}", file_path, e)))?;
+
+    let nids_file: OrganisationNIDsFile = serde_yaml::from_str(&file_content)
+        .map_err(|e| Error::for_user(Kind::InvalidInput, format!("Could not deserialize the organisation NIDs file '{}': {}", file_path, e)))?;
+
+    for nid in nids_file.nids.iter() {
+        NID::new(nid).map_err(|e| Error::for_user(Kind::InvalidInput,
+                                                   format!("The organisation NIDs file '{}' has an invalid NID. {}", file_path, e.message)))?;
+    }
+
+    Ok(nids_file.nids)
+}
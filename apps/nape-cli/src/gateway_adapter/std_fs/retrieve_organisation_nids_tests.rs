@@ -0,0 +1,59 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use nape_testing_filesystem::{canonical_path, create_file, remove};
+use crate::gateway_adapter::std_fs::retrieve_organisation_nids::organisation_nids_from_yaml_on_filesystem;
+
+#[test]
+fn success() {
+
+    // Clean up space if any previous test failed
+    remove!("retrieve_organisation_nids_success");
+
+    // Assemble
+    let file_path_buff = create_file!("retrieve_organisation_nids_success/organisation_nids.yaml", r#"---
+nids:
+  - bank-product
+  - branch
+"#);
+    let canonical_path = canonical_path!(file_path_buff);
+
+    // Act
+    let result = organisation_nids_from_yaml_on_filesystem(&canonical_path);
+
+    // Assert
+    let nids = is_ok!(result);
+    assert_eq!(nids, vec!["bank-product".to_string(), "branch".to_string()]);
+
+    // Clean up
+    remove!("retrieve_organisation_nids_success");
+}
+
+#[test]
+fn file_not_found_success() {
+
+    let result = organisation_nids_from_yaml_on_filesystem("retrieve_organisation_nids_file_not_found/organisation_nids.yaml");
+
+    let nids = is_ok!(result);
+    assert!(nids.is_empty());
+}
+
+#[test]
+fn invalid_nid_error() {
+
+    // Clean up space if any previous test failed
+    remove!("retrieve_organisation_nids_invalid_nid_error");
+
+    // Assemble
+    let file_path_buff = create_file!("retrieve_organisation_nids_invalid_nid_error/organisation_nids.yaml", "nids:\n  - bank_product\n");
+    let canonical_path = canonical_path!(file_path_buff);
+
+    // Act
+    let result = organisation_nids_from_yaml_on_filesystem(&canonical_path);
+
+    // Assert
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        format!("The organisation NIDs file '{}' has an invalid NID. NID 'bank_product' contains invalid character '_' at position 4.  An NID can only contains alphanumeric characters or a dash '-'.", canonical_path));
+
+    // Clean up
+    remove!("retrieve_organisation_nids_invalid_nid_error");
+}
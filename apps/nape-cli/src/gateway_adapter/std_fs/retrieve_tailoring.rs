@@ -0,0 +1,38 @@
+use std::fs::File;
+use std::io::Read;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+use crate::gateway_adapter::serde::tailoring_file::TailoringFile;
+
+
+/// # Overview
+///
+/// Retrieves the [`Tailoring`] from a tailoring file serialized as a YAML on the machine's filesystem.  This is used to read the tailoring file supplied when a collection is started.
+///
+/// # Returns
+///
+/// The [`Tailoring`] of the actions which do not apply to the subject, or an [`Error`] if the file could not be read or is invalid.
+///
+/// # Example
+///
+/// ```no_run
+///
+/// let result = tailoring_from_yaml_on_filesystem("path/to/tailoring.yaml");
+///  // Now do something with the result
+///
+/// ```
+pub fn tailoring_from_yaml_on_filesystem(file_path: &str) -> Result<Tailoring, Error> {
+
+        let mut file = File::open(file_path)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not open file: {}", e)))?;
+
+        let mut file_content = String::new();
+        file.read_to_string(&mut file_content)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read file: {}", e)))?;
+
+        let tailoring_file: TailoringFile = serde_yaml::from_str(&file_content)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not deserialize the tailoring file: {}", e)))?;
+
+        tailoring_file.try_to()
+
+}
@@ -0,0 +1,38 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create_file, remove};
+use crate::gateway_adapter::std_fs::retrieve_tailoring::tailoring_from_yaml_on_filesystem;
+
+#[test]
+fn success() {
+
+    // Clean up space if any previous test failed
+    remove!("retrieve_tailoring_success");
+
+    // Assemble
+    let file_path_buff = create_file!("retrieve_tailoring_success/tailoring.yaml", r#"---
+exclude:
+  - action: at-least-two-reviewers
+    justification: "The repository does not accept pull requests."
+"#);
+    let canonical_path = canonical_path!(file_path_buff);
+
+    // Act
+    let result = tailoring_from_yaml_on_filesystem(&canonical_path);
+
+    // Assert
+    let tailoring = is_ok!(result);
+    assert_eq!(tailoring.list().len(), 1);
+    assert_eq!(tailoring.list()[0].action.value, "at-least-two-reviewers");
+
+    // Clean up
+    remove!("retrieve_tailoring_success");
+}
+
+#[test]
+fn file_not_found_error() {
+
+    let result = tailoring_from_yaml_on_filesystem("retrieve_tailoring_file_not_found/tailoring.yaml");
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not open file: ");
+}
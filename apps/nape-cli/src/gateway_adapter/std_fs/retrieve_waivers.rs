@@ -0,0 +1,38 @@
+use std::fs::File;
+use std::io::Read;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_report::waivers::Waivers;
+use crate::gateway_adapter::serde::waiver_file::WaiverFile;
+
+
+/// # Overview
+///
+/// An implementation of the [`RetrieveWaivers`] gateway which retrieves the [`Waivers`] from a waiver file serialized as a YAML on the machine's filesystem.
+///
+/// # Returns
+///
+/// A [`RetrieveWaivers`] function which will attempt to retrieve the [`Waivers`] from a file on the filesystem.
+///
+/// # Example
+///
+/// ```no_run
+///
+/// let result = waivers_from_yaml_on_filesystem("path/to/waivers.yaml");
+///  // Now do something with the result
+///
+/// ```
+pub fn waivers_from_yaml_on_filesystem(file_path: &str) -> Result<Waivers, Error> {
+
+        let mut file = File::open(file_path)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not open file: {}", e)))?;
+
+        let mut file_content = String::new();
+        file.read_to_string(&mut file_content)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read file: {}", e)))?;
+
+        let waiver_file: WaiverFile = serde_yaml::from_str(&file_content)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not deserialize the waiver file: {}", e)))?;
+
+        waiver_file.try_to()
+
+}
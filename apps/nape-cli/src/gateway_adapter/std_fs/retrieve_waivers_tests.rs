@@ -0,0 +1,40 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create_file, remove};
+use crate::gateway_adapter::std_fs::retrieve_waivers::waivers_from_yaml_on_filesystem;
+
+#[test]
+fn success() {
+
+    // Clean up space if any previous test failed
+    remove!("retrieve_waivers_success");
+
+    // Assemble
+    let file_path_buff = create_file!("retrieve_waivers_success/waivers.yaml", r#"---
+waiver:
+  - action: at-least-two-reviewers
+    justification: "The team only has one reviewer until the new hire starts."
+    approver: "Jane Doe"
+    expires: "2024-06-30"
+"#);
+    let canonical_path = canonical_path!(file_path_buff);
+
+    // Act
+    let result = waivers_from_yaml_on_filesystem(&canonical_path);
+
+    // Assert
+    let waivers = is_ok!(result);
+    assert_eq!(waivers.list().len(), 1);
+    assert_eq!(waivers.list()[0].action.value, "at-least-two-reviewers");
+
+    // Clean up
+    remove!("retrieve_waivers_success");
+}
+
+#[test]
+fn file_not_found_error() {
+
+    let result = waivers_from_yaml_on_filesystem("retrieve_waivers_file_not_found/waivers.yaml");
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Could not open file: ");
+}
@@ -0,0 +1,168 @@
+use std::fs;
+use std::path::Path;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::gateways::file_selection::{SelectedFile, SelectedFiles};
+
+/// Selects the files a file path refers to on the file system.  A regular file is selected as a single file, a directory
+/// selects every file beneath it, and a path containing '*', '?' or '[' is treated as a glob pattern.  The glob pattern
+/// supports '*' and '?' within a path component, and '**' to match any number of directories.  Symbolic links to
+/// directories are not followed.
+pub fn select_files_on_filesystem(file_path: &str) -> Result<SelectedFiles, Error> {
+
+    if is_glob_pattern(file_path) {
+        return select_files_by_glob(file_path);
+    }
+
+    let path = Path::new(file_path);
+
+    if !path.exists() {
+        return Err(Error::for_system(Kind::GatewayError,
+                                     format!("The file '{}' does not exist.", file_path)));
+    }
+
+    if !path.is_dir() {
+        return Ok(SelectedFiles::File);
+    }
+
+    let mut files = Vec::new();
+    walk_directory(path, "", &mut files)?;
+    files.sort_by(|first, second| first.relative_path.cmp(&second.relative_path));
+
+    Ok(SelectedFiles::Files(files))
+}
+
+fn is_glob_pattern(file_path: &str) -> bool {
+    file_path.contains(['*', '?', '['])
+}
+
+fn select_files_by_glob(pattern: &str) -> Result<SelectedFiles, Error> {
+
+    let components: Vec<&str> = pattern.split('/').collect();
+    let base_length = components.iter()
+        .position(|component| is_glob_pattern(component))
+        .unwrap_or(components.len());
+
+    let base_directory = match components[..base_length].join("/") {
+        base if base.is_empty() && pattern.starts_with('/') => "/".to_string(),
+        base if base.is_empty() => ".".to_string(),
+        base => base
+    };
+    let pattern_components: Vec<&str> = components[base_length..].iter()
+        .copied()
+        .filter(|component| !component.is_empty())
+        .collect();
+
+    let base_path = Path::new(&base_directory);
+    if !base_path.is_dir() {
+        return Err(Error::for_system(Kind::GatewayError,
+                                     format!("The base directory '{}' of the pattern '{}' does not exist.", base_directory, pattern)));
+    }
+
+    let mut files = Vec::new();
+    walk_directory(base_path, "", &mut files)?;
+    files.retain(|file| {
+        let path_components: Vec<&str> = file.relative_path.split('/').collect();
+        matches_path(&pattern_components, &path_components)
+    });
+    files.sort_by(|first, second| first.relative_path.cmp(&second.relative_path));
+
+    Ok(SelectedFiles::Files(files))
+}
+
+fn walk_directory(directory: &Path, relative_directory: &str, files: &mut Vec<SelectedFile>) -> Result<(), Error> {
+
+    let entries = fs::read_dir(directory)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue reading the directory '{}'. {}", directory.display(), error)))?;
+
+    for entry in entries {
+        let entry = entry
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue reading an entry in the directory '{}'. {}", directory.display(), error)))?;
+
+        let file_name = entry.file_name().into_string()
+            .map_err(|os_string| Error::for_system(Kind::GatewayError,
+                                                   format!("The directory '{}' contains the file '{:?}', which does not have a valid Unicode name.", directory.display(), os_string)))?;
+
+        let relative_path = match relative_directory {
+            "" => file_name,
+            _ => format!("{}/{}", relative_directory, file_name)
+        };
+
+        let file_type = entry.file_type()
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue reading the file type of '{}'. {}", entry.path().display(), error)))?;
+
+        if file_type.is_dir() {
+            walk_directory(&entry.path(), &relative_path, files)?;
+        } else if file_type.is_file() || (file_type.is_symlink() && entry.path().is_file()) {
+            files.push(SelectedFile {
+                source: entry.path().to_str().unwrap_or("").to_string(),
+                relative_path,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn matches_path(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", remaining_pattern)) => {
+            (0..=path.len()).any(|skipped| matches_path(remaining_pattern, &path[skipped..]))
+        },
+        Some((component_pattern, remaining_pattern)) => match path.split_first() {
+            Some((component, remaining_path)) => {
+                let pattern_characters: Vec<char> = component_pattern.chars().collect();
+                let characters: Vec<char> = component.chars().collect();
+                matches_component(&pattern_characters, &characters) && matches_path(remaining_pattern, remaining_path)
+            },
+            None => false
+        }
+    }
+}
+
+fn matches_component(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', remaining_pattern)) => {
+            (0..=name.len()).any(|skipped| matches_component(remaining_pattern, &name[skipped..]))
+        },
+        Some(('?', remaining_pattern)) => {
+            !name.is_empty() && matches_component(remaining_pattern, &name[1..])
+        },
+        Some(('[', remaining_pattern)) => match remaining_pattern.iter().position(|character| *character == ']') {
+            Some(end) if !name.is_empty() => {
+                let (set, after_set) = (&remaining_pattern[..end], &remaining_pattern[end + 1..]);
+                matches_set(set, name[0]) && matches_component(after_set, &name[1..])
+            },
+            Some(_) => false,
+            None => name.first() == Some(&'[') && matches_component(remaining_pattern, &name[1..])
+        },
+        Some((character, remaining_pattern)) => {
+            name.first() == Some(character) && matches_component(remaining_pattern, &name[1..])
+        }
+    }
+}
+
+fn matches_set(set: &[char], character: char) -> bool {
+    let (negated, set) = match set.first() {
+        Some('!') | Some('^') => (true, &set[1..]),
+        _ => (false, set)
+    };
+
+    let mut index = 0;
+    let mut matched = false;
+    while index < set.len() {
+        if index + 2 < set.len() && set[index + 1] == '-' {
+            matched |= set[index] <= character && character <= set[index + 2];
+            index += 3;
+        } else {
+            matched |= set[index] == character;
+            index += 1;
+        }
+    }
+
+    matched != negated
+}
@@ -0,0 +1,93 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::gateways::file_selection::SelectedFiles;
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use nape_testing_filesystem::{canonical_path, create_file, remove};
+use crate::gateway_adapter::std_fs::select_files_gateway::select_files_on_filesystem;
+
+fn relative_paths(selected_files: SelectedFiles) -> Vec<String> {
+    match selected_files {
+        SelectedFiles::File => panic!("A list of files was expected, not a single file."),
+        SelectedFiles::Files(files) => files.into_iter().map(|file| file.relative_path).collect()
+    }
+}
+
+#[test]
+fn single_file_success() {
+
+    remove!("select_files_single_file_success");
+    let file_path_buff = create_file!("select_files_single_file_success/report.json", "{}");
+    let file_path = canonical_path!(file_path_buff);
+
+    let result = select_files_on_filesystem(&file_path);
+
+    assert_eq!(is_ok!(result), SelectedFiles::File);
+    remove!("select_files_single_file_success");
+}
+
+#[test]
+fn directory_success() {
+
+    remove!("select_files_directory_success");
+    create_file!("select_files_directory_success/manifests/service.yaml", "kind: Service");
+    create_file!("select_files_directory_success/manifests/app/deploy.yaml", "kind: Deployment");
+    let file_path_buff = create_file!("select_files_directory_success/manifests/app/config/map.yaml", "kind: ConfigMap");
+    let directory = canonical_path!(file_path_buff.ancestors().nth(3).unwrap().to_path_buf());
+
+    let result = select_files_on_filesystem(&directory);
+
+    let selected_files = is_ok!(result);
+    if let SelectedFiles::Files(files) = &selected_files {
+        assert!(files[0].source.ends_with("manifests/app/config/map.yaml"));
+    }
+    assert_eq!(relative_paths(selected_files), vec!["app/config/map.yaml", "app/deploy.yaml", "service.yaml"]);
+    remove!("select_files_directory_success");
+}
+
+#[test]
+fn glob_success() {
+
+    remove!("select_files_glob_success");
+    create_file!("select_files_glob_success/manifests/service.yaml", "kind: Service");
+    create_file!("select_files_glob_success/manifests/notes.txt", "notes");
+    create_file!("select_files_glob_success/manifests/app/deploy.yaml", "kind: Deployment");
+    let file_path_buff = create_file!("select_files_glob_success/manifests/app/config/map.yml", "kind: ConfigMap");
+    let directory = canonical_path!(file_path_buff.ancestors().nth(3).unwrap().to_path_buf());
+
+    let recursive = select_files_on_filesystem(&format!("{}/**/*.y*ml", directory));
+    let top_level = select_files_on_filesystem(&format!("{}/*.yaml", directory));
+    let nested = select_files_on_filesystem(&format!("{}/app/*/[l-n]ap.yml", directory));
+
+    assert_eq!(relative_paths(is_ok!(recursive)), vec!["app/config/map.yml", "app/deploy.yaml", "service.yaml"]);
+    assert_eq!(relative_paths(is_ok!(top_level)), vec!["service.yaml"]);
+    assert_eq!(relative_paths(is_ok!(nested)), vec!["config/map.yml"]);
+    remove!("select_files_glob_success");
+}
+
+#[test]
+fn glob_no_matches_success() {
+
+    remove!("select_files_glob_no_matches_success");
+    let file_path_buff = create_file!("select_files_glob_no_matches_success/notes.txt", "notes");
+    let directory = canonical_path!(file_path_buff.parent().unwrap().to_path_buf());
+
+    let result = select_files_on_filesystem(&format!("{}/*.yaml", directory));
+
+    assert_eq!(is_ok!(result), SelectedFiles::Files(Vec::new()));
+    remove!("select_files_glob_no_matches_success");
+}
+
+#[test]
+fn file_not_found_error() {
+
+    let result = select_files_on_filesystem("select_files_file_not_found_error/report.json");
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System, "The file 'select_files_file_not_found_error/report.json' does not exist.");
+}
+
+#[test]
+fn glob_base_directory_not_found_error() {
+
+    let result = select_files_on_filesystem("select_files_glob_base_not_found/**/*.yaml");
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System, "The base directory 'select_files_glob_base_not_found' of the pattern 'select_files_glob_base_not_found/**/*.yaml' does not exist.");
+}
@@ -0,0 +1,44 @@
+use std::fs;
+use std::path::Path;
+use nape_domain::procedure_management::usecases::scaffold_procedure::gateway_boundary::ScaffoldFile;
+use nape_kernel::error::{Error, Kind};
+
+/// # Overview
+///
+/// An implementation of the [`WriteScaffoldGateway`] which writes the files generated for a new procedure to the machine's filesystem.
+///
+/// # Arguments
+///
+/// * `directory` - The procedure directory, which is created when it does not exist.
+/// * `files` - The [`ScaffoldFile`]s to write, relative to the `directory`.
+///
+/// # Returns
+///
+/// A [`Result`] of either the paths of the files written, or an [`Error`].
+///
+/// # Errors
+///
+/// * An [`Error`] for [`Audience::User`] with [`Kind::InvalidInput`] is returned, before anything is written, if one of the files already exists.
+/// * An [`Error`] for [`Audience::System`] with [`Kind::ProcessingFailure`] is returned if a directory or file could not be written.
+///
+pub fn write_scaffold_to_filesystem(directory: &str, files: &[ScaffoldFile]) -> Result<Vec<String>, Error> {
+
+    let root = Path::new(directory);
+    if let Some(existing) = files.iter().map(|file| root.join(&file.path)).find(|path| path.exists()) {
+        return Err(Error::for_user(Kind::InvalidInput,
+                                   format!("The file '{}' already exists. Please choose another directory for the procedure.", existing.display())));
+    }
+
+    let mut written = Vec::new();
+    for file in files {
+        let target_file = root.join(&file.path);
+        if let Some(parent) = target_file.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not create the directory '{}': {}", parent.display(), e)))?;
+        }
+        fs::write(&target_file, &file.content)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not write the file '{}': {}", target_file.display(), e)))?;
+        written.push(target_file.to_string_lossy().to_string());
+    }
+    Ok(written)
+}
@@ -0,0 +1,56 @@
+use std::fs;
+use nape_domain::procedure_management::usecases::scaffold_procedure::gateway_boundary::ScaffoldFile;
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use crate::gateway_adapter::std_fs::write_scaffold_gateway::write_scaffold_to_filesystem;
+
+fn files() -> Vec<ScaffoldFile> {
+    vec![
+        ScaffoldFile { path: "assurance_procedure.yaml".to_string(), content: "apiVersion: 1.1.0".to_string() },
+        ScaffoldFile { path: "activity/peer-review/at-least-two-reviewers.py".to_string(), content: "# placeholder".to_string() },
+    ]
+}
+
+#[test]
+fn success() {
+
+    // Assemble
+    remove!("write_scaffold_success");
+    let parent = canonical_path!(create!("write_scaffold_success"));
+    let directory = format!("{}/rust-ci", parent);
+
+    // Act
+    let result = write_scaffold_to_filesystem(&directory, &files());
+
+    // Assert
+    let written = is_ok!(result);
+    assert_eq!(written, vec![
+        format!("{}/assurance_procedure.yaml", directory),
+        format!("{}/activity/peer-review/at-least-two-reviewers.py", directory),
+    ]);
+    assert_eq!(fs::read_to_string(&written[1]).unwrap(), "# placeholder");
+
+    // Clean up
+    remove!("write_scaffold_success");
+}
+
+#[test]
+fn file_exists_error() {
+
+    // Assemble
+    remove!("write_scaffold_file_exists_error");
+    let existing = create_file!("write_scaffold_file_exists_error/activity/peer-review/at-least-two-reviewers.py", "# my test");
+    let directory = canonical_path!(existing.ancestors().nth(3).unwrap().to_path_buf());
+
+    // Act
+    let result = write_scaffold_to_filesystem(&directory, &files());
+
+    // Assert
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        format!("The file '{}/activity/peer-review/at-least-two-reviewers.py' already exists. Please choose another directory for the procedure.", directory));
+    assert!(!std::path::Path::new(&format!("{}/assurance_procedure.yaml", directory)).exists());
+
+    // Clean up
+    remove!("write_scaffold_file_exists_error");
+}
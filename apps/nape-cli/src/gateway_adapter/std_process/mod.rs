@@ -0,0 +1,2 @@
+pub mod run_command_gateway;
+#[cfg(test)] mod run_command_gateway_tests;
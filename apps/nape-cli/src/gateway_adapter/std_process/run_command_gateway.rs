@@ -0,0 +1,19 @@
+use std::process::{Command, Stdio};
+use nape_domain::evidence_collection::usecases::capture_evidence::gateway_boundary::CommandOutput;
+use nape_kernel::error::{Error, Kind};
+
+/// Implementation of the [`RunCommandGateway`] which runs the command as a child process and captures its standard out.  Standard error is passed through to the terminal so the person collecting the evidence can see any problems.
+pub fn run_command_as_process(command: &[String]) -> Result<CommandOutput, Error> {
+
+    let (program, arguments) = command.split_first()
+        .ok_or_else(|| Error::for_system(Kind::InvalidInput, String::from("No command was provided to run.")))?;
+
+    let output = Command::new(program)
+        .args(arguments)
+        .stdin(Stdio::null())
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Failed to execute '{}'. {}", program, e)))?;
+
+    Ok(CommandOutput { stdout: output.stdout, exit_code: output.status.code() })
+}
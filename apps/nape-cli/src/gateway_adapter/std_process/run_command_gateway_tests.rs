@@ -0,0 +1,39 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::gateway_adapter::std_process::run_command_gateway::run_command_as_process;
+
+fn to_command(arguments: &[&str]) -> Vec<String> {
+    arguments.iter().map(|argument| argument.to_string()).collect()
+}
+
+#[test]
+fn success() {
+    let result = run_command_as_process(&to_command(&["echo", "captured evidence"]));
+
+    let output = is_ok!(result);
+    assert_eq!(output.stdout, b"captured evidence\n".to_vec());
+    assert_eq!(output.exit_code, Some(0));
+}
+
+#[test]
+fn non_zero_exit_code_success() {
+    let result = run_command_as_process(&to_command(&["sh", "-c", "echo partial; exit 3"]));
+
+    let output = is_ok!(result);
+    assert_eq!(output.stdout, b"partial\n".to_vec());
+    assert_eq!(output.exit_code, Some(3));
+}
+
+#[test]
+fn program_not_found_error() {
+    let result = run_command_as_process(&to_command(&["nape-program-that-does-not-exist"]));
+
+    kernel_error_starts_with!(result, Kind::ProcessingFailure, Audience::System, "Failed to execute 'nape-program-that-does-not-exist'. ");
+}
+
+#[test]
+fn no_command_error() {
+    let result = run_command_as_process(&[]);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::System, "No command was provided to run.");
+}
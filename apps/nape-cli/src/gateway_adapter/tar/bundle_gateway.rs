@@ -0,0 +1,98 @@
+use std::fs;
+use std::fs::File;
+use std::path::Path;
+use std::io::Read;
+use tar::{Archive, Builder, EntryType, Header, HeaderMode};
+use nape_domain::evidence_collection::usecases::bundle_collection::gateway_boundary::{BundleEntry, CollectionBundle};
+use nape_domain::evidence_collection::usecases::bundle_collection::manifest::BUNDLE_MANIFEST_FILE;
+use nape_kernel::error::{Error, Kind};
+
+/// The zstd compression level, which is the zstd default and a balance of speed and size.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Implementation of the [`PersistBundleGateway`] which writes the collection as a tar archive compressed with zstd.  The files are written with deterministic metadata, so bundling the same collection twice writes the same archive.
+pub fn write_tar_zstd_bundle(bundle: &CollectionBundle, bundle_file: &str) -> Result<String, Error> {
+
+    let file = File::create(bundle_file)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not create the bundle file '{}': {}", bundle_file, e)))?;
+    let encoder = zstd::Encoder::new(file, COMPRESSION_LEVEL)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not compress the bundle file '{}': {}", bundle_file, e)))?;
+
+    let mut builder = Builder::new(encoder);
+    builder.mode(HeaderMode::Deterministic);
+
+    for file in &bundle.files {
+        builder.append_path_with_name(&file.source, &file.relative_path)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not add the file '{}' to the bundle: {}", file.source, e)))?;
+    }
+
+    let mut header = Header::new_gnu();
+    header.set_entry_type(EntryType::Regular);
+    header.set_size(bundle.manifest.len() as u64);
+    header.set_mode(0o644);
+    builder.append_data(&mut header, BUNDLE_MANIFEST_FILE, bundle.manifest.as_slice())
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not add the manifest to the bundle: {}", e)))?;
+
+    builder.into_inner()
+        .and_then(|encoder| encoder.finish())
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not finish the bundle file '{}': {}", bundle_file, e)))?;
+
+    Ok(bundle_file.to_string())
+}
+
+/// Implementation of the [`RetrieveBundleGateway`] which reads each file from a tar archive compressed with zstd.  Directories and links in the archive are ignored.
+pub fn read_tar_zstd_bundle(bundle_file: &str) -> Result<Vec<BundleEntry>, Error> {
+
+    let file = File::open(bundle_file)
+        .map_err(|e| Error::for_user(Kind::NotFound, format!("Could not open the bundle file '{}': {}", bundle_file, e)))?;
+    let decoder = zstd::Decoder::new(file)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not decompress the bundle file '{}': {}", bundle_file, e)))?;
+
+    let mut archive = Archive::new(decoder);
+    let archive_entries = archive.entries()
+        .map_err(|e| Error::for_user(Kind::InvalidInput, format!("The file '{}' is not a bundle: {}", bundle_file, e)))?;
+
+    let mut entries = Vec::new();
+    for archive_entry in archive_entries {
+        let mut archive_entry = archive_entry
+            .map_err(|e| Error::for_user(Kind::InvalidInput, format!("The file '{}' is not a bundle: {}", bundle_file, e)))?;
+        if archive_entry.header().entry_type() != EntryType::Regular {
+            continue;
+        }
+        let path = archive_entry.path()
+            .map_err(|e| Error::for_user(Kind::InvalidInput, format!("The bundle '{}' has a file with an invalid path: {}", bundle_file, e)))?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let mut data = Vec::new();
+        archive_entry.read_to_end(&mut data)
+            .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not read the file '{}' from the bundle: {}", path, e)))?;
+        entries.push(BundleEntry { path, data });
+    }
+
+    Ok(entries)
+}
+
+/// Implementation of the [`UnpackBundleGateway`] which unpacks a tar archive compressed with zstd into a new directory.  Files whose path would be written outside of the directory are skipped.
+pub fn unpack_tar_zstd_bundle(bundle_file: &str, directory: &str) -> Result<String, Error> {
+
+    if Path::new(directory).exists() {
+        return Err(Error::for_user(Kind::InvalidInput,
+                                   format!("The directory '{}' already exists. Please use '--home {}' to report on a bundle which is already unpacked.", directory, directory)));
+    }
+
+    let file = File::open(bundle_file)
+        .map_err(|e| Error::for_user(Kind::NotFound, format!("Could not open the bundle file '{}': {}", bundle_file, e)))?;
+    let decoder = zstd::Decoder::new(file)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not decompress the bundle file '{}': {}", bundle_file, e)))?;
+
+    fs::create_dir_all(directory)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure, format!("Could not create the directory '{}': {}", directory, e)))?;
+
+    Archive::new(decoder).unpack(directory)
+        .map_err(|e| {
+            let _ = fs::remove_dir_all(directory);
+            Error::for_user(Kind::InvalidInput, format!("The file '{}' is not a bundle: {}", bundle_file, e))
+        })?;
+
+    Ok(directory.to_string())
+}
@@ -0,0 +1,113 @@
+use nape_domain::evidence_collection::usecases::bundle_collection::gateway_boundary::{BundleEntry, CollectionBundle};
+use nape_kernel::error::{Audience, Kind};
+use nape_kernel::gateways::file_selection::SelectedFile;
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use nape_testing_filesystem::{canonical_path, create, create_file, remove};
+use std::fs;
+use crate::gateway_adapter::tar::bundle_gateway::{read_tar_zstd_bundle, unpack_tar_zstd_bundle, write_tar_zstd_bundle};
+
+fn entry(path: &str, data: &str) -> BundleEntry {
+    BundleEntry { path: path.to_string(), data: data.as_bytes().to_vec() }
+}
+
+#[test]
+fn write_and_read_success() {
+
+    // Assemble
+    remove!("tar_bundle_write_and_read_success");
+    create_file!("tar_bundle_write_and_read_success/collection/assurance_report.yaml", "outcome: pass");
+    let review = create_file!("tar_bundle_write_and_read_success/collection/evidence/peer-review/review.json", "{}");
+    let collection_directory = canonical_path!(review.ancestors().nth(3).unwrap().to_path_buf());
+    let bundle = CollectionBundle {
+        files: ["assurance_report.yaml", "evidence/peer-review/review.json"].iter()
+            .map(|relative_path| SelectedFile { source: format!("{}/{}", collection_directory, relative_path), relative_path: relative_path.to_string() })
+            .collect(),
+        manifest: b"SHA256[abc] assurance_report.yaml\n".to_vec(),
+    };
+    let bundle_file = format!("{}/../collection.tar.zst", collection_directory);
+
+    // Act
+    let written = write_tar_zstd_bundle(&bundle, &bundle_file);
+    let entries = read_tar_zstd_bundle(&bundle_file);
+
+    // Assert
+    assert_eq!(is_ok!(written), bundle_file);
+    assert_eq!(is_ok!(entries), vec![
+        entry("assurance_report.yaml", "outcome: pass"),
+        entry("evidence/peer-review/review.json", "{}"),
+        entry("bundle_manifest.txt", "SHA256[abc] assurance_report.yaml\n"),
+    ]);
+
+    // Clean up
+    remove!("tar_bundle_write_and_read_success");
+}
+
+#[test]
+fn unpack_success() {
+
+    // Assemble
+    remove!("tar_bundle_unpack_success");
+    let report = create_file!("tar_bundle_unpack_success/collection/assurance_report.yaml", "outcome: pass");
+    let collection_directory = canonical_path!(report.parent().unwrap().to_path_buf());
+    let bundle = CollectionBundle {
+        files: vec![SelectedFile { source: format!("{}/assurance_report.yaml", collection_directory), relative_path: "assurance_report.yaml".to_string() }],
+        manifest: b"SHA256[abc] assurance_report.yaml\n".to_vec(),
+    };
+    let bundle_file = format!("{}/../collection.tar.zst", collection_directory);
+    let unpack_directory = format!("{}/../unpacked", collection_directory);
+    is_ok!(write_tar_zstd_bundle(&bundle, &bundle_file));
+
+    // Act
+    let result = unpack_tar_zstd_bundle(&bundle_file, &unpack_directory);
+
+    // Assert
+    assert_eq!(is_ok!(result), unpack_directory);
+    assert_eq!(fs::read_to_string(format!("{}/assurance_report.yaml", unpack_directory)).unwrap(), "outcome: pass");
+    assert!(fs::metadata(format!("{}/bundle_manifest.txt", unpack_directory)).is_ok());
+
+    // Clean up
+    remove!("tar_bundle_unpack_success");
+}
+
+#[test]
+fn unpack_directory_exists_error() {
+
+    // Assemble
+    remove!("tar_bundle_unpack_directory_exists_error");
+    let directory = canonical_path!(create!("tar_bundle_unpack_directory_exists_error/unpacked"));
+
+    // Act
+    let result = unpack_tar_zstd_bundle("collection.tar.zst", &directory);
+
+    // Assert
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, &format!("The directory '{}' already exists. ", directory));
+
+    // Clean up
+    remove!("tar_bundle_unpack_directory_exists_error");
+}
+
+#[test]
+fn read_not_a_bundle_error() {
+
+    // Assemble
+    remove!("tar_bundle_read_not_a_bundle_error");
+    let not_a_bundle = create_file!("tar_bundle_read_not_a_bundle_error/collection.tar.zst", "plain text");
+    let bundle_file = canonical_path!(not_a_bundle);
+
+    // Act
+    let result = read_tar_zstd_bundle(&bundle_file);
+
+    // Assert
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, &format!("The file '{}' is not a bundle: ", bundle_file));
+
+    // Clean up
+    remove!("tar_bundle_read_not_a_bundle_error");
+}
+
+#[test]
+fn read_missing_bundle_error() {
+
+    let result = read_tar_zstd_bundle("tar_bundle_read_missing_bundle_error/collection.tar.zst");
+
+    kernel_error_starts_with!(result, Kind::NotFound, Audience::User, "Could not open the bundle file 'tar_bundle_read_missing_bundle_error/collection.tar.zst': ");
+}
@@ -0,0 +1,2 @@
+pub mod bundle_gateway;
+#[cfg(test)] mod bundle_gateway_tests;
@@ -8,6 +8,8 @@ pub fn run() -> Result<ArgMatches, Error> {
         .propagate_version(true)
         .about("Collects evidence, applies test of details, generates report, and uploads results to your repository.")
         .subcommand(cli_commands::collect())
+        .subcommand(cli_commands::bundle_commands())
+        .subcommand(cli_commands::procedure_commands())
         .get_matches())
 }
 
@@ -62,8 +62,65 @@ pub fn evidence_file_path() -> Arg {
         .short('f')
         .long("file-path")
         .value_name("Evidence File Path")
-        .help("The path to the file that contains the evidence.")
-        .required(true)
+        .help("The path to the file, directory, or glob pattern (e.g. 'manifests/**/*.yaml') that contains the evidence.  Directories and glob patterns are collected recursively.")
+        .required_unless_present_any(["evidence-command", "evidence-url"])
+        .conflicts_with_all(["evidence-command", "evidence-url"])
+}
+
+pub fn evidence_command() -> Arg {
+    Arg::new("evidence-command")
+        .value_name("Command")
+        .help("A command whose standard out is captured as the evidence, provided after '--'.  For example, 'nape collect evidence -a pod-inventory -- kubectl get pods -o json'.")
+        .num_args(1..)
+        .last(true)
+        .required(false)
+        .conflicts_with("evidence-url")
+}
+
+pub fn evidence_url() -> Arg {
+    Arg::new("evidence-url")
+        .long("url")
+        .value_name("URL")
+        .help("A URL whose response body is captured as the evidence.  The response status code, headers, and fetch time are recorded in a sidecar file next to the evidence.")
+        .required(false)
+}
+
+pub fn evidence_url_header() -> Arg {
+    Arg::new("evidence-url-header")
+        .short('H')
+        .long("header")
+        .value_name("Header")
+        .help("A header to send with the '--url' request, in the format 'Name: value'.  This option can be provided more than once.")
+        .action(ArgAction::Append)
+        .requires("evidence-url")
+        .required(false)
+}
+
+pub fn bearer_token_env() -> Arg {
+    Arg::new("bearer-token-env")
+        .long("bearer-token-env")
+        .value_name("Environment Variable")
+        .help("The name of an environment variable which holds a bearer token to send with the '--url' request.  The token is never recorded with the evidence.")
+        .requires("evidence-url")
+        .required(false)
+}
+
+pub fn no_redact() -> Arg {
+    Arg::new("no-redact")
+        .long("no-redact")
+        .help("Collect the evidence without redacting secrets.  By default, private keys, JWTs, AWS keys, and values such as 'password=...' are redacted before the evidence is written and signed.")
+        .action(ArgAction::SetTrue)
+        .required(false)
+}
+
+pub fn redaction_rule() -> Arg {
+    Arg::new("redaction-rule")
+        .long("redact")
+        .value_name("Pattern")
+        .help("A regular expression which matches an additional secret to redact from the evidence.  When the pattern has a capture group, only the group is redacted.  This option can be provided more than once.")
+        .action(ArgAction::Append)
+        .conflicts_with("no-redact")
+        .required(false)
 }
 
 pub fn evidence_file_name() -> Arg {
@@ -71,6 +128,164 @@ pub fn evidence_file_name() -> Arg {
         .short('n')
         .long("file-name")
         .value_name("Evidence File Name")
-        .help("The a file name and type that you'd like to rename the evidence file to.  This is optional and usefule when the control activity expects a file by a specific name, although the file is currently stored as a different name.")
+        .help("The name of the evidence file, when the action declares a directory as its evidence, such as 'evidence/pods/'.  When not provided, a file is named after the file collected, and the output of a command or URL is named after the program or the last segment of the URL.  When the action declares a file, the evidence is always named after it, so the name must match it.")
         .required(false)
-}
\ No newline at end of file
+}
+
+pub fn evidence_input() -> Arg {
+    Arg::new("evidence-input")
+        .short('i')
+        .long("input")
+        .value_name("Evidence Input")
+        .help("The name of the evidence input the action declares in the procedure to collect the evidence for.  The evidence is written to the path the input declares.  When not provided, the evidence is collected as the primary 'evidence' of the action.")
+        .required(false)
+}
+
+pub fn attestation_outcome() -> Arg {
+    Arg::new("attestation-outcome")
+        .long("outcome")
+        .value_name("Outcome")
+        .help("The outcome you attest for the attestation action, which is one of 'pass', 'fail', or 'inconclusive'.")
+        .required(true)
+}
+
+pub fn attestation_rationale() -> Arg {
+    Arg::new("attestation-rationale")
+        .long("rationale")
+        .value_name("Rationale")
+        .help("Why you attest the outcome.  The rationale is recorded in the signed statement along with who attested the action and when.")
+        .required(true)
+}
+
+pub fn supporting_file() -> Arg {
+    Arg::new("supporting-file")
+        .short('f')
+        .long("file")
+        .value_name("Supporting File")
+        .help("The path to a file which supports the attestation, such as the minutes of a review.  The file is copied next to the statement and signed.  This option can be provided more than once.")
+        .action(ArgAction::Append)
+        .required(false)
+}
+
+pub fn outcome_policy() -> Arg {
+    Arg::new("outcome-policy")
+        .long("fail-on")
+        .value_name("Outcome Policy")
+        .help("The severities of failed actions which fail the report, as a comma separated list such as 'critical,high'. Failed actions without a severity always fail the report. Defaults to 'any', where any failed action fails the report.")
+        .required(false)
+}
+
+pub fn waiver_file() -> Arg {
+    Arg::new("waiver-file")
+        .long("waivers")
+        .value_name("Waiver File")
+        .help("The path to a YAML file of waivers.  A failed action with an unexpired waiver is reported as waived rather than failed.")
+        .required(false)
+}
+
+pub fn tailoring_file() -> Arg {
+    Arg::new("tailoring-file")
+        .long("tailoring")
+        .value_name("Tailoring File")
+        .help("The path to a YAML file of actions which do not apply to the subject, each with a justification.  Excluded actions are not evaluated and are reported as not applicable.")
+        .required(false)
+}
+
+pub fn publish_git() -> Arg {
+    Arg::new("publish-git")
+        .long("git")
+        .value_name("Repository URL")
+        .help("The URL, or local path, of a git evidence repository.  The collection is committed and pushed to the branch the repository's HEAD refers to.")
+        .required(false)
+}
+
+pub fn publish_directory() -> Arg {
+    Arg::new("publish-directory")
+        .long("directory")
+        .value_name("Directory")
+        .help("The path of an existing directory, such as a mounted share, to copy the collection to.")
+        .required(false)
+}
+
+pub fn publish_s3() -> Arg {
+    Arg::new("publish-s3")
+        .long("s3")
+        .value_name("Bucket URL")
+        .help("The path-style URL of a bucket in an S3 compatible store, such as 'http://localhost:9000/evidence'.  The requests are signed with the credentials in the 'AWS_ACCESS_KEY_ID', 'AWS_SECRET_ACCESS_KEY', and optional 'AWS_SESSION_TOKEN' and 'AWS_REGION' environment variables.")
+        .required(false)
+}
+
+pub fn bundle_output() -> Arg {
+    Arg::new("bundle-output")
+        .short('o')
+        .long("output")
+        .value_name("Bundle File")
+        .help("The path of the bundle file to write.  Defaults to a file in the current directory named after the subject and the start time of the collection, such as 'nrn_sourcecode_example_1719326666123.tar.zst'.")
+        .required(false)
+}
+
+pub fn bundle_file() -> Arg {
+    Arg::new("bundle-file")
+        .value_name("Bundle File")
+        .help("The path of the bundle file, a tar archive compressed with zstd written by 'nape collect bundle'.")
+        .required(true)
+}
+
+pub fn report_home() -> Arg {
+    Arg::new("report-home")
+        .long("home")
+        .value_name("Collection Directory")
+        .help("The home directory of a collection to report on, such as an archived collection, rather than the collection started with 'nape collect start'.  The subject and procedure are read from the assurance report in the directory, which is replaced.")
+        .conflicts_with("report-bundle")
+        .required(false)
+}
+
+pub fn report_bundle() -> Arg {
+    Arg::new("report-bundle")
+        .long("bundle")
+        .value_name("Bundle File")
+        .help("A bundle written by 'nape collect bundle' to report on.  The bundle is unpacked into a directory next to it, named after the bundle without the '.tar.zst' extension, and the new report is written there.")
+        .required(false)
+}
+
+pub fn procedure_repository() -> Arg {
+    Arg::new("procedure-repository")
+        .value_name("Procedure Repository")
+        .help("The URL of the git repository, or the local directory, which holds the NAPE Assurance Procedures.")
+        .required(true)
+}
+
+pub fn procedure_nrn() -> Arg {
+    Arg::new("procedure-nrn")
+        .value_name("Procedure NRN")
+        .help("The NRN of the new procedure, such as 'nrn:procedure:nape:rust-ci/sourcecode-integration'.")
+        .required(true)
+}
+
+pub fn procedure_activity() -> Arg {
+    Arg::new("procedure-activity")
+        .short('a')
+        .long("activity")
+        .value_name("Activity:Action,Action")
+        .help("An activity of the new procedure and the names of its actions, such as 'peer-review:at-least-two-reviewers,requester-not-a-reviewer'.  This option can be provided more than once.")
+        .action(ArgAction::Append)
+        .required(true)
+}
+
+pub fn procedure_short_description() -> Arg {
+    Arg::new("procedure-short-description")
+        .short('s')
+        .long("short")
+        .value_name("Short Description")
+        .help("The short description of the new procedure.  A placeholder is written when it is not provided.")
+        .required(false)
+}
+
+pub fn procedure_output() -> Arg {
+    Arg::new("procedure-output")
+        .short('o')
+        .long("output")
+        .value_name("Procedure Directory")
+        .help("The directory to write the new procedure to.  Defaults to a directory in the current directory named after the last part of the procedure NRN, such as 'sourcecode-integration'.")
+        .required(false)
+}
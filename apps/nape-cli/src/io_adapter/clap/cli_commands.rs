@@ -1,12 +1,15 @@
-use clap::{Command};
-use crate::io_adapter::clap::cli_arguments::{control_action_name, evidence_file_name, evidence_file_path, metadata, procedure_directory, procedure_link, subject, subject_id};
+use clap::{ArgGroup, Command};
+use crate::io_adapter::clap::cli_arguments::{attestation_outcome, attestation_rationale, bearer_token_env, bundle_file, bundle_output, control_action_name, evidence_command, evidence_file_name, evidence_file_path, evidence_input, evidence_url, evidence_url_header, metadata, no_redact, outcome_policy, procedure_activity, procedure_directory, procedure_link, procedure_nrn, procedure_output, procedure_repository, procedure_short_description, publish_directory, publish_git, publish_s3, redaction_rule, report_bundle, report_home, subject, subject_id, supporting_file, tailoring_file, waiver_file};
 
 pub fn collect() -> Command {
     Command::new("collect")
         .about("Collects evidence, applies test of details, generates report, and uploads results to your evidence repository.")
         .subcommand(start())
         .subcommand(evidence())
+        .subcommand(attest())
         .subcommand(report())
+        .subcommand(bundle())
+        .subcommand(publish())
 }
 
 
@@ -18,6 +21,7 @@ pub fn start() -> Command {
         .arg(procedure_link())
         .arg(procedure_directory())
         .arg(metadata())
+        .arg(tailoring_file())
 }
 
 pub fn evidence() -> Command {
@@ -26,9 +30,81 @@ pub fn evidence() -> Command {
         .arg(control_action_name())
         .arg(evidence_file_path())
         .arg(evidence_file_name())
+        .arg(evidence_input())
+        .arg(evidence_url())
+        .arg(evidence_url_header())
+        .arg(bearer_token_env())
+        .arg(no_redact())
+        .arg(redaction_rule())
+        .arg(evidence_command())
+}
+
+pub fn attest() -> Command {
+    Command::new("attest")
+        .about("Attest the outcome of an attestation action, which has no test, by recording your signed statement as its evidence.")
+        .arg(control_action_name())
+        .arg(attestation_outcome())
+        .arg(attestation_rationale())
+        .arg(supporting_file())
 }
 
 pub fn report() -> Command {
     Command::new("report")
         .about("Evaluate all of the collected evidence and generate a report.")
+        .arg(outcome_policy())
+        .arg(waiver_file())
+        .arg(report_home())
+        .arg(report_bundle())
+}
+
+pub fn publish() -> Command {
+    Command::new("publish")
+        .about("Publish the assurance report, evidence, and procedure snapshot of the collection to your evidence repository.")
+        .arg(publish_git())
+        .arg(publish_directory())
+        .arg(publish_s3())
+        .group(ArgGroup::new("publish-target")
+            .args(["publish-git", "publish-directory", "publish-s3"])
+            .required(true))
+}
+
+
+pub fn bundle() -> Command {
+    Command::new("bundle")
+        .about("Package the assurance report, evidence, activity tests, and procedure of the collection into a single archive with a manifest of signatures, which can be handed to an auditor.")
+        .arg(bundle_output())
+}
+
+pub fn bundle_commands() -> Command {
+    Command::new("bundle")
+        .about("Work with the evidence bundles written by 'nape collect bundle'.")
+        .subcommand(verify())
+}
+
+pub fn verify() -> Command {
+    Command::new("verify")
+        .about("Check every file in a bundle against its manifest, and recompute the signatures of the test and evidence files in its assurance report.  No network access is needed.")
+        .arg(bundle_file())
+}
+
+pub fn procedure_commands() -> Command {
+    Command::new("procedure")
+        .about("Work with the NAPE Assurance Procedures in a procedure repository.")
+        .subcommand(list())
+        .subcommand(new())
+}
+
+pub fn list() -> Command {
+    Command::new("list")
+        .about("Find every procedure in a procedure repository, and show its NRN, short description, and the number of activities and actions.  The directory shown for each procedure is the '--procedure-directory' for 'nape collect start'.")
+        .arg(procedure_repository())
+}
+
+pub fn new() -> Command {
+    Command::new("new")
+        .about("Create a new procedure with an assurance procedure file, and a placeholder test for each action at the path of its test.  The new procedure is valid, and ready for its descriptions and tests to be written.")
+        .arg(procedure_nrn())
+        .arg(procedure_activity())
+        .arg(procedure_short_description())
+        .arg(procedure_output())
 }
\ No newline at end of file
@@ -0,0 +1,42 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use clap::{ArgMatches, Command};
+use nape_kernel::error::Error;
+use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+use crate::io_adapter::clap::command_handlers::bundle::bundle_command_handler::BundleCommandHandler;
+
+/// Test when a subcommand matches: This test will ensure that the handle method calls the subcommand whose name matches the arguments.
+#[test]
+fn bundle_command_handle_success() {
+    let mock_handler = MockCommandHandler::new("verify");
+    let bundle_handler = BundleCommandHandler::new(vec![Box::new(mock_handler.clone())]);
+
+    let matches = Command::new("bundle")
+        .subcommand(Command::new("verify"))
+        .get_matches_from(vec!["bundle", "verify"]);
+
+    let result = bundle_handler.handle(&matches);
+
+    assert!(result.is_ok());
+    assert!(*mock_handler.was_called.borrow());
+}
+
+#[derive(Clone)]
+struct MockCommandHandler {
+    name: String,
+    was_called: Rc<RefCell<bool>>,
+}
+
+impl MockCommandHandler {
+    fn new(name: &str) -> Self {
+        MockCommandHandler { name: name.to_string(), was_called: Rc::new(RefCell::new(false)) }
+    }
+}
+
+impl CommandHandlerBoundary for MockCommandHandler {
+    fn name(&self) -> &str { &self.name }
+    fn handle(&self, _args: &ArgMatches) -> Result<(), Error> {
+        *self.was_called.borrow_mut() = true;
+        Ok(())
+    }
+}
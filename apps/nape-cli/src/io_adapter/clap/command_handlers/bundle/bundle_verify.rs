@@ -0,0 +1,32 @@
+use clap::ArgMatches;
+use nape_domain::evidence_collection::usecases::bundle_collection::usecase::{UCVerifyBundle, VerifyBundleRequest};
+use nape_kernel::error::{Error, Kind};
+use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+
+pub struct VerifyBundleCommandHandler<'a> {
+    pub command_name: &'a str,
+    usecase: UCVerifyBundle,
+    retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>,
+}
+
+impl<'a> VerifyBundleCommandHandler<'a> {
+    pub fn new(usecase: UCVerifyBundle, retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>) -> VerifyBundleCommandHandler<'a> {
+        VerifyBundleCommandHandler { command_name: "verify", usecase, retrieve_organisation_nids }
+    }
+}
+
+impl<'a> CommandHandlerBoundary for VerifyBundleCommandHandler<'a> {
+    fn name(&self) -> &str {
+        self.command_name
+    }
+    fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
+        let bundle_file = args.get_one::<String>("bundle-file")
+            .ok_or_else(|| Error::for_user(Kind::InvalidInput, String::from("The bundle file is required to verify a bundle.")))?;
+        let organisation_nids = (self.retrieve_organisation_nids)()?;
+        let request = VerifyBundleRequest { bundle_file, organisation_nids: &organisation_nids };
+        match (self.usecase)(&request) {
+            Ok(_) => { Ok(()) },
+            Err(e) => Err(e)
+        }
+    }
+}
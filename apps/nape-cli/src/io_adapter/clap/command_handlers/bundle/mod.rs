@@ -0,0 +1,4 @@
+pub mod bundle_command_handler;
+pub mod bundle_verify;
+
+#[cfg(test)] mod bundle_command_handler_tests;
@@ -0,0 +1,46 @@
+use clap::ArgMatches;
+use nape_domain::evidence_collection::usecases::attest_action::usecase::{AttestActionRequest, UCAttestAction};
+use nape_kernel::error::{Error, Kind};
+use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+
+pub struct CollectAttestCommandHandler<'a> {
+    pub command_name: &'a str,
+    usecase: UCAttestAction,
+}
+
+impl<'a> CollectAttestCommandHandler<'a> {
+    pub fn new(usecase: UCAttestAction) -> CollectAttestCommandHandler<'a> {
+        CollectAttestCommandHandler { command_name: "attest", usecase }
+    }
+}
+
+impl<'a> CommandHandlerBoundary for CollectAttestCommandHandler<'a> {
+    fn name(&self) -> &str {
+        self.command_name
+    }
+    fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
+        let supporting_files: Vec<String> = args.get_many::<String>("supporting-file")
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default();
+        let request = extract_arguments(args, &supporting_files)?;
+        let _ = (self.usecase)(&request)?;
+        Ok(())
+    }
+}
+
+fn extract_arguments<'a>(matches: &'a ArgMatches, supporting_files: &'a [String]) -> Result<AttestActionRequest<'a>, Error> {
+
+    let action_name = matches.get_one::<String>("control-activity-name")
+        .ok_or(Error::for_user(Kind::InvalidInput, String::from("The control action name is required.")))?;
+    let outcome = matches.get_one::<String>("attestation-outcome")
+        .ok_or(Error::for_user(Kind::InvalidInput, String::from("The attested outcome is required.")))?;
+    let rationale = matches.get_one::<String>("attestation-rationale")
+        .ok_or(Error::for_user(Kind::InvalidInput, String::from("The rationale of the attestation is required.")))?;
+
+    Ok(AttestActionRequest {
+        action_name,
+        outcome,
+        rationale,
+        supporting_files,
+    })
+}
@@ -0,0 +1,36 @@
+use clap::ArgMatches;
+use nape_domain::evidence_collection::usecases::bundle_collection::usecase::{BundleCollectionRequest, UCBundleCollection};
+use nape_kernel::error::Error;
+use crate::gateway_adapter::state_management::retrieve_app_state::app_state_from_nape_config;
+use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+
+pub struct BundleCollectionCommandHandler<'a> {
+    pub command_name: &'a str,
+    usecase: UCBundleCollection,
+    retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>,
+}
+
+impl<'a> BundleCollectionCommandHandler<'a> {
+    pub fn new(usecase: UCBundleCollection, retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>) -> BundleCollectionCommandHandler<'a> {
+        BundleCollectionCommandHandler { command_name: "bundle", usecase, retrieve_organisation_nids }
+    }
+}
+
+impl<'a> CommandHandlerBoundary for BundleCollectionCommandHandler<'a> {
+    fn name(&self) -> &str {
+        self.command_name
+    }
+    fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
+        let app_state = app_state_from_nape_config()?;
+        let organisation_nids = (self.retrieve_organisation_nids)()?;
+        let request = BundleCollectionRequest {
+            subject_nrn: &app_state.subject_nrn,
+            bundle_file: args.get_one::<String>("bundle-output").map(String::as_str),
+            organisation_nids: &organisation_nids,
+        };
+        match (self.usecase)(&request) {
+            Ok(_) => { Ok(()) },
+            Err(e) => Err(e)
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use std::env;
 use clap::ArgMatches;
+use nape_domain::evidence_collection::usecases::capture_evidence::usecase::{CaptureEvidenceRequest, CaptureUrlRequest, UCCaptureCommandEvidence, UCCaptureUrlEvidence};
 use nape_domain::evidence_collection::usecases::collect_evidence::usecase::{CollectEvidenceRequest, UCCollectEvidenceFile};
 use nape_kernel::error::{Error, Kind};
 use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
@@ -6,11 +8,13 @@ use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
 pub struct CollectEvidenceCommandHandler<'a> {
     pub command_name: &'a str,
     usecase: UCCollectEvidenceFile,
+    capture_usecase: UCCaptureCommandEvidence,
+    url_capture_usecase: UCCaptureUrlEvidence,
 }
 
 impl<'a> CollectEvidenceCommandHandler<'a> {
-    pub fn new(usecase: UCCollectEvidenceFile) -> CollectEvidenceCommandHandler<'a> {
-        CollectEvidenceCommandHandler { command_name: "evidence", usecase }
+    pub fn new(usecase: UCCollectEvidenceFile, capture_usecase: UCCaptureCommandEvidence, url_capture_usecase: UCCaptureUrlEvidence) -> CollectEvidenceCommandHandler<'a> {
+        CollectEvidenceCommandHandler { command_name: "evidence", usecase, capture_usecase, url_capture_usecase }
     }
 }
 
@@ -19,23 +23,97 @@ impl<'a> CommandHandlerBoundary for CollectEvidenceCommandHandler<'a> {
         self.command_name
     }
     fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
-        let request = extract_arguments(args)?;
+        let redaction_rules: Vec<String> = args.get_many::<String>("redaction-rule")
+            .map(|values| values.cloned().collect())
+            .unwrap_or_default();
+        if args.contains_id("evidence-command") {
+            let command: Vec<String> = args.get_many::<String>("evidence-command")
+                .map(|values| values.cloned().collect())
+                .unwrap_or_default();
+            let request = extract_capture_arguments(args, &command, &redaction_rules)?;
+            let _ = (self.capture_usecase)(&request)?;
+            return Ok(());
+        }
+        if args.contains_id("evidence-url") {
+            let headers: Vec<String> = args.get_many::<String>("evidence-url-header")
+                .map(|values| values.cloned().collect())
+                .unwrap_or_default();
+            let bearer_token = extract_bearer_token(args)?;
+            let request = extract_url_arguments(args, &headers, bearer_token.as_deref(), &redaction_rules)?;
+            let _ = (self.url_capture_usecase)(&request)?;
+            return Ok(());
+        }
+        let request = extract_arguments(args, &redaction_rules)?;
         let _ = (self.usecase)(&request)?;
         Ok(())
     }
 }
 
-fn extract_arguments(matches: &ArgMatches)  -> Result<CollectEvidenceRequest, Error> {
+fn extract_arguments<'a>(matches: &'a ArgMatches, redaction_rules: &'a [String])  -> Result<CollectEvidenceRequest<'a>, Error> {
 
     let action_name = matches.get_one::<String>("control-activity-name")
         .ok_or(Error::for_user(Kind::InvalidInput, String::from("The control action name is required.")))?;
     let file_path = matches.get_one::<String>("evidence-file-path")
         .ok_or(Error::for_user(Kind::InvalidInput, String::from("The evidence file path is required.")))?;
     let file_name = matches.get_one::<String>("evidence-file-name");
+    let input = matches.get_one::<String>("evidence-input");
 
     Ok(CollectEvidenceRequest {
         action_name, file_path,
         file_name: file_name.as_ref().map(|arg0: &&String| String::as_str(*arg0)),
+        input: input.map(String::as_str),
+        no_redact: matches.get_flag("no-redact"),
+        redaction_rules,
     })
 
 }
+
+fn extract_capture_arguments<'a>(matches: &'a ArgMatches, command: &'a [String], redaction_rules: &'a [String]) -> Result<CaptureEvidenceRequest<'a>, Error> {
+
+    let action_name = matches.get_one::<String>("control-activity-name")
+        .ok_or(Error::for_user(Kind::InvalidInput, String::from("The control action name is required.")))?;
+    let file_name = matches.get_one::<String>("evidence-file-name");
+    let input = matches.get_one::<String>("evidence-input");
+
+    Ok(CaptureEvidenceRequest {
+        action_name,
+        command,
+        file_name: file_name.map(String::as_str),
+        input: input.map(String::as_str),
+        no_redact: matches.get_flag("no-redact"),
+        redaction_rules,
+    })
+
+}
+
+fn extract_url_arguments<'a>(matches: &'a ArgMatches, headers: &'a [String], bearer_token: Option<&'a str>, redaction_rules: &'a [String]) -> Result<CaptureUrlRequest<'a>, Error> {
+
+    let action_name = matches.get_one::<String>("control-activity-name")
+        .ok_or(Error::for_user(Kind::InvalidInput, String::from("The control action name is required.")))?;
+    let url = matches.get_one::<String>("evidence-url")
+        .ok_or(Error::for_user(Kind::InvalidInput, String::from("The URL is required.")))?;
+    let file_name = matches.get_one::<String>("evidence-file-name");
+    let input = matches.get_one::<String>("evidence-input");
+
+    Ok(CaptureUrlRequest {
+        action_name,
+        url,
+        headers,
+        bearer_token,
+        file_name: file_name.map(String::as_str),
+        input: input.map(String::as_str),
+        no_redact: matches.get_flag("no-redact"),
+        redaction_rules,
+    })
+
+}
+
+fn extract_bearer_token(matches: &ArgMatches) -> Result<Option<String>, Error> {
+    match matches.get_one::<String>("bearer-token-env") {
+        Some(variable) => env::var(variable)
+            .map(Some)
+            .map_err(|_| Error::for_user(Kind::InvalidInput,
+                                         format!("The environment variable '{}' for the bearer token is not set.", variable))),
+        None => Ok(None)
+    }
+}
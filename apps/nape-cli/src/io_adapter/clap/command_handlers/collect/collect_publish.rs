@@ -0,0 +1,50 @@
+use clap::ArgMatches;
+use nape_domain::evidence_collection::usecases::publish_collection::gateway_boundary::PublishTarget;
+use nape_domain::evidence_collection::usecases::publish_collection::usecase::{PublishCollectionRequest, UCPublishCollection};
+use nape_kernel::error::{Error, Kind};
+use crate::gateway_adapter::state_management::retrieve_app_state::app_state_from_nape_config;
+use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+
+pub struct PublishCollectionCommandHandler<'a> {
+    pub command_name: &'a str,
+    usecase: UCPublishCollection,
+    retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>,
+}
+
+impl<'a> PublishCollectionCommandHandler<'a> {
+    pub fn new(usecase: UCPublishCollection, retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>) -> PublishCollectionCommandHandler<'a> {
+        PublishCollectionCommandHandler { command_name: "publish", usecase, retrieve_organisation_nids }
+    }
+}
+
+impl<'a> CommandHandlerBoundary for PublishCollectionCommandHandler<'a> {
+    fn name(&self) -> &str {
+        self.command_name
+    }
+    fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
+        let app_state = app_state_from_nape_config()?;
+        let organisation_nids = (self.retrieve_organisation_nids)()?;
+        let request = PublishCollectionRequest {
+            subject_nrn: &app_state.subject_nrn,
+            target: publish_target(args)?,
+            organisation_nids: &organisation_nids,
+        };
+        let published = (self.usecase)(&request)?;
+        println!("Published {} files to '{}'.", published.file_count, published.location);
+        Ok(())
+    }
+}
+
+fn publish_target(args: &ArgMatches) -> Result<PublishTarget, Error> {
+    if let Some(repository) = args.get_one::<String>("publish-git") {
+        return Ok(PublishTarget::Git { repository: repository.to_string() });
+    }
+    if let Some(path) = args.get_one::<String>("publish-directory") {
+        return Ok(PublishTarget::Directory { path: path.to_string() });
+    }
+    if let Some(bucket_url) = args.get_one::<String>("publish-s3") {
+        return Ok(PublishTarget::S3 { bucket_url: bucket_url.to_string() });
+    }
+    Err(Error::for_user(Kind::InvalidInput,
+                        String::from("An evidence repository is required to publish the collection. Please provide one of '--git', '--directory', or '--s3'.")))
+}
@@ -1,18 +1,20 @@
 use clap::ArgMatches;
+use nape_domain::evidence_collection::usecases::bundle_collection::gateway::UnpackBundleGateway;
 use nape_domain::evidence_collection::usecases::evaluate_evidence::usecase::EvaluateAndReportEvidenceUC;
 use nape_domain::evidence_collection::usecases::evaluate_evidence::usecase_boundary::request::EvaluateEvidence;
 use nape_kernel::error::{Error, Kind};
-use crate::gateway_adapter::state_management::retrieve_app_state::app_state_from_nape_config;
+use crate::gateway_adapter::state_management::retrieve_app_state::{app_state_from_collection, app_state_from_nape_config};
 use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
 
 pub struct EvaluateAndReportCommandHandler<'a> {
     pub command_name: &'a str,
     usecase: EvaluateAndReportEvidenceUC,
+    unpack_bundle: UnpackBundleGateway,
 }
 
 impl<'a> EvaluateAndReportCommandHandler<'a> {
-    pub fn new(usecase: EvaluateAndReportEvidenceUC) -> EvaluateAndReportCommandHandler<'a> {
-        EvaluateAndReportCommandHandler { command_name: "report", usecase }
+    pub fn new(usecase: EvaluateAndReportEvidenceUC, unpack_bundle: UnpackBundleGateway) -> EvaluateAndReportCommandHandler<'a> {
+        EvaluateAndReportCommandHandler { command_name: "report", usecase, unpack_bundle }
     }
 }
 
@@ -20,8 +22,12 @@ impl<'a> CommandHandlerBoundary for EvaluateAndReportCommandHandler<'a> {
     fn name(&self) -> &str {
         self.command_name
     }
-    fn handle(&self, _args: &ArgMatches) -> Result<(), Error> {
-        let request = create_request()?;
+    fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
+        let home_directory = match args.get_one::<String>("report-bundle") {
+            Some(bundle_file) => Some((self.unpack_bundle)(bundle_file, &unpack_directory(bundle_file))?),
+            None => args.get_one::<String>("report-home").cloned()
+        };
+        let request = create_request(args, home_directory.as_deref())?;
         match (self.usecase)(&request) {
             Ok(_) => { Ok(()) },
             Err(e) => Err(e)
@@ -29,11 +35,35 @@ impl<'a> CommandHandlerBoundary for EvaluateAndReportCommandHandler<'a> {
     }
 }
 
-fn create_request()  -> Result<EvaluateEvidence, Error> {
+/// The directory a bundle is unpacked into, which is next to the bundle and named after it without its extension.
+fn unpack_directory(bundle_file: &str) -> String {
+    match bundle_file.strip_suffix(".tar.zst") {
+        Some(directory) if !directory.is_empty() => directory.to_string(),
+        _ => format!("{}.unpacked", bundle_file)
+    }
+}
+
+fn create_request(args: &ArgMatches, home_directory: Option<&str>)  -> Result<EvaluateEvidence, Error> {
+
+    let app_state = match home_directory {
+        Some(home_directory) => app_state_from_collection(home_directory)?,
+        None => app_state_from_nape_config()?
+    };
+    let outcome_policy = args.get_one::<String>("outcome-policy");
+    let waiver_file = args.get_one::<String>("waiver-file");
 
-    let app_state = app_state_from_nape_config()?;
+    let mut builder = EvaluateEvidence::builder();
+    if let Some(policy) = outcome_policy {
+        builder.outcome_policy(policy);
+    }
+    if let Some(file) = waiver_file {
+        builder.waiver_file(file);
+    }
+    if let Some(directory) = home_directory {
+        builder.home_directory(directory);
+    }
 
-    EvaluateEvidence::builder()
+    builder
         .subject_nrn(&app_state.subject_nrn)
         .subject_id(&app_state.subject_id)
         .procedure_directory(&app_state.procedure_directory)
@@ -2,16 +2,19 @@ use clap::ArgMatches;
 use nape_domain::evidence_collection::usecases::start_collection::usecase::{UCStartCollectionProcedure};
 use nape_domain::evidence_collection::usecases::start_collection::usecase_boundary::request::{StartProcedureBuilder, StartProcedure};
 use nape_kernel::error::Error;
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
 use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
 
 pub struct StartCollectionCommandHandler<'a> {
     pub command_name: &'a str,
     usecase: UCStartCollectionProcedure,
+    retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>,
+    retrieve_tailoring: fn(file_path: &str) -> Result<Tailoring, Error>,
 }
 
 impl<'a> StartCollectionCommandHandler<'a> {
-    pub fn new(usecase: UCStartCollectionProcedure) -> StartCollectionCommandHandler<'a> {
-        StartCollectionCommandHandler { command_name: "start", usecase }
+    pub fn new(usecase: UCStartCollectionProcedure, retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>, retrieve_tailoring: fn(file_path: &str) -> Result<Tailoring, Error>) -> StartCollectionCommandHandler<'a> {
+        StartCollectionCommandHandler { command_name: "start", usecase, retrieve_organisation_nids, retrieve_tailoring }
     }
 }
 
@@ -20,7 +23,12 @@ impl<'a> CommandHandlerBoundary for StartCollectionCommandHandler<'a> {
         self.command_name
     }
     fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
-        let request = extract_arguments(args)?;
+        let organisation_nids = (self.retrieve_organisation_nids)()?;
+        let tailoring = match args.get_one::<String>("tailoring-file") {
+            Some(tailoring_file) => (self.retrieve_tailoring)(tailoring_file)?,
+            None => Tailoring::default()
+        };
+        let request = extract_arguments(args, &organisation_nids, &tailoring)?;
         match (self.usecase)(request) {
             Ok(_) => { Ok(()) },
             Err(e) => Err(e)
@@ -28,7 +36,7 @@ impl<'a> CommandHandlerBoundary for StartCollectionCommandHandler<'a> {
     }
 }
 
-fn extract_arguments(matches: &ArgMatches)  -> Result<StartProcedure, Error> {
+fn extract_arguments(matches: &ArgMatches, organisation_nids: &[String], tailoring: &Tailoring)  -> Result<StartProcedure, Error> {
 
     let nrn = matches.get_one::<String>("subject").unwrap();
     let subject_id = matches.get_one::<String>("subject-id").unwrap();
@@ -42,9 +50,11 @@ fn extract_arguments(matches: &ArgMatches)  -> Result<StartProcedure, Error> {
         .api_version("1.0.0")
         .subject_nrn(nrn)
         .subject_id(subject_id)
+        .organisation_nids(organisation_nids)
         .procedure_repository(procedure_link)
         .procedure_directory(procedure_directory)
         .merge_metadata(&metadata)
+        .tailoring(tailoring)
         .try_build()
 
 }
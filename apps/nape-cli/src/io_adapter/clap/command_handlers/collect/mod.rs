@@ -1,5 +1,8 @@
+pub mod collect_attest;
+pub mod collect_bundle;
 pub mod collect_command_handler;
 pub mod collect_evidence;
+pub mod collect_publish;
 pub mod collect_report;
 pub mod collect_start;
 
@@ -1 +1,3 @@
+pub mod bundle;
 pub mod collect;
+pub mod procedure;
@@ -0,0 +1,6 @@
+pub mod procedure_command_handler;
+pub mod procedure_list;
+pub mod procedure_new;
+
+#[cfg(test)] mod procedure_list_tests;
+#[cfg(test)] mod procedure_new_tests;
@@ -0,0 +1,31 @@
+use clap::ArgMatches;
+use nape_kernel::error::Error;
+use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+
+pub struct ProcedureCommandHandler<'a> {
+    command_name: &'a str,
+    subcommands: Vec<Box<dyn CommandHandlerBoundary>>,
+}
+
+impl<'a> ProcedureCommandHandler<'a> {
+    pub fn new(subcommands: Vec<Box<dyn CommandHandlerBoundary>>) -> ProcedureCommandHandler<'a> {
+        ProcedureCommandHandler {
+            command_name: "procedure",
+            subcommands
+        }
+    }
+}
+
+impl<'a> CommandHandlerBoundary for ProcedureCommandHandler<'a> {
+    fn name(&self) -> &str {
+        self.command_name
+    }
+    fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
+        for subcommand in &self.subcommands {
+            if let Some(subcommand_matches) = args.subcommand_matches(subcommand.name()) {
+                return subcommand.handle(subcommand_matches);
+            }
+        }
+        Ok(())
+    }
+}
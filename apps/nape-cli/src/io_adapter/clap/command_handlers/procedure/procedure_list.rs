@@ -0,0 +1,45 @@
+use clap::ArgMatches;
+use nape_domain::procedure_management::usecases::list_procedures::usecase::{ListedProcedures, ListProceduresRequest, UCListProcedures};
+use nape_kernel::error::{Error, Kind};
+use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+
+pub struct ListProceduresCommandHandler<'a> {
+    pub command_name: &'a str,
+    usecase: UCListProcedures,
+    retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>,
+}
+
+impl<'a> ListProceduresCommandHandler<'a> {
+    pub fn new(usecase: UCListProcedures, retrieve_organisation_nids: fn() -> Result<Vec<String>, Error>) -> ListProceduresCommandHandler<'a> {
+        ListProceduresCommandHandler { command_name: "list", usecase, retrieve_organisation_nids }
+    }
+}
+
+impl<'a> CommandHandlerBoundary for ListProceduresCommandHandler<'a> {
+    fn name(&self) -> &str {
+        self.command_name
+    }
+    fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
+        let repository = args.get_one::<String>("procedure-repository")
+            .ok_or_else(|| Error::for_user(Kind::InvalidInput, String::from("The procedure repository is required to list its procedures.")))?;
+        let organisation_nids = (self.retrieve_organisation_nids)()?;
+        let request = ListProceduresRequest { repository, organisation_nids: &organisation_nids };
+        let listed = (self.usecase)(&request)?;
+        print!("{}", format_procedures(&listed));
+        for invalid in &listed.invalid {
+            eprintln!("The procedure in '{}' could not be read. {}", invalid.directory, invalid.reason);
+        }
+        Ok(())
+    }
+}
+
+/// Formats each procedure as its directory, followed by its NRN, short description, and the number of activities and actions, indented beneath it.
+pub fn format_procedures(listed: &ListedProcedures) -> String {
+    listed.procedures.iter()
+        .map(|procedure| {
+            let directory = if procedure.directory.is_empty() { "." } else { procedure.directory.as_str() };
+            format!("{}\n    {}\n    {}\n    {} activities, {} actions\n",
+                    directory, procedure.nrn, procedure.short_description, procedure.activity_count, procedure.action_count)
+        })
+        .collect()
+}
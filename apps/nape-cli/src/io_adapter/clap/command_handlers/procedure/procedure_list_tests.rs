@@ -0,0 +1,27 @@
+use nape_domain::procedure_management::usecases::list_procedures::usecase::{ListedProcedures, ProcedureSummary};
+use crate::io_adapter::clap::command_handlers::procedure::procedure_list::format_procedures;
+
+fn summary(directory: &str, nrn: &str) -> ProcedureSummary {
+    ProcedureSummary {
+        directory: directory.to_string(),
+        nrn: nrn.to_string(),
+        short_description: "Source code integration".to_string(),
+        activity_count: 2,
+        action_count: 5,
+    }
+}
+
+#[test]
+fn format_procedures_success() {
+    let listed = ListedProcedures {
+        procedures: vec![
+            summary("", "nrn:procedure:nape:root"),
+            summary("rust-ci/sourcecode-integration", "nrn:procedure:nape:rust-ci"),
+        ],
+        invalid: vec![],
+    };
+
+    assert_eq!(format_procedures(&listed),
+               ".\n    nrn:procedure:nape:root\n    Source code integration\n    2 activities, 5 actions\n\
+                rust-ci/sourcecode-integration\n    nrn:procedure:nape:rust-ci\n    Source code integration\n    2 activities, 5 actions\n");
+}
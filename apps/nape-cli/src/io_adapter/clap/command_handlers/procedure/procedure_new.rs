@@ -0,0 +1,49 @@
+use clap::ArgMatches;
+use nape_domain::procedure_management::usecases::scaffold_procedure::usecase::{ActivityOutline, ScaffoldProcedureRequest, UCScaffoldProcedure};
+use nape_kernel::error::{Error, Kind};
+use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+
+pub struct NewProcedureCommandHandler<'a> {
+    pub command_name: &'a str,
+    usecase: UCScaffoldProcedure,
+}
+
+impl<'a> NewProcedureCommandHandler<'a> {
+    pub fn new(usecase: UCScaffoldProcedure) -> NewProcedureCommandHandler<'a> {
+        NewProcedureCommandHandler { command_name: "new", usecase }
+    }
+}
+
+impl<'a> CommandHandlerBoundary for NewProcedureCommandHandler<'a> {
+    fn name(&self) -> &str {
+        self.command_name
+    }
+    fn handle(&self, args: &ArgMatches) -> Result<(), Error> {
+        let nrn = args.get_one::<String>("procedure-nrn")
+            .ok_or_else(|| Error::for_user(Kind::InvalidInput, String::from("The procedure NRN is required to create a procedure.")))?;
+        let activities = args.get_many::<String>("procedure-activity")
+            .map(|values| values.map(|value| parse_activity_outline(value)).collect::<Result<Vec<ActivityOutline>, Error>>())
+            .unwrap_or_else(|| Ok(Vec::new()))?;
+        let request = ScaffoldProcedureRequest {
+            nrn,
+            short_description: args.get_one::<String>("procedure-short-description").map(String::as_str),
+            activities,
+            directory: args.get_one::<String>("procedure-output").map(String::as_str),
+        };
+        match (self.usecase)(&request) {
+            Ok(_) => { Ok(()) },
+            Err(e) => Err(e)
+        }
+    }
+}
+
+/// Parses an activity outline in the format 'activity:action,action'.
+pub fn parse_activity_outline(value: &str) -> Result<ActivityOutline, Error> {
+    let (name, actions) = value.split_once(':')
+        .ok_or_else(|| Error::for_user(Kind::InvalidInput,
+                                       format!("The activity '{}' must be in the format 'activity:action,action'.", value)))?;
+    Ok(ActivityOutline {
+        name: name.trim().to_string(),
+        actions: actions.split(',').map(str::trim).filter(|action| !action.is_empty()).map(str::to_string).collect(),
+    })
+}
@@ -0,0 +1,20 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::io_adapter::clap::command_handlers::procedure::procedure_new::parse_activity_outline;
+
+#[test]
+fn parse_activity_outline_success() {
+    let result = parse_activity_outline("peer-review: at-least-two-reviewers, requester-not-a-reviewer,");
+
+    let outline = is_ok!(result);
+    assert_eq!(outline.name, "peer-review");
+    assert_eq!(outline.actions, vec!["at-least-two-reviewers".to_string(), "requester-not-a-reviewer".to_string()]);
+}
+
+#[test]
+fn parse_activity_outline_without_actions_error() {
+    let result = parse_activity_outline("peer-review");
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The activity 'peer-review' must be in the format 'activity:action,action'.");
+}
@@ -7,13 +7,24 @@ mod filesystem_state_configuration;
 
 use clap::{ArgMatches};
 use nape_kernel::error::{Error};
+use crate::gateway_adapter::std_fs::retrieve_organisation_nids::organisation_nids_from_nape_config;
+use crate::gateway_adapter::std_fs::retrieve_tailoring::tailoring_from_yaml_on_filesystem;
+use crate::gateway_adapter::tar::bundle_gateway::unpack_tar_zstd_bundle;
 use crate::io_adapter::clap::{cli};
 use crate::io_adapter::clap::command_handler_boundary::CommandHandlerBoundary;
+use crate::io_adapter::clap::command_handlers::bundle::bundle_command_handler::BundleCommandHandler;
+use crate::io_adapter::clap::command_handlers::bundle::bundle_verify::VerifyBundleCommandHandler;
+use crate::io_adapter::clap::command_handlers::collect::collect_attest::CollectAttestCommandHandler;
+use crate::io_adapter::clap::command_handlers::collect::collect_bundle::BundleCollectionCommandHandler;
 use crate::io_adapter::clap::command_handlers::collect::collect_command_handler::CollectCommandHandler;
 use crate::io_adapter::clap::command_handlers::collect::collect_evidence::CollectEvidenceCommandHandler;
+use crate::io_adapter::clap::command_handlers::collect::collect_publish::PublishCollectionCommandHandler;
 use crate::io_adapter::clap::command_handlers::collect::collect_report::EvaluateAndReportCommandHandler;
 use crate::io_adapter::clap::command_handlers::collect::collect_start::StartCollectionCommandHandler;
-use crate::usecase_configuration::{collect_evidence, evidence_report, start_collection};
+use crate::io_adapter::clap::command_handlers::procedure::procedure_command_handler::ProcedureCommandHandler;
+use crate::io_adapter::clap::command_handlers::procedure::procedure_list::ListProceduresCommandHandler;
+use crate::io_adapter::clap::command_handlers::procedure::procedure_new::NewProcedureCommandHandler;
+use crate::usecase_configuration::{attest_action, bundle_collection, capture_evidence, collect_evidence, evidence_report, list_procedures, publish_collection, scaffold_procedure, start_collection};
 
 
 fn main() {
@@ -26,9 +37,13 @@ fn main() {
 fn handle_command_results(matches: &ArgMatches) -> Result<(), Error>{
 
     let collect_command_handler = configure_collect_command_handler();
+    let bundle_command_handler = configure_bundle_command_handler();
+    let procedure_command_handler = configure_procedure_command_handler();
 
     match matches.subcommand() {
         Some(("collect", args)) => { collect_command_handler.handle(args) },
+        Some(("bundle", args)) => { bundle_command_handler.handle(args) },
+        Some(("procedure", args)) => { procedure_command_handler.handle(args) },
         _ => { Ok(()) }
     }
 
@@ -39,18 +54,62 @@ fn configure_collect_command_handler() -> CollectCommandHandler<'static> {
     // #1 - Instantiate injectable dependencies here
     let uc_start_collection = start_collection::factory_std_fs_git2();
     let uc_evidence_collection = collect_evidence::std_fs_factory();
+    let uc_evidence_capture = capture_evidence::std_process_factory();
+    let uc_url_evidence_capture = capture_evidence::reqwest_factory();
+    let uc_attest_action = attest_action::std_fs_factory();
     let uc_evidence_report = evidence_report::std_fs_factory();
+    let uc_bundle_collection = bundle_collection::std_fs_tar_factory();
+    let uc_publish_collection = publish_collection::std_fs_git2_reqwest_factory();
 
     // #2 - Instantiate the subcommand handlers here
-    let start_collection_subcommand = StartCollectionCommandHandler::new(uc_start_collection);
-    let evidence_collection_subcommand = CollectEvidenceCommandHandler::new(uc_evidence_collection);
-    let evidence_report_subcommand = EvaluateAndReportCommandHandler::new(uc_evidence_report);
+    let start_collection_subcommand = StartCollectionCommandHandler::new(uc_start_collection, organisation_nids_from_nape_config, tailoring_from_yaml_on_filesystem);
+    let evidence_collection_subcommand = CollectEvidenceCommandHandler::new(uc_evidence_collection, uc_evidence_capture, uc_url_evidence_capture);
+    let attest_action_subcommand = CollectAttestCommandHandler::new(uc_attest_action);
+    let evidence_report_subcommand = EvaluateAndReportCommandHandler::new(uc_evidence_report, unpack_tar_zstd_bundle);
+    let bundle_collection_subcommand = BundleCollectionCommandHandler::new(uc_bundle_collection, organisation_nids_from_nape_config);
+    let publish_collection_subcommand = PublishCollectionCommandHandler::new(uc_publish_collection, organisation_nids_from_nape_config);
 
     // #3 - Instantiate the command handler here
     CollectCommandHandler::new(
         vec![
             Box::new(start_collection_subcommand),
             Box::new(evidence_collection_subcommand),
-            Box::new(evidence_report_subcommand)
+            Box::new(attest_action_subcommand),
+            Box::new(evidence_report_subcommand),
+            Box::new(bundle_collection_subcommand),
+            Box::new(publish_collection_subcommand)
+        ])
+}
+
+fn configure_bundle_command_handler() -> BundleCommandHandler<'static> {
+
+    // #1 - Instantiate injectable dependencies here
+    let uc_verify_bundle = bundle_collection::tar_verify_factory();
+
+    // #2 - Instantiate the subcommand handlers here
+    let verify_bundle_subcommand = VerifyBundleCommandHandler::new(uc_verify_bundle, organisation_nids_from_nape_config);
+
+    // #3 - Instantiate the command handler here
+    BundleCommandHandler::new(
+        vec![
+            Box::new(verify_bundle_subcommand)
+        ])
+}
+
+fn configure_procedure_command_handler() -> ProcedureCommandHandler<'static> {
+
+    // #1 - Instantiate injectable dependencies here
+    let uc_list_procedures = list_procedures::std_fs_git2_factory();
+    let uc_scaffold_procedure = scaffold_procedure::std_fs_serde_factory();
+
+    // #2 - Instantiate the subcommand handlers here
+    let list_procedures_subcommand = ListProceduresCommandHandler::new(uc_list_procedures, organisation_nids_from_nape_config);
+    let new_procedure_subcommand = NewProcedureCommandHandler::new(uc_scaffold_procedure);
+
+    // #3 - Instantiate the command handler here
+    ProcedureCommandHandler::new(
+        vec![
+            Box::new(list_procedures_subcommand),
+            Box::new(new_procedure_subcommand)
         ])
 }
@@ -66,7 +66,7 @@ impl CLIAppStateBuilder {
         Ok(
             CLIAppState {
                 metadata,
-                subject_nrn: subject.nrn.value,
+                subject_nrn: subject.nrn.to_string(),
                 subject_id: subject.id.value,
                 procedure_repository: procedure.repository.to_string(),
                 procedure_directory: procedure.directory.to_string(),
@@ -0,0 +1,30 @@
+use nape_domain::evidence_collection::usecases::collect_evidence::gateway::{CustodyGateways, DeclarationGateways};
+use nape_domain::evidence_collection::usecases::attest_action::usecase::{attest_action, AttestActionRequest, UCAttestAction};
+use nape_domain::evidence_collection::usecases::collect_evidence::usecase::CollectedEvidence;
+use nape_kernel::error::Error;
+use crate::gateway_adapter::serde::attestation_gateway::save_attestation_as_yaml;
+use crate::gateway_adapter::serde::custody_ledger_gateway::append_custody_entry_as_json_line;
+use crate::gateway_adapter::sha2::signature_algorithm::sha256_signature;
+use crate::gateway_adapter::state_management::retrieve_directory_path::directory_path_from_app_state;
+use crate::gateway_adapter::std_env::retrieve_user::user_from_environment;
+use crate::gateway_adapter::std_fs::copy_file_gateway::copy_file_to_filesystem;
+use crate::gateway_adapter::std_fs::retrieve_assurance_procedure::from_yaml_on_filesystem;
+use crate::gateway_adapter::std_fs::retrieve_file_data_gateway::retrieve_file_data_from_filesystem;
+
+pub fn std_fs_factory() -> UCAttestAction {
+    move |request: &AttestActionRequest| -> Result<CollectedEvidence, Error> {
+        attest_action(request,
+                      DeclarationGateways {
+                          retrieve_directory: directory_path_from_app_state,
+                          retrieve_definition: from_yaml_on_filesystem
+                      },
+                      retrieve_file_data_from_filesystem,
+                      copy_file_to_filesystem,
+                      save_attestation_as_yaml,
+                      CustodyGateways {
+                          signature_algorithm: sha256_signature,
+                          retrieve_user: user_from_environment,
+                          append_custody_entry: append_custody_entry_as_json_line
+                      })
+    }
+}
@@ -0,0 +1,30 @@
+use nape_domain::evidence_collection::usecases::bundle_collection::usecase::{bundle_collection, verify_bundle, BundleCollectionRequest, BundledCollection, UCBundleCollection, UCVerifyBundle, VerifiedBundle, VerifyBundleRequest};
+use nape_kernel::error::Error;
+use crate::gateway_adapter::serde::report_signatures_gateway::signed_files_from_yaml_report;
+use crate::gateway_adapter::sha2::signature_algorithm::sha256_signature;
+use crate::gateway_adapter::state_management::retrieve_directory_path::directory_path_from_app_state;
+use crate::gateway_adapter::std_fs::file_data_gateway::read_file_data;
+use crate::gateway_adapter::std_fs::select_files_gateway::select_files_on_filesystem;
+use crate::gateway_adapter::tar::bundle_gateway::{read_tar_zstd_bundle, write_tar_zstd_bundle};
+
+/// The [`UCBundleCollection`] implementation which writes the collection as a tar archive compressed with zstd.
+pub fn std_fs_tar_factory() -> UCBundleCollection {
+    move |request: &BundleCollectionRequest| -> Result<BundledCollection, Error> {
+        bundle_collection(request,
+                          directory_path_from_app_state,
+                          select_files_on_filesystem,
+                          read_file_data,
+                          sha256_signature,
+                          write_tar_zstd_bundle)
+    }
+}
+
+/// The [`UCVerifyBundle`] implementation which reads a tar archive compressed with zstd and its YAML assurance report.
+pub fn tar_verify_factory() -> UCVerifyBundle {
+    move |request: &VerifyBundleRequest| -> Result<VerifiedBundle, Error> {
+        verify_bundle(request,
+                      read_tar_zstd_bundle,
+                      sha256_signature,
+                      signed_files_from_yaml_report)
+    }
+}
@@ -0,0 +1,50 @@
+use nape_domain::evidence_collection::usecases::collect_evidence::gateway::{CustodyGateways, DeclarationGateways};
+use nape_domain::evidence_collection::usecases::capture_evidence::usecase::{capture_command_evidence, capture_url_evidence, CaptureEvidenceRequest, CaptureUrlRequest, UCCaptureCommandEvidence, UCCaptureUrlEvidence};
+use nape_domain::evidence_collection::usecases::collect_evidence::usecase::CollectedEvidence;
+use nape_kernel::error::Error;
+use crate::gateway_adapter::reqwest::fetch_url_gateway::fetch_url_with_reqwest;
+use crate::gateway_adapter::serde::custody_ledger_gateway::append_custody_entry_as_json_line;
+use crate::gateway_adapter::serde::persist_sidecar_gateway::save_sidecar_as_yaml;
+use crate::gateway_adapter::sha2::signature_algorithm::sha256_signature;
+use crate::gateway_adapter::state_management::retrieve_directory_path::directory_path_from_app_state;
+use crate::gateway_adapter::std_env::retrieve_user::user_from_environment;
+use crate::gateway_adapter::std_fs::copy_file_gateway::copy_file_to_filesystem;
+use crate::gateway_adapter::std_fs::retrieve_assurance_procedure::from_yaml_on_filesystem;
+use crate::gateway_adapter::std_process::run_command_gateway::run_command_as_process;
+
+pub fn std_process_factory() -> UCCaptureCommandEvidence {
+    move |request: &CaptureEvidenceRequest| -> Result<CollectedEvidence, Error> {
+        capture_command_evidence(request,
+                                 declaration_gateways(),
+                                 run_command_as_process,
+                                 copy_file_to_filesystem,
+                                 save_sidecar_as_yaml,
+                                 custody_gateways())
+    }
+}
+
+pub fn reqwest_factory() -> UCCaptureUrlEvidence {
+    move |request: &CaptureUrlRequest| -> Result<CollectedEvidence, Error> {
+        capture_url_evidence(request,
+                             declaration_gateways(),
+                             fetch_url_with_reqwest,
+                             copy_file_to_filesystem,
+                             save_sidecar_as_yaml,
+                             custody_gateways())
+    }
+}
+
+fn declaration_gateways() -> DeclarationGateways {
+    DeclarationGateways {
+        retrieve_directory: directory_path_from_app_state,
+        retrieve_definition: from_yaml_on_filesystem
+    }
+}
+
+fn custody_gateways() -> CustodyGateways {
+    CustodyGateways {
+        signature_algorithm: sha256_signature,
+        retrieve_user: user_from_environment,
+        append_custody_entry: append_custody_entry_as_json_line
+    }
+}
@@ -1,23 +1,43 @@
+use nape_domain::evidence_collection::usecases::evaluate_evidence::gateway::{CollectionGateways, EvaluationGateways, ReportGateways};
 use nape_domain::evidence_collection::usecases::evaluate_evidence::usecase::{evaluate_and_report, EvaluateAndReportEvidenceUC};
 use nape_domain::evidence_collection::usecases::evaluate_evidence::usecase_boundary::request::EvaluateEvidence;
 use nape_kernel::error::Error;
 use nape_kernel::values::specification::file_path::FilePath;
 use crate::gateway_adapter::nape_evaluator::evaluate_evidence_gateway::nape_evidence_evaluator;
+use crate::gateway_adapter::serde::attestation_gateway::attestation_from_yaml_file;
+use crate::gateway_adapter::serde::custody_ledger_gateway::custody_ledger_from_json_lines;
 use crate::gateway_adapter::serde::persist_report_gateway::save_report_as_yaml;
+use crate::gateway_adapter::serde::tailoring_gateway::tailoring_from_collection_home;
 use crate::gateway_adapter::sha2::signature_algorithm::sha256_signature;
-use crate::gateway_adapter::state_management::retrieve_directory_path::directory_path_from_app_state;
+use crate::gateway_adapter::state_management::retrieve_directory_path::{directory_path_from_app_state, directory_path_from_home};
+use crate::gateway_adapter::std_env::retrieve_provenance::provenance_from_environment;
 use crate::gateway_adapter::std_fs::file_data_gateway::read_file_data;
 use crate::gateway_adapter::std_fs::retrieve_assurance_procedure::from_yaml_on_filesystem;
+use crate::gateway_adapter::std_fs::retrieve_waivers::waivers_from_yaml_on_filesystem;
+use crate::gateway_adapter::std_fs::select_files_gateway::select_files_on_filesystem;
 
 pub fn std_fs_factory() -> EvaluateAndReportEvidenceUC {
     move |request: &EvaluateEvidence| -> Result<FilePath, Error> {
         evaluate_and_report(request,
-                            directory_path_from_app_state,
-                            from_yaml_on_filesystem,
-                            nape_evidence_evaluator,
-                            sha256_signature,
-                            read_file_data,
-                            save_report_as_yaml
+                            CollectionGateways {
+                                retrieve_path: directory_path_from_app_state,
+                                resolve_path: directory_path_from_home,
+                                retrieve_definition: from_yaml_on_filesystem,
+                                retrieve_waivers: waivers_from_yaml_on_filesystem,
+                                retrieve_tailoring: tailoring_from_collection_home,
+                                retrieve_custody_ledger: custody_ledger_from_json_lines
+                            },
+                            EvaluationGateways {
+                                evaluate_evidence: nape_evidence_evaluator,
+                                retrieve_attestation: attestation_from_yaml_file,
+                                retrieve_provenance: provenance_from_environment
+                            },
+                            ReportGateways {
+                                signature_algorithm: sha256_signature,
+                                file_data_gateway: read_file_data,
+                                select_files: select_files_on_filesystem,
+                                persist_report: save_report_as_yaml
+                            }
         )
     }
 }
\ No newline at end of file
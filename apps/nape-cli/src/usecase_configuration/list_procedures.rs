@@ -0,0 +1,18 @@
+use std::path::Path;
+use nape_domain::procedure_management::usecases::list_procedures::gateway::FindProcedureFilesGateway;
+use nape_domain::procedure_management::usecases::list_procedures::usecase::{list_procedures, ListedProcedures, ListProceduresRequest, UCListProcedures};
+use nape_kernel::error::Error;
+use crate::gateway_adapter::git2::find_procedure_files_gateway::procedure_files_from_git;
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::factory;
+use crate::gateway_adapter::std_fs::find_procedure_files_gateway::procedure_files_on_filesystem;
+
+/// The [`UCListProcedures`] implementation which walks a local directory when the repository of the request is one, and otherwise clones it as a git repository.
+pub fn std_fs_git2_factory() -> UCListProcedures {
+    move |request: &ListProceduresRequest| -> Result<ListedProcedures, Error> {
+        let find_procedure_files: FindProcedureFilesGateway = match Path::new(request.repository).is_dir() {
+            true => procedure_files_on_filesystem,
+            false => procedure_files_from_git,
+        };
+        list_procedures(request, find_procedure_files, factory::from_yaml)
+    }
+}
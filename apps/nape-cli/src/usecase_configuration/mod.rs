@@ -1,5 +1,11 @@
+pub mod attest_action;
+pub mod bundle_collection;
+pub mod capture_evidence;
 pub mod collect_evidence;
 pub mod evidence_report;
+pub mod list_procedures;
+pub mod publish_collection;
+pub mod scaffold_procedure;
 pub mod start_collection;
 
 #[cfg(test)] mod collect_evidence_tests;
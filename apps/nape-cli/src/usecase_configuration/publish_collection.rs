@@ -0,0 +1,24 @@
+use nape_domain::evidence_collection::usecases::publish_collection::gateway::PublishCollectionGateway;
+use nape_domain::evidence_collection::usecases::publish_collection::gateway_boundary::PublishTarget;
+use nape_domain::evidence_collection::usecases::publish_collection::usecase::{publish_collection, PublishCollectionRequest, PublishedCollection, UCPublishCollection};
+use nape_kernel::error::Error;
+use crate::gateway_adapter::git2::publish_collection_gateway::publish_to_git;
+use crate::gateway_adapter::reqwest::publish_s3_gateway::publish_to_s3;
+use crate::gateway_adapter::state_management::retrieve_directory_path::directory_path_from_app_state;
+use crate::gateway_adapter::std_fs::publish_directory_gateway::publish_to_directory;
+use crate::gateway_adapter::std_fs::select_files_gateway::select_files_on_filesystem;
+
+/// The [`UCPublishCollection`] implementation which publishes to the git, directory, or S3 evidence repository of the request.
+pub fn std_fs_git2_reqwest_factory() -> UCPublishCollection {
+    move |request: &PublishCollectionRequest| -> Result<PublishedCollection, Error> {
+        let publish: PublishCollectionGateway = match request.target {
+            PublishTarget::Git { .. } => publish_to_git,
+            PublishTarget::Directory { .. } => publish_to_directory,
+            PublishTarget::S3 { .. } => publish_to_s3,
+        };
+        publish_collection(request,
+                           directory_path_from_app_state,
+                           select_files_on_filesystem,
+                           publish)
+    }
+}
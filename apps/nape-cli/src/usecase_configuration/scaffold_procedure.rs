@@ -0,0 +1,14 @@
+use nape_domain::procedure_management::usecases::scaffold_procedure::usecase::{scaffold_procedure, ScaffoldedProcedure, ScaffoldProcedureRequest, UCScaffoldProcedure};
+use nape_kernel::error::Error;
+use crate::gateway_adapter::serde::specification_serializer::assurance_procedure::factory;
+use crate::gateway_adapter::std_fs::write_scaffold_gateway::write_scaffold_to_filesystem;
+
+/// The [`UCScaffoldProcedure`] implementation which writes the new procedure as YAML to the filesystem.
+pub fn std_fs_serde_factory() -> UCScaffoldProcedure {
+    move |request: &ScaffoldProcedureRequest| -> Result<ScaffoldedProcedure, Error> {
+        scaffold_procedure(request,
+                           factory::to_yaml,
+                           factory::from_yaml,
+                           write_scaffold_to_filesystem)
+    }
+}
@@ -1,3 +1,4 @@
+use nape_domain::evidence_collection::usecases::start_collection::gateway::ProcedureGateways;
 use nape_domain::evidence_collection::usecases::start_collection::usecase::{start_collection, UCStartCollectionProcedure};
 use nape_domain::evidence_collection::usecases::start_collection::usecase_boundary::request::StartProcedure;
 use nape_domain::evidence_collection::usecases::start_collection::usecase_boundary::response::ProcedureStarted;
@@ -5,10 +6,13 @@ use nape_kernel::error::{Error, Kind};
 use nape_kernel::values::directory::directory_list::DirectoryList;
 use nape_kernel::values::nrn::filepath_codec::encode_as_directory_name;
 use crate::filesystem_state_configuration::nape_cli_config_file_path;
-use crate::gateway_adapter::git2::process_retrieval_gateway::retrieve_procedure_from_git;
+use crate::gateway_adapter::git2::process_retrieval_gateway::{retrieve_include_from_git, retrieve_procedure_from_git};
+use crate::gateway_adapter::serde::persist_procedure_gateway::save_procedure_as_yaml;
+use crate::gateway_adapter::serde::tailoring_gateway::save_tailoring_as_yaml;
 use crate::gateway_adapter::std_fs::directory_creation_gateway::create_directories_on_filesystem;
 use crate::gateway_adapter::std_fs::file_delete_gateway::delete_file_on_filesystem;
 use crate::gateway_adapter::std_fs::file_move_gateway::move_file_on_filesystem;
+use crate::gateway_adapter::std_fs::retrieve_assurance_procedure::from_yaml_on_filesystem;
 
 use crate::state_management::cli_app_state::CLIAppState;
 use crate::state_management::write_state_file::write_to_filesystem;
@@ -27,7 +31,12 @@ pub fn factory_std_fs_git2() -> UCStartCollectionProcedure {
             create_directories_on_filesystem,
             retrieve_procedure_from_git,
             move_file_on_filesystem,
-            delete_file_on_filesystem)?;
+            delete_file_on_filesystem,
+            ProcedureGateways {
+                retrieve_include: retrieve_include_from_git,
+                read_procedure: from_yaml_on_filesystem,
+                persist_procedure: save_procedure_as_yaml,
+                persist_tailoring: save_tailoring_as_yaml })?;
 
         let app_state = build_app_state(&request, &result.directory_list)?;
 
@@ -0,0 +1,31 @@
+use nape_kernel::error::Error;
+use nape_kernel::values::specification::assurance_report::attestation::Attestation;
+
+/// # Overview
+///
+/// The [`PersistAttestationGateway`] persists the signed statement of an [`Attestation`] as the evidence of an attestation action.
+///
+/// # Arguments
+///
+/// * `attestation` - The [`Attestation`] to persist.
+/// * `file_path` - The path of the statement file, which is the `evidence` path the action declares within the collection's home directory.
+///
+/// # Returns
+///
+/// A [`Result`] of either the location of the statement file, or an [`Error`].
+///
+pub type PersistAttestationGateway = fn(attestation: &Attestation, file_path: &str) -> Result<String, Error>;
+
+/// # Overview
+///
+/// The [`RetrieveAttestationGateway`] retrieves the [`Attestation`] from the statement file of an attestation action.
+///
+/// # Arguments
+///
+/// * `file_path` - The path of the statement file.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`Attestation`], `None` when the action has not been attested, or an [`Error`].
+///
+pub type RetrieveAttestationGateway = fn(file_path: &str) -> Result<Option<Attestation>, Error>;
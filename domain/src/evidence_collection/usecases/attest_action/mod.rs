@@ -0,0 +1,3 @@
+pub mod gateway;
+pub mod usecase;
+#[cfg(test)] mod usecase_tests;
@@ -0,0 +1,129 @@
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::assurance_report::attestation::Attestation;
+use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use nape_kernel::values::specification::name::Name;
+use nape_kernel::values::time::start_time::StartTime;
+use crate::evidence_collection::usecases::attest_action::gateway::PersistAttestationGateway;
+use crate::evidence_collection::usecases::collect_evidence::gateway::{CustodyGateways, DeclarationGateways};
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::relative_to_home;
+use crate::evidence_collection::usecases::collect_evidence::usecase::{unknown_action_error, CollectedEvidence, CopyFileGateway, CustodyRecorder, RetrieveFileDataGateway};
+
+/// The source recorded in the custody ledger for the statement of an attestation.
+const ATTESTATION_SOURCE: &str = "attestation";
+
+/// The [`AttestActionRequest`] is the request to record the signed statement of a person who attests an attestation action.
+///
+/// * `action_name` - The name of the attestation action.
+/// * `outcome` - The attested outcome, which is one of pass, fail, or inconclusive.
+/// * `rationale` - Why the person attests the outcome.
+/// * `supporting_files` - The paths of the files which support the statement, such as the minutes of a review.
+///
+#[derive(Clone, Debug, Default)]
+pub struct AttestActionRequest<'a> {
+    pub action_name: &'a str,
+    pub outcome: &'a str,
+    pub rationale: &'a str,
+    pub supporting_files: &'a [String],
+}
+
+/// `UCAttestAction` is a function pointer type that represents the use case for attesting an attestation action.
+///
+/// # Parameters
+///
+/// * `request`: The [`AttestActionRequest`] which describes the attested outcome and the action it is for.
+///
+/// # Returns
+///
+/// This function returns a [`Result`] that contains the [`CollectedEvidence`] with the location of the statement if the action was attested, or an [`Error`] if it could not be attested.
+pub type UCAttestAction = fn(request: &AttestActionRequest) -> Result<CollectedEvidence, Error>;
+
+/// # Overview
+///
+/// The `attest_action` use case records the signed statement of the user who attests an attestation action, which is an action that has no test.  The statement records who attested the action, when, the outcome, and the rationale, and is written to the `evidence` path the action declares, relative to the home directory.
+///
+/// Each supporting file is copied into the directory of the statement, and its signature is recorded in the statement.  A [`CustodyEntry`] is appended to the custody ledger for every supporting file and for the statement.
+///
+/// # Arguments
+///
+/// * `request` - The [`AttestActionRequest`].
+/// * `declaration` - The [`DeclarationGateways`] used to find the action and where its statement is declared.
+/// * `retrieve_file_data` - An implementation of the [`RetrieveFileDataGateway`] gateway.
+/// * `copy_file` - An implementation of the [`CopyFileGateway`] gateway, used to copy the supporting files.
+/// * `persist_attestation` - An implementation of the [`PersistAttestationGateway`] gateway.
+/// * `custody` - The [`CustodyGateways`] used to sign the supporting files and the statement, record who attested the action, and record each file in the custody ledger.
+///
+/// # Returns
+///
+/// The [`CollectedEvidence`] with the location of the statement.
+///
+pub fn attest_action(
+    request: &AttestActionRequest,
+    declaration: DeclarationGateways,
+    retrieve_file_data: RetrieveFileDataGateway,
+    copy_file: CopyFileGateway,
+    persist_attestation: PersistAttestationGateway,
+    custody: CustodyGateways) -> Result<CollectedEvidence, Error> {
+
+    let valid_action_name = Name::try_from(request.action_name)
+        .map_err(|error| Error::for_user(Kind::InvalidInput,
+                                         format!("There is an issue with the action name '{}'. {}", request.action_name, error.message)))?;
+
+    let definition_path = (declaration.retrieve_directory)("assurance-procedure-file")
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue retrieving the assurance procedure file path. {}", error.message)))?;
+
+    let procedure = (declaration.retrieve_definition)(&definition_path)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue retrieving the assurance procedure. {}", error.message)))?;
+
+    let action = procedure.activities.find_action(&valid_action_name.value)
+        .ok_or_else(|| unknown_action_error(&valid_action_name, &procedure))?;
+
+    if let Some(test) = &action.test {
+        return Err(Error::for_user(Kind::InvalidInput,
+                                   format!("The action '{}' is not an attestation action. Its evidence is evaluated by the test '{}', so please collect its evidence instead.", valid_action_name.value, test.as_str())));
+    }
+
+    let signature_algorithm = custody.signature_algorithm;
+    let custody = CustodyRecorder::try_new(&valid_action_name, declaration.retrieve_directory, custody)?;
+    let mut attestation = Attestation::try_new(&valid_action_name.value, request.outcome, request.rationale, custody.user(), &StartTime::now())?;
+
+    let home_directory = custody.home_directory();
+    let declared_statement = action.evidence.as_str().trim_start_matches("./");
+    let (statement_directory, statement_name) = match declared_statement.rsplit_once('/') {
+        Some((declared_directory, declared_name)) => (format!("{}/{}", home_directory, declared_directory), declared_name),
+        None => (home_directory.to_string(), declared_statement)
+    };
+
+    for supporting_file in request.supporting_files {
+        let (file_name, file_data) = retrieve_file_data(supporting_file)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue retrieving the supporting file '{}'. {}", supporting_file, error.message)))?;
+        if file_name == statement_name {
+            return Err(Error::for_user(Kind::InvalidInput,
+                                       format!("The supporting file '{}' has the same name as the statement of the action '{}'. Please rename the supporting file.", supporting_file, valid_action_name.value)));
+        }
+
+        let file_location = copy_file(&file_name, &file_data, &statement_directory)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue copying the supporting file '{}' to '{}'. {}", supporting_file, statement_directory, error.message)))?;
+        custody.record(supporting_file, &file_location, &file_data)?;
+
+        let signature = signature_algorithm(&file_data)
+            .map_err(|error| Error::for_system(Kind::ProcessingFailure,
+                                               format!("There was an issue signing the supporting file '{}'. {}", supporting_file, error.message)))?;
+        attestation = attestation.with_supporting_file(&SignedFile::new(&relative_to_home(home_directory, &file_location), &signature)?);
+    }
+
+    let statement_path = format!("{}/{}", statement_directory, statement_name);
+    let statement_location = persist_attestation(&attestation, &statement_path)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue writing the attestation of the action '{}' to '{}'. {}", valid_action_name.value, statement_path, error.message)))?;
+
+    let (_, statement_data) = retrieve_file_data(&statement_location)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue retrieving the attestation '{}'. {}", statement_location, error.message)))?;
+    custody.record(ATTESTATION_SOURCE, &statement_location, &statement_data)?;
+
+    Ok(CollectedEvidence { file_location: statement_location })
+}
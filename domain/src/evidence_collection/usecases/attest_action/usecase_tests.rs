@@ -0,0 +1,231 @@
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureType};
+use nape_kernel::error::{Audience, Error, Kind};
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+use nape_kernel::values::specification::assurance_report::attestation::Attestation;
+use nape_kernel::values::specification::outcome::Outcome;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::evidence_collection::usecases::collect_evidence::gateway::{CustodyGateways, DeclarationGateways};
+use crate::evidence_collection::usecases::attest_action::usecase::{attest_action, AttestActionRequest};
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::CustodyEntry;
+
+/*** Happy Path Tests ***/
+
+#[test]
+fn attest_success() {
+    let persist_assertion = |attestation: &Attestation, file_path: &str| -> Result<String, Error> {
+        assert_eq!(file_path, "/collection/evidence/access-review/attestation.yaml");
+        assert_eq!(attestation.action.value, "access-review");
+        assert_eq!(attestation.outcome, Outcome::PASS);
+        assert_eq!(attestation.rationale.value, "All accounts were reviewed.");
+        assert_eq!(attestation.attested_by.value, "jane");
+        assert!(attestation.supporting_files.is_empty());
+        Ok(file_path.to_string())
+    };
+
+    let result = attest_action(&request(&[]),
+                               declaration_gateways(),
+                               retrieve_file_data_success,
+                               copy_file_success,
+                               persist_assertion,
+                               custody_gateways());
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/access-review/attestation.yaml");
+}
+
+#[test]
+fn attest_success_with_supporting_files() {
+    let copy_assertion = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_name, "minutes.pdf");
+        assert_eq!(target_directory, "/collection/evidence/access-review");
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+    let persist_assertion = |attestation: &Attestation, file_path: &str| -> Result<String, Error> {
+        let supporting_files: Vec<(&str, String)> = attestation.supporting_files.iter()
+            .map(|file| (file.file().as_str(), file.signature().structure_signature()))
+            .collect();
+        assert_eq!(supporting_files, vec![("evidence/access-review/minutes.pdf", "SHA256[length5]".to_string())]);
+        Ok(file_path.to_string())
+    };
+
+    let supporting_files = ["./review/minutes.pdf".to_string()];
+    let result = attest_action(&request(&supporting_files),
+                               declaration_gateways(),
+                               retrieve_file_data_success,
+                               copy_assertion,
+                               persist_assertion,
+                               custody_gateways());
+
+    is_ok!(result);
+}
+
+#[test]
+fn attest_success_records_custody() {
+    let custody_assertion = |entry: &CustodyEntry, home_directory: &str| -> Result<(), Error> {
+        assert_eq!(home_directory, "/collection");
+        assert_eq!(entry.action.value, "access-review");
+        assert_eq!(entry.source, "attestation");
+        assert_eq!(entry.target, "evidence/access-review/attestation.yaml");
+        assert_eq!(entry.user, "jane");
+        Ok(())
+    };
+
+    let result = attest_action(&request(&[]),
+                               declaration_gateways(),
+                               retrieve_file_data_success,
+                               copy_file_success,
+                               persist_attestation_success,
+                               CustodyGateways { append_custody_entry: custody_assertion, ..custody_gateways() });
+
+    is_ok!(result);
+}
+
+/*** Sad Path Tests ***/
+
+#[test]
+fn attest_error_not_an_attestation_action() {
+    let request = AttestActionRequest { action_name: "peer-review", ..request(&[]) };
+
+    let result = attest_action(&request,
+                               declaration_gateways(),
+                               retrieve_file_data_success,
+                               copy_file_success,
+                               persist_attestation_success,
+                               custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'peer-review' is not an attestation action. Its evidence is evaluated by the test 'tests/peer-review.rego', so please collect its evidence instead.");
+}
+
+#[test]
+fn attest_error_unknown_action() {
+    let request = AttestActionRequest { action_name: "access-reveiw", ..request(&[]) };
+
+    let result = attest_action(&request,
+                               declaration_gateways(),
+                               retrieve_file_data_success,
+                               copy_file_success,
+                               persist_attestation_success,
+                               custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'access-reveiw' does not exist in the assurance procedure. Did you mean 'access-review'?");
+}
+
+#[test]
+fn attest_error_outcome_cannot_be_attested() {
+    let request = AttestActionRequest { outcome: "waived", ..request(&[]) };
+
+    let result = attest_action(&request,
+                               declaration_gateways(),
+                               retrieve_file_data_success,
+                               copy_file_success,
+                               persist_attestation_success,
+                               custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The Attestation could not be created. The outcome 'waived' cannot be attested for the action 'access-review'. Must be one of: [pass, fail, inconclusive].");
+}
+
+#[test]
+fn attest_error_supporting_file_named_as_statement() {
+    let retrieve_statement_named_file = |_file_path: &str| -> Result<(String, Vec<u8>), Error> {
+        Ok(("attestation.yaml".to_string(), vec![1, 2, 3]))
+    };
+
+    let supporting_files = ["./attestation.yaml".to_string()];
+    let result = attest_action(&request(&supporting_files),
+                               declaration_gateways(),
+                               retrieve_statement_named_file,
+                               copy_file_success,
+                               persist_attestation_success,
+                               custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The supporting file './attestation.yaml' has the same name as the statement of the action 'access-review'. Please rename the supporting file.");
+}
+
+#[test]
+fn attest_error_persist_attestation_gateway_error() {
+    let persist_attestation_error = |_attestation: &Attestation, _file_path: &str| -> Result<String, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some PersistAttestation Gateway Error".to_string()))
+    };
+
+    let result = attest_action(&request(&[]),
+                               declaration_gateways(),
+                               retrieve_file_data_success,
+                               copy_file_success,
+                               persist_attestation_error,
+                               custody_gateways());
+
+    kernel_error_starts_with!(result, Kind::GatewayError, Audience::System,
+        "There was an issue writing the attestation of the action 'access-review' to '/collection/evidence/access-review/attestation.yaml'. ");
+}
+
+/*** Testing Utilities, Mocks, and Assertions ***/
+
+fn request(supporting_files: &[String]) -> AttestActionRequest<'_> {
+    AttestActionRequest {
+        action_name: "access-review",
+        outcome: "pass",
+        rationale: "All accounts were reviewed.",
+        supporting_files,
+    }
+}
+
+fn declaration_gateways() -> DeclarationGateways {
+    DeclarationGateways { retrieve_directory: retrieve_directory_success, retrieve_definition: retrieve_definition_success }
+}
+
+fn custody_gateways() -> CustodyGateways {
+    CustodyGateways { signature_algorithm: signature_algorithm_success, retrieve_user: retrieve_user_success, append_custody_entry: append_custody_entry_success }
+}
+
+fn retrieve_directory_success(directory_name: &str) -> Result<String, Error> {
+    match directory_name {
+        "home" => Ok("/collection".to_string()),
+        "assurance-procedure-file" => Ok("/collection/assurance_procedure.yaml".to_string()),
+        _ => Ok(format!("/collection/{}", directory_name))
+    }
+}
+
+fn retrieve_definition_success(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+    let peer_review = Action::builder().name("peer-review").short_description("Peer review").long_description("The change was peer reviewed.")
+        .test_file_path("tests/peer-review.rego").evidence_file_path("evidence/peer-review/file.txt").try_build()?;
+    let access_review = Action::builder().name("access-review").short_description("Access review").long_description("The quarterly access review was completed.")
+        .evidence_file_path("evidence/access-review/attestation.yaml").attestation().try_build()?;
+    let activity = Activity::new("review", "Review", "The review activity.")?.add(peer_review).add(access_review);
+
+    AssuranceProcedure::builder()
+        .api_version("1.6.0")
+        .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+        .add_activity(&activity)
+        .try_build()
+}
+
+fn retrieve_file_data_success(file_path: &str) -> Result<(String, Vec<u8>), Error> {
+    let file_name = file_path.rsplit('/').next().unwrap_or(file_path);
+    Ok((file_name.to_string(), vec![1, 2, 3, 4, 5]))
+}
+
+fn copy_file_success(file_name: &str, _file_data: &Vec<u8>, target_directory: &str) -> Result<String, Error> {
+    Ok(format!("{}/{}", target_directory, file_name))
+}
+
+fn persist_attestation_success(_attestation: &Attestation, file_path: &str) -> Result<String, Error> {
+    Ok(file_path.to_string())
+}
+
+fn signature_algorithm_success(file_data: &Vec<u8>) -> Result<Signature, Error> {
+    Signature::try_new(SignatureType::SHA256, &format!("length{}", file_data.len()))
+}
+
+fn retrieve_user_success() -> Result<String, Error> {
+    Ok("jane".to_string())
+}
+
+fn append_custody_entry_success(_entry: &CustodyEntry, _home_directory: &str) -> Result<(), Error> {
+    Ok(())
+}
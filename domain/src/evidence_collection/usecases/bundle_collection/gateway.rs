@@ -0,0 +1,60 @@
+use nape_kernel::error::Error;
+use crate::evidence_collection::usecases::bundle_collection::gateway_boundary::{BundleEntry, CollectionBundle, ReportSignatures};
+
+/// # Overview
+///
+/// The [`PersistBundleGateway`] packages the files of a finished collection, and their manifest, into a single bundle file.
+///
+/// # Arguments
+///
+/// * `bundle` - The [`CollectionBundle`] to package.
+/// * `bundle_file` - The path of the bundle file to write.
+///
+/// # Returns
+///
+/// A [`Result`] of either the path of the bundle file that was written, or an [`Error`].
+///
+pub type PersistBundleGateway = fn(bundle: &CollectionBundle, bundle_file: &str) -> Result<String, Error>;
+
+/// # Overview
+///
+/// The [`RetrieveBundleGateway`] reads every file from a bundle file.
+///
+/// # Arguments
+///
+/// * `bundle_file` - The path of the bundle file to read.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`BundleEntry`] for each file in the bundle, or an [`Error`].
+///
+pub type RetrieveBundleGateway = fn(bundle_file: &str) -> Result<Vec<BundleEntry>, Error>;
+
+/// # Overview
+///
+/// The [`ReportSignaturesGateway`] reads the signed test and evidence files, and the subject NRN, recorded in an assurance report.
+///
+/// # Arguments
+///
+/// * `report_data` - The content of the assurance report.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`ReportSignatures`] of the report, or an [`Error`].
+///
+pub type ReportSignaturesGateway = fn(report_data: &[u8]) -> Result<ReportSignatures, Error>;
+
+/// # Overview
+///
+/// The [`UnpackBundleGateway`] unpacks every file from a bundle file into a directory, so the collection in the bundle can be evaluated again.
+///
+/// # Arguments
+///
+/// * `bundle_file` - The path of the bundle file to unpack.
+/// * `directory` - The path of the directory to unpack the bundle into, which must not exist.
+///
+/// # Returns
+///
+/// A [`Result`] of either the path of the directory the bundle was unpacked into, or an [`Error`].
+///
+pub type UnpackBundleGateway = fn(bundle_file: &str, directory: &str) -> Result<String, Error>;
@@ -0,0 +1,35 @@
+use nape_kernel::gateways::file_selection::SelectedFile;
+use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+
+/// The [`CollectionBundle`] is the set of files from a finished collection, and their manifest, which are packaged into a single bundle file.
+///
+/// * `files` - The files to package, where each [`SelectedFile::relative_path`] is relative to the collection home directory, and is the path of the file in the bundle.
+/// * `manifest` - The content of the [`BundleManifest`](crate::evidence_collection::usecases::bundle_collection::manifest::BundleManifest), which is packaged as the file 'bundle_manifest.txt'.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionBundle {
+    pub files: Vec<SelectedFile>,
+    pub manifest: Vec<u8>,
+}
+
+/// The [`BundleEntry`] is a file read from a bundle.
+///
+/// * `path` - The path of the file in the bundle, which is separated by '/'.
+/// * `data` - The content of the file.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BundleEntry {
+    pub path: String,
+    pub data: Vec<u8>,
+}
+
+/// The [`ReportSignatures`] are the signed files recorded in an assurance report, and the subject they were recorded for.
+///
+/// * `subject_nrn` - The NRN of the subject of the assurance report.
+/// * `signed_files` - Each [`SignedFile`] in the report, including the evidence directories and the files within them.
+///
+#[derive(Clone, Debug)]
+pub struct ReportSignatures {
+    pub subject_nrn: String,
+    pub signed_files: Vec<SignedFile>,
+}
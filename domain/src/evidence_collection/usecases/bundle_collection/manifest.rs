@@ -0,0 +1,72 @@
+use nape_kernel::algorithms::signature_algorithm::Signature;
+use nape_kernel::error::{Error, Kind};
+
+/// The name of the manifest file in a bundle.
+pub const BUNDLE_MANIFEST_FILE: &str = "bundle_manifest.txt";
+
+/// The [`ManifestEntry`] is the signature of a file in a bundle.
+///
+/// * `file` - The path of the file in the bundle.
+/// * `signature` - The [`Signature`] of the content of the file.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ManifestEntry {
+    pub file: String,
+    pub signature: Signature,
+}
+
+/// The [`BundleManifest`] lists the signature of every file in a bundle, so the bundle can be checked for missing, added, or changed files without access to the original collection.
+///
+/// The manifest is a text file with a line for each file, made of the signature in the format ALGO[signature] followed by a space and the path of the file, such as:
+///
+/// ```text
+/// SHA256[9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08] evidence/peer-review/review.json
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BundleManifest {
+    entries: Vec<ManifestEntry>,
+}
+
+impl BundleManifest {
+
+    /// Creates a [`BundleManifest`] from the entries, which are sorted by the path of the file.
+    pub fn new(entries: Vec<ManifestEntry>) -> BundleManifest {
+        let mut entries = entries;
+        entries.sort_by(|first, second| first.file.cmp(&second.file));
+        BundleManifest { entries }
+    }
+
+    /// Parses the content of a manifest file.
+    ///
+    /// ## Errors
+    ///
+    /// A [`Kind::InvalidInput`] error for the [`Audience::User`](nape_kernel::error::Audience::User) is returned if the content is not UTF-8, or a line is not a signature followed by a file path.
+    pub fn try_parse(data: &[u8]) -> Result<BundleManifest, Error> {
+        let text = std::str::from_utf8(data)
+            .map_err(|_| Error::for_user(Kind::InvalidInput, String::from("The bundle manifest is not a text file.")))?;
+
+        let mut entries = Vec::new();
+        for (index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let (signature, file) = line.split_once(' ')
+                .filter(|(_, file)| !file.is_empty())
+                .ok_or_else(|| Error::for_user(Kind::InvalidInput,
+                                               format!("The line {} of the bundle manifest is not a signature followed by a file path.", index + 1)))?;
+            let signature = Signature::try_from(signature)
+                .map_err(|error| Error::for_user(Kind::InvalidInput,
+                                                 format!("The line {} of the bundle manifest does not have a valid signature. {}", index + 1, error.message)))?;
+            entries.push(ManifestEntry { file: file.to_string(), signature });
+        }
+        Ok(BundleManifest::new(entries))
+    }
+
+    /// The entries of the manifest, sorted by the path of the file.
+    pub fn entries(&self) -> &Vec<ManifestEntry> { &self.entries }
+
+    /// The content of the manifest file.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.entries.iter()
+            .map(|entry| format!("{} {}\n", entry.signature.structure_signature(), entry.file))
+            .collect::<String>()
+            .into_bytes()
+    }
+}
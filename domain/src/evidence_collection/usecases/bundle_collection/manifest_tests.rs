@@ -0,0 +1,45 @@
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureType};
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::evidence_collection::usecases::bundle_collection::manifest::{BundleManifest, ManifestEntry};
+
+fn entry(file: &str, signature: &str) -> ManifestEntry {
+    ManifestEntry { file: file.to_string(), signature: Signature::try_new(SignatureType::SHA256, signature).unwrap() }
+}
+
+/*** Happy Path Tests ***/
+
+#[test]
+fn to_bytes_sorted_success() {
+    let manifest = BundleManifest::new(vec![entry("evidence/peer review/review.json", "def"), entry("assurance_report.yaml", "abc")]);
+
+    let text = String::from_utf8(manifest.to_bytes()).unwrap();
+
+    assert_eq!(text, "SHA256[abc] assurance_report.yaml\nSHA256[def] evidence/peer review/review.json\n");
+}
+
+#[test]
+fn try_parse_round_trip_success() {
+    let manifest = BundleManifest::new(vec![entry("assurance_report.yaml", "abc"), entry("evidence/peer review/review.json", "def")]);
+
+    let result = BundleManifest::try_parse(&manifest.to_bytes());
+
+    let parsed = is_ok!(result);
+    assert_eq!(parsed, manifest);
+}
+
+/*** Sad Path Tests ***/
+
+#[test]
+fn try_parse_missing_file_error() {
+    let result = BundleManifest::try_parse(b"SHA256[abc] assurance_report.yaml\nSHA256[def]\n");
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The line 2 of the bundle manifest is not a signature followed by a file path.");
+}
+
+#[test]
+fn try_parse_invalid_signature_error() {
+    let result = BundleManifest::try_parse(b"MD5[abc] assurance_report.yaml\n");
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The line 1 of the bundle manifest does not have a valid signature. ");
+}
@@ -0,0 +1,6 @@
+pub mod gateway;
+pub mod gateway_boundary;
+pub mod manifest;
+pub mod usecase;
+#[cfg(test)] mod manifest_tests;
+#[cfg(test)] mod usecase_tests;
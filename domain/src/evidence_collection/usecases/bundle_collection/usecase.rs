@@ -0,0 +1,259 @@
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureAlgorithm};
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::gateways::directory_list::RetrieveDirectoryPath;
+use nape_kernel::gateways::file_data_gateway::FileDataGateway;
+use nape_kernel::gateways::file_selection::{SelectedFiles, SelectFilesGateway};
+use nape_kernel::values::nrn::filepath_codec::encode_as_directory_name;
+use nape_kernel::values::nrn::nrn::NRN;
+use crate::evidence_collection::usecases::bundle_collection::gateway::{PersistBundleGateway, ReportSignaturesGateway, RetrieveBundleGateway};
+use crate::evidence_collection::usecases::bundle_collection::gateway_boundary::{BundleEntry, CollectionBundle};
+use crate::evidence_collection::usecases::bundle_collection::manifest::{BUNDLE_MANIFEST_FILE, BundleManifest, ManifestEntry};
+
+/// The name of the assurance report, without its extension, which must be in the home directory before a collection can be bundled.
+const REPORT_FILE_STEM: &str = "assurance_report.";
+
+/// The directory in the home directory which holds temporary files, and is never bundled.
+const TEMP_DIRECTORY: &str = "temp/";
+
+/// The extension of a bundle file, which is a tar archive compressed with zstd.
+const BUNDLE_FILE_EXTENSION: &str = "tar.zst";
+
+/// The [`BundleCollectionRequest`] is the request to package a finished collection as a bundle.
+///
+/// * `subject_nrn` - The NRN of the subject the collection was started for.
+/// * `bundle_file` - The path of the bundle file to write.  When not provided, the bundle is written to the current directory and named after the encoded subject NRN and the start time of the collection, such as 'nrn_sourcecode_example_1719326666123.tar.zst'.
+/// * `organisation_nids` - The organisation-defined NIDs which the subject NRN may use.
+///
+#[derive(Clone, Debug, Default)]
+pub struct BundleCollectionRequest<'a> {
+    pub subject_nrn: &'a str,
+    pub bundle_file: Option<&'a str>,
+    pub organisation_nids: &'a [String],
+}
+
+/// The [`BundledCollection`] is the result of bundling a collection.
+///
+/// * `bundle_file` - The path of the bundle file that was written.
+/// * `file_count` - The number of files from the collection in the bundle, which excludes the manifest.
+///
+#[derive(Clone, Debug)]
+pub struct BundledCollection {
+    pub bundle_file: String,
+    pub file_count: usize,
+}
+
+/// The [`VerifyBundleRequest`] is the request to verify a bundle.
+///
+/// * `bundle_file` - The path of the bundle file to verify.
+/// * `organisation_nids` - The organisation-defined NIDs which the subject NRN of the assurance report may use.
+///
+#[derive(Clone, Debug)]
+pub struct VerifyBundleRequest<'a> {
+    pub bundle_file: &'a str,
+    pub organisation_nids: &'a [String],
+}
+
+/// The [`VerifiedBundle`] is the result of verifying a bundle.
+///
+/// * `file_count` - The number of files in the bundle whose signature matches the manifest.
+/// * `report_file_count` - The number of test and evidence files whose signature matches the assurance report.
+///
+#[derive(Clone, Debug)]
+pub struct VerifiedBundle {
+    pub file_count: usize,
+    pub report_file_count: usize,
+}
+
+/// `UCBundleCollection` is a function pointer type that represents the use case for packaging a finished collection as a bundle.
+///
+/// # Parameters
+///
+/// * `request`: The [`BundleCollectionRequest`] which describes the collection and where to write the bundle.
+///
+/// # Returns
+///
+/// This function returns a [`Result`] that contains the [`BundledCollection`], or an [`Error`] if the collection could not be bundled.
+pub type UCBundleCollection = fn(request: &BundleCollectionRequest) -> Result<BundledCollection, Error>;
+
+/// `UCVerifyBundle` is a function pointer type that represents the use case for verifying a bundle.
+///
+/// # Parameters
+///
+/// * `request`: The [`VerifyBundleRequest`] which describes the bundle to verify.
+///
+/// # Returns
+///
+/// This function returns a [`Result`] that contains the [`VerifiedBundle`], or an [`Error`] which lists every issue found in the bundle.
+pub type UCVerifyBundle = fn(request: &VerifyBundleRequest) -> Result<VerifiedBundle, Error>;
+
+
+/// # Overview
+///
+/// The `bundle_collection` use case packages the assurance report, the evidence, the activity tests, and the snapshot of the assurance procedure in the home directory of a collection into a single bundle file, which can be handed to an auditor.  The bundle holds a [`BundleManifest`] of the signature of each file.
+///
+/// The collection must be reported with `collect report` before it is bundled, and the temporary files in the home directory are never bundled.
+///
+/// # Arguments
+///
+/// * `request` - The [`BundleCollectionRequest`].
+/// * `retrieve_directory` - An implementation of the [`RetrieveDirectoryPath`] gateway.
+/// * `select_files` - An implementation of the [`SelectFilesGateway`] gateway, used to select every file in the home directory.
+/// * `file_data_gateway` - An implementation of the [`FileDataGateway`] gateway, used to read each file to sign it.
+/// * `signature_algorithm` - An implementation of the [`SignatureAlgorithm`] used to sign each file.
+/// * `persist_bundle` - An implementation of the [`PersistBundleGateway`] gateway.
+///
+pub fn bundle_collection(
+    request: &BundleCollectionRequest,
+    retrieve_directory: RetrieveDirectoryPath,
+    select_files: SelectFilesGateway,
+    file_data_gateway: FileDataGateway,
+    signature_algorithm: SignatureAlgorithm,
+    persist_bundle: PersistBundleGateway) -> Result<BundledCollection, Error> {
+
+    let subject_nrn = NRN::new(request.subject_nrn)
+        .map_err(|error| Error::for_system(Kind::InvalidInput,
+                                           format!("There is an issue with the subject NRN '{}'. {}", request.subject_nrn, error.message)))?;
+    subject_nrn.nid.check_allowed(request.organisation_nids)
+        .map_err(|error| Error::for_user(Kind::InvalidInput,
+                                         format!("There is an issue with the subject NRN '{}'. {}", request.subject_nrn, error.message)))?;
+
+    let home_directory = retrieve_directory("home")
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue retrieving the home directory path. {}", error.message)))?;
+
+    let files = match select_files(&home_directory) {
+        Ok(SelectedFiles::Files(files)) => files,
+        Ok(SelectedFiles::File) => return Err(Error::for_system(Kind::InvalidInput,
+                                                                format!("The home directory '{}' is not a directory.", home_directory))),
+        Err(error) => return Err(Error::for_system(Kind::GatewayError,
+                                                   format!("There was an issue selecting the files in the home directory '{}'. {}", home_directory, error.message)))
+    };
+
+    let files: Vec<_> = files.into_iter()
+        .filter(|file| !file.relative_path.starts_with(TEMP_DIRECTORY))
+        .collect();
+
+    if !files.iter().any(|file| file.relative_path.starts_with(REPORT_FILE_STEM)) {
+        return Err(Error::for_user(Kind::InvalidInput,
+                                   String::from("The collection does not have an assurance report. Please run 'nape collect report' before bundling the collection.")));
+    }
+
+    let mut entries = Vec::new();
+    for file in &files {
+        let data = file_data_gateway(&file.source)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("Could not get file data for signing: {}. {}", file.relative_path, error.message)))?;
+        let signature = signature_algorithm(&data)
+            .map_err(|error| Error::for_system(Kind::ProcessingFailure,
+                                               format!("Failed to sign the file: {}. {}", file.relative_path, error.message)))?;
+        entries.push(ManifestEntry { file: file.relative_path.clone(), signature });
+    }
+
+    let bundle_file = match request.bundle_file {
+        Some(bundle_file) => bundle_file.to_string(),
+        None => {
+            let start_time = home_directory.trim_end_matches('/').rsplit('/').next().unwrap_or_default();
+            format!("{}_{}.{}", encode_as_directory_name(&subject_nrn), start_time, BUNDLE_FILE_EXTENSION)
+        }
+    };
+
+    let bundle = CollectionBundle { manifest: BundleManifest::new(entries).to_bytes(), files };
+
+    let bundle_file = persist_bundle(&bundle, &bundle_file)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue writing the bundle '{}'. {}", bundle_file, error.message)))?;
+
+    Ok(BundledCollection { bundle_file, file_count: bundle.files.len() })
+}
+
+/// # Overview
+///
+/// The `verify_bundle` use case checks a bundle without access to the original collection.  The signature of each file in the bundle is recomputed and compared to the [`BundleManifest`], and then the signature of each test and evidence file in the assurance report is recomputed and compared to the report.
+///
+/// # Arguments
+///
+/// * `request` - The [`VerifyBundleRequest`].
+/// * `retrieve_bundle` - An implementation of the [`RetrieveBundleGateway`] gateway.
+/// * `signature_algorithm` - An implementation of the [`SignatureAlgorithm`] used to recompute the signatures.
+/// * `report_signatures` - An implementation of the [`ReportSignaturesGateway`] gateway.
+///
+/// # Design Decision
+///
+/// * Every issue is collected before the error is returned, so the auditor sees each missing, added, or changed file at once.
+/// * An evidence directory is verified by the signatures of the files within it, which the report records individually.
+///
+pub fn verify_bundle(
+    request: &VerifyBundleRequest,
+    retrieve_bundle: RetrieveBundleGateway,
+    signature_algorithm: SignatureAlgorithm,
+    report_signatures: ReportSignaturesGateway) -> Result<VerifiedBundle, Error> {
+
+    let entries = retrieve_bundle(request.bundle_file)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue reading the bundle '{}'. {}", request.bundle_file, error.message)))?;
+
+    let manifest_entry = find_entry(&entries, BUNDLE_MANIFEST_FILE)
+        .ok_or_else(|| Error::for_user(Kind::InvalidInput,
+                                       format!("The bundle '{}' does not have a manifest.", request.bundle_file)))?;
+    let manifest = BundleManifest::try_parse(&manifest_entry.data)?;
+
+    let mut issues = Vec::new();
+    let mut file_count = 0;
+    for manifest_file in manifest.entries() {
+        match find_entry(&entries, &manifest_file.file) {
+            Some(entry) if has_signature(entry, &manifest_file.signature, signature_algorithm)? => file_count += 1,
+            Some(_) => issues.push(format!("The file '{}' does not match its signature in the manifest.", manifest_file.file)),
+            None => issues.push(format!("The file '{}' in the manifest is missing from the bundle.", manifest_file.file)),
+        }
+    }
+    for entry in entries.iter().filter(|entry| entry.path != BUNDLE_MANIFEST_FILE) {
+        if !manifest.entries().iter().any(|manifest_file| manifest_file.file == entry.path) {
+            issues.push(format!("The file '{}' is not in the manifest.", entry.path));
+        }
+    }
+
+    let mut report_file_count = 0;
+    match entries.iter().find(|entry| entry.path.starts_with(REPORT_FILE_STEM)) {
+        None => issues.push(String::from("The bundle does not have an assurance report.")),
+        Some(report) => {
+            let report_signatures = report_signatures(&report.data)
+                .map_err(|error| Error::for_system(Kind::GatewayError,
+                                                   format!("There was an issue reading the signatures in the assurance report '{}'. {}", report.path, error.message)))?;
+            if let Err(error) = NRN::new(&report_signatures.subject_nrn).and_then(|subject_nrn| subject_nrn.nid.check_allowed(request.organisation_nids)) {
+                issues.push(format!("The subject NRN '{}' in the assurance report is not valid. {}", report_signatures.subject_nrn, error.message));
+            }
+            for signed_file in &report_signatures.signed_files {
+                let file = signed_file.file().as_str().trim_start_matches("./");
+                match find_entry(&entries, file) {
+                    Some(entry) if has_signature(entry, signed_file.signature(), signature_algorithm)? => report_file_count += 1,
+                    Some(_) => issues.push(format!("The file '{}' does not match its signature in the assurance report.", file)),
+                    None if is_directory(&entries, file) => {},
+                    None => issues.push(format!("The file '{}' in the assurance report is missing from the bundle.", file)),
+                }
+            }
+        }
+    }
+
+    if !issues.is_empty() {
+        return Err(Error::for_user(Kind::InvalidInput,
+                                   format!("The bundle '{}' could not be verified. {}", request.bundle_file, issues.join(" "))));
+    }
+
+    Ok(VerifiedBundle { file_count, report_file_count })
+}
+
+fn has_signature(entry: &BundleEntry, expected: &Signature, signature_algorithm: SignatureAlgorithm) -> Result<bool, Error> {
+    let signature = signature_algorithm(&entry.data)
+        .map_err(|error| Error::for_system(Kind::ProcessingFailure,
+                                           format!("Failed to sign the file: {}. {}", entry.path, error.message)))?;
+    Ok(signature.structure_signature() == expected.structure_signature())
+}
+
+fn find_entry<'a>(entries: &'a [BundleEntry], path: &str) -> Option<&'a BundleEntry> {
+    entries.iter().find(|entry| entry.path == path)
+}
+
+fn is_directory(entries: &[BundleEntry], path: &str) -> bool {
+    let prefix = format!("{}/", path.trim_end_matches('/'));
+    entries.iter().any(|entry| entry.path.starts_with(&prefix))
+}
@@ -0,0 +1,233 @@
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureType};
+use nape_kernel::error::{Audience, Error, Kind};
+use nape_kernel::gateways::file_selection::{SelectedFile, SelectedFiles};
+use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::evidence_collection::usecases::bundle_collection::gateway_boundary::{BundleEntry, CollectionBundle, ReportSignatures};
+use crate::evidence_collection::usecases::bundle_collection::usecase::{bundle_collection, verify_bundle, BundleCollectionRequest, VerifyBundleRequest};
+
+/*** Happy Path Tests ***/
+
+#[test]
+fn bundle_success() {
+    let persist_assertion = |bundle: &CollectionBundle, bundle_file: &str| -> Result<String, Error> {
+        assert_eq!(bundle_file, "nrn_sourcecode_nape_project_-_nape-cli_1719326666123.tar.zst");
+        let relative_paths: Vec<&str> = bundle.files.iter().map(|file| file.relative_path.as_str()).collect();
+        assert_eq!(relative_paths, vec!["activity/peer-review.rego", "assurance_procedure.yaml", "assurance_report.yaml", "evidence/peer-review/review.json"]);
+        assert_eq!(String::from_utf8(bundle.manifest.clone()).unwrap(),
+                   "SHA256[length25] activity/peer-review.rego\n\
+                    SHA256[length24] assurance_procedure.yaml\n\
+                    SHA256[length21] assurance_report.yaml\n\
+                    SHA256[length32] evidence/peer-review/review.json\n");
+        Ok(bundle_file.to_string())
+    };
+
+    let result = bundle_collection(&bundle_request(None), retrieve_directory_success, select_files_success, read_path_as_data, length_signature, persist_assertion);
+
+    let bundled = is_ok!(result);
+    assert_eq!(bundled.bundle_file, "nrn_sourcecode_nape_project_-_nape-cli_1719326666123.tar.zst");
+    assert_eq!(bundled.file_count, 4);
+}
+
+#[test]
+fn bundle_to_file_success() {
+    let persist_assertion = |_bundle: &CollectionBundle, bundle_file: &str| -> Result<String, Error> {
+        assert_eq!(bundle_file, "/tmp/audit/collection.tar.zst");
+        Ok(bundle_file.to_string())
+    };
+
+    let result = bundle_collection(&bundle_request(Some("/tmp/audit/collection.tar.zst")), retrieve_directory_success, select_files_success, read_path_as_data, length_signature, persist_assertion);
+
+    is_ok!(result);
+}
+
+#[test]
+fn verify_success() {
+    let result = verify_bundle(&verify_request(), retrieve_bundle_success, length_signature, report_signatures_success);
+
+    let verified = is_ok!(result);
+    assert_eq!(verified.file_count, 4);
+    assert_eq!(verified.report_file_count, 3);
+}
+
+/*** Sad Path Tests ***/
+
+#[test]
+fn bundle_without_report_error() {
+    let select_files_without_report = |_file_path: &str| -> Result<SelectedFiles, Error> {
+        Ok(SelectedFiles::Files(vec![selected_file("evidence/peer-review/review.json")]))
+    };
+
+    let result = bundle_collection(&bundle_request(None), retrieve_directory_success, select_files_without_report, read_path_as_data, length_signature, persist_bundle_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The collection does not have an assurance report. Please run 'nape collect report' before bundling the collection.");
+}
+
+#[test]
+fn bundle_unregistered_nid_error() {
+    let request = BundleCollectionRequest { subject_nrn: "nrn:bank-product:nape:payments", ..bundle_request(None) };
+
+    let result = bundle_collection(&request, retrieve_directory_success, select_files_success, read_path_as_data, length_signature, persist_bundle_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "There is an issue with the subject NRN 'nrn:bank-product:nape:payments'. 'bank-product' is not a valid NID. Must be one of: [procedure, sourcecode, service, image, cloudaccount, dataset, pipeline].");
+}
+
+#[test]
+fn bundle_persist_gateway_error() {
+    let persist_error = |_bundle: &CollectionBundle, _bundle_file: &str| -> Result<String, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some PersistBundle Gateway Error".to_string()))
+    };
+
+    let result = bundle_collection(&bundle_request(Some("collection.tar.zst")), retrieve_directory_success, select_files_success, read_path_as_data, length_signature, persist_error);
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue writing the bundle 'collection.tar.zst'. Some PersistBundle Gateway Error");
+}
+
+#[test]
+fn verify_changed_added_and_missing_files_error() {
+    let retrieve_tampered_bundle = |_bundle_file: &str| -> Result<Vec<BundleEntry>, Error> {
+        let mut entries = bundle_entries();
+        entries.retain(|entry| entry.path != "activity/peer-review.rego");
+        entries.iter_mut().filter(|entry| entry.path == "evidence/peer-review/review.json").for_each(|entry| entry.data = b"{\"approved\": false}".to_vec());
+        entries.push(entry("evidence/extra.txt", "added"));
+        Ok(entries)
+    };
+
+    let result = verify_bundle(&verify_request(), retrieve_tampered_bundle, length_signature, report_signatures_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The bundle 'collection.tar.zst' could not be verified. \
+         The file 'activity/peer-review.rego' in the manifest is missing from the bundle. \
+         The file 'evidence/peer-review/review.json' does not match its signature in the manifest. \
+         The file 'evidence/extra.txt' is not in the manifest. \
+         The file 'activity/peer-review.rego' in the assurance report is missing from the bundle. \
+         The file 'evidence/peer-review/review.json' does not match its signature in the assurance report.");
+}
+
+#[test]
+fn verify_unregistered_nid_error() {
+    let report_signatures_custom_nid = |report_data: &[u8]| -> Result<ReportSignatures, Error> {
+        Ok(ReportSignatures { subject_nrn: "nrn:bank-product:nape:payments".to_string(), ..report_signatures_success(report_data)? })
+    };
+
+    let result = verify_bundle(&verify_request(), retrieve_bundle_success, length_signature, report_signatures_custom_nid);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The bundle 'collection.tar.zst' could not be verified. \
+         The subject NRN 'nrn:bank-product:nape:payments' in the assurance report is not valid. 'bank-product' is not a valid NID. Must be one of: [procedure, sourcecode, service, image, cloudaccount, dataset, pipeline].");
+}
+
+#[test]
+fn verify_registered_nid_success() {
+    let report_signatures_custom_nid = |report_data: &[u8]| -> Result<ReportSignatures, Error> {
+        Ok(ReportSignatures { subject_nrn: "nrn:bank-product:nape:payments".to_string(), ..report_signatures_success(report_data)? })
+    };
+    let organisation_nids = vec!["bank-product".to_string()];
+
+    let result = verify_bundle(&VerifyBundleRequest { organisation_nids: &organisation_nids, ..verify_request() }, retrieve_bundle_success, length_signature, report_signatures_custom_nid);
+
+    is_ok!(result);
+}
+
+#[test]
+fn verify_without_manifest_error() {
+    let retrieve_bundle_without_manifest = |_bundle_file: &str| -> Result<Vec<BundleEntry>, Error> {
+        Ok(vec![entry("assurance_report.yaml", "outcome: pass")])
+    };
+
+    let result = verify_bundle(&verify_request(), retrieve_bundle_without_manifest, length_signature, report_signatures_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The bundle 'collection.tar.zst' does not have a manifest.");
+}
+
+#[test]
+fn verify_retrieve_bundle_gateway_error() {
+    let retrieve_bundle_error = |_bundle_file: &str| -> Result<Vec<BundleEntry>, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some RetrieveBundle Gateway Error".to_string()))
+    };
+
+    let result = verify_bundle(&verify_request(), retrieve_bundle_error, length_signature, report_signatures_success);
+
+    kernel_error_starts_with!(result, Kind::GatewayError, Audience::System, "There was an issue reading the bundle 'collection.tar.zst'. ");
+}
+
+/*** Testing Utilities & Mocks ***/
+
+fn bundle_request(bundle_file: Option<&str>) -> BundleCollectionRequest<'_> {
+    BundleCollectionRequest { subject_nrn: "nrn:sourcecode:nape:project/nape-cli", bundle_file, organisation_nids: &[] }
+}
+
+fn verify_request() -> VerifyBundleRequest<'static> {
+    VerifyBundleRequest { bundle_file: "collection.tar.zst", organisation_nids: &[] }
+}
+
+fn selected_file(relative_path: &str) -> SelectedFile {
+    SelectedFile {
+        source: relative_path.to_string(),
+        relative_path: relative_path.to_string(),
+    }
+}
+
+fn retrieve_directory_success(_directory_name: &str) -> Result<String, Error> {
+    Ok("nrn_sourcecode_nape_project_-_nape-cli/1719326666123".to_string())
+}
+
+fn select_files_success(_file_path: &str) -> Result<SelectedFiles, Error> {
+    Ok(SelectedFiles::Files(vec![
+        selected_file("activity/peer-review.rego"),
+        selected_file("assurance_procedure.yaml"),
+        selected_file("assurance_report.yaml"),
+        selected_file("evidence/peer-review/review.json"),
+        selected_file("temp/clone/HEAD"),
+    ]))
+}
+
+/// Reads the path of the file as its data, so the signature of each file is distinct.
+fn read_path_as_data(file_path: &str) -> Result<Vec<u8>, Error> {
+    Ok(file_path.as_bytes().to_vec())
+}
+
+fn length_signature(data: &Vec<u8>) -> Result<Signature, Error> {
+    Signature::try_new(SignatureType::SHA256, &format!("length{}", data.len()))
+}
+
+fn persist_bundle_success(_bundle: &CollectionBundle, bundle_file: &str) -> Result<String, Error> {
+    Ok(bundle_file.to_string())
+}
+
+fn entry(path: &str, data: &str) -> BundleEntry {
+    BundleEntry { path: path.to_string(), data: data.as_bytes().to_vec() }
+}
+
+fn bundle_entries() -> Vec<BundleEntry> {
+    vec![
+        entry("bundle_manifest.txt", "SHA256[length9] activity/peer-review.rego\n\
+                                      SHA256[length13] assurance_report.yaml\n\
+                                      SHA256[length18] evidence/peer-review/review.json\n\
+                                      SHA256[length5] evidence/scans/a.txt\n"),
+        entry("activity/peer-review.rego", "package x"),
+        entry("assurance_report.yaml", "outcome: pass"),
+        entry("evidence/peer-review/review.json", "{\"approved\": true}"),
+        entry("evidence/scans/a.txt", "clean"),
+    ]
+}
+
+fn retrieve_bundle_success(_bundle_file: &str) -> Result<Vec<BundleEntry>, Error> {
+    Ok(bundle_entries())
+}
+
+fn report_signatures_success(_report_data: &[u8]) -> Result<ReportSignatures, Error> {
+    let signed_file = |file: &str, signature: &str| SignedFile::new(file, &Signature::try_new(SignatureType::SHA256, signature).unwrap()).unwrap();
+    Ok(ReportSignatures {
+        subject_nrn: "nrn:sourcecode:nape:project/nape-cli".to_string(),
+        signed_files: vec![
+            signed_file("activity/peer-review.rego", "length9"),
+            signed_file("evidence/peer-review/review.json", "length18"),
+            signed_file("evidence/scans", "directory-signature"),
+            signed_file("evidence/scans/a.txt", "length5"),
+        ],
+    })
+}
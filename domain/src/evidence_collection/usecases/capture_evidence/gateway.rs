@@ -0,0 +1,53 @@
+use nape_kernel::error::Error;
+use crate::evidence_collection::usecases::capture_evidence::gateway_boundary::{CommandOutput, EvidenceSidecar, HttpRequest, HttpResponse};
+
+/// # Overview
+///
+/// The [`RunCommandGateway`] runs a command and captures its output so the output can be collected as evidence.
+///
+/// # Arguments
+///
+/// * `command` - The program to run followed by its arguments.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`CommandOutput`], or an [`Error`] if the command could not be run.
+///
+/// # Design Decision
+///
+/// * A command which runs but exits with a non-zero exit code is not an error.  The exit code is recorded with the evidence so the control action test can decide whether the evidence is acceptable.
+///
+pub type RunCommandGateway = fn(command: &[String]) -> Result<CommandOutput, Error>;
+
+/// # Overview
+///
+/// The [`FetchUrlGateway`] sends a GET request to an HTTP endpoint so the response body can be collected as evidence.
+///
+/// # Arguments
+///
+/// * `request` - The [`HttpRequest`] to send.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`HttpResponse`], or an [`Error`] if no response was received.
+///
+/// # Design Decision
+///
+/// * A response with an unsuccessful status code, such as a 404, is not an error.  The status code is recorded with the evidence so the control action test can decide whether the evidence is acceptable.
+///
+pub type FetchUrlGateway = fn(request: &HttpRequest) -> Result<HttpResponse, Error>;
+
+/// # Overview
+///
+/// The [`PersistSidecarGateway`] persists the [`EvidenceSidecar`] next to the evidence file it describes.
+///
+/// # Arguments
+///
+/// * `sidecar` - The [`EvidenceSidecar`] to persist.
+/// * `target_directory` - The directory the evidence file was written to.
+///
+/// # Returns
+///
+/// A [`Result`] of either the location of the sidecar file, or an [`Error`].
+///
+pub type PersistSidecarGateway = fn(sidecar: &EvidenceSidecar, target_directory: &str) -> Result<String, Error>;
@@ -0,0 +1,101 @@
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::specification::name::Name;
+use nape_kernel::values::time::start_time::StartTime;
+use crate::evidence_collection::usecases::collect_evidence::redaction::Redaction;
+
+/// The [`CommandOutput`] is the result of running a command with the [`RunCommandGateway`].
+///
+/// * `stdout` - The data the command wrote to standard out.
+/// * `exit_code` - The exit code of the command, or `None` if the command was terminated before it exited, such as by a signal.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommandOutput {
+    pub stdout: Vec<u8>,
+    pub exit_code: Option<i32>,
+}
+
+/// The [`HttpRequest`] is the request the [`FetchUrlGateway`] sends to retrieve evidence from an HTTP endpoint.
+///
+/// * `url` - The URL to send a GET request to.
+/// * `headers` - The headers to send with the request, as name and value pairs.
+/// * `bearer_token` - The token to send in the `Authorization` header, if any.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpRequest {
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub bearer_token: Option<String>,
+}
+
+/// The [`HttpResponse`] is the response returned by the [`FetchUrlGateway`].
+///
+/// * `status` - The HTTP status code of the response.
+/// * `headers` - The headers of the response, as name and value pairs.
+/// * `body` - The body of the response.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// The [`EvidenceSource`] describes where the data in an evidence file came from.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EvidenceSource {
+    /// The evidence is the standard out of a command.  Secrets are redacted from the command line.
+    Command { command_line: String, exit_code: Option<i32> },
+    /// The evidence is the body of the response from an HTTP endpoint.  The request headers, the bearer token, and the response headers which carry credentials are never recorded, and secrets are redacted from the URL and the other response headers.
+    Url { url: String, status: u16, headers: Vec<(String, String)> },
+    /// The evidence is a file, a directory, or the files selected by a glob pattern.
+    File { path: String },
+}
+
+/// The [`EvidenceSidecar`] is the metadata which is stored next to an evidence file to record how, and when, the evidence was captured.
+///
+/// * `action` - The [`Name`] of the action the evidence was collected for.
+/// * `evidence_file` - The file name of the evidence file the sidecar describes.
+/// * `source` - The [`EvidenceSource`] the evidence was captured from.
+/// * `captured` - The time the evidence was captured, or for a URL, fetched.
+/// * `redactions` - The secrets which were redacted from the evidence before it was written, so the control action test can take them into account.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EvidenceSidecar {
+    pub action: Name,
+    pub evidence_file: String,
+    pub source: EvidenceSource,
+    pub captured: StartTime,
+    pub redactions: Vec<Redaction>,
+}
+
+/// Format a command as a single command line, quoting any argument which would otherwise be ambiguous when the command line is read back, so the exact command can be re-run.
+pub fn command_line(command: &[String]) -> String {
+    command.iter()
+        .map(|argument| quote_argument(argument))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn quote_argument(argument: &str) -> String {
+    let needs_quotes = argument.is_empty()
+        || argument.chars().any(|c| c.is_whitespace() || "'\"\\$`|&;<>()*?!#~{}[]".contains(c));
+    match needs_quotes {
+        true => format!("'{}'", argument.replace('\'', "'\\''")),
+        false => argument.to_string()
+    }
+}
+
+/// Parse a header in the format 'Name: value' into its name and value.
+///
+/// ## Errors
+///
+/// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the header does not have a name, or is not separated by a ':'.
+///
+pub fn parse_header(header: &str) -> Result<(String, String), Error> {
+    match header.split_once(':') {
+        Some((name, value)) if !name.trim().is_empty() && !name.trim().contains(char::is_whitespace) =>
+            Ok((name.trim().to_string(), value.trim().to_string())),
+        _ => Err(Error::for_user(Kind::InvalidInput,
+                                 format!("The header '{}' is not valid. A header must be in the format 'Name: value'.", header)))
+    }
+}
@@ -0,0 +1,49 @@
+use nape_kernel::error::{Audience, Kind};
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::evidence_collection::usecases::capture_evidence::gateway_boundary::{command_line, parse_header};
+
+fn to_command(arguments: &[&str]) -> Vec<String> {
+    arguments.iter().map(|argument| argument.to_string()).collect()
+}
+
+#[test]
+fn command_line_plain_arguments() {
+    let command = to_command(&["kubectl", "get", "pods", "-o", "json"]);
+    assert_eq!(command_line(&command), "kubectl get pods -o json");
+}
+
+#[test]
+fn command_line_quotes_ambiguous_arguments() {
+    let command = to_command(&["aws", "iam", "list-users", "--query", "Users[*].UserName", "--profile", "my profile", ""]);
+    assert_eq!(command_line(&command), "aws iam list-users --query 'Users[*].UserName' --profile 'my profile' ''");
+}
+
+#[test]
+fn command_line_escapes_single_quotes() {
+    let command = to_command(&["echo", "it's"]);
+    assert_eq!(command_line(&command), "echo 'it'\\''s'");
+}
+
+#[test]
+fn parse_header_success() {
+    let header = is_ok!(parse_header("X-Request-Source:  nape "));
+    assert_eq!(header, ("X-Request-Source".to_string(), "nape".to_string()));
+}
+
+#[test]
+fn parse_header_value_with_colon_success() {
+    let header = is_ok!(parse_header("Forwarded: for=192.0.2.60:8080"));
+    assert_eq!(header, ("Forwarded".to_string(), "for=192.0.2.60:8080".to_string()));
+}
+
+#[test]
+fn parse_header_no_separator_error() {
+    let result = parse_header("X-Request-Source nape");
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The header 'X-Request-Source nape' is not valid. A header must be in the format 'Name: value'.");
+}
+
+#[test]
+fn parse_header_no_name_error() {
+    let result = parse_header(": nape");
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The header ': nape' is not valid. A header must be in the format 'Name: value'.");
+}
@@ -0,0 +1,5 @@
+pub mod gateway;
+pub mod gateway_boundary;
+pub mod usecase;
+#[cfg(test)] mod gateway_boundary_tests;
+#[cfg(test)] mod usecase_tests;
@@ -0,0 +1,247 @@
+use std::path::Path;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::time::start_time::StartTime;
+use crate::evidence_collection::usecases::capture_evidence::gateway::{FetchUrlGateway, PersistSidecarGateway, RunCommandGateway};
+use crate::evidence_collection::usecases::capture_evidence::gateway_boundary::{command_line, parse_header, EvidenceSidecar, EvidenceSource, HttpRequest};
+use crate::evidence_collection::usecases::collect_evidence::gateway::{CustodyGateways, DeclarationGateways};
+use crate::evidence_collection::usecases::collect_evidence::redaction::RedactionRules;
+use crate::evidence_collection::usecases::collect_evidence::usecase::{CollectedEvidence, CopyFileGateway, CustodyRecorder, DeclaredEvidence};
+
+/// The response headers which carry credentials, and are never recorded in the sidecar.
+const CREDENTIAL_HEADERS: &[&str] = &["authorization", "proxy-authorization", "cookie", "set-cookie", "set-cookie2"];
+
+/// The [`CaptureEvidenceRequest`] is the request to capture the output of a command as the evidence for an action.
+///
+/// * `action_name` - The name of the action the evidence is collected for.
+/// * `command` - The program to run followed by its arguments.
+/// * `file_name` - The name of the evidence file to write the output to, when the action declares a directory as its evidence.  When not provided, the file is named after the program, such as 'kubectl-output.txt'.  When the action declares a file, the evidence is always named after it, so the name must match it.
+/// * `input` - The name of the evidence input the output is captured for.  When not provided, the output is captured as the primary `evidence` of the action.
+/// * `no_redact` - When `true`, secrets are not redacted from the output.
+/// * `redaction_rules` - The patterns of additional secrets to redact from the output.
+///
+#[derive(Clone, Debug, Default)]
+pub struct CaptureEvidenceRequest<'a> {
+    pub action_name: &'a str,
+    pub command: &'a [String],
+    pub file_name: Option<&'a str>,
+    pub input: Option<&'a str>,
+    pub no_redact: bool,
+    pub redaction_rules: &'a [String],
+}
+
+/// The [`CaptureUrlRequest`] is the request to capture the response body of an HTTP endpoint as the evidence for an action.
+///
+/// * `action_name` - The name of the action the evidence is collected for.
+/// * `url` - The URL to send a GET request to.
+/// * `headers` - The headers to send with the request, each in the format 'Name: value'.
+/// * `bearer_token` - The token to send in the `Authorization` header, if any.
+/// * `file_name` - The name of the evidence file to write the response body to, when the action declares a directory as its evidence.  When not provided, the file is named after the last segment of the URL path, such as 'health-response.txt'.  When the action declares a file, the evidence is always named after it, so the name must match it.
+/// * `input` - The name of the evidence input the response body is captured for.  When not provided, the response body is captured as the primary `evidence` of the action.
+/// * `no_redact` - When `true`, secrets are not redacted from the response body.
+/// * `redaction_rules` - The patterns of additional secrets to redact from the response body.
+///
+#[derive(Clone, Debug, Default)]
+pub struct CaptureUrlRequest<'a> {
+    pub action_name: &'a str,
+    pub url: &'a str,
+    pub headers: &'a [String],
+    pub bearer_token: Option<&'a str>,
+    pub file_name: Option<&'a str>,
+    pub input: Option<&'a str>,
+    pub no_redact: bool,
+    pub redaction_rules: &'a [String],
+}
+
+/// `UCCaptureCommandEvidence` is a function pointer type that represents the use case for capturing the output of a command as evidence.
+///
+/// # Parameters
+///
+/// * `request`: The [`CaptureEvidenceRequest`] which describes the command to run and the action the evidence is collected for.
+///
+/// # Returns
+///
+/// This function returns a [`Result`] that contains the [`CollectedEvidence`] if the output was captured, or an [`Error`] if it could not be captured.
+pub type UCCaptureCommandEvidence = fn(request: &CaptureEvidenceRequest) -> Result<CollectedEvidence, Error>;
+
+/// `UCCaptureUrlEvidence` is a function pointer type that represents the use case for capturing the response body of an HTTP endpoint as evidence.
+///
+/// # Parameters
+///
+/// * `request`: The [`CaptureUrlRequest`] which describes the endpoint to fetch and the action the evidence is collected for.
+///
+/// # Returns
+///
+/// This function returns a [`Result`] that contains the [`CollectedEvidence`] if the response was captured, or an [`Error`] if it could not be captured.
+pub type UCCaptureUrlEvidence = fn(request: &CaptureUrlRequest) -> Result<CollectedEvidence, Error>;
+
+
+/// # Overview
+///
+/// The `capture_command_evidence` use case runs a command, writes its standard out to the `evidence` path the action declares in the assurance procedure, and writes an [`EvidenceSidecar`] next to the evidence file which records the command line, the exit code, and the time the evidence was captured.
+///
+/// The action must exist in the assurance procedure, and must not be an attestation action.  When it does not exist, the error suggests the actions with the most similar names.  When the request names an evidence input, the output is written to the path the input declares instead.
+///
+/// Secrets are redacted from the output before it is written, unless the request turns redaction off, and the redactions are recorded in the sidecar.  The evidence is signed after it is redacted.  Secrets are redacted in the same way from the command line before it is recorded in the sidecar, the custody ledger, or an error message.
+///
+/// # Arguments
+///
+/// * `request` - The [`CaptureEvidenceRequest`].
+/// * `declaration` - The [`DeclarationGateways`] used to find the action and where its evidence is declared.
+/// * `run_command` - An implementation of the [`RunCommandGateway`] gateway.
+/// * `copy_file` - An implementation of the [`CopyFileGateway`] gateway, used to write the evidence file.
+/// * `persist_sidecar` - An implementation of the [`PersistSidecarGateway`] gateway.
+/// * `custody` - The [`CustodyGateways`] used to record the evidence file in the custody ledger.
+///
+pub fn capture_command_evidence(
+    request: &CaptureEvidenceRequest,
+    declaration: DeclarationGateways,
+    run_command: RunCommandGateway,
+    copy_file: CopyFileGateway,
+    persist_sidecar: PersistSidecarGateway,
+    custody: CustodyGateways) -> Result<CollectedEvidence, Error> {
+
+    let program = request.command.first()
+        .ok_or_else(|| Error::for_user(Kind::InvalidInput,
+                                       String::from("A command is required to capture evidence. Please provide the command after '--'.")))?;
+
+    let redaction_rules = RedactionRules::try_for_request(request.no_redact, request.redaction_rules)?;
+    let command_line = redaction_rules.redact_text(&command_line(request.command));
+    let declared = DeclaredEvidence::try_resolve(request.action_name, request.input, declaration)?;
+    let valid_action_name = declared.action_name.clone();
+    let (target_directory, target_file_name) = declared.try_target_file(request.file_name, &default_file_name(program))?;
+    let custody = CustodyRecorder::try_new(&valid_action_name, declaration.retrieve_directory, custody)?;
+
+    let captured = StartTime::now();
+    let output = run_command(request.command)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue running the command '{}'. {}", command_line, error.message)))?;
+
+    let (stdout, redactions) = redaction_rules.redact(&target_file_name, &output.stdout);
+
+    let evidence_file_location = copy_file(&target_file_name, &stdout, &target_directory)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue writing the output of the command '{}' to '{}'. {}", command_line, target_directory, error.message)))?;
+
+    custody.record(&command_line, &evidence_file_location, &stdout)?;
+
+    let sidecar = EvidenceSidecar {
+        action: valid_action_name,
+        evidence_file: target_file_name,
+        source: EvidenceSource::Command { command_line, exit_code: output.exit_code },
+        captured,
+        redactions,
+    };
+
+    write_sidecar(&sidecar, &target_directory, persist_sidecar)?;
+
+    Ok(CollectedEvidence { file_location: evidence_file_location })
+}
+
+/// # Overview
+///
+/// The `capture_url_evidence` use case sends a GET request to an HTTP endpoint, writes the response body to the `evidence` path the action declares in the assurance procedure, and writes an [`EvidenceSidecar`] next to the evidence file which records the URL, the status code, the response headers, and the time the response was fetched.
+///
+/// The action must exist in the assurance procedure, and must not be an attestation action, as for the `capture_command_evidence` use case.
+///
+/// Secrets are redacted from the response body before it is written, unless the request turns redaction off, and the redactions are recorded in the sidecar.  Secrets are redacted in the same way from the URL and the response headers before they are recorded in the sidecar, the custody ledger, or an error message, and the headers which carry credentials, such as 'Set-Cookie', are never recorded.
+///
+/// # Arguments
+///
+/// * `request` - The [`CaptureUrlRequest`].
+/// * `declaration` - The [`DeclarationGateways`] used to find the action and where its evidence is declared.
+/// * `fetch_url` - An implementation of the [`FetchUrlGateway`] gateway.
+/// * `copy_file` - An implementation of the [`CopyFileGateway`] gateway, used to write the evidence file.
+/// * `persist_sidecar` - An implementation of the [`PersistSidecarGateway`] gateway.
+/// * `custody` - The [`CustodyGateways`] used to record the evidence file in the custody ledger.
+///
+pub fn capture_url_evidence(
+    request: &CaptureUrlRequest,
+    declaration: DeclarationGateways,
+    fetch_url: FetchUrlGateway,
+    copy_file: CopyFileGateway,
+    persist_sidecar: PersistSidecarGateway,
+    custody: CustodyGateways) -> Result<CollectedEvidence, Error> {
+
+    let redaction_rules = RedactionRules::try_for_request(request.no_redact, request.redaction_rules)?;
+    let url = redaction_rules.redact_text(request.url);
+
+    if !(request.url.starts_with("http://") || request.url.starts_with("https://")) {
+        return Err(Error::for_user(Kind::InvalidInput,
+                                   format!("The URL '{}' is not valid. A URL must start with 'http://' or 'https://'.", url)));
+    }
+
+    let headers = request.headers.iter()
+        .map(|header| parse_header(header))
+        .collect::<Result<Vec<(String, String)>, Error>>()?;
+
+    let declared = DeclaredEvidence::try_resolve(request.action_name, request.input, declaration)?;
+    let valid_action_name = declared.action_name.clone();
+    let (target_directory, target_file_name) = declared.try_target_file(request.file_name, &default_url_file_name(request.url))?;
+    let custody = CustodyRecorder::try_new(&valid_action_name, declaration.retrieve_directory, custody)?;
+
+    let http_request = HttpRequest {
+        url: request.url.to_string(),
+        headers,
+        bearer_token: request.bearer_token.map(str::to_string),
+    };
+
+    let captured = StartTime::now();
+    let response = fetch_url(&http_request)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue fetching the URL '{}'. {}", url, error.message)))?;
+
+    let (body, redactions) = redaction_rules.redact(&target_file_name, &response.body);
+
+    let evidence_file_location = copy_file(&target_file_name, &body, &target_directory)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue writing the response of the URL '{}' to '{}'. {}", url, target_directory, error.message)))?;
+
+    custody.record(&url, &evidence_file_location, &body)?;
+
+    let sidecar = EvidenceSidecar {
+        action: valid_action_name,
+        evidence_file: target_file_name,
+        source: EvidenceSource::Url { url, status: response.status, headers: recorded_headers(&response.headers, &redaction_rules) },
+        captured,
+        redactions,
+    };
+
+    write_sidecar(&sidecar, &target_directory, persist_sidecar)?;
+
+    Ok(CollectedEvidence { file_location: evidence_file_location })
+}
+
+fn write_sidecar(sidecar: &EvidenceSidecar, target_directory: &str, persist_sidecar: PersistSidecarGateway) -> Result<String, Error> {
+    persist_sidecar(sidecar, target_directory)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue writing the sidecar for the evidence file '{}'. {}", sidecar.evidence_file, error.message)))
+}
+
+/// The response headers which are recorded in the sidecar.  The headers which carry credentials, such as 'Set-Cookie', are left out, and secrets are redacted from the values of the others.
+fn recorded_headers(headers: &[(String, String)], redaction_rules: &RedactionRules) -> Vec<(String, String)> {
+    headers.iter()
+        .filter(|(name, _)| !CREDENTIAL_HEADERS.iter().any(|credential_header| name.eq_ignore_ascii_case(credential_header)))
+        .map(|(name, value)| (name.clone(), redaction_rules.redact_text(value)))
+        .collect()
+}
+
+fn default_file_name(program: &str) -> String {
+    let program_name = Path::new(program).file_stem()
+        .and_then(|stem| stem.to_str())
+        .unwrap_or("command");
+    format!("{}-output.txt", program_name)
+}
+
+fn default_url_file_name(url: &str) -> String {
+    let without_query = url.split(['?', '#']).next().unwrap_or(url);
+    let without_scheme = without_query.split_once("://").map(|(_, rest)| rest).unwrap_or(without_query);
+    let segment = without_scheme.split('/')
+        .skip(1)
+        .filter(|segment| !segment.is_empty())
+        .last()
+        .unwrap_or("url");
+    let name: String = segment.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' { c } else { '-' })
+        .collect();
+    format!("{}-response.txt", name)
+}
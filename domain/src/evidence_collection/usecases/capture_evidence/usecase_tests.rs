@@ -0,0 +1,515 @@
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureType};
+use nape_kernel::error::{Audience, Error, Kind};
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::evidence_collection::usecases::collect_evidence::gateway::{CustodyGateways, DeclarationGateways};
+use crate::evidence_collection::usecases::capture_evidence::gateway_boundary::{CommandOutput, EvidenceSidecar, EvidenceSource, HttpRequest, HttpResponse};
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::CustodyEntry;
+use crate::evidence_collection::usecases::collect_evidence::redaction::Redaction;
+use crate::evidence_collection::usecases::capture_evidence::usecase::{capture_command_evidence, capture_url_evidence, CaptureEvidenceRequest, CaptureUrlRequest};
+
+/*** Happy Path Tests ***/
+
+#[test]
+fn capture_success() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: Some("pods.json"), ..Default::default() };
+
+    let copy_assertion = |file_name: &str, file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_name, "pods.json");
+        assert_eq!(file_data, &b"{\"items\": []}".to_vec());
+        assert_eq!(target_directory, "/collection/evidence/pod-inventory");
+        Ok("/collection/evidence/pod-inventory/pods.json".to_string())
+    };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(sidecar.action.value, "pod-inventory");
+        assert_eq!(sidecar.evidence_file, "pods.json");
+        assert_eq!(sidecar.source, EvidenceSource::Command { command_line: "kubectl get pods -o json".to_string(), exit_code: Some(0) });
+        assert!(sidecar.captured.time > 0);
+        assert_eq!(target_directory, "/collection/evidence/pod-inventory");
+        Ok("/collection/evidence/pod-inventory/pods.json.sidecar.yaml".to_string())
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_assertion, sidecar_assertion, custody_gateways());
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/pod-inventory/pods.json");
+}
+
+#[test]
+fn capture_default_file_name_success() {
+    let command = vec!["/usr/local/bin/terraform".to_string(), "show".to_string(), "-json".to_string()];
+    let request = CaptureEvidenceRequest { action_name: "infrastructure-plan", command: &command, file_name: None, ..Default::default() };
+
+    let copy_assertion = |file_name: &str, _file_data: &Vec<u8>, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_name, "terraform-output.txt");
+        Ok(String::new())
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_assertion, persist_sidecar_success, custody_gateways());
+
+    is_ok!(result);
+}
+
+#[test]
+fn capture_non_zero_exit_code_success() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: None, ..Default::default() };
+
+    let run_command_failed = |_command: &[String]| -> Result<CommandOutput, Error> {
+        Ok(CommandOutput { stdout: Vec::new(), exit_code: Some(1) })
+    };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(sidecar.source, EvidenceSource::Command { command_line: "kubectl get pods -o json".to_string(), exit_code: Some(1) });
+        Ok(String::new())
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_failed, copy_file_success, sidecar_assertion, custody_gateways());
+
+    is_ok!(result);
+}
+
+#[test]
+fn capture_redacted_success() {
+    let command = kubectl_command();
+    let rules = vec!["db-[0-9]+".to_string()];
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: Some("pods.txt"), redaction_rules: &rules, ..Default::default() };
+
+    let run_command_secret = |_command: &[String]| -> Result<CommandOutput, Error> {
+        Ok(CommandOutput { stdout: b"host=db-42 password=hunter2".to_vec(), exit_code: Some(0) })
+    };
+    let copy_assertion = |_file_name: &str, file_data: &Vec<u8>, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_data, &b"host=[REDACTED:custom-1] password=[REDACTED:password]".to_vec());
+        Ok(String::new())
+    };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(sidecar.redactions, vec![
+            Redaction { file: "pods.txt".to_string(), rule: "password".to_string(), count: 1 },
+            Redaction { file: "pods.txt".to_string(), rule: "custom-1".to_string(), count: 1 }]);
+        Ok(String::new())
+    };
+    let custody_assertion = |entry: &CustodyEntry, _home_directory: &str| -> Result<(), Error> {
+        assert_eq!(entry.signature.as_str(), "length53");
+        Ok(())
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_secret, copy_assertion, sidecar_assertion, CustodyGateways { append_custody_entry: custody_assertion, ..custody_gateways() });
+
+    is_ok!(result);
+}
+
+#[test]
+fn capture_no_redact_success() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: None, no_redact: true, ..Default::default() };
+
+    let run_command_secret = |_command: &[String]| -> Result<CommandOutput, Error> {
+        Ok(CommandOutput { stdout: b"password=hunter2".to_vec(), exit_code: Some(0) })
+    };
+    let copy_assertion = |_file_name: &str, file_data: &Vec<u8>, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_data, &b"password=hunter2".to_vec());
+        Ok(String::new())
+    };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        assert!(sidecar.redactions.is_empty());
+        Ok(String::new())
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_secret, copy_assertion, sidecar_assertion, custody_gateways());
+
+    is_ok!(result);
+}
+
+#[test]
+fn capture_input_success() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, input: Some("nodes"), ..Default::default() };
+
+    let copy_assertion = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_name, "nodes.json");
+        assert_eq!(target_directory, "/collection/evidence/pod-inventory");
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_assertion, persist_sidecar_success, custody_gateways());
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/pod-inventory/nodes.json");
+}
+
+/*** Sad Path Tests ***/
+
+#[test]
+fn capture_unknown_action_error() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventroy", command: &command, file_name: None, ..Default::default() };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'pod-inventroy' does not exist in the assurance procedure. Did you mean 'pod-inventory'?");
+}
+
+#[test]
+fn capture_attestation_action_error() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "access-review", command: &command, file_name: None, ..Default::default() };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'access-review' is an attestation action, so its evidence is the signed statement of the person who attests it. Please attest the action instead.");
+}
+
+#[test]
+fn capture_unknown_input_error() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, input: Some("services"), ..Default::default() };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'pod-inventory' does not have the evidence input 'services'. The evidence inputs of the action are: evidence, nodes.");
+}
+
+#[test]
+fn capture_invalid_redaction_rule_error() {
+    let command = kubectl_command();
+    let rules = vec!["db-(".to_string()];
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: None, redaction_rules: &rules, ..Default::default() };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "There is an issue with the redaction rule 1. ");
+}
+
+#[test]
+fn capture_invalid_action_name_error() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod inventory", command: &command, file_name: None, ..Default::default() };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "There is an issue with the action name 'pod inventory'. ");
+}
+
+#[test]
+fn capture_no_command_error() {
+    let command: Vec<String> = Vec::new();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: None, ..Default::default() };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "A command is required to capture evidence. Please provide the command after '--'.");
+}
+
+#[test]
+fn capture_run_command_gateway_error() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: None, ..Default::default() };
+
+    let run_command_error = |_command: &[String]| -> Result<CommandOutput, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some RunCommand Gateway Error".to_string()))
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_error, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue running the command 'kubectl get pods -o json'. Some RunCommand Gateway Error");
+}
+
+#[test]
+fn capture_copy_file_gateway_error() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: None, ..Default::default() };
+
+    let copy_file_error = |_file_name: &str, _file_data: &Vec<u8>, _target_directory: &str| -> Result<String, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some CopyFile Gateway Error".to_string()))
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_error, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue writing the output of the command 'kubectl get pods -o json' to '/collection/evidence/pod-inventory'. Some CopyFile Gateway Error");
+}
+
+#[test]
+fn capture_persist_sidecar_gateway_error() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: Some("pods.json"), ..Default::default() };
+
+    let persist_sidecar_error = |_sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some PersistSidecar Gateway Error".to_string()))
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_success, persist_sidecar_error, custody_gateways());
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue writing the sidecar for the evidence file 'pods.json'. Some PersistSidecar Gateway Error");
+}
+
+#[test]
+fn capture_command_records_custody() {
+    let command = kubectl_command();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: None, ..Default::default() };
+    let custody_assertion = |entry: &CustodyEntry, _home_directory: &str| -> Result<(), Error> {
+        assert_eq!(entry.action.value, "pod-inventory");
+        assert_eq!(entry.source, "kubectl get pods -o json");
+        assert_eq!(entry.target, "evidence/pod-inventory/kubectl-output.txt");
+        assert_eq!(entry.signature.structure_signature(), "SHA256[length13]");
+        Ok(())
+    };
+    let copy_file = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file, persist_sidecar_success, CustodyGateways { append_custody_entry: custody_assertion, ..custody_gateways() });
+
+    is_ok!(result);
+}
+
+#[test]
+fn capture_command_redacts_command_line() {
+    let command: Vec<String> = ["curl", "-H", "token=abc123", "https://internal.example.com/pods"].iter().map(|arg| arg.to_string()).collect();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: Some("pods.json"), ..Default::default() };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(sidecar.source, EvidenceSource::Command { command_line: "curl -H token=[REDACTED:password] https://internal.example.com/pods".to_string(), exit_code: Some(0) });
+        Ok(String::new())
+    };
+    let custody_assertion = |entry: &CustodyEntry, _home_directory: &str| -> Result<(), Error> {
+        assert_eq!(entry.source, "curl -H token=[REDACTED:password] https://internal.example.com/pods");
+        Ok(())
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_success, copy_file_success, sidecar_assertion, CustodyGateways { append_custody_entry: custody_assertion, ..custody_gateways() });
+
+    is_ok!(result);
+}
+
+#[test]
+fn capture_command_error_redacts_command_line() {
+    let command: Vec<String> = ["curl", "-H", "token=abc123", "https://internal.example.com/pods"].iter().map(|arg| arg.to_string()).collect();
+    let request = CaptureEvidenceRequest { action_name: "pod-inventory", command: &command, file_name: Some("pods.json"), ..Default::default() };
+    let run_command_error = |_command: &[String]| -> Result<CommandOutput, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some RunCommand Gateway Error".to_string()))
+    };
+
+    let result = capture_command_evidence(&request, declaration_gateways(), run_command_error, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue running the command 'curl -H token=[REDACTED:password] https://internal.example.com/pods'. Some RunCommand Gateway Error");
+}
+
+/*** URL Happy Path Tests ***/
+
+#[test]
+fn capture_url_success() {
+    let headers = vec!["Accept: application/json".to_string()];
+    let request = CaptureUrlRequest { action_name: "service-health", url: "https://internal.example.com/health", headers: &headers, bearer_token: Some("the-token"), file_name: Some("health.json"), ..Default::default() };
+
+    let fetch_assertion = |request: &HttpRequest| -> Result<HttpResponse, Error> {
+        assert_eq!(request.url, "https://internal.example.com/health");
+        assert_eq!(request.headers, vec![("Accept".to_string(), "application/json".to_string())]);
+        assert_eq!(request.bearer_token, Some("the-token".to_string()));
+        fetch_url_success(request)
+    };
+    let copy_assertion = |file_name: &str, file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_name, "health.json");
+        assert_eq!(file_data, &b"{\"status\": \"up\"}".to_vec());
+        assert_eq!(target_directory, "/collection/evidence/service-health");
+        Ok("/collection/evidence/service-health/health.json".to_string())
+    };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(sidecar.action.value, "service-health");
+        assert_eq!(sidecar.evidence_file, "health.json");
+        assert_eq!(sidecar.source, EvidenceSource::Url {
+            url: "https://internal.example.com/health".to_string(),
+            status: 200,
+            headers: vec![("content-type".to_string(), "application/json".to_string())] });
+        assert!(sidecar.captured.time > 0);
+        Ok(String::new())
+    };
+
+    let result = capture_url_evidence(&request, declaration_gateways(), fetch_assertion, copy_assertion, sidecar_assertion, custody_gateways());
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/service-health/health.json");
+}
+
+#[test]
+fn capture_url_redacts_url_and_headers_success() {
+    let request = CaptureUrlRequest { action_name: "service-health", url: "https://internal.example.com/health?access_token=abc123", headers: &[], bearer_token: None, file_name: None, ..Default::default() };
+
+    let fetch_assertion = |request: &HttpRequest| -> Result<HttpResponse, Error> {
+        assert_eq!(request.url, "https://internal.example.com/health?access_token=abc123");
+        Ok(HttpResponse {
+            status: 200,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("Set-Cookie".to_string(), "session=s3cr3t".to_string()),
+                ("x-debug".to_string(), "token=abc123".to_string())],
+            body: b"{\"status\": \"up\"}".to_vec() })
+    };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(sidecar.source, EvidenceSource::Url {
+            url: "https://internal.example.com/health?access_token=[REDACTED:password]".to_string(),
+            status: 200,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("x-debug".to_string(), "token=[REDACTED:password]".to_string())] });
+        Ok(String::new())
+    };
+    let custody_assertion = |entry: &CustodyEntry, _home_directory: &str| -> Result<(), Error> {
+        assert_eq!(entry.source, "https://internal.example.com/health?access_token=[REDACTED:password]");
+        Ok(())
+    };
+
+    let result = capture_url_evidence(&request, declaration_gateways(), fetch_assertion, copy_file_success, sidecar_assertion, CustodyGateways { append_custody_entry: custody_assertion, ..custody_gateways() });
+
+    is_ok!(result);
+}
+
+#[test]
+fn capture_url_default_file_name_success() {
+    let request = CaptureUrlRequest { action_name: "service-config", url: "http://localhost:8080/api/v1/config/?format=json", headers: &[], bearer_token: None, file_name: None, ..Default::default() };
+
+    let copy_assertion = |file_name: &str, _file_data: &Vec<u8>, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_name, "config-response.txt");
+        Ok(String::new())
+    };
+
+    let result = capture_url_evidence(&request, declaration_gateways(), fetch_url_success, copy_assertion, persist_sidecar_success, custody_gateways());
+
+    is_ok!(result);
+}
+
+/*** URL Sad Path Tests ***/
+
+#[test]
+fn capture_url_unknown_action_error() {
+    let request = CaptureUrlRequest { action_name: "service-helth", url: "https://internal.example.com/health", headers: &[], bearer_token: None, file_name: None, ..Default::default() };
+
+    let result = capture_url_evidence(&request, declaration_gateways(), fetch_url_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'service-helth' does not exist in the assurance procedure. Did you mean 'service-health'?");
+}
+
+#[test]
+fn capture_url_file_name_does_not_match_declared_evidence_error() {
+    let request = CaptureUrlRequest { action_name: "service-health", url: "https://internal.example.com/health", headers: &[], bearer_token: None, file_name: Some("status.json"), ..Default::default() };
+
+    let result = capture_url_evidence(&request, declaration_gateways(), fetch_url_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'service-health' declares its evidence as 'evidence/service-health/health.json', so the evidence cannot be named 'status.json'. Please remove the file name, or use 'health.json'.");
+}
+
+#[test]
+fn capture_url_invalid_url_error() {
+    let request = CaptureUrlRequest { action_name: "service-health", url: "ftp://internal.example.com/health", headers: &[], bearer_token: None, file_name: None, ..Default::default() };
+
+    let result = capture_url_evidence(&request, declaration_gateways(), fetch_url_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The URL 'ftp://internal.example.com/health' is not valid. A URL must start with 'http://' or 'https://'.");
+}
+
+#[test]
+fn capture_url_invalid_header_error() {
+    let headers = vec!["Accept application/json".to_string()];
+    let request = CaptureUrlRequest { action_name: "service-health", url: "https://internal.example.com/health", headers: &headers, bearer_token: None, file_name: None, ..Default::default() };
+
+    let result = capture_url_evidence(&request, declaration_gateways(), fetch_url_success, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The header 'Accept application/json' is not valid. A header must be in the format 'Name: value'.");
+}
+
+#[test]
+fn capture_url_fetch_gateway_error() {
+    let request = CaptureUrlRequest { action_name: "service-health", url: "https://internal.example.com/health", headers: &[], bearer_token: None, file_name: None, ..Default::default() };
+
+    let fetch_url_error = |_request: &HttpRequest| -> Result<HttpResponse, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some FetchUrl Gateway Error".to_string()))
+    };
+
+    let result = capture_url_evidence(&request, declaration_gateways(), fetch_url_error, copy_file_success, persist_sidecar_success, custody_gateways());
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue fetching the URL 'https://internal.example.com/health'. Some FetchUrl Gateway Error");
+}
+
+/*** Testing Utilities & Mocks ***/
+
+fn kubectl_command() -> Vec<String> {
+    vec!["kubectl".to_string(), "get".to_string(), "pods".to_string(), "-o".to_string(), "json".to_string()]
+}
+
+fn declaration_gateways() -> DeclarationGateways {
+    DeclarationGateways { retrieve_directory: retrieve_directory_success, retrieve_definition: retrieve_definition_success }
+}
+
+fn custody_gateways() -> CustodyGateways {
+    CustodyGateways { signature_algorithm: signature_algorithm_success, retrieve_user: retrieve_user_success, append_custody_entry: append_custody_entry_success }
+}
+
+fn retrieve_directory_success(directory_name: &str) -> Result<String, Error> {
+    match directory_name {
+        "home" => Ok("/collection".to_string()),
+        "assurance-procedure-file" => Ok("/collection/assurance_procedure.yaml".to_string()),
+        _ => Ok(format!("/collection/{}", directory_name))
+    }
+}
+
+fn retrieve_definition_success(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+    let pod_inventory = Action::builder().name("pod-inventory").short_description("Pod inventory").long_description("The pods are inventoried.")
+        .test_file_path("tests/pod-inventory.rego").evidence_file_path("evidence/pod-inventory/").add_input("nodes", "evidence/pod-inventory/nodes.json").try_build()?;
+    let infrastructure_plan = Action::builder().name("infrastructure-plan").short_description("Infrastructure plan").long_description("The infrastructure plan is reviewed.")
+        .test_file_path("tests/infrastructure-plan.rego").evidence_file_path("evidence/infrastructure-plan/").try_build()?;
+    let service_health = Action::builder().name("service-health").short_description("Service health").long_description("The service is healthy.")
+        .test_file_path("tests/service-health.rego").evidence_file_path("evidence/service-health/health.json").try_build()?;
+    let service_config = Action::builder().name("service-config").short_description("Service config").long_description("The service is configured.")
+        .test_file_path("tests/service-config.rego").evidence_file_path("evidence/service-config/").try_build()?;
+    let access_review = Action::builder().name("access-review").short_description("Access review").long_description("The quarterly access review was completed.")
+        .evidence_file_path("evidence/access-review/attestation.yaml").attestation().try_build()?;
+    let activity = Activity::new("operations", "Operations", "The operations activity.")?
+        .add(pod_inventory).add(infrastructure_plan).add(service_health).add(service_config).add(access_review);
+
+    AssuranceProcedure::builder()
+        .api_version("1.6.0")
+        .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+        .add_activity(&activity)
+        .try_build()
+}
+
+fn run_command_success(_command: &[String]) -> Result<CommandOutput, Error> {
+    Ok(CommandOutput { stdout: b"{\"items\": []}".to_vec(), exit_code: Some(0) })
+}
+
+fn copy_file_success(_file_name: &str, _file_data: &Vec<u8>, _target_directory: &str) -> Result<String, Error> {
+    Ok(String::new())
+}
+
+fn persist_sidecar_success(_sidecar: &EvidenceSidecar, _target_directory: &str) -> Result<String, Error> {
+    Ok(String::new())
+}
+
+fn fetch_url_success(_request: &HttpRequest) -> Result<HttpResponse, Error> {
+    Ok(HttpResponse {
+        status: 200,
+        headers: vec![("content-type".to_string(), "application/json".to_string())],
+        body: b"{\"status\": \"up\"}".to_vec() })
+}
+
+fn signature_algorithm_success(file_data: &Vec<u8>) -> Result<Signature, Error> {
+    Signature::try_new(SignatureType::SHA256, &format!("length{}", file_data.len()))
+}
+
+fn retrieve_user_success() -> Result<String, Error> {
+    Ok("jane".to_string())
+}
+
+fn append_custody_entry_success(_entry: &CustodyEntry, _home_directory: &str) -> Result<(), Error> {
+    Ok(())
+}
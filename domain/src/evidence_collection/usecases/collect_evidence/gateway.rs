@@ -0,0 +1,62 @@
+use nape_kernel::algorithms::signature_algorithm::SignatureAlgorithm;
+use nape_kernel::error::Error;
+use nape_kernel::gateways::directory_list::RetrieveDirectoryPath;
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::CustodyEntry;
+use crate::evidence_collection::usecases::evaluate_evidence::gateway::RetrieveAssuranceProcedure;
+
+/// # Overview
+///
+/// The [`AppendCustodyEntryGateway`] appends a [`CustodyEntry`] to the custody ledger in the collection's home directory.
+///
+/// # Arguments
+///
+/// * `entry` - The [`CustodyEntry`] to append.
+/// * `home_directory` - The home directory of the collection.
+///
+/// # Returns
+///
+/// A [`Result`] of either `()`, or an [`Error`] if the entry could not be appended.
+///
+/// # Design Decision
+///
+/// * The ledger is append only.  An implementation must never rewrite or remove an existing entry, so the ledger is a history of every collection of evidence.
+///
+pub type AppendCustodyEntryGateway = fn(entry: &CustodyEntry, home_directory: &str) -> Result<(), Error>;
+
+/// # Overview
+///
+/// The [`RetrieveCustodyLedgerGateway`] retrieves every [`CustodyEntry`] in the custody ledger in the collection's home directory.
+///
+/// # Arguments
+///
+/// * `home_directory` - The home directory of the collection.
+///
+/// # Returns
+///
+/// A [`Result`] of either the entries in the order they were appended, or an [`Error`].  When no evidence has been collected, and there is no ledger, the list is empty.
+///
+pub type RetrieveCustodyLedgerGateway = fn(home_directory: &str) -> Result<Vec<CustodyEntry>, Error>;
+
+/// # Overview
+///
+/// The [`RetrieveUserGateway`] retrieves the name of the user collecting the evidence.
+///
+pub type RetrieveUserGateway = fn() -> Result<String, Error>;
+
+/// The [`DeclarationGateways`] groups the gateways used to find an action in the assurance procedure of the collection, and where it declares its evidence.
+#[derive(Clone, Copy)]
+pub struct DeclarationGateways {
+    pub retrieve_directory: RetrieveDirectoryPath,
+    pub retrieve_definition: RetrieveAssuranceProcedure,
+}
+
+/// The [`CustodyGateways`] groups the gateways used to record each file collected for an action in the custody ledger.
+///
+/// * `signature_algorithm` - used to sign each file recorded in the custody ledger.
+/// * `retrieve_user` - used to record who collected the evidence.
+#[derive(Clone, Copy)]
+pub struct CustodyGateways {
+    pub signature_algorithm: SignatureAlgorithm,
+    pub retrieve_user: RetrieveUserGateway,
+    pub append_custody_entry: AppendCustodyEntryGateway,
+}
@@ -0,0 +1,31 @@
+use nape_kernel::algorithms::signature_algorithm::Signature;
+use nape_kernel::values::specification::name::Name;
+use nape_kernel::values::time::start_time::StartTime;
+
+/// The [`CustodyEntry`] is an entry in the custody ledger, which records where each piece of evidence came from when it was collected.
+///
+/// * `action` - The [`Name`] of the action the evidence was collected for.
+/// * `source` - Where the evidence was collected from, such as the original file path, the command line, or the URL.
+/// * `target` - The path of the collected evidence file, relative to the collection's home directory.
+/// * `signature` - The [`Signature`] of the evidence file when it was collected.
+/// * `collected` - The time the evidence was collected.
+/// * `user` - The user who collected the evidence.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustodyEntry {
+    pub action: Name,
+    pub source: String,
+    pub target: String,
+    pub signature: Signature,
+    pub collected: StartTime,
+    pub user: String,
+}
+
+/// Returns the path of the `location` relative to the `home_directory`, or the `location` unchanged if it is not within the home directory.
+pub fn relative_to_home(home_directory: &str, location: &str) -> String {
+    let home = home_directory.trim_end_matches('/');
+    match location.strip_prefix(home).and_then(|relative| relative.strip_prefix('/')) {
+        Some(relative) if !home.is_empty() => relative.to_string(),
+        _ => location.to_string()
+    }
+}
@@ -0,0 +1,13 @@
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::relative_to_home;
+
+#[test]
+fn relative_to_home_success() {
+    assert_eq!(relative_to_home("/home/collection", "/home/collection/evidence/peer-review/report.json"), "evidence/peer-review/report.json");
+    assert_eq!(relative_to_home("/home/collection/", "/home/collection/evidence/report.json"), "evidence/report.json");
+}
+
+#[test]
+fn relative_to_home_outside_home_success() {
+    assert_eq!(relative_to_home("/home/collection", "/home/collection-other/report.json"), "/home/collection-other/report.json");
+    assert_eq!(relative_to_home("", "evidence/report.json"), "evidence/report.json");
+}
@@ -1,2 +1,7 @@
+pub mod gateway;
+pub mod gateway_boundary;
+pub mod redaction;
 pub mod usecase;
-#[cfg(test)] mod usecase_tests;
\ No newline at end of file
+#[cfg(test)] mod gateway_boundary_tests;
+#[cfg(test)] mod redaction_tests;
+#[cfg(test)] mod usecase_tests;
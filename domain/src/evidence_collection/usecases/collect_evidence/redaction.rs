@@ -0,0 +1,108 @@
+use nape_kernel::algorithms::pattern::Pattern;
+use nape_kernel::error::{Error, Kind};
+
+/// The detectors which are always used to redact evidence, unless redaction is turned off, as a name and a [`Pattern`].  When a pattern has a capture group, only the group is redacted, so the key or label before a secret is kept for the control action test.
+pub const BUILT_IN_DETECTORS: &[(&str, &str)] = &[
+    ("private-key", r"-----BEGIN [A-Z ]*PRIVATE KEY-----[\s\S]*?-----END [A-Z ]*PRIVATE KEY-----"),
+    ("jwt", r"eyJ[A-Za-z0-9_-]{4,}\.eyJ[A-Za-z0-9_-]{4,}\.[A-Za-z0-9_-]*"),
+    ("aws-access-key-id", r"(?:AKIA|ASIA|ABIA|ACCA)[0-9A-Z]{16}"),
+    ("aws-secret-access-key", r#"(?i)aws_?secret_?access_?key["']?\s*[:=]\s*["']?([A-Za-z0-9/+=]{40})"#),
+    ("password", r#"(?i)(?:password|passwd|pwd|secret|token|api_?key)["']?\s*[:=]\s*["']?([^\s"',;&]+)"#),
+];
+
+/// The prefix of the text which replaces a redacted secret, such as '[REDACTED:jwt]'.
+pub const REDACTED_PREFIX: &str = "[REDACTED:";
+
+/// A [`RedactionRule`] is a named [`Pattern`] which finds a secret to redact.
+#[derive(Clone, Debug)]
+pub struct RedactionRule {
+    pub name: String,
+    pub pattern: Pattern,
+}
+
+/// The [`Redaction`] records how many secrets a [`RedactionRule`] redacted from an evidence file.
+///
+/// * `file` - The evidence file, or for a directory, the path of the file relative to the directory.
+/// * `rule` - The name of the [`RedactionRule`].
+/// * `count` - The number of secrets redacted.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Redaction {
+    pub file: String,
+    pub rule: String,
+    pub count: usize,
+}
+
+/// The [`RedactionRules`] are applied, in order, to the evidence before it is collected.
+#[derive(Clone, Debug, Default)]
+pub struct RedactionRules {
+    rules: Vec<RedactionRule>,
+}
+
+impl RedactionRules {
+
+    /// The [`RedactionRules`] for the [`BUILT_IN_DETECTORS`], followed by a rule for each user supplied pattern, named 'custom-1', 'custom-2', and so on.
+    pub fn try_new(custom_patterns: &[String]) -> Result<RedactionRules, Error> {
+
+        let mut rules = Vec::new();
+        for (name, pattern) in BUILT_IN_DETECTORS {
+            rules.push(RedactionRule { name: name.to_string(), pattern: Pattern::try_new(pattern)? });
+        }
+        for (index, pattern) in custom_patterns.iter().enumerate() {
+            let pattern = Pattern::try_new(pattern)
+                .map_err(|error| Error::for_user(Kind::InvalidInput,
+                                                 format!("There is an issue with the redaction rule {}. {}", index + 1, error.message)))?;
+            rules.push(RedactionRule { name: format!("custom-{}", index + 1), pattern });
+        }
+        Ok(RedactionRules { rules })
+    }
+
+    /// [`RedactionRules`] which redact nothing, used when redaction is turned off.
+    pub fn none() -> RedactionRules {
+        RedactionRules::default()
+    }
+
+    /// The [`RedactionRules`] for a collection request, which are [`RedactionRules::none`] when `no_redact` is set, and otherwise the built-in detectors followed by the custom patterns.
+    pub fn try_for_request(no_redact: bool, custom_patterns: &[String]) -> Result<RedactionRules, Error> {
+        match no_redact {
+            true => Ok(RedactionRules::none()),
+            false => RedactionRules::try_new(custom_patterns)
+        }
+    }
+
+    /// The rules, in the order they are applied.
+    pub fn list(&self) -> &Vec<RedactionRule> {
+        &self.rules
+    }
+
+    /// Redacts the secrets in the data of the evidence `file`, and returns the redacted data with a [`Redaction`] for each rule which found a secret.
+    ///
+    /// The data is searched as bytes, so secrets are also redacted from data which is not UTF-8 text.  A match which is already redacted by an earlier rule is not redacted again.
+    pub fn redact(&self, file: &str, data: &[u8]) -> (Vec<u8>, Vec<Redaction>) {
+
+        let mut data = data.to_vec();
+        let mut redactions = Vec::new();
+        for rule in &self.rules {
+            let ranges: Vec<(usize, usize)> = rule.pattern.find_all(&data).iter()
+                .map(|found| found.group.unwrap_or((found.start, found.end)))
+                .filter(|(start, end)| start < end && !data[*start..*end].starts_with(REDACTED_PREFIX.as_bytes()))
+                .collect();
+
+            if ranges.is_empty() {
+                continue;
+            }
+            let replacement = format!("{}{}]", REDACTED_PREFIX, rule.name);
+            for (start, end) in ranges.iter().rev() {
+                data.splice(*start..*end, replacement.bytes());
+            }
+            redactions.push(Redaction { file: file.to_string(), rule: rule.name.clone(), count: ranges.len() });
+        }
+
+        (data, redactions)
+    }
+    /// Redacts the secrets in text which is recorded about the evidence rather than in it, such as a command line or a URL, so the secret is not leaked into the sidecar, the custody ledger, or an error message.
+    pub fn redact_text(&self, text: &str) -> String {
+        let (data, _) = self.redact("", text.as_bytes());
+        String::from_utf8_lossy(&data).into_owned()
+    }
+}
@@ -1,8 +1,18 @@
 use std::fmt::{Debug};
+use nape_kernel::algorithms::signature_algorithm::SignatureAlgorithm;
 use nape_kernel::error::{Error, Kind};
 use nape_kernel::gateways::directory_list::RetrieveDirectoryPath;
-use nape_kernel::values::directory::name::DirectoryName;
+use nape_kernel::gateways::file_selection::{SelectedFiles, SelectFilesGateway};
 use nape_kernel::values::specification::name::Name;
+use nape_kernel::values::specification::assurance_procedure::evidence_input::PRIMARY_EVIDENCE_INPUT;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_kernel::values::time::start_time::StartTime;
+use strsim::levenshtein;
+use crate::evidence_collection::usecases::capture_evidence::gateway::PersistSidecarGateway;
+use crate::evidence_collection::usecases::capture_evidence::gateway_boundary::{EvidenceSidecar, EvidenceSource};
+use crate::evidence_collection::usecases::collect_evidence::gateway::{AppendCustodyEntryGateway, CustodyGateways, DeclarationGateways};
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::{CustodyEntry, relative_to_home};
+use crate::evidence_collection::usecases::collect_evidence::redaction::RedactionRules;
 
 /// `ActionEvidenceFile` is a trait that represents an evidence file associated with an action.
 ///
@@ -21,13 +31,19 @@ use nape_kernel::values::specification::name::Name;
 /// # Methods
 ///
 /// * `action_name`: Returns a reference to a string that represents the name of the action.
-/// * `file_path`: Returns a reference to a string that represents the path of the file.
-/// * `file_name`: Returns an `Option` that contains a reference to a string that represents the name of the file. If the file name is not available, this method should return `None`
-#[derive(Clone, Debug)]
+/// * `file_path`: Returns a reference to a string that represents the path of the file.  The path may also be a directory, or a glob pattern such as 'manifests/**/*.yaml'.
+/// * `file_name`: Returns an `Option` that contains a reference to a string that represents the name of the file. If the file name is not available, this method should return `None`.  The evidence is always named after the `evidence` path the action declares, so when provided, the file name must match it.
+/// * `no_redact`: When `true`, secrets are not redacted from the evidence.
+/// * `redaction_rules`: The patterns of additional secrets to redact from the evidence.
+/// * `input`: The name of the evidence input the file is collected for.  When not provided, the file is collected as the primary `evidence` of the action.
+#[derive(Clone, Debug, Default)]
 pub struct CollectEvidenceRequest<'a> {
     pub action_name: &'a str,
     pub file_path: &'a str,
     pub file_name:  Option<&'a str>,
+    pub input: Option<&'a str>,
+    pub no_redact: bool,
+    pub redaction_rules: &'a [String],
 }
 
 #[derive(Clone, Debug)]
@@ -58,36 +74,281 @@ pub type CopyFileGateway = fn(file_name: &str, file_data: &Vec<u8>, target_direc
 pub type RetrieveFileDataGateway = fn(file_path: &str) -> Result<(String, Vec<u8>), Error>;
 
 
+/// # Overview
+///
+/// The `collect_action_evidence` use case copies the evidence for an action to the `evidence` path the action declares in the assurance procedure, relative to the home directory.  When the file path is a directory or glob pattern, every file it selects is copied into the declared path, preserving its path relative to the directory, or the base directory of the glob pattern.
+///
+/// The action must exist in the assurance procedure, and must not be an attestation action.  When it does not exist, the error suggests the actions with the most similar names.  When the request names an evidence input, the evidence is copied to the path the input declares instead, and the input must exist on the action.
+///
+/// A [`CustodyEntry`] is appended to the custody ledger for every file copied, recording the original file path, where it was copied to, and its signature.
+///
+/// Secrets are redacted from each file before it is copied, unless the request turns redaction off, so the signature is of the redacted file.  When any secret is redacted, an [`EvidenceSidecar`] which records the redactions is written next to the copied file, or for a directory or glob pattern, next to the directory the files were copied into.
+///
+/// # Arguments
+///
+/// * `request` - The [`CollectEvidenceRequest`].
+/// * `declaration` - The [`DeclarationGateways`] used to find the action and where its evidence is declared.
+/// * `select_files` - An implementation of the [`SelectFilesGateway`] gateway.
+/// * `retrieve_file_data` - An implementation of the [`RetrieveFileDataGateway`] gateway.
+/// * `copy_file` - An implementation of the [`CopyFileGateway`] gateway.
+/// * `persist_sidecar` - An implementation of the [`PersistSidecarGateway`] gateway, used to record the redactions.
+/// * `custody` - The [`CustodyGateways`] used to record each file in the custody ledger.
+///
+/// # Returns
+///
+/// The [`CollectedEvidence`] with the location of the copied file, or for a directory or glob pattern, the directory the files were copied into.
+///
 pub fn collect_action_evidence(
     request: &CollectEvidenceRequest,
-    retrieve_directory: RetrieveDirectoryPath,
+    declaration: DeclarationGateways,
+    select_files: SelectFilesGateway,
     retrieve_file_data: RetrieveFileDataGateway,
-    copy_file: CopyFileGateway) -> Result<CollectedEvidence, Error> {
+    copy_file: CopyFileGateway,
+    persist_sidecar: PersistSidecarGateway,
+    custody: CustodyGateways) -> Result<CollectedEvidence, Error> {
 
-    let valid_action_name = Name::try_from(request.action_name)
-        .map_err(|error| Error::for_user(Kind::InvalidInput,
-                                         format!("There is an issue with the action name '{}'. {}", request.action_name, error.message)))?;
+    let redaction_rules = RedactionRules::try_for_request(request.no_redact, request.redaction_rules)?;
+    let declared = DeclaredEvidence::try_resolve(request.action_name, request.input, declaration)?;
+    let valid_action_name = declared.action_name.clone();
 
-    let (current_file_name, file_data) = retrieve_file_data(request.file_path)
+    let selected_files = select_files(request.file_path)
         .map_err(|error| Error::for_system(Kind::GatewayError,
-                                           format!("There was an issue retrieving the file '{}'. {}", request.file_path, error.message)))?;
+                                           format!("There was an issue selecting the files for '{}'. {}", request.file_path, error.message)))?;
+
+    let custody = CustodyRecorder::try_new(&valid_action_name, declaration.retrieve_directory, custody)?;
+    let collected = StartTime::now();
 
-    let target_file_name = match request.file_name {
-        Some(file_name) => file_name,
-        None => &current_file_name
+    let (file_location, sidecar_directory, sidecar_name, redactions) = match selected_files {
+        SelectedFiles::File => {
+            let (current_file_name, file_data) = retrieve_file(request.file_path, retrieve_file_data)?;
+            let (target_directory, target_name) = declared.try_target_file(request.file_name, &current_file_name)?;
+            let (redacted_data, redactions) = redaction_rules.redact(&target_name, &file_data);
+            let copied_file_location = copy_evidence_file(request.file_path, &target_name, &redacted_data, &target_directory, copy_file)?;
+            custody.record(request.file_path, &copied_file_location, &redacted_data)?;
+            (copied_file_location, target_directory, target_name, redactions)
+        },
+        SelectedFiles::Files(files) => {
+            if files.is_empty() {
+                return Err(Error::for_user(Kind::InvalidInput,
+                                           format!("There are no files in '{}' to collect as evidence.", request.file_path)));
+            }
+            declared.try_target_directory(request.file_name)?;
+            let collection_directory = format!("{}/{}", declared.directory, declared.name);
+            let mut redactions = Vec::new();
+            for file in &files {
+                let (relative_directory, file_name) = match file.relative_path.rsplit_once('/') {
+                    Some((relative_directory, file_name)) => (format!("{}/{}", collection_directory, relative_directory), file_name),
+                    None => (collection_directory.clone(), file.relative_path.as_str())
+                };
+                let (_, file_data) = retrieve_file(&file.source, retrieve_file_data)?;
+                let (redacted_data, file_redactions) = redaction_rules.redact(&file.relative_path, &file_data);
+                let copied_file_location = copy_evidence_file(&file.source, file_name, &redacted_data, &relative_directory, copy_file)?;
+                custody.record(&file.source, &copied_file_location, &redacted_data)?;
+                redactions.extend(file_redactions);
+            }
+            (collection_directory, declared.directory.clone(), declared.name.clone(), redactions)
+        }
     };
 
-    let evidence_root_directory = retrieve_directory("evidence")
-        .map_err(|error| Error::for_system(Kind::GatewayError,
-                                           format!("There was an issue retrieving the evidence directory path. {}", error.message)))?;
+    if !redactions.is_empty() {
+        let sidecar = EvidenceSidecar {
+            action: valid_action_name,
+            evidence_file: sidecar_name,
+            source: EvidenceSource::File { path: request.file_path.to_string() },
+            captured: collected,
+            redactions,
+        };
+        persist_sidecar(&sidecar, &sidecar_directory)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue writing the sidecar for the evidence file '{}'. {}", sidecar.evidence_file, error.message)))?;
+    }
 
-    let action_dir_name = DirectoryName::from(&valid_action_name);
-    let target_directory = format!("{}/{}",evidence_root_directory, action_dir_name.value);
+    Ok(CollectedEvidence { file_location })
+}
 
-    let copied_file_location = copy_file(&target_file_name, &file_data, &target_directory)
-        .map_err(|error| Error::for_system(Kind::GatewayError,
-                                           format!("There was an issue copying the file '{}' to '{}'. {}", request.file_path, target_directory, error.message)))?;
+/// The [`DeclaredEvidence`] is where an action declares its evidence in the assurance procedure, either as its primary `evidence` or as one of its evidence inputs.
+///
+/// * `action_name` - The name of the action.
+/// * `path` - The evidence path as it is declared in the procedure.
+/// * `directory` - The directory the evidence is declared in, relative to the home directory.
+/// * `name` - The name of the declared file or directory.
+/// * `is_directory` - When `true`, the declared path ends with a '/', and the evidence is written into it rather than named after it.
+pub(crate) struct DeclaredEvidence {
+    pub action_name: Name,
+    pub path: String,
+    pub directory: String,
+    pub name: String,
+    pub is_directory: bool,
+}
+
+impl DeclaredEvidence {
+
+    /// Finds the action in the assurance procedure, and resolves the path it declares for the evidence `input`, or its primary `evidence` when no input is provided.
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] for [`Audience::User`] is returned if the action name is not valid, the action does not exist in the procedure, the action is an attestation action, or the action does not have the evidence input.  When the action does not exist, the error suggests the actions with the most similar names.
+    ///
+    pub fn try_resolve(action_name: &str,
+                       input: Option<&str>,
+                       declaration: DeclarationGateways) -> Result<Self, Error> {
+
+        let valid_action_name = Name::try_from(action_name)
+            .map_err(|error| Error::for_user(Kind::InvalidInput,
+                                             format!("There is an issue with the action name '{}'. {}", action_name, error.message)))?;
+
+        let definition_path = (declaration.retrieve_directory)("assurance-procedure-file")
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue retrieving the assurance procedure file path. {}", error.message)))?;
+
+        let procedure = (declaration.retrieve_definition)(&definition_path)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue retrieving the assurance procedure. {}", error.message)))?;
 
-    Ok(CollectedEvidence { file_location: copied_file_location })
+        let action = procedure.activities.find_action(&valid_action_name.value)
+            .ok_or_else(|| unknown_action_error(&valid_action_name, &procedure))?;
 
-}
\ No newline at end of file
+        if action.is_attestation() {
+            return Err(Error::for_user(Kind::InvalidInput,
+                                       format!("The action '{}' is an attestation action, so its evidence is the signed statement of the person who attests it. Please attest the action instead.", valid_action_name.value)));
+        }
+
+        let input_name = input.unwrap_or(PRIMARY_EVIDENCE_INPUT);
+        let declared_path = action.input_evidence(input_name)
+            .ok_or_else(|| Error::for_user(Kind::InvalidInput,
+                                           format!("The action '{}' does not have the evidence input '{}'. The evidence inputs of the action are: {}.",
+                                                   valid_action_name.value, input_name, action.input_names().join(", "))))?;
+
+        let home_directory = (declaration.retrieve_directory)("home")
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue retrieving the home directory path. {}", error.message)))?;
+
+        let path = declared_path.as_str().to_string();
+        let declared_evidence = path.trim_start_matches("./").trim_end_matches('/');
+        let (directory, name) = match declared_evidence.rsplit_once('/') {
+            Some((declared_directory, declared_name)) => (format!("{}/{}", home_directory, declared_directory), declared_name.to_string()),
+            None => (home_directory, declared_evidence.to_string())
+        };
+        let is_directory = path.ends_with('/');
+
+        Ok(Self { action_name: valid_action_name, path, directory, name, is_directory })
+    }
+
+    /// Returns the directory and the name of the evidence file to write.  When the action declares a file, the evidence is named after it, so a requested `file_name` must match it.  When the action declares a directory, the evidence is written into it, named after the requested `file_name`, or else the `default_name`.
+    pub fn try_target_file(&self, file_name: Option<&str>, default_name: &str) -> Result<(String, String), Error> {
+        if self.is_directory {
+            let target_name = file_name.unwrap_or(default_name);
+            return Ok((format!("{}/{}", self.directory, self.name), target_name.to_string()));
+        }
+        self.try_target_directory(file_name)?;
+        Ok((self.directory.clone(), self.name.clone()))
+    }
+
+    /// Checks a requested `file_name` for evidence which is collected into the declared path as a whole, such as a directory or glob pattern, which is always named after the declared path.
+    pub fn try_target_directory(&self, file_name: Option<&str>) -> Result<(), Error> {
+        match file_name.filter(|file_name| *file_name != self.name) {
+            Some(file_name) => Err(Error::for_user(Kind::InvalidInput,
+                                                   format!("The action '{}' declares its evidence as '{}', so the evidence cannot be named '{}'. Please remove the file name, or use '{}'.",
+                                                           self.action_name.value, self.path, file_name, self.name))),
+            None => Ok(())
+        }
+    }
+}
+
+/// The most distant name, as a number of single character edits, suggested for an unknown action.
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// Creates the [`Error`] for an action which does not exist in the procedure, suggesting the actions with similar names, or listing the actions when none are similar.
+pub(crate) fn unknown_action_error(action_name: &Name, procedure: &AssuranceProcedure) -> Error {
+
+    let action_names: Vec<&str> = procedure.activities.list.iter()
+        .flat_map(|activity| activity.actions.iter())
+        .map(|action| action.name.value.as_str())
+        .collect();
+
+    let mut suggestions: Vec<(usize, &str)> = action_names.iter()
+        .map(|name| (levenshtein(&action_name.value, name), *name))
+        .filter(|(distance, _)| *distance <= MAX_SUGGESTION_DISTANCE)
+        .collect();
+    suggestions.sort();
+
+    let help = match suggestions.as_slice() {
+        [] if action_names.is_empty() => String::from("The procedure does not have any actions."),
+        [] => format!("The actions in the procedure are: {}.", action_names.join(", ")),
+        _ => format!("Did you mean {}?", suggestions.iter().take(3).map(|(_, name)| format!("'{}'", name)).collect::<Vec<String>>().join(" or "))
+    };
+
+    Error::for_user(Kind::InvalidInput,
+                    format!("The action '{}' does not exist in the assurance procedure. {}", action_name.value, help))
+}
+
+/// The [`CustodyRecorder`] appends a [`CustodyEntry`] to the custody ledger for each file collected for an action.
+pub struct CustodyRecorder {
+    action: Name,
+    home_directory: String,
+    user: String,
+    signature_algorithm: SignatureAlgorithm,
+    append_custody_entry: AppendCustodyEntryGateway,
+}
+
+impl CustodyRecorder {
+
+    /// Creates a [`CustodyRecorder`] for the action, retrieving the home directory and the user collecting the evidence.
+    pub fn try_new(action: &Name,
+                   retrieve_directory: RetrieveDirectoryPath,
+                   gateways: CustodyGateways) -> Result<Self, Error> {
+
+        let home_directory = retrieve_directory("home")
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue retrieving the home directory path. {}", error.message)))?;
+
+        let user = (gateways.retrieve_user)()
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue retrieving the user collecting the evidence. {}", error.message)))?;
+
+        Ok(Self { action: action.clone(), home_directory, user, signature_algorithm: gateways.signature_algorithm, append_custody_entry: gateways.append_custody_entry })
+    }
+
+    /// The home directory of the collection.
+    pub fn home_directory(&self) -> &str {
+        &self.home_directory
+    }
+
+    /// The user collecting the evidence.
+    pub fn user(&self) -> &str {
+        &self.user
+    }
+
+    /// Signs the `file_data` collected from the `source`, and appends a [`CustodyEntry`] for the file at the `file_location` to the custody ledger.
+    pub fn record(&self, source: &str, file_location: &str, file_data: &Vec<u8>) -> Result<(), Error> {
+
+        let signature = (self.signature_algorithm)(file_data)
+            .map_err(|error| Error::for_system(Kind::ProcessingFailure,
+                                               format!("There was an issue signing the evidence file '{}' for the custody ledger. {}", file_location, error.message)))?;
+
+        let entry = CustodyEntry {
+            action: self.action.clone(),
+            source: source.to_string(),
+            target: relative_to_home(&self.home_directory, file_location),
+            signature,
+            collected: StartTime::now(),
+            user: self.user.clone(),
+        };
+
+        (self.append_custody_entry)(&entry, &self.home_directory)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("There was an issue recording the evidence file '{}' in the custody ledger. {}", file_location, error.message)))
+    }
+}
+
+fn retrieve_file(file_path: &str, retrieve_file_data: RetrieveFileDataGateway) -> Result<(String, Vec<u8>), Error> {
+    retrieve_file_data(file_path)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue retrieving the file '{}'. {}", file_path, error.message)))
+}
+
+fn copy_evidence_file(file_path: &str, file_name: &str, file_data: &Vec<u8>, target_directory: &str, copy_file: CopyFileGateway) -> Result<String, Error> {
+    copy_file(file_name, file_data, target_directory)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue copying the file '{}' to '{}'. {}", file_path, target_directory, error.message)))
+}
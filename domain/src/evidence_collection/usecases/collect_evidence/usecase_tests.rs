@@ -1,5 +1,14 @@
+use nape_kernel::algorithms::signature_algorithm::{Signature, SignatureType};
 use nape_kernel::error::{Audience, Error, Kind};
-use nape_testing_assertions::kernel_error_eq;
+use nape_kernel::gateways::file_selection::{SelectedFile, SelectedFiles};
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::evidence_collection::usecases::collect_evidence::gateway::{CustodyGateways, DeclarationGateways};
+use crate::evidence_collection::usecases::capture_evidence::gateway_boundary::{EvidenceSidecar, EvidenceSource};
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::CustodyEntry;
+use crate::evidence_collection::usecases::collect_evidence::redaction::Redaction;
 use crate::evidence_collection::usecases::collect_evidence::usecase::{collect_action_evidence, CollectEvidenceRequest};
 
 /*** Happy Path Tests ***/
@@ -11,42 +20,389 @@ fn collect_file_success() {
 
     let request = request_no_name_override();
     let result= collect_action_evidence(&request,
-                                        retrieve_directory_success,
+                                        declaration_gateways(),
+                                        select_files_single_file,
                                         retrieve_file_data_success,
-                                        file_name_assertion);
+                                        file_name_assertion,
+                                        persist_sidecar_success,
+                                        custody_gateways());
 
     assert!(result.is_ok(), "{}", format!("An error was not expected but one was not returned: {:?}", result.err()) );
 
 }
 #[test]
-fn collect_file_success_with_name_override() {
+fn collect_file_success_with_matching_name() {
 
-    let file_name_assertion = move |file_name: &str, _file_data: &Vec<u8>, _target_directory: &str| copy_evidence_file_gateway_file_name_assertion(file_name, _file_data, _target_directory, "new_file_name.txt");
+    let file_name_assertion = move |file_name: &str, _file_data: &Vec<u8>, _target_directory: &str| copy_evidence_file_gateway_file_name_assertion(file_name, _file_data, _target_directory, "file.txt");
 
-    let request = request_with_name_override();
+    let request = CollectEvidenceRequest { action_name: "peer-review", file_path: "./some/relative/link/to/renamed.txt", file_name: Some("file.txt"), ..Default::default() };
     let result= collect_action_evidence(&request,
-                                        retrieve_directory_success,
+                                        declaration_gateways(),
+                                        select_files_single_file,
                                         retrieve_file_data_success,
-                                        file_name_assertion);
+                                        file_name_assertion,
+                                        persist_sidecar_success,
+                                        custody_gateways());
 
     assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()) );
 
 }
 
+#[test]
+fn collect_input_success() {
+
+    let copy_input_assertion = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_name, "approvals.json");
+        assert_eq!(target_directory, "/collection/evidence/peer-review");
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+
+    let request = CollectEvidenceRequest { input: Some("approvals"), ..request_no_name_override() };
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_input_assertion,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/peer-review/approvals.json");
+}
+
+#[test]
+fn collect_file_into_declared_directory_success() {
+    let retrieve_directory_definition = |_file_path: &str| -> Result<AssuranceProcedure, Error> {
+        let pod_inventory = Action::builder().name("pod-inventory").short_description("Pod inventory").long_description("The pods are inventoried.")
+            .test_file_path("tests/pod-inventory.rego").evidence_file_path("evidence/pod-inventory/").try_build()?;
+        AssuranceProcedure::builder()
+            .api_version("1.0.0")
+            .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+            .add_activity(&Activity::new("operations", "Operations", "The operations activity.")?.add(pod_inventory))
+            .try_build()
+    };
+    let copy_assertion = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_name, "pods.json");
+        assert_eq!(target_directory, "/collection/evidence/pod-inventory");
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+    let request = CollectEvidenceRequest { action_name: "pod-inventory", file_path: "./some/relative/link/to/file.txt", file_name: Some("pods.json"), ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        DeclarationGateways { retrieve_definition: retrieve_directory_definition, ..declaration_gateways() },
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_assertion,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/pod-inventory/pods.json");
+}
+
+#[test]
+fn collect_directory_success() {
+    let copy_assertion = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        match file_name {
+            "deploy.yaml" => assert_eq!(target_directory, "/collection/evidence/manifest-review/manifests/app"),
+            "service.yaml" => assert_eq!(target_directory, "/collection/evidence/manifest-review/manifests"),
+            _ => panic!("The file '{}' was not expected to be copied.", file_name)
+        }
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+    let request = CollectEvidenceRequest { action_name: "manifest-review", file_path: "./manifests", file_name: None, ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_directory,
+                                        retrieve_file_data_success,
+                                        copy_assertion,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/manifest-review/manifests");
+}
+
+#[test]
+fn collect_directory_success_with_matching_name() {
+    let copy_assertion = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        match file_name {
+            "deploy.yaml" => assert_eq!(target_directory, "/collection/evidence/manifest-review/manifests/app"),
+            "service.yaml" => assert_eq!(target_directory, "/collection/evidence/manifest-review/manifests"),
+            _ => panic!("The file '{}' was not expected to be copied.", file_name)
+        }
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+    let request = CollectEvidenceRequest { action_name: "manifest-review", file_path: "./k8s/**/*.yaml", file_name: Some("manifests"), ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_directory,
+                                        retrieve_file_data_success,
+                                        copy_assertion,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/manifest-review/manifests");
+}
+
+#[test]
+fn collect_file_success_records_custody() {
+    let custody_assertion = |entry: &CustodyEntry, home_directory: &str| -> Result<(), Error> {
+        assert_eq!(home_directory, "/collection");
+        assert_eq!(entry.action.value, "peer-review");
+        assert_eq!(entry.source, "./some/relative/link/to/file.txt");
+        assert_eq!(entry.target, "evidence/peer-review/file.txt");
+        assert_eq!(entry.signature.structure_signature(), "SHA256[length5]");
+        assert_eq!(entry.user, "jane");
+        Ok(())
+    };
+    let copy_file = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+
+    let request = request_no_name_override();
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_file,
+                                        persist_sidecar_success,
+                                        CustodyGateways { append_custody_entry: custody_assertion, ..custody_gateways() });
+
+    let collected = is_ok!(result);
+    assert_eq!(collected.file_location, "/collection/evidence/peer-review/file.txt");
+}
+
+#[test]
+fn collect_directory_success_records_custody_for_each_file() {
+    let custody_assertion = |entry: &CustodyEntry, _home_directory: &str| -> Result<(), Error> {
+        match entry.source.as_str() {
+            "./manifests/app/deploy.yaml" => assert_eq!(entry.target, "evidence/manifest-review/manifests/app/deploy.yaml"),
+            "./manifests/service.yaml" => assert_eq!(entry.target, "evidence/manifest-review/manifests/service.yaml"),
+            _ => panic!("The source '{}' was not expected in the custody ledger.", entry.source)
+        }
+        Ok(())
+    };
+    let copy_file = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+    let request = CollectEvidenceRequest { action_name: "manifest-review", file_path: "./manifests", file_name: None, ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_directory,
+                                        retrieve_file_data_success,
+                                        copy_file,
+                                        persist_sidecar_success,
+                                        CustodyGateways { append_custody_entry: custody_assertion, ..custody_gateways() });
+
+    is_ok!(result);
+}
+
+#[test]
+fn collect_file_success_redacts_secrets() {
+    let retrieve_secret_file = |_file_path: &str| -> Result<(String, Vec<u8>), Error> {
+        Ok(("file.txt".to_string(), b"reviewer: jane\ntoken: abc123\n".to_vec()))
+    };
+    let copy_assertion = |_file_name: &str, file_data: &Vec<u8>, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_data, &b"reviewer: jane\ntoken: [REDACTED:password]\n".to_vec());
+        Ok(String::new())
+    };
+    let custody_assertion = |entry: &CustodyEntry, _home_directory: &str| -> Result<(), Error> {
+        assert_eq!(entry.signature.structure_signature(), "SHA256[length42]");
+        Ok(())
+    };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(target_directory, "/collection/evidence/peer-review");
+        assert_eq!(sidecar.evidence_file, "file.txt");
+        assert_eq!(sidecar.source, EvidenceSource::File { path: "./some/relative/link/to/file.txt".to_string() });
+        assert_eq!(sidecar.redactions, vec![Redaction { file: "file.txt".to_string(), rule: "password".to_string(), count: 1 }]);
+        Ok(String::new())
+    };
+
+    let request = request_no_name_override();
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_secret_file,
+                                        copy_assertion,
+                                        sidecar_assertion,
+                                        CustodyGateways { append_custody_entry: custody_assertion, ..custody_gateways() });
+
+    is_ok!(result);
+}
+
+#[test]
+fn collect_directory_success_records_redactions_for_each_file() {
+    let retrieve_secret_file = |file_path: &str| -> Result<(String, Vec<u8>), Error> {
+        match file_path {
+            "./manifests/app/deploy.yaml" => Ok(("deploy.yaml".to_string(), b"password: hunter2".to_vec())),
+            _ => Ok(("service.yaml".to_string(), b"port: 80".to_vec()))
+        }
+    };
+    let sidecar_assertion = |sidecar: &EvidenceSidecar, target_directory: &str| -> Result<String, Error> {
+        assert_eq!(target_directory, "/collection/evidence/manifest-review");
+        assert_eq!(sidecar.evidence_file, "manifests");
+        assert_eq!(sidecar.redactions, vec![Redaction { file: "app/deploy.yaml".to_string(), rule: "password".to_string(), count: 1 }]);
+        Ok(String::new())
+    };
+    let request = CollectEvidenceRequest { action_name: "manifest-review", file_path: "./manifests", file_name: None, ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_directory,
+                                        retrieve_secret_file,
+                                        copy_evidence_file_gateway_success,
+                                        sidecar_assertion,
+                                        custody_gateways());
+
+    is_ok!(result);
+}
+
+#[test]
+fn collect_file_success_no_redact() {
+    let retrieve_secret_file = |_file_path: &str| -> Result<(String, Vec<u8>), Error> {
+        Ok(("file.txt".to_string(), b"token: abc123".to_vec()))
+    };
+    let copy_assertion = |_file_name: &str, file_data: &Vec<u8>, _target_directory: &str| -> Result<String, Error> {
+        assert_eq!(file_data, &b"token: abc123".to_vec());
+        Ok(String::new())
+    };
+    let sidecar_assertion = |_sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        panic!("A sidecar was not expected when nothing is redacted.");
+    };
+
+    let request = CollectEvidenceRequest { no_redact: true, ..request_no_name_override() };
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_secret_file,
+                                        copy_assertion,
+                                        sidecar_assertion,
+                                        custody_gateways());
+
+    is_ok!(result);
+}
+
 /*** Sad Path Tests ***/
 
+#[test]
+fn collect_file_error_persist_sidecar_gateway_error() {
+    let retrieve_secret_file = |_file_path: &str| -> Result<(String, Vec<u8>), Error> {
+        Ok(("file.txt".to_string(), b"token: abc123".to_vec()))
+    };
+    let persist_sidecar_error = |_sidecar: &EvidenceSidecar, _target_directory: &str| -> Result<String, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some PersistSidecar Gateway Error".to_string()))
+    };
+
+    let request = request_no_name_override();
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_secret_file,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_error,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue writing the sidecar for the evidence file 'file.txt'. Some PersistSidecar Gateway Error");
+}
+
+#[test]
+fn collect_file_error_retrieve_user_gateway_error() {
+    let retrieve_user_error = || -> Result<String, Error> {
+        Err(Error::for_system(Kind::NotFound, "Some RetrieveUser Gateway Error".to_string()))
+    };
+
+    let request = request_no_name_override();
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        CustodyGateways { retrieve_user: retrieve_user_error, ..custody_gateways() });
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue retrieving the user collecting the evidence. Some RetrieveUser Gateway Error");
+}
+
+#[test]
+fn collect_file_error_append_custody_entry_gateway_error() {
+    let append_custody_entry_error = |_entry: &CustodyEntry, _home_directory: &str| -> Result<(), Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some AppendCustodyEntry Gateway Error".to_string()))
+    };
+    let copy_file = |file_name: &str, _file_data: &Vec<u8>, target_directory: &str| -> Result<String, Error> {
+        Ok(format!("{}/{}", target_directory, file_name))
+    };
+
+    let request = request_no_name_override();
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_file,
+                                        persist_sidecar_success,
+                                        CustodyGateways { append_custody_entry: append_custody_entry_error, ..custody_gateways() });
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue recording the evidence file '/collection/evidence/peer-review/file.txt' in the custody ledger. Some AppendCustodyEntry Gateway Error");
+}
+
+#[test]
+fn collect_directory_no_files_error() {
+    let select_no_files = |_file_path: &str| -> Result<SelectedFiles, Error> { Ok(SelectedFiles::Files(Vec::new())) };
+    let request = CollectEvidenceRequest { action_name: "peer-review", file_path: "./manifests/*.yml", file_name: None, ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_no_files,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "There are no files in './manifests/*.yml' to collect as evidence.");
+}
+
+#[test]
+fn collect_file_error_select_files_gateway_error() {
+    let select_files_error = |_file_path: &str| -> Result<SelectedFiles, Error> {
+        Err(Error::for_system(Kind::NotFound, "Some SelectFiles Gateway Error".to_string()))
+    };
+    let request = request_no_name_override();
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_error,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue selecting the files for './some/relative/link/to/file.txt'. Some SelectFiles Gateway Error");
+}
+
 #[test]
 fn collect_file_error_action_name_error() {
     let request = CollectEvidenceRequest {
         action_name: "a band action name",
         file_path: "./some/relative/link/to/file.txt",
-        file_name: None
+        file_name: None,
+        ..Default::default()
     };
 
     let result= collect_action_evidence(&request,
-                                        retrieve_directory_success,
+                                        declaration_gateways(),
+                                        select_files_single_file,
                                         retrieve_file_data_success,
-                                        copy_evidence_file_gateway_success);
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
 
     assert!(result.is_err(), "{}", format!("An error was expected but one was not returned: {:?}", result.unwrap()) );
 
@@ -60,19 +416,130 @@ fn collect_file_error_action_name_error() {
             "{}", format!("The error message '{}' did not start with the expected message '{}'", error.message, expected_message));
 }
 
+#[test]
+fn collect_file_error_unknown_action_with_suggestion() {
+    let request = CollectEvidenceRequest { action_name: "peer-reveiw", file_path: "./some/relative/link/to/file.txt", file_name: None, ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'peer-reveiw' does not exist in the assurance procedure. Did you mean 'peer-review'?");
+}
+
+#[test]
+fn collect_file_error_unknown_action_without_suggestion() {
+    let request = CollectEvidenceRequest { action_name: "deployment-approval", file_path: "./some/relative/link/to/file.txt", file_name: None, ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'deployment-approval' does not exist in the assurance procedure. The actions in the procedure are: peer-review, manifest-review.");
+}
+
+#[test]
+fn collect_file_error_file_name_does_not_match_declared_evidence() {
+    let request = CollectEvidenceRequest { action_name: "peer-review", file_path: "./some/relative/link/to/file.txt", file_name: Some("new_file_name.txt"), ..Default::default() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'peer-review' declares its evidence as 'evidence/peer-review/file.txt', so the evidence cannot be named 'new_file_name.txt'. Please remove the file name, or use 'file.txt'.");
+}
+
+#[test]
+fn collect_file_error_unknown_input() {
+    let request = CollectEvidenceRequest { input: Some("runtime"), ..request_no_name_override() };
+
+    let result= collect_action_evidence(&request,
+                                        declaration_gateways(),
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'peer-review' does not have the evidence input 'runtime'. The evidence inputs of the action are: evidence, approvals.");
+}
+
+#[test]
+fn collect_file_error_attestation_action() {
+    let retrieve_attestation_definition = |_file_path: &str| -> Result<AssuranceProcedure, Error> {
+        let access_review = Action::builder().name("access-review").short_description("Access review").long_description("The quarterly access review was completed.")
+            .evidence_file_path("evidence/access-review/attestation.yaml").attestation().try_build()?;
+        AssuranceProcedure::builder()
+            .api_version("1.6.0")
+            .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+            .add_activity(&Activity::new("review", "Review", "The review activity.")?.add(access_review))
+            .try_build()
+    };
+    let request = CollectEvidenceRequest { action_name: "access-review", ..request_no_name_override() };
+
+    let result= collect_action_evidence(&request,
+                                        DeclarationGateways { retrieve_definition: retrieve_attestation_definition, ..declaration_gateways() },
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The action 'access-review' is an attestation action, so its evidence is the signed statement of the person who attests it. Please attest the action instead.");
+}
+
+#[test]
+fn collect_file_error_retrieve_definition_gateway_error() {
+    let retrieve_definition_error = |_file_path: &str| -> Result<AssuranceProcedure, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some RetrieveAssuranceProcedure Gateway Error".to_string()))
+    };
+
+    let request = request_no_name_override();
+    let result= collect_action_evidence(&request,
+                                        DeclarationGateways { retrieve_definition: retrieve_definition_error, ..declaration_gateways() },
+                                        select_files_single_file,
+                                        retrieve_file_data_success,
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue retrieving the assurance procedure. Some RetrieveAssuranceProcedure Gateway Error");
+}
+
 #[test]
 fn collect_file_error_retrieve_directory_gateway_error() {
 
     let request = request_no_name_override();
     let result= collect_action_evidence(&request,
-                                        retrieve_directory_error,
+                                        DeclarationGateways { retrieve_directory: retrieve_directory_error, ..declaration_gateways() },
+                                        select_files_single_file,
                                         retrieve_file_data_success,
-                                        copy_evidence_file_gateway_success);
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
 
     kernel_error_eq!(result,
         Kind::GatewayError,
         Audience::System,
-        "There was an issue retrieving the evidence directory path. Some RetrieveDirectoryPath Gateway Error");
+        "There was an issue retrieving the assurance procedure file path. Some RetrieveDirectoryPath Gateway Error");
 
 }
 
@@ -81,9 +548,12 @@ fn collect_file_error_retrieve_file_data_gateway_error() {
 
     let request = request_no_name_override();
     let result= collect_action_evidence(&request,
-                                        retrieve_directory_success,
+                                        declaration_gateways(),
+                                        select_files_single_file,
                                         retrieve_file_data_error,
-                                        copy_evidence_file_gateway_success);
+                                        copy_evidence_file_gateway_success,
+                                        persist_sidecar_success,
+                                        custody_gateways());
 
     assert!(result.is_err(), "{}", format!("An error was expected but one was not returned: {:?}", result.unwrap()) );
 
@@ -100,14 +570,17 @@ fn collect_file_error_copy_evidence_file_gateway_error() {
 
     let request = request_no_name_override();
     let result= collect_action_evidence(&request,
-                                        retrieve_directory_success,
+                                        declaration_gateways(),
+                                        select_files_single_file,
                                         retrieve_file_data_success,
-                                        copy_evidence_file_gateway_error);
+                                        copy_evidence_file_gateway_error,
+                                        persist_sidecar_success,
+                                        custody_gateways());
 
     kernel_error_eq!(result,
         Kind::GatewayError,
         Audience::System,
-        "There was an issue copying the file './some/relative/link/to/file.txt' to 'evidence/peer-review'. Some CopyEvidenceFile Gateway Error");
+        "There was an issue copying the file './some/relative/link/to/file.txt' to '/collection/evidence/peer-review'. Some CopyEvidenceFile Gateway Error");
 
 
 }
@@ -118,32 +591,66 @@ fn request_no_name_override() -> CollectEvidenceRequest<'static> {
     CollectEvidenceRequest {
         action_name: "peer-review",
         file_path: "./some/relative/link/to/file.txt",
-        file_name: None
+        file_name: None,
+        ..Default::default()
     }
 }
 
-fn request_with_name_override() -> CollectEvidenceRequest<'static> {
-    CollectEvidenceRequest {
-        action_name: "peer-review",
-        file_path: "./some/relative/link/to/file.txt",
-        file_name: Some("new_file_name.txt")
-    }
+/*** RetrieveDirectoryPath Gateway Mocks***/
+
+fn declaration_gateways() -> DeclarationGateways {
+    DeclarationGateways { retrieve_directory: retrieve_directory_success, retrieve_definition: retrieve_definition_success }
 }
 
-/*** RetrieveDirectoryPath Gateway Mocks***/
+fn custody_gateways() -> CustodyGateways {
+    CustodyGateways { signature_algorithm: signature_algorithm_success, retrieve_user: retrieve_user_success, append_custody_entry: append_custody_entry_success }
+}
 
-fn retrieve_directory_success(_directory_name: &str) -> Result<String, Error> {
-    Ok("evidence".to_string())
+fn retrieve_directory_success(directory_name: &str) -> Result<String, Error> {
+    match directory_name {
+        "home" => Ok("/collection".to_string()),
+        "assurance-procedure-file" => Ok("/collection/assurance_procedure.yaml".to_string()),
+        _ => Ok(format!("/collection/{}", directory_name))
+    }
 }
 
 fn retrieve_directory_error(_directory_name: &str) -> Result<String, Error> {
     Err(Error::for_user(Kind::GatewayError, "Some RetrieveDirectoryPath Gateway Error".to_string()))
 }
 
+/*** RetrieveAssuranceProcedure Gateway Mocks***/
+
+fn retrieve_definition_success(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+    let peer_review = Action::builder().name("peer-review").short_description("Peer review").long_description("The change was peer reviewed.")
+        .test_file_path("tests/peer-review.rego").evidence_file_path("evidence/peer-review/file.txt").add_input("approvals", "evidence/peer-review/approvals.json").try_build()?;
+    let manifest_review = Action::builder().name("manifest-review").short_description("Manifest review").long_description("The manifests were reviewed.")
+        .test_file_path("tests/manifest-review.rego").evidence_file_path("evidence/manifest-review/manifests").try_build()?;
+    let activity = Activity::new("review", "Review", "The review activity.")?.add(peer_review).add(manifest_review);
+
+    AssuranceProcedure::builder()
+        .api_version("1.0.0")
+        .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+        .add_activity(&activity)
+        .try_build()
+}
+
+/*** SelectFiles Gateway Mocks***/
+
+fn select_files_single_file(_file_path: &str) -> Result<SelectedFiles, Error> {
+    Ok(SelectedFiles::File)
+}
+
+fn select_files_directory(_file_path: &str) -> Result<SelectedFiles, Error> {
+    Ok(SelectedFiles::Files(vec![
+        SelectedFile { source: "./manifests/app/deploy.yaml".to_string(), relative_path: "app/deploy.yaml".to_string() },
+        SelectedFile { source: "./manifests/service.yaml".to_string(), relative_path: "service.yaml".to_string() },
+    ]))
+}
+
 /*** RetrieveFileData Gateway Mocks***/
 
 /// This assumes that the file_path which is being provided has a file name of 'file.txt'
-/// This assumption is aligned with the file_path provided in the [`request_no_name_override()`] and [`request_with_name_override()`] functions.
+/// This assumption is aligned with the file_path provided in the [`request_no_name_override()`] function.
 fn retrieve_file_data_success(_file_path: &str) -> Result<(String, Vec<u8>), Error> {
     Ok( ("file.txt".to_string(), vec![1,2,3,4,5]) )
 }
@@ -164,3 +671,23 @@ fn copy_evidence_file_gateway_file_name_assertion(file_name: &str, _file_data: &
                "{}", format!("The file name '{}' was not the expected file name of '{}'.", file_name, expected_file_name));
     Ok("".to_string())
 }
+
+/*** Custody Ledger Gateway Mocks***/
+
+fn signature_algorithm_success(file_data: &Vec<u8>) -> Result<Signature, Error> {
+    Signature::try_new(SignatureType::SHA256, &format!("length{}", file_data.len()))
+}
+
+fn retrieve_user_success() -> Result<String, Error> {
+    Ok("jane".to_string())
+}
+
+fn append_custody_entry_success(_entry: &CustodyEntry, _home_directory: &str) -> Result<(), Error> {
+    Ok(())
+}
+
+/*** PersistSidecar Gateway Mocks***/
+
+fn persist_sidecar_success(_sidecar: &EvidenceSidecar, _target_directory: &str) -> Result<String, Error> {
+    Ok(String::new())
+}
@@ -1,7 +1,16 @@
+use nape_kernel::algorithms::signature_algorithm::SignatureAlgorithm;
 use nape_kernel::error::Error;
+use nape_kernel::gateways::directory_list::{ResolveDirectoryPath, RetrieveDirectoryPath};
+use nape_kernel::gateways::file_data_gateway::FileDataGateway;
+use nape_kernel::gateways::file_selection::SelectFilesGateway;
 use nape_kernel::values::specification::file_path::FilePath;
+use nape_kernel::values::specification::assurance_report::provenance::Provenance;
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+use nape_kernel::values::specification::assurance_report::waivers::Waivers;
 use nape_kernel::values::specification::traits::AssuranceReport;
 use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use crate::evidence_collection::usecases::attest_action::gateway::RetrieveAttestationGateway;
+use crate::evidence_collection::usecases::collect_evidence::gateway::RetrieveCustodyLedgerGateway;
 use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::request::EvaluationFiles;
 use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::response::EvaluationResults;
 
@@ -23,9 +32,48 @@ use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::r
 ///
 ///  * This interface does not explicitly depend upon context to determine which procedure definition to return. The current gateway design assumes the underlying implementation will contain proper context to identify the procedure definition.
 /// * In the future, this gateway interface can be updated to include an argument or context dependency which identifies the procedure definition to be returned.  This may happen when enough evidence suggests that the current design is not sufficient for the needs of the system.
+/// * The implementation is responsible for reading the `apiVersion` of the procedure definition and upgrading older schema versions to the in-memory [`AssuranceProcedure`] model, so the use cases never depend on a specific schema version.
 ///
 pub type RetrieveAssuranceProcedure = fn(file_path: &str) -> Result<AssuranceProcedure, Error>;
 
+/// # Overview
+///
+/// An interface for the gateway which retrieves the [`Waivers`] from a waiver file.
+///
+/// # Arguments
+///
+/// * `file_path` - The file path of the waiver file to be retrieved.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`Waivers`] or an [`Error`].
+///
+pub type RetrieveWaivers = fn(file_path: &str) -> Result<Waivers, Error>;
+
+/// # Overview
+///
+/// An interface for the gateway which retrieves the [`Tailoring`] that was supplied when the evidence collection was started.
+///
+/// # Arguments
+///
+/// * `home_directory` - The home directory of the evidence collection.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`Tailoring`], which is empty when the collection was not tailored, or an [`Error`].
+///
+pub type RetrieveTailoring = fn(home_directory: &str) -> Result<Tailoring, Error>;
+
+/// # Overview
+///
+/// An interface for the gateway which retrieves the [`Provenance`] of the environment the assurance report is generated in, such as the `nape` and evaluator versions, the operating system, the hostname, and the user.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`Provenance`] or an [`Error`].
+///
+pub type RetrieveProvenance = fn() -> Result<Provenance, Error>;
+
 ///  # Overview
 ///
 /// An interface for the gateway which handles the individual evaluation of an evidence file against a series of control actions
@@ -58,3 +106,41 @@ pub type EvaluateEvidenceGateway = fn (files: &EvaluationFiles) -> Result<Evalua
 ///  * This gateway_adapter does not assume the format of the report, only that it will be persisted in the directory provided, given the file name.
 ///
 pub type PersistReportGateway = fn(report: &dyn AssuranceReport, report_directory: &str) -> Result<FilePath, Error>;
+
+/// The [`CollectionGateways`] groups the gateways used to read the procedure, waivers, tailoring, and custody ledger of the collection being evaluated.
+///
+/// * `retrieve_path` - used when the request does not have a home directory.
+/// * `resolve_path` - used when the request has the home directory of the collection to evaluate.
+/// * `retrieve_waivers` - only used when the request contains a waiver file.
+/// * `retrieve_tailoring` - used to skip the actions which do not apply to the subject.
+/// * `retrieve_custody_ledger` - used to flag evidence which was changed after it was collected.
+#[derive(Clone, Copy)]
+pub struct CollectionGateways {
+    pub retrieve_path: RetrieveDirectoryPath,
+    pub resolve_path: ResolveDirectoryPath,
+    pub retrieve_definition: RetrieveAssuranceProcedure,
+    pub retrieve_waivers: RetrieveWaivers,
+    pub retrieve_tailoring: RetrieveTailoring,
+    pub retrieve_custody_ledger: RetrieveCustodyLedgerGateway,
+}
+
+/// The [`EvaluationGateways`] groups the gateways used to evaluate the actions of the procedure, and to record how they were evaluated.
+///
+/// * `retrieve_attestation` - used to read the statement of each attestation action instead of evaluating it.
+#[derive(Clone, Copy)]
+pub struct EvaluationGateways {
+    pub evaluate_evidence: EvaluateEvidenceGateway,
+    pub retrieve_attestation: RetrieveAttestationGateway,
+    pub retrieve_provenance: RetrieveProvenance,
+}
+
+/// The [`ReportGateways`] groups the gateways used to sign the files recorded in the report, and to persist the report.
+///
+/// * `select_files` - used to sign each file individually when the evidence for an action is a directory.
+#[derive(Clone, Copy)]
+pub struct ReportGateways {
+    pub signature_algorithm: SignatureAlgorithm,
+    pub file_data_gateway: FileDataGateway,
+    pub select_files: SelectFilesGateway,
+    pub persist_report: PersistReportGateway,
+}
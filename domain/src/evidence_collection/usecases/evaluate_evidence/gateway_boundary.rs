@@ -13,13 +13,19 @@ pub type EvidenceFilePath = FilePath;
 pub type TestFilePath = FilePath;
 
 pub mod request {
+    use nape_kernel::values::specification::assurance_procedure::action::Action;
+    use nape_kernel::values::specification::assurance_procedure::evidence_input::EvidenceInput;
+    use nape_kernel::values::specification::assurance_procedure::parameters::Parameters;
+    use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
     use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
     use super::*;
 
-    /// Contains the mapping of evidence files to the control action test files that each evidence file should be evaluated against.
+    /// Contains the mapping of evidence files to the control action test files that each evidence file should be evaluated against, the named [`EvidenceInput`]s of each evidence and test file in addition to the evidence file, and the [`Parameters`] of the collection which are passed to each test.
     #[derive(Clone, Debug, Default)]
     pub struct EvaluationFiles {
-        pub file_map: HashMap<EvidenceFilePath, Vec<TestFilePath>>
+        pub file_map: HashMap<EvidenceFilePath, Vec<TestFilePath>>,
+        pub inputs: HashMap<(EvidenceFilePath, TestFilePath), Vec<EvidenceInput>>,
+        pub parameters: Parameters
     }
 
     impl EvaluationFiles {
@@ -38,24 +44,67 @@ pub mod request {
         /// * A new instance of the [`EvaluationFiles`] struct or an [`Error`] if the method fails.
         ///
         pub fn from(home_root: &FilePath, procedure : &AssuranceProcedure) -> Result<Self, Error> {
+            EvaluationFiles::from_tailored(home_root, procedure, &Tailoring::default())
+        }
 
-            let mut evidence_actions_tests = HashMap::new();
+        /// Create a new instance of the [`EvaluationFiles`] struct, without the actions which are excluded by the [`Tailoring`].
+        ///
+        /// # Arguments
+        ///
+        /// * `home_root` - The root path of the home directory where the reports, proceudres, and directoris such as evidence reside.
+        /// * `procedure` - The assurance procedure that contains the control actions and evidence files.
+        /// * `tailoring` - The [`Tailoring`] of the actions which do not apply to the subject, and are not evaluated.
+        ///
+        /// # Returns
+        ///
+        /// * A new instance of the [`EvaluationFiles`] struct or an [`Error`] if the method fails.
+        ///
+        pub fn from_tailored(home_root: &FilePath, procedure : &AssuranceProcedure, tailoring: &Tailoring) -> Result<Self, Error> {
 
-            for activity in &procedure.activities.list {
-                for action in &activity.actions {
-                    let test_file_path = combine_paths(home_root, &action.test)?;
-                    let evidence_file_path = combine_paths(home_root, &action.evidence)?;
+            let actions: Vec<&Action> = procedure.activities.list.iter()
+                .flat_map(|activity| activity.actions.iter())
+                .filter(|action| tailoring.for_action(&action.name).is_none())
+                .collect();
+            EvaluationFiles::from_actions(home_root, &actions)
+        }
 
-                    let action_tests = evidence_actions_tests.entry(evidence_file_path)
-                        .or_insert_with(Vec::new);
+        /// Create a new instance of the [`EvaluationFiles`] struct for only the `actions`, e.g. the actions of a single level of dependencies.  Attestation actions are not evaluated, as they have no test.
+        ///
+        /// # Arguments
+        ///
+        /// * `home_root` - The root path of the home directory where the reports, proceudres, and directoris such as evidence reside.
+        /// * `actions` - The control actions to evaluate.
+        ///
+        /// # Returns
+        ///
+        /// * A new instance of the [`EvaluationFiles`] struct or an [`Error`] if the method fails.
+        ///
+        pub fn from_actions(home_root: &FilePath, actions: &[&Action]) -> Result<Self, Error> {
 
-                    if !action_tests.contains(&test_file_path) {
-                        action_tests.push(test_file_path);
+            let mut evidence_actions_tests = HashMap::new();
+            let mut inputs = HashMap::new();
+
+            for (action, test) in actions.iter().filter_map(|action| action.test.as_ref().map(|test| (action, test))) {
+                let test_file_path = combine_paths(home_root, test)?;
+                let evidence_file_path = combine_paths(home_root, &action.evidence)?;
+
+                if !action.inputs.is_empty() {
+                    let mut action_inputs = Vec::new();
+                    for input in &action.inputs {
+                        action_inputs.push(EvidenceInput { evidence: combine_paths(home_root, &input.evidence)?, ..input.clone() });
                     }
+                    inputs.insert((evidence_file_path.clone(), test_file_path.clone()), action_inputs);
+                }
+
+                let action_tests = evidence_actions_tests.entry(evidence_file_path)
+                    .or_insert_with(Vec::new);
+
+                if !action_tests.contains(&test_file_path) {
+                    action_tests.push(test_file_path);
                 }
             }
 
-            Ok(EvaluationFiles { file_map: evidence_actions_tests })
+            Ok(EvaluationFiles { file_map: evidence_actions_tests, inputs, parameters: Parameters::default() })
         }
 
         // TODO - test the add method
@@ -64,7 +113,19 @@ pub mod request {
             new_file_map.entry(evidence_file.clone())
                 .or_insert_with(Vec::new)
                 .push(test_file.clone());
-            EvaluationFiles { file_map: new_file_map }
+            EvaluationFiles { file_map: new_file_map, ..self.clone() }
+        }
+
+        /// Returns a new instance of the [`EvaluationFiles`] with the [`Parameters`] of the collection which are passed to each test.
+        pub fn with_parameters(&self, parameters: &Parameters) -> Self {
+            EvaluationFiles { parameters: parameters.clone(), ..self.clone() }
+        }
+
+        /// Returns the named [`EvidenceInput`]s, with their canonical paths, which are passed to the test file along with the evidence file.
+        pub fn inputs_for(&self, evidence_file: &EvidenceFilePath, test_file: &TestFilePath) -> &[EvidenceInput] {
+            self.inputs.get(&(evidence_file.clone(), test_file.clone()))
+                .map(|inputs| inputs.as_slice())
+                .unwrap_or(&[])
         }
 
         // TODO - find all instances of the .file_mape and replace with list()
@@ -77,6 +138,8 @@ pub mod request {
 pub mod response {
 
     use super::*;
+    use nape_kernel::values::specification::assurance_report::finding::Finding;
+    use nape_kernel::values::specification::assurance_report::observations::Observations;
     use nape_kernel::values::specification::description::Description;
     use nape_kernel::values::specification::outcome::Outcome;
 
@@ -96,6 +159,17 @@ pub mod response {
             EvaluationResults {  results: new_results }
         }
 
+        /// Returns a new instance of the [`EvaluationResults`] with the results of `other` added, e.g. the results of the next level of dependencies.
+        pub fn merge(&self, other: &EvaluationResults) -> Self {
+            let mut merged = self.clone();
+            for (evidence_file, test_results) in &other.results {
+                for (test_file, test_result) in test_results {
+                    merged = merged.add_result(evidence_file, test_file, test_result.clone());
+                }
+            }
+            merged
+        }
+
         pub fn result_for(&self, evidence_file: &EvidenceFilePath, test_file: &TestFilePath ) -> Option<TestResult> {
             self.results
                 .get(evidence_file)
@@ -105,24 +179,50 @@ pub mod response {
 
     }
 
-    /// Contains the results of the evaluation of an evidence file against a single control action.
+    /// Contains the results of the evaluation of an evidence file against a single control action.  The `findings` and `observations` are only reported by tests which use version 2 of the evaluator protocol.
     #[derive(Clone, Debug, Eq, Hash, PartialEq)]
     pub struct TestResult {
         pub outcome: Outcome,
         pub reason: Description,
+        pub findings: Vec<Finding>,
+        pub observations: Observations,
     }
 
     impl TestResult {
 
+        /// Creates a new [`TestResult`] from the outcome and reason reported by a control action test.  The outcome must be one a test can report, as the other outcomes are only assigned during the evaluation, e.g. [`Outcome::WAIVED`] when an unexpired waiver is applied.
         pub fn try_from(outcome: &str, reason: &str,) -> Result<Self, Error> {
-            let valid_outcome = Outcome::try_from(outcome)?;
+            let valid_outcome = Outcome::try_from_test(outcome)?;
             let valid_reason = Description::try_from(reason)?;
             Ok(TestResult {
                 outcome: valid_outcome,
-                reason: valid_reason
+                reason: valid_reason,
+                findings: Vec::new(),
+                observations: Observations::default()
             })
         }
 
+        /// Creates a new [`TestResult`] from an existing outcome and reason, without any findings or observations.
+        pub fn new(outcome: &Outcome, reason: &Description) -> Self {
+            TestResult {
+                outcome: outcome.clone(),
+                reason: reason.clone(),
+                findings: Vec::new(),
+                observations: Observations::default()
+            }
+        }
+
+        /// Adds a [`Finding`] the test reported, and returns a new instance of the [`TestResult`].
+        pub fn with_finding(mut self, finding: &Finding) -> Self {
+            self.findings.push(finding.clone());
+            self
+        }
+
+        /// Records the [`Observations`] the test reported, and returns a new instance of the [`TestResult`].
+        pub fn with_observations(self, observations: &Observations) -> Self {
+            TestResult { observations: observations.clone(), ..self }
+        }
+
         pub fn reason(&self) -> String {
             self.reason.value.clone()
         }
@@ -6,6 +6,8 @@ use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::r
 
 mod test_result_tests {
     use super::*;
+    use nape_kernel::values::specification::assurance_report::finding::Finding;
+    use nape_kernel::values::specification::assurance_report::observations::Observations;
     use nape_kernel::values::specification::outcome::Outcome;
     /* Happy Path */
 
@@ -38,6 +40,20 @@ mod test_result_tests {
         assert_eq!(test_result.reason(), "The error reason.");
     }
 
+    #[test]
+    fn with_findings_and_observations_success() {
+        let finding = Finding::try_new("The change has only one reviewer.", "high").unwrap();
+        let mut observations = Observations::default();
+        observations.add("reviewers", "1").unwrap();
+
+        let test_result = TestResult::try_from("fail", "The fail reason.").unwrap()
+            .with_finding(&finding)
+            .with_observations(&observations);
+
+        assert_eq!(test_result.findings, vec![finding]);
+        assert_eq!(test_result.observations.get("reviewers"), Some("1".to_string()));
+    }
+
     /* Error Path */
 
     #[test]
@@ -46,6 +62,24 @@ mod test_result_tests {
         assert!(test_result.is_err());
     }
 
+    #[test]
+    fn try_from_waived_outcome_error() {
+        let test_result = TestResult::try_from("waived", "The reason.");
+        assert!(test_result.is_err());
+    }
+
+    #[test]
+    fn try_from_not_applicable_outcome_error() {
+        let test_result = TestResult::try_from("not_applicable", "The reason.");
+        assert!(test_result.is_err());
+    }
+
+    #[test]
+    fn try_from_skipped_outcome_error() {
+        let test_result = TestResult::try_from("skipped", "The reason.");
+        assert!(test_result.is_err());
+    }
+
     #[test]
     fn try_from_invalid_outcome_error() {
         let test_result = TestResult::try_from("invalid", "The reason.");
@@ -65,7 +99,12 @@ mod request  {
     use std::collections::HashMap;
     use nape_kernel::values::specification::assurance_procedure::action::Action;
     use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+    use nape_kernel::values::specification::assurance_procedure::parameters::Parameters;
+    use nape_kernel::values::specification::assurance_report::exclusion::Exclusion;
+    use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
     use nape_kernel::values::specification::file_path::FilePath;
+    use nape_kernel::values::specification::metadata::MetaData;
+    use nape_kernel::values::specification::subject::Subject;
     use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
     use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::request::EvaluationFiles;
 
@@ -343,6 +382,70 @@ mod request  {
         assert_eq!(*evidence_file_2_3, expect_file_2_3);
     }
 
+    /// Testing that the actions excluded by the tailoring are not evaluated.
+    ///
+    #[test]
+    fn from_tailored_procedure_excludes_actions_success() {
+
+        let activity = Activity::new("activity-1", "short description", "long description", ).unwrap();
+        let action1 = Action::builder().name("action-1").short_description("short description").long_description("long description").test_file_path("activity/test_dir/test_file_1").evidence_file_path("evidence/test_dir/evidence_file_1").try_build().unwrap();
+        let action2 = Action::builder().name("action-2").short_description("short description").long_description("long description").test_file_path("activity/test_dir/test_file_2").evidence_file_path("evidence/test_dir/evidence_file_2").try_build().unwrap();
+        let activity = activity.add(action1).add(action2);
+
+        let procedure = AssuranceProcedure::builder()
+            .api_version("1.0.0")
+            .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+            .add_activity(&activity)
+            .try_build().unwrap();
+
+        let tailoring = Tailoring::default().try_add(Exclusion::try_new("action-2", "Not applicable.").unwrap()).unwrap();
+
+        let home_root = FilePath::from("/Users/someone/procedure/home");
+        let evidence_action_files = EvaluationFiles::from_tailored(&home_root, &procedure, &tailoring).unwrap();
+
+        let mut expected_tests = HashMap::new();
+        expected_tests.insert(
+            EvidenceFilePath::from("/Users/someone/procedure/home/evidence/test_dir/evidence_file_1"),
+            vec![TestFilePath::from("/Users/someone/procedure/home/activity/test_dir/test_file_1")],
+        );
+
+        assert_eq!(expected_tests, evidence_action_files.file_map)
+    }
+
+    #[test]
+    fn from_actions_success() {
+
+        let action1 = Action::builder().name("action-1").short_description("short description").long_description("long description").test_file_path("activity/test_dir/test_file_1").evidence_file_path("evidence/test_dir/evidence_file_1").try_build().unwrap();
+        let action2 = Action::builder().name("action-2").short_description("short description").long_description("long description").test_file_path("activity/test_dir/test_file_2").evidence_file_path("evidence/test_dir/evidence_file_1").try_build().unwrap();
+
+        let home_root = FilePath::from("/Users/someone/procedure/home");
+        let evidence_action_files = EvaluationFiles::from_actions(&home_root, &[&action1, &action2]).unwrap();
+
+        let mut expected_tests = HashMap::new();
+        expected_tests.insert(
+            EvidenceFilePath::from("/Users/someone/procedure/home/evidence/test_dir/evidence_file_1"),
+            vec![TestFilePath::from("/Users/someone/procedure/home/activity/test_dir/test_file_1"), TestFilePath::from("/Users/someone/procedure/home/activity/test_dir/test_file_2")],
+        );
+
+        assert_eq!(expected_tests, evidence_action_files.file_map)
+    }
+
+    #[test]
+    fn from_actions_with_inputs_success() {
+
+        let action1 = Action::builder().name("action-1").short_description("short description").long_description("long description").test_file_path("activity/test_dir/test_file_1").evidence_file_path("evidence/test_dir/config.yaml").add_input("runtime", "evidence/test_dir/runtime.json").try_build().unwrap();
+        let action2 = Action::builder().name("action-2").short_description("short description").long_description("long description").test_file_path("activity/test_dir/test_file_2").evidence_file_path("evidence/test_dir/evidence_file_2").try_build().unwrap();
+
+        let home_root = FilePath::from("/Users/someone/procedure/home");
+        let evaluation_files = EvaluationFiles::from_actions(&home_root, &[&action1, &action2]).unwrap();
+
+        let inputs = evaluation_files.inputs_for(&EvidenceFilePath::from("/Users/someone/procedure/home/evidence/test_dir/config.yaml"), &TestFilePath::from("/Users/someone/procedure/home/activity/test_dir/test_file_1"));
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].name.value, "runtime");
+        assert_eq!(inputs[0].evidence.as_str(), "/Users/someone/procedure/home/evidence/test_dir/runtime.json");
+        assert!(evaluation_files.inputs_for(&EvidenceFilePath::from("/Users/someone/procedure/home/evidence/test_dir/evidence_file_2"), &TestFilePath::from("/Users/someone/procedure/home/activity/test_dir/test_file_2")).is_empty());
+    }
+
     #[test]
     fn add_success() {
 
@@ -369,6 +472,20 @@ mod request  {
 
     }
 
+    #[test]
+    fn with_parameters_success() {
+        let mut metadata = MetaData::default();
+        metadata.add("environment", "staging").unwrap();
+        let parameters = Parameters::from(&metadata, &Subject::try_new("nrn:sourcecode:example", "1234").unwrap());
+
+        let evaluation_files = EvaluationFiles::default()
+            .add(&EvidenceFilePath::from("evidence_file.yaml"), &TestFilePath::from("test_file.yaml"))
+            .with_parameters(&parameters);
+
+        assert_eq!(evaluation_files.parameters, parameters);
+        assert_eq!(evaluation_files.file_map.len(), 1);
+    }
+
 }
 
 mod response {
@@ -428,6 +545,21 @@ mod response {
         assert_eq!(retrieve_result4.reason(), "the error reason");
     }
 
+    #[test]
+    fn merge_success() {
+        let evidence_file = FilePath::from("evidence.txt");
+        let test_file1 = FilePath::from("test1.txt");
+        let test_file2 = FilePath::from("test2.txt");
+
+        let first = EvaluationResults::default().add_result(&evidence_file, &test_file1, TestResult::try_from("pass", "the pass reason").unwrap());
+        let second = EvaluationResults::default().add_result(&evidence_file, &test_file2, TestResult::try_from("fail", "the fail reason").unwrap());
+
+        let merged = first.merge(&second);
+
+        assert_eq!(merged.result_for(&evidence_file, &test_file1).unwrap().outcome, Outcome::PASS);
+        assert_eq!(merged.result_for(&evidence_file, &test_file2).unwrap().outcome, Outcome::FAIL);
+    }
+
     /* Error Path */
 
     #[test]
@@ -1,13 +1,30 @@
+use std::collections::HashMap;
 use nape_kernel::algorithms::signature_algorithm::{SignatureAlgorithm};
 use nape_kernel::error::{Error, Kind};
-use nape_kernel::gateways::directory_list::RetrieveDirectoryPath;
 use nape_kernel::gateways::file_data_gateway::FileDataGateway;
+use nape_kernel::gateways::file_selection::{SelectedFiles, SelectFilesGateway};
 use nape_kernel::values::specification::{assurance_report};
+use nape_kernel::values::specification::description::Description;
 use nape_kernel::values::specification::file_path::FilePath;
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::parameters::Parameters;
+use nape_kernel::values::specification::assurance_report::activities::Activities;
+use nape_kernel::values::specification::assurance_report::attestation::Attestation;
+use nape_kernel::values::specification::assurance_report::provenance::Provenance;
 use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use nape_kernel::values::specification::assurance_report::signed_input::SignedInput;
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+use nape_kernel::values::specification::assurance_report::waivers::Waivers;
+use nape_kernel::values::specification::outcome::Outcome;
+use nape_kernel::values::specification::metadata::MetaData;
+use nape_kernel::values::specification::name::Name;
+use nape_kernel::values::time::date::Date;
+use nape_kernel::values::time::start_time::StartTime;
 use nape_kernel::values::specification::v1_0_0::assurance_report::AssuranceReportV1;
 use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
-use crate::evidence_collection::usecases::evaluate_evidence::gateway::{EvaluateEvidenceGateway, PersistReportGateway, RetrieveAssuranceProcedure};
+use crate::evidence_collection::usecases::attest_action::gateway::RetrieveAttestationGateway;
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::CustodyEntry;
+use crate::evidence_collection::usecases::evaluate_evidence::gateway::{CollectionGateways, EvaluateEvidenceGateway, EvaluationGateways, ReportGateways};
 use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::combine_paths;
 use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::request::EvaluationFiles;
 use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::response::{EvaluationResults, TestResult};
@@ -35,12 +52,9 @@ pub type EvaluateAndReportEvidenceUC = fn(request: &EvaluateEvidence) -> Result<
 /// # Arguments
 ///
 /// * `&request` - A reference to an [`EvidenceEvaluation`] request containing all the necessary evidence and control action data for the usecase to evaluate.
-/// * `retrieve_path` - An implementation of the [`RetrieveDirectoryPath`] gateway.
-/// * `retrieve_definition` - An implementation of the [`RetrieveAssuranceProcedure`] gateway.
-/// * `evaluate_evidence` -An implementation of the [`EvaluateEvidenceGateway`] gateway.
-/// * `signature_algorithm` - An implementation of the [`SignatureAlgorithm`] gateway.
-/// * `file_data_gateway` - An implementation of the [`FileDataGateway`] gateway.
-/// * `persist_report` - An implementation of the [`PersistReportGateway`] gateway.
+/// * `collection` - The [`CollectionGateways`] used to read the procedure, waivers, tailoring, and custody ledger of the collection.
+/// * `evaluation` - The [`EvaluationGateways`] used to evaluate the actions, read the attestations, and retrieve the provenance.
+/// * `report` - The [`ReportGateways`] used to sign the files recorded in the report, and to persist it.
 ///
 /// # Returns
 ///
@@ -50,46 +64,68 @@ pub type EvaluateAndReportEvidenceUC = fn(request: &EvaluateEvidence) -> Result<
 ///
 pub fn evaluate_and_report(
     request: &EvaluateEvidence,
-    retrieve_path: RetrieveDirectoryPath,
-    retrieve_definition: RetrieveAssuranceProcedure,
-    evaluate_evidence: EvaluateEvidenceGateway,
-    signature_algorithm: SignatureAlgorithm,
-    file_data_gateway: FileDataGateway,
-    persist_report: PersistReportGateway) -> Result<FilePath, Error> {
+    collection: CollectionGateways,
+    evaluation: EvaluationGateways,
+    report: ReportGateways) -> Result<FilePath, Error> {
+
+    let retrieve_collection_path = |directory_key: &str| match request.home_directory() {
+        Some(home_directory) => (collection.resolve_path)(home_directory.as_str(), directory_key),
+        None => (collection.retrieve_path)(directory_key)
+    };
 
-    let definition_path = retrieve_path("assurance-procedure-file")
+    let definition_path = retrieve_collection_path("assurance-procedure-file")
         .map_err(|error| Error::for_system(Kind::GatewayError,
                                            format!("Failed to retrieve the 'assurance-procedure-file' path. {}", error.message)))?;
 
-    let home_dir = retrieve_path("home")
+    let home_dir = retrieve_collection_path("home")
         .map_err(|error| Error::for_system(Kind::GatewayError,
                                            format!("Failed to retrieve the 'home' directory path. {}", error.message)))?;
 
-    let procedure = retrieve_definition(&definition_path)
+    let procedure = (collection.retrieve_definition)(&definition_path)
         .map_err(|error| Error::for_system(Kind::GatewayError,
                                            format!("Failed to retrieve procedure definition. {}", error.message)))?;
 
+    let waivers = match request.waiver_file() {
+        Some(waiver_file) => (collection.retrieve_waivers)(waiver_file.as_str())
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("Failed to retrieve the waivers. {}", error.message)))?,
+        None => Waivers::default()
+    };
+
+    let tailoring = (collection.retrieve_tailoring)(&home_dir)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("Failed to retrieve the tailoring. {}", error.message)))?;
+
     let home_root = FilePath::from(&home_dir);
-    let evaluation_files = EvaluationFiles::from(&home_root, &procedure)
+    let parameters = Parameters::from(request.metadata(), request.subject());
+    let (evaluation_results, attestations) = evaluate_in_dependency_order(&home_root, &procedure, &tailoring, &parameters, evaluation.evaluate_evidence, evaluation.retrieve_attestation)?;
+
+    let provenance = (evaluation.retrieve_provenance)()
         .map_err(|error| Error::for_system(Kind::GatewayError,
-                                           format!("Failed to create evaluation files. {}", error.message)))?;
+                                           format!("Failed to retrieve the provenance. {}", error.message)))?;
 
-    let evaluation_results = evaluate_evidence(&evaluation_files)
+    let custody_ledger = (collection.retrieve_custody_ledger)(&home_dir)
         .map_err(|error| Error::for_system(Kind::GatewayError,
-                                           format!("Failed to evaluate evidence files. {}", error.message)))?;
+                                           format!("Failed to retrieve the custody ledger. {}", error.message)))?;
 
-    let report = AssuranceReportBuilder::new()
+    let assurance_report = AssuranceReportBuilder::new()
         .with_home_dir(&home_root)
         .with_results(&evaluation_results)
+        .with_attestations(&attestations)
         .with_definition(&procedure)
         .with_request(&request)
-        .with_signature_algorithm(signature_algorithm)
-        .with_file_data_gateway(file_data_gateway)
+        .with_waivers(&waivers)
+        .with_tailoring(&tailoring)
+        .with_provenance(&provenance)
+        .with_custody_ledger(&custody_ledger)
+        .with_signature_algorithm(report.signature_algorithm)
+        .with_file_data_gateway(report.file_data_gateway)
+        .with_select_files_gateway(report.select_files)
         .try_build()
         .map_err(|error| Error::for_system(Kind::ProcessingFailure,
                                            format!("Failed to generate assurance report. {}", error.message)))?;
 
-    let report_path = persist_report(&report, &home_dir).map_err(|error|
+    let report_path = (report.persist_report)(&assurance_report, &home_dir).map_err(|error|
         Error::for_system(Kind::GatewayError,
                           format!("Failed to persist the assurance report document. {}", error.message)))?;
 
@@ -97,12 +133,99 @@ pub fn evaluate_and_report(
 
 }
 
+/// Evaluates the actions which are not excluded by the [`Tailoring`] one level of dependencies at a time, so an action is only evaluated once the actions it depends on have been.  An action with a prerequisite which did not pass is not evaluated, and is given the [`Outcome::SKIPPED`] outcome.
+///
+/// An attestation action is not evaluated.  Its result is the outcome and rationale of its [`Attestation`], which are returned along with the results.
+fn evaluate_in_dependency_order(home_root: &FilePath, procedure: &AssuranceProcedure, tailoring: &Tailoring, parameters: &Parameters, evaluate_evidence: EvaluateEvidenceGateway, retrieve_attestation: RetrieveAttestationGateway) -> Result<(EvaluationResults, Vec<Attestation>), Error> {
+
+    let levels = procedure.activities.dependency_levels()
+        .map_err(|error| Error::for_system(Kind::ProcessingFailure,
+                                           format!("Failed to order the actions by their dependencies. {}", error.message)))?;
+
+    let mut evaluation_results = EvaluationResults::default();
+    let mut attestations = Vec::new();
+    let mut outcomes: HashMap<&Name, Outcome> = HashMap::new();
+
+    for level in levels {
+        let mut runnable: Vec<&Action> = Vec::new();
+        for action in level {
+            if let Some(exclusion) = tailoring.for_action(&action.name) {
+                let not_applicable = TestResult::new(&Outcome::NOT_APPLICABLE, &exclusion.justification);
+                evaluation_results = evaluation_results.add_result(&combine_paths(home_root, &action.evidence)?, &combine_paths(home_root, result_test_file(action))?, not_applicable);
+                outcomes.insert(&action.name, Outcome::NOT_APPLICABLE);
+                continue;
+            }
+            match action.depends_on.iter().find(|dependency| outcomes.get(dependency) != Some(&Outcome::PASS)) {
+                Some(prerequisite) => {
+                    let skipped = TestResult::new(&Outcome::SKIPPED, &Description { value: format!("The action '{}' depends on '{}', which did not pass.", action.name.value, prerequisite.value) });
+                    evaluation_results = evaluation_results.add_result(&combine_paths(home_root, &action.evidence)?, &combine_paths(home_root, result_test_file(action))?, skipped);
+                    outcomes.insert(&action.name, Outcome::SKIPPED);
+                },
+                None if action.is_attestation() => {
+                    let attestation = try_retrieve_attestation(home_root, action, retrieve_attestation)?;
+                    let attested = TestResult::new(&attestation.outcome, &attestation.rationale);
+                    let statement_path = combine_paths(home_root, &action.evidence)?;
+                    evaluation_results = evaluation_results.add_result(&statement_path, &statement_path, attested);
+                    outcomes.insert(&action.name, attestation.outcome.clone());
+                    attestations.push(attestation);
+                },
+                None => runnable.push(action)
+            }
+        }
+
+        if runnable.is_empty() {
+            continue;
+        }
+
+        let evaluation_files = EvaluationFiles::from_actions(home_root, &runnable)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("Failed to create evaluation files. {}", error.message)))?
+            .with_parameters(parameters);
+
+        let level_results = evaluate_evidence(&evaluation_files)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("Failed to evaluate evidence files. {}", error.message)))?;
+
+        for action in runnable {
+            if let Ok(test_result) = try_get_test_result(&level_results, home_root, &action.evidence, result_test_file(action)) {
+                outcomes.insert(&action.name, test_result.outcome);
+            }
+        }
+        evaluation_results = evaluation_results.merge(&level_results);
+    }
+
+    Ok((evaluation_results, attestations))
+}
+
+/// Retrieves the [`Attestation`] from the statement of an attestation action, which must have been attested.
+fn try_retrieve_attestation(home_root: &FilePath, action: &Action, retrieve_attestation: RetrieveAttestationGateway) -> Result<Attestation, Error> {
+    let statement_path = combine_paths(home_root, &action.evidence)?;
+    retrieve_attestation(statement_path.as_str())
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("Failed to retrieve the attestation of the action '{}'. {}", action.name.value, error.message)))?
+        .ok_or_else(|| Error::for_user(Kind::InvalidInput,
+                                       format!("The action '{}' is an attestation action which has not been attested. Please attest the action before the report is generated.", action.name.value)))
+}
+
+/// The file an action's result is recorded against along with its evidence, which is the test, or for an attestation action, the statement.
+fn result_test_file(action: &Action) -> &FilePath {
+    action.test.as_ref().unwrap_or(&action.evidence)
+}
+
 pub struct AssuranceReportBuilder<'a> {
     home_dir: Option<FilePath>,
     request: Option<&'a EvaluateEvidence>,
     procedure_definition: Option<&'a AssuranceProcedure>,
     evaluation_results: Option<&'a EvaluationResults>,
+    attestations: Option<&'a [Attestation]>,
+    waivers: Option<&'a Waivers>,
+    tailoring: Option<&'a Tailoring>,
+    evaluation_date: Option<Date>,
+    provenance: Option<&'a Provenance>,
+    custody_ledger: Option<&'a [CustodyEntry]>,
+    generation_time: Option<StartTime>,
     file_data_gateway: Option<FileDataGateway>,
+    select_files: Option<SelectFilesGateway>,
     signature_algorithm: Option<SignatureAlgorithm>,
 }
 
@@ -113,7 +236,15 @@ impl<'a> AssuranceReportBuilder<'a> {
             request: None,
             procedure_definition: None,
             evaluation_results: None,
+            attestations: None,
+            waivers: None,
+            tailoring: None,
+            evaluation_date: None,
+            provenance: None,
+            custody_ledger: None,
+            generation_time: None,
             file_data_gateway: None,
+            select_files: None,
             signature_algorithm: None,
         }
     }
@@ -138,6 +269,48 @@ impl<'a> AssuranceReportBuilder<'a> {
         self
     }
 
+    /// The [`Attestation`]s of the attestation actions, which are recorded in the report instead of a test file signature.  When not provided, the procedure must not have any attestation actions which apply.
+    pub fn with_attestations(&mut self, attestations: &'a [Attestation]) -> &mut Self {
+        self.attestations = Some(attestations);
+        self
+    }
+
+    /// The [`Waivers`] applied to failed actions.  When not provided, no waivers are applied.
+    pub fn with_waivers(&mut self, waivers: &'a Waivers) -> &mut Self {
+        self.waivers = Some(waivers);
+        self
+    }
+
+    /// The [`Tailoring`] of the actions which do not apply to the subject.  Excluded actions are reported with the [`Outcome::NOT_APPLICABLE`] outcome and their justification.  When not provided, no actions are excluded.
+    pub fn with_tailoring(&mut self, tailoring: &'a Tailoring) -> &mut Self {
+        self.tailoring = Some(tailoring);
+        self
+    }
+
+    /// The [`Date`] used to determine whether a waiver has expired.  When not provided, today's date is used.
+    pub fn with_evaluation_date(&mut self, evaluation_date: &Date) -> &mut Self {
+        self.evaluation_date = Some(evaluation_date.clone());
+        self
+    }
+
+    /// The [`Provenance`] of the environment the report is generated in.  When provided, the provenance, collection start time, and generation time are recorded in the report's metadata and additional information.
+    pub fn with_provenance(&mut self, provenance: &'a Provenance) -> &mut Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// The entries of the custody ledger.  When provided, the signature of each evidence file is compared to its signature when it was collected, and evidence which was changed after it was collected is recorded in the report's additional information.
+    pub fn with_custody_ledger(&mut self, custody_ledger: &'a [CustodyEntry]) -> &mut Self {
+        self.custody_ledger = Some(custody_ledger);
+        self
+    }
+
+    /// The time the report is generated.  When not provided, the current time is used.
+    pub fn with_generation_time(&mut self, generation_time: &StartTime) -> &mut Self {
+        self.generation_time = Some(generation_time.clone());
+        self
+    }
+
     pub fn with_signature_algorithm(&mut self, signature_algorithm: SignatureAlgorithm) -> &mut Self {
         self.signature_algorithm = Some(signature_algorithm);
         self
@@ -148,6 +321,12 @@ impl<'a> AssuranceReportBuilder<'a> {
         self
     }
 
+    /// The [`SelectFilesGateway`] used to find the files within evidence which is a directory, so each file can be signed individually.  When not provided, all evidence is signed as a single file.
+    pub fn with_select_files_gateway(&mut self, select_files: SelectFilesGateway) -> &mut Self {
+        self.select_files = Some(select_files);
+        self
+    }
+
     pub fn try_build(&self) -> Result<AssuranceReportV1, Error> {
 
         let definition = self.validate_definition()?;
@@ -159,14 +338,52 @@ impl<'a> AssuranceReportBuilder<'a> {
             .ok_or_else(|| Error::for_system(Kind::InvalidInput, String::from("A File Data Gateway was not provided.")))?;
         let home = self.home_dir.as_ref()
             .ok_or_else(|| Error::for_system(Kind::InvalidInput, String::from("A Home Directory was not provided.")))?;
-        let activities = try_create_report_activities(home, &definition, &results, file_data_gw, signer)?;
+        let no_waivers = Waivers::default();
+        let waivers = self.waivers.unwrap_or(&no_waivers);
+        let no_tailoring = Tailoring::default();
+        let tailoring = self.tailoring.unwrap_or(&no_tailoring);
+        let evaluation_date = self.evaluation_date.clone().unwrap_or_else(Date::today);
+        let attestations = self.attestations.unwrap_or(&[]);
+        let report_signer = ReportSigner { home_root: home, file_data_gateway: file_data_gw, select_files: self.select_files, signature_algorithm: signer };
+        let activities = try_create_report_activities(&definition, &results, attestations, waivers, tailoring, &evaluation_date, &report_signer)?;
+
+        let mut metadata = request.metadata().clone();
+        let mut provenance_information = Vec::new();
+        if let Some(provenance) = self.provenance {
+            let generation_time = self.generation_time.clone().unwrap_or_else(StartTime::now);
+            provenance_information = record_provenance(&mut metadata, provenance, &generation_time)?;
+        }
 
-        let final_report = AssuranceReportV1::builder()
-            .use_metadata(&request.metadata())
+        let mut report_builder = AssuranceReportV1::builder()
+            .use_metadata(&metadata)
             .use_subject(&request.subject())
             .use_procedure(&request.procedure())
             .use_activities(&activities)
-            .try_build()?;
+            .use_outcome_policy(request.outcome_policy());
+
+        for information in provenance_information.iter() {
+            report_builder = report_builder.additional_information(information);
+        }
+
+        for waiver in waivers.list().iter().filter(|waiver| waiver.is_expired(&evaluation_date)) {
+            report_builder = report_builder.additional_information(
+                &format!("The waiver for the action '{}', approved by {}, expired on {} and was not applied.",
+                         waiver.action.value, waiver.approver.value, waiver.expires));
+        }
+
+        for waiver in waivers.list().iter().filter(|waiver| definition.activities.find_action(&waiver.action.value).is_none()) {
+            report_builder = report_builder.additional_information(
+                &format!("The waiver for the action '{}', approved by {}, does not match an action in the procedure and was not applied.",
+                         waiver.action.value, waiver.approver.value));
+        }
+
+        if let Some(custody_ledger) = self.custody_ledger {
+            for information in check_custody(&activities, custody_ledger).iter() {
+                report_builder = report_builder.additional_information(information);
+            }
+        }
+
+        let final_report = report_builder.try_build()?;
 
         Ok(final_report)
     }
@@ -190,32 +407,167 @@ impl<'a> AssuranceReportBuilder<'a> {
 
 
 
+/// Records the [`Provenance`] and generation time in the metadata, and returns the statements of provenance for the report's additional information.
+fn record_provenance(metadata: &mut MetaData, provenance: &Provenance, generation_time: &StartTime) -> Result<Vec<String>, Error> {
+
+    metadata.upsert("utc-generated", &generation_time.to_string())?;
+    metadata.upsert("nape-version", &provenance.nape_version.value)?;
+    metadata.upsert("evaluator-version", &provenance.evaluator_version.value)?;
+    metadata.upsert("os", &provenance.os.value)?;
+    metadata.upsert("arch", &provenance.arch.value)?;
+    metadata.upsert("hostname", &provenance.hostname.value)?;
+    metadata.upsert("user", &provenance.user.value)?;
+
+    let mut information = Vec::new();
+    if let Some(start_time) = metadata.get("utc-start").and_then(|time| time.parse::<u128>().ok()) {
+        information.push(format!("The evidence collection started at {}.", StartTime::from(start_time).to_utc_string()));
+    }
+    information.push(format!("The report was generated at {} by nape {} using the evaluator '{}' on the host '{}' ({}/{}) by the user '{}'.",
+                             generation_time.to_utc_string(),
+                             provenance.nape_version.value,
+                             provenance.evaluator_version.value,
+                             provenance.hostname.value,
+                             provenance.os.value,
+                             provenance.arch.value,
+                             provenance.user.value));
+    Ok(information)
+}
+
+/// Compares the signature of each evidence file, including the files of the evidence inputs and the supporting files of an attestation, to the signature in its most recent [`CustodyEntry`], and returns a statement for each evidence file which was changed after it was collected.  When the ledger is in use, evidence files which were not collected are also reported.
+fn check_custody(activities: &Activities, custody_ledger: &[CustodyEntry]) -> Vec<String> {
+
+    let mut information = Vec::new();
+    if custody_ledger.is_empty() {
+        return information;
+    }
+
+    for action in activities.list().iter().flat_map(|activity| activity.actions.iter()) {
+        let mut evidence_files: Vec<SignedFile> = match action.evidence_files().is_empty() {
+            true => action.evidence_file().into_iter().cloned().collect(),
+            false => action.evidence_files().clone()
+        };
+        for input in action.inputs() {
+            match input.evidence_files.is_empty() {
+                true => evidence_files.push(input.evidence_file.clone()),
+                false => evidence_files.extend(input.evidence_files.iter().cloned())
+            }
+        }
+        if let Some(attestation) = action.attestation() {
+            evidence_files.extend(attestation.supporting_files.iter().cloned());
+        }
+        for evidence_file in evidence_files.iter() {
+            let file = evidence_file.file().as_str();
+            match custody_ledger.iter().rev().find(|entry| entry.target == file) {
+                Some(entry) if entry.signature.structure_signature() != evidence_file.signature().structure_signature() =>
+                    information.push(format!("The evidence '{}' for the action '{}' was changed after it was collected from '{}' by '{}' at {}. Its signature was {} when it was collected and is {} now.",
+                                             file, action.name().value, entry.source, entry.user, entry.collected.to_utc_string(),
+                                             entry.signature.structure_signature(), evidence_file.signature().structure_signature())),
+                Some(_) => {},
+                None => information.push(format!("The evidence '{}' for the action '{}' has no entry in the custody ledger.", file, action.name().value))
+            }
+        }
+    }
+    information
+}
+
+/// The [`ReportSigner`] groups the home directory of the collection with the gateways used to sign the files it contains.
+struct ReportSigner<'a> {
+    home_root: &'a FilePath,
+    file_data_gateway: FileDataGateway,
+    select_files: Option<SelectFilesGateway>,
+    signature_algorithm: SignatureAlgorithm,
+}
+
 fn try_create_report_activities(
-    home: &FilePath,
     definition: &AssuranceProcedure,
     results: &EvaluationResults,
-    file_data_gateway: FileDataGateway,
-    signature_algorithm: SignatureAlgorithm) -> Result< assurance_report::activities::Activities, Error> {
+    attestations: &[Attestation],
+    waivers: &Waivers,
+    tailoring: &Tailoring,
+    evaluation_date: &Date,
+    signer: &ReportSigner) -> Result< assurance_report::activities::Activities, Error> {
 
+    let home = signer.home_root;
     let mut builder = assurance_report::activities::Activities::builder();
 
     for definition_activity in &definition.activities.list {
+        if let Some(source) = &definition_activity.source {
+            builder.use_source(&definition_activity.name.value, source);
+        }
         // TODO - Move home the combine_paths onto the try_get_test_result, and try_create_signed_file functions so you can combine to retrive the file data, but record in the assurance report as the non-canonical path
         for definition_action in &definition_activity.actions {
-            let test_result = try_get_test_result(results, home, &definition_action.evidence, &definition_action.test)?;
-            let signed_evidence = try_create_signed_file(home, &definition_action.evidence, file_data_gateway, signature_algorithm)?;
-            let signed_test = try_create_signed_file(home, &definition_action.test, file_data_gateway, signature_algorithm)?;
+            let test_result = try_get_test_result(results, home, &definition_action.evidence, result_test_file(definition_action))?;
+            if test_result.outcome == Outcome::NOT_APPLICABLE {
+                let exclusion = tailoring.for_action(&definition_action.name)
+                    .ok_or_else(|| Error::for_system(Kind::InvalidInput, format!("The action '{}' is not applicable, although it is not excluded by the tailoring.", definition_action.name.value)))?;
+                let signed_test = try_sign_test(definition_action, signer)?;
+                let mut action_builder = assurance_report::action::Action::builder()
+                    .use_name(&definition_action.name)
+                    .use_outcome(&Outcome::NOT_APPLICABLE)
+                    .use_reason(&exclusion.justification)
+                    .use_exclusion(exclusion);
+                if let Some(signed_test) = &signed_test {
+                    action_builder = action_builder.use_test_file_signature(signed_test);
+                }
+                if let Some(severity) = &definition_action.severity {
+                    action_builder = action_builder.use_severity(severity);
+                }
+                builder.add_action(&definition_activity.name.value, &action_builder.try_build()?);
+                continue;
+            }
+            if test_result.outcome == Outcome::SKIPPED {
+                let signed_test = try_sign_test(definition_action, signer)?;
+                let mut action_builder = assurance_report::action::Action::builder()
+                    .use_name(&definition_action.name)
+                    .use_outcome(&Outcome::SKIPPED)
+                    .use_reason(&test_result.reason);
+                if let Some(signed_test) = &signed_test {
+                    action_builder = action_builder.use_test_file_signature(signed_test);
+                }
+                if let Some(severity) = &definition_action.severity {
+                    action_builder = action_builder.use_severity(severity);
+                }
+                builder.add_action(&definition_activity.name.value, &action_builder.try_build()?);
+                continue;
+            }
+            let (signed_evidence, signed_evidence_files) = try_sign_evidence(&definition_action.evidence, signer)?;
+            let signed_test = try_sign_test(definition_action, signer)?;
             // TODO - REMOVE ONCE - Testing of Canonical paths works
             // let test_result = try_get_test_result(results, &definition_action.evidence, &definition_action.test)?;
             // let signed_evidence = try_create_signed_file(&definition_action.evidence, file_data_gateway, signature_algorithm)?;
             // let signed_test = try_create_signed_file(&definition_action.test, file_data_gateway, signature_algorithm)?;
-            let report_action = assurance_report::action::Action::builder()
+            let mut action_builder = assurance_report::action::Action::builder()
                 .use_name(&definition_action.name)
                 .use_outcome(&test_result.outcome)
                 .use_reason(&test_result.reason)
-                .use_test_file_signature(&signed_test)
                 .use_evidence_file_signature(&signed_evidence)
-                .try_build()?;
+                .use_observations(&test_result.observations);
+            if let Some(signed_test) = &signed_test {
+                action_builder = action_builder.use_test_file_signature(signed_test);
+            }
+            if definition_action.is_attestation() {
+                let attestation = try_sign_attestation(&definition_action.name, attestations, signer)?;
+                action_builder = action_builder.use_attestation(&attestation);
+            }
+            for finding in &test_result.findings {
+                action_builder = action_builder.add_finding(finding);
+            }
+            for signed_evidence_file in &signed_evidence_files {
+                action_builder = action_builder.add_evidence_file(signed_evidence_file);
+            }
+            for input in &definition_action.inputs {
+                let (signed_input, signed_input_files) = try_sign_evidence(&input.evidence, signer)?;
+                action_builder = action_builder.add_input(&SignedInput::new(&input.name, &signed_input, &signed_input_files));
+            }
+            if let Some(severity) = &definition_action.severity {
+                action_builder = action_builder.use_severity(severity);
+            }
+            if test_result.outcome == Outcome::FAIL {
+                if let Some(waiver) = waivers.for_action(&definition_action.name).filter(|waiver| !waiver.is_expired(evaluation_date)) {
+                    action_builder = action_builder.use_outcome(&Outcome::WAIVED).use_waiver(waiver);
+                }
+            }
+            let report_action = action_builder.try_build()?;
 
             builder.add_action(&definition_activity.name.value, &report_action);
         }
@@ -226,6 +578,25 @@ fn try_create_report_activities(
 
     Ok(report_activities)
 }
+/// Signs the test file of an action, or returns `None` for an attestation action, which has no test.
+fn try_sign_test(action: &Action, signer: &ReportSigner) -> Result<Option<SignedFile>, Error> {
+    action.test.as_ref()
+        .map(|test| try_create_signed_file(test, signer))
+        .transpose()
+}
+
+/// Finds the [`Attestation`] of an attestation action, and signs each of its supporting files again, so the report records the signature of the supporting files when the report was generated.
+fn try_sign_attestation(action: &Name, attestations: &[Attestation], signer: &ReportSigner) -> Result<Attestation, Error> {
+    let attestation = attestations.iter().find(|attestation| &attestation.action == action)
+        .ok_or_else(|| Error::for_system(Kind::InvalidInput, format!("No attestation found for the action '{}'.", action.value)))?;
+
+    let mut supporting_files = Vec::new();
+    for supporting_file in &attestation.supporting_files {
+        supporting_files.push(try_create_signed_file(supporting_file.file(), signer)?);
+    }
+    Ok(Attestation { supporting_files, ..attestation.clone() })
+}
+
 fn try_get_test_result(results: &EvaluationResults, home_root: &FilePath, evidence: &FilePath, test: &FilePath) -> Result<TestResult, Error> {
     let canonical_test_path = combine_paths(home_root, test)?;
     let canonical_evidence_path = combine_paths(home_root, evidence)?;
@@ -233,16 +604,59 @@ fn try_get_test_result(results: &EvaluationResults, home_root: &FilePath, eviden
         .ok_or_else(|| Error::for_system(Kind::InvalidInput, format!("No test result found for evidence: {:?} and test: {:?}", evidence, test)))
 }
 
-fn try_create_signed_file(home_root: &FilePath, file_path:  &FilePath, file_data_gateway: FileDataGateway, signature_algorithm: SignatureAlgorithm) -> Result<SignedFile, Error> {
+/// Sign the evidence for an action.  When the evidence is a directory, each file within it is signed individually, and the directory is signed with the signature of the list of each file's relative path and signature, so adding, removing, or changing any file changes the directory's signature.
+fn try_sign_evidence(evidence: &FilePath, signer: &ReportSigner) -> Result<(SignedFile, Vec<SignedFile>), Error> {
+
+    let selected_files = match signer.select_files {
+        Some(select_files) => {
+            let canonical_path = combine_paths(signer.home_root, evidence)?;
+            select_files(canonical_path.as_str())
+                .map_err(|error| Error::for_system(Kind::ProcessingFailure,
+                                                   format!("Could not select the evidence files for: {}. {}", evidence.as_str(), error)))?
+        },
+        None => SelectedFiles::File
+    };
+
+    let files = match selected_files {
+        SelectedFiles::File => return Ok((try_create_signed_file(evidence, signer)?, Vec::new())),
+        SelectedFiles::Files(files) => files
+    };
+
+    if files.is_empty() {
+        return Err(Error::for_system(Kind::ProcessingFailure,
+                                     format!("The evidence directory '{}' does not contain any files to sign.", evidence.as_str())));
+    }
+
+    let mut signed_files = Vec::new();
+    let mut manifest = String::new();
+    for file in &files {
+        let relative_file_path = FilePath::try_from(&format!("{}/{}", evidence.as_str().trim_end_matches('/'), file.relative_path))?;
+        let signed_file = try_create_signed_file(&relative_file_path, signer)?;
+        manifest.push_str(&format!("{} {}\n", file.relative_path, signed_file.signature().structure_signature()));
+        signed_files.push(signed_file);
+    }
+
+    let directory_signature = (signer.signature_algorithm)(&manifest.into_bytes())
+        .map_err(|error| Error::for_system(Kind::ProcessingFailure,
+                                           format!("Failed to sign the evidence directory: {}. {}", evidence.as_str(), error)))?;
+
+    let signed_directory = SignedFile::new(evidence.as_str(), &directory_signature)
+        .map_err(|error| Error::for_system(Kind::ProcessingFailure,
+                                           format!("Could not create a signed file for: {}. {}", evidence.as_str(), error)))?;
+
+    Ok((signed_directory, signed_files))
+}
+
+fn try_create_signed_file(file_path:  &FilePath, signer: &ReportSigner) -> Result<SignedFile, Error> {
 
-    let canonical_path = combine_paths(home_root, file_path)?;
+    let canonical_path = combine_paths(signer.home_root, file_path)?;
 
     // Use Canonical Path to get the file data
-    let file_data = file_data_gateway(&canonical_path.as_str())
+    let file_data = (signer.file_data_gateway)(&canonical_path.as_str())
         .map_err(|error| Error::for_system(Kind::ProcessingFailure,
                                          format!("Could not get file data for signing: {}. {}", file_path.as_str(), error)))?;
 
-    let signature_result = signature_algorithm(&file_data)
+    let signature_result = (signer.signature_algorithm)(&file_data)
         .map_err(|error| Error::for_system(Kind::ProcessingFailure,
                                          format!("Failed to sign the file: {}. {}", file_path.as_str(), error)))?;
 
@@ -2,6 +2,8 @@ use nape_kernel::error::{Error, Kind};
 use nape_kernel::values::specification::procedure::Procedure;
 use nape_kernel::values::specification::subject::Subject;
 use nape_kernel::values::specification::metadata::MetaData;
+use nape_kernel::values::specification::assurance_report::outcome_policy::OutcomePolicy;
+use nape_kernel::values::specification::file_path::FilePath;
 
 pub mod request {
     use super::*;
@@ -14,6 +16,9 @@ pub mod request {
 /// * `subject` - A reference to the [`Subject`] of the evidence collection.
 /// * `procedure` - A reference to the [`Procedure`] of the evidence collection.
 /// * `metadata` - A reference to the [`MetaData`] of the evidence collection.
+/// * `outcome_policy` - The [`OutcomePolicy`] used to determine the overall outcome of the report. When not provided, any failed action fails the report.
+/// * `waiver_file` - The optional [`FilePath`] to a file of waivers which are applied to failed actions.
+/// * `home_directory` - The optional [`FilePath`] to the home directory of the collection to evaluate.  When not provided, the collection recorded in the application state is evaluated.
 ///
 /// # Design Decision
 ///
@@ -25,6 +30,9 @@ pub mod request {
         subject: Subject,
         procedure: Procedure,
          metadata: MetaData,
+        outcome_policy: OutcomePolicy,
+        waiver_file: Option<FilePath>,
+        home_directory: Option<FilePath>,
     }
 
     impl EvaluateEvidence {
@@ -39,6 +47,9 @@ pub mod request {
                 procedure_dir: None,
                 procedure_repo: None,
                 metadata: Vec::new(),
+                outcome_policy: None,
+                waiver_file: None,
+                home_directory: None,
             }
         }
 
@@ -57,6 +68,21 @@ pub mod request {
             &self.metadata
         }
 
+        /// Returns a reference to the [`OutcomePolicy`] of the [`EvaluateEvidence`] struct.
+        pub fn outcome_policy(&self) -> &OutcomePolicy {
+            &self.outcome_policy
+        }
+
+        /// Returns a reference to the waiver file [`FilePath`] of the [`EvaluateEvidence`] struct, if one was provided.
+        pub fn waiver_file(&self) -> Option<&FilePath> {
+            self.waiver_file.as_ref()
+        }
+
+        /// Returns a reference to the home directory [`FilePath`] of the collection to evaluate, if one was provided.
+        pub fn home_directory(&self) -> Option<&FilePath> {
+            self.home_directory.as_ref()
+        }
+
     }
 
     pub struct EvaluateEvidenceBuilder<'a> {
@@ -65,6 +91,9 @@ pub mod request {
         procedure_dir: Option<&'a str>,
         procedure_repo: Option<&'a str>,
         metadata: Vec<(String, String)>,
+        outcome_policy: Option<&'a str>,
+        waiver_file: Option<&'a str>,
+        home_directory: Option<&'a str>,
     }
     impl<'a> EvaluateEvidenceBuilder<'a> {
 
@@ -93,13 +122,31 @@ pub mod request {
             self
         }
 
+        pub fn outcome_policy(&mut self, policy: &'a str) -> &mut Self {
+            self.outcome_policy = Some(policy);
+            self
+        }
+
+        pub fn waiver_file(&mut self, file_path: &'a str) -> &mut Self {
+            self.waiver_file = Some(file_path);
+            self
+        }
+
+        pub fn home_directory(&mut self, directory_path: &'a str) -> &mut Self {
+            self.home_directory = Some(directory_path);
+            self
+        }
+
         pub fn try_build(&self) -> Result<EvaluateEvidence, Error> {
 
             let subject = validate_subject(self.subject_nrn, self.subject_id).map_err( custom_error)?;
             let procedure = validate_procedure(self.procedure_repo, self.procedure_dir).map_err(custom_error)?;
             let metadata = validate_metadata(&self.metadata).map_err(custom_error)?;
+            let outcome_policy = validate_outcome_policy(self.outcome_policy).map_err(custom_error)?;
+            let waiver_file = validate_waiver_file(self.waiver_file).map_err(custom_error)?;
+            let home_directory = validate_home_directory(self.home_directory).map_err(custom_error)?;
 
-           Ok( EvaluateEvidence { subject, procedure, metadata } )
+           Ok( EvaluateEvidence { subject, procedure, metadata, outcome_policy, waiver_file, home_directory } )
 
         }
 
@@ -132,6 +179,30 @@ pub mod request {
         Ok(valid_metadata)
     }
 
+    fn validate_outcome_policy(policy: Option<&str>) -> Result<OutcomePolicy, Error> {
+        match policy {
+            Some(policy) => OutcomePolicy::try_from(policy).map_err(|e| Error::for_user(Kind::InvalidInput,
+            format!("There is an issue with the Outcome Policy you provided. {}", e.message))),
+            None => Ok(OutcomePolicy::default())
+        }
+    }
+
+    fn validate_waiver_file(file_path: Option<&str>) -> Result<Option<FilePath>, Error> {
+        match file_path {
+            Some(file_path) => FilePath::try_from(file_path).map(Some).map_err(|e| Error::for_user(Kind::InvalidInput,
+            format!("There is an issue with the waiver file path you provided. {}", e.message))),
+            None => Ok(None)
+        }
+    }
+
+    fn validate_home_directory(directory_path: Option<&str>) -> Result<Option<FilePath>, Error> {
+        match directory_path {
+            Some(directory_path) => FilePath::try_from(directory_path).map(Some).map_err(|e| Error::for_user(Kind::InvalidInput,
+            format!("There is an issue with the home directory path you provided. {}", e.message))),
+            None => Ok(None)
+        }
+    }
+
     fn custom_error(error: Error) -> Error {
         Error::for_user(error.kind,
                         format!("We are unable to create a valid Evaluate Evidence request. {}", error.message))
@@ -3,6 +3,8 @@ use crate::evidence_collection::usecases::evaluate_evidence::usecase_boundary::r
 
 mod request {
     use nape_kernel::error::{Audience, Kind};
+    use nape_kernel::values::specification::assurance_report::outcome_policy::OutcomePolicy;
+    use nape_kernel::values::specification::severity::Severity;
     use nape_testing_assertions::{kernel_error_eq, kernel_error_starts_with};
     use super::*;
 
@@ -157,4 +159,53 @@ mod request {
         kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "We are unable to create a valid Evaluate Evidence request. There is an issue with the Metadata you provided.");
     }
 
+    #[test]
+    fn outcome_policy_success() {
+        let default_policy = EvaluateEvidence::builder()
+            .subject_id("1234567")
+            .subject_nrn("nrn:procedure:123")
+            .procedure_repository("https://example.com/repo")
+            .procedure_directory("path/to/procedure")
+            .try_build();
+
+        assert_eq!(is_ok!(default_policy).outcome_policy(), &OutcomePolicy::AnyFailure);
+
+        let configured_policy = EvaluateEvidence::builder()
+            .subject_id("1234567")
+            .subject_nrn("nrn:procedure:123")
+            .procedure_repository("https://example.com/repo")
+            .procedure_directory("path/to/procedure")
+            .outcome_policy("critical,high")
+            .try_build();
+
+        assert_eq!(is_ok!(configured_policy).outcome_policy(), &OutcomePolicy::FailOn(vec![Severity::CRITICAL, Severity::HIGH]));
+    }
+
+    #[test]
+    fn invalid_outcome_policy_error() {
+        let result = EvaluateEvidence::builder()
+            .subject_id("1234567")
+            .subject_nrn("nrn:procedure:123")
+            .procedure_repository("https://example.com/repo")
+            .procedure_directory("path/to/procedure")
+            .outcome_policy("urgent")
+            .try_build();
+
+        kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "We are unable to create a valid Evaluate Evidence request. There is an issue with the Outcome Policy you provided. ");
+    }
+
+    #[test]
+    fn waiver_file_success() {
+        let result = EvaluateEvidence::builder()
+            .subject_id("1234567")
+            .subject_nrn("nrn:procedure:123")
+            .procedure_repository("https://example.com/repo")
+            .procedure_directory("path/to/procedure")
+            .waiver_file("path/to/waivers.yaml")
+            .try_build();
+
+        let request = is_ok!(result);
+        assert_eq!(request.waiver_file().unwrap().as_str(), "path/to/waivers.yaml");
+    }
+
 }
\ No newline at end of file
@@ -10,10 +10,29 @@ use crate::evidence_collection::usecases::evaluate_evidence::usecase::AssuranceR
 use crate::evidence_collection::usecases::evaluate_evidence::usecase_boundary::request::EvaluateEvidence;
 use nape_kernel::error::{Audience, Kind};
 use nape_kernel::values::specification::outcome::Outcome;
+use nape_kernel::values::specification::description::Description;
+use nape_kernel::values::specification::assurance_report::outcome_policy::OutcomePolicy;
+use nape_kernel::values::specification::severity::Severity;
+use nape_kernel::values::specification::assurance_report::provenance::Provenance;
+use nape_kernel::values::specification::assurance_report::attestation::Attestation;
+use nape_kernel::values::specification::assurance_report::exclusion::Exclusion;
+use nape_kernel::values::specification::assurance_report::finding::{EvidenceLocation, Finding, LineRange};
+use nape_kernel::values::specification::assurance_report::observations::Observations;
+use nape_kernel::values::specification::assurance_report::signed_file::SignedFile;
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+use nape_kernel::values::specification::assurance_report::waiver::Waiver;
+use nape_kernel::values::specification::assurance_report::waivers::Waivers;
+use nape_kernel::values::time::date::Date;
+use nape_kernel::values::time::start_time::StartTime;
+use nape_kernel::gateways::file_selection::{SelectedFile, SelectedFiles};
+use nape_kernel::values::specification::name::Name;
+use crate::evidence_collection::usecases::collect_evidence::gateway_boundary::CustodyEntry;
 use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_kernel::values::specification::v1_0_0::assurance_report::AssuranceReportV1;
 use nape_kernel::values::specification::api_version::APIVersion;
 use nape_testing_assertions::{kernel_error_eq, kernel_error_contains, kernel_error_starts_with};
 use crate::evidence_collection::usecases::evaluate_evidence::gateway_boundary::request::EvaluationFiles;
+use crate::evidence_collection::usecases::evaluate_evidence::gateway::{CollectionGateways, EvaluationGateways, ReportGateways};
 use crate::evidence_collection::usecases::evaluate_evidence::usecase::evaluate_and_report;
 
 mod usecase {
@@ -24,31 +43,183 @@ mod usecase {
     fn success() {
         let request = generate_valid_request();
 
-        let report_result = evaluate_and_report(
-            &request,
-            mock_retrieve_directory_path,
-            mock_retrieve_procedure_definition,
-            mock_evaluate_evidence,
-            mock_sig_algo,
-            mock_file_data_gw,
-            mock_persist_report_gw);
+        let report_result = evaluate_and_report(&request, collection_gateways(), evaluation_gateways(), report_gateways());
 
         is_ok!(&report_result);
 
     }
 
+    #[test]
+    fn success_with_parameters() {
+        let request = generate_valid_request();
+        let evaluate_evidence_assertion = |files: &EvaluationFiles| -> Result<EvaluationResults, Error> {
+            assert_eq!(files.parameters.get("meta.key"), Some("value"));
+            assert_eq!(files.parameters.get("subject.id"), Some("123456789"));
+            Ok(generate_evaluation_results())
+        };
+
+        let report_result = evaluate_and_report(&request, collection_gateways(), EvaluationGateways { evaluate_evidence: evaluate_evidence_assertion, ..evaluation_gateways() }, report_gateways());
+
+        is_ok!(&report_result);
+    }
+
+    #[test]
+    fn dependencies_evaluated_in_order_success() {
+        let request = generate_valid_request();
+        let evaluate_one_level = |files: &EvaluationFiles| -> Result<EvaluationResults, Error> {
+            assert_eq!(files.list().len(), 1);
+            Ok(evaluation_results_for(files, "pass"))
+        };
+        let persist_report_assertion = |report: &dyn AssuranceReport, _home_directory: &str| -> Result<FilePath, Error> {
+            let report = report.as_any().downcast_ref::<AssuranceReportV1>().unwrap();
+            let actions = &report.activities().list()[0].actions;
+            assert_eq!(actions[0].name().value, "action-2");
+            assert_eq!(actions[0].outcome(), &Outcome::PASS);
+            assert_eq!(report.summary().skipped, 0);
+            Ok(FilePath::from("/User/procedure-root/assurance_report.yaml"))
+        };
+
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_definition: mock_retrieve_dependent_procedure_definition, ..collection_gateways() }, EvaluationGateways { evaluate_evidence: evaluate_one_level, ..evaluation_gateways() }, ReportGateways { persist_report: persist_report_assertion, ..report_gateways() });
+
+        is_ok!(&report_result);
+    }
+
+    #[test]
+    fn dependency_failed_skipped_success() {
+        let request = generate_valid_request();
+        let evaluate_failing_prerequisite = |files: &EvaluationFiles| -> Result<EvaluationResults, Error> {
+            assert!(!files.list().contains_key(&FilePath::from("/User/procedure-root/the/action-2/evidence/file.txt")));
+            Ok(evaluation_results_for(files, "fail"))
+        };
+        let persist_report_assertion = |report: &dyn AssuranceReport, _home_directory: &str| -> Result<FilePath, Error> {
+            let report = report.as_any().downcast_ref::<AssuranceReportV1>().unwrap();
+            let actions = &report.activities().list()[0].actions;
+            assert_eq!(actions[0].outcome(), &Outcome::SKIPPED);
+            assert_eq!(actions[0].reason().value, "The action 'action-2' depends on 'action-1', which did not pass.");
+            assert_eq!(actions[0].evidence_file(), None);
+            assert_eq!(actions[1].outcome(), &Outcome::FAIL);
+            assert_eq!(report.summary().skipped, 1);
+            assert_eq!(report.summary().outcome, Outcome::FAIL);
+            Ok(FilePath::from("/User/procedure-root/assurance_report.yaml"))
+        };
+
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_definition: mock_retrieve_dependent_procedure_definition, ..collection_gateways() }, EvaluationGateways { evaluate_evidence: evaluate_failing_prerequisite, ..evaluation_gateways() }, ReportGateways { persist_report: persist_report_assertion, ..report_gateways() });
+
+        is_ok!(&report_result);
+    }
+
+    #[test]
+    fn attestation_success() {
+        let request = generate_valid_request();
+        let evaluate_without_attestation = |files: &EvaluationFiles| -> Result<EvaluationResults, Error> {
+            assert!(!files.list().contains_key(&FilePath::from("/User/procedure-root/the/access-review/attestation.yaml")));
+            Ok(evaluation_results_for(files, "pass"))
+        };
+        let retrieve_attestation = |file_path: &str| -> Result<Option<Attestation>, Error> {
+            assert_eq!(file_path, "/User/procedure-root/the/access-review/attestation.yaml");
+            Ok(Some(generate_attestation()))
+        };
+        let persist_report_assertion = |report: &dyn AssuranceReport, _home_directory: &str| -> Result<FilePath, Error> {
+            let report = report.as_any().downcast_ref::<AssuranceReportV1>().unwrap();
+            let action = &report.activities().list()[0].actions[1];
+            assert_eq!(action.name().value, "access-review");
+            assert_eq!(action.outcome(), &Outcome::PASS);
+            assert_eq!(action.reason().value, "All accounts were reviewed.");
+            assert_eq!(action.test_file(), None);
+            assert_eq!(action.evidence_file().unwrap().file().as_str(), "the/access-review/attestation.yaml");
+            assert_eq!(action.attestation().unwrap().attested_by.value, "jane");
+            Ok(FilePath::from("/User/procedure-root/assurance_report.yaml"))
+        };
+
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_definition: mock_retrieve_attestation_procedure_definition, ..collection_gateways() }, EvaluationGateways { evaluate_evidence: evaluate_without_attestation, retrieve_attestation, ..evaluation_gateways() }, ReportGateways { persist_report: persist_report_assertion, ..report_gateways() });
+
+        is_ok!(&report_result);
+    }
+
+    #[test]
+    fn attestation_not_attested_error() {
+        let request = generate_valid_request();
+
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_definition: mock_retrieve_attestation_procedure_definition, ..collection_gateways() }, evaluation_gateways(), report_gateways());
+
+        kernel_error_eq!(report_result, Kind::InvalidInput, Audience::User,
+            "The action 'access-review' is an attestation action which has not been attested. Please attest the action before the report is generated.");
+    }
+
+    #[test]
+    fn home_directory_success() {
+        let request = EvaluateEvidence::builder()
+            .subject_id("123456789")
+            .subject_nrn("nrn:sourcecode::example")
+            .procedure_repository("https://github.com/nape-central")
+            .procedure_directory("some/directory/location")
+            .home_directory("/User/procedure-root")
+            .try_build().unwrap();
+        let retrieve_directory_path_unused = |_dir_key: &str| -> Result<String, Error> {
+            panic!("The application state must not be used when the request has a home directory.")
+        };
+        let persist_report_assertion = |_report: &dyn AssuranceReport, home_directory: &str| -> Result<FilePath, Error> {
+            assert_eq!(home_directory, "/User/procedure-root");
+            Ok(FilePath::from("/User/procedure-root/assurance_report.yaml"))
+        };
+
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_path: retrieve_directory_path_unused, ..collection_gateways() }, evaluation_gateways(), ReportGateways { persist_report: persist_report_assertion, ..report_gateways() });
+
+        let report_path = is_ok!(report_result);
+        assert_eq!(report_path.as_str(), "/User/procedure-root/assurance_report.yaml");
+    }
+
+    #[test]
+    fn retrieve_waivers_error() {
+        let request = EvaluateEvidence::builder()
+            .subject_id("123456789")
+            .subject_nrn("nrn:sourcecode::example")
+            .procedure_repository("https://github.com/nape-central")
+            .procedure_directory("some/directory/location")
+            .waiver_file("the/waivers.yaml")
+            .try_build().unwrap();
+
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_waivers: mock_retrieve_waivers_error, ..collection_gateways() }, evaluation_gateways(), report_gateways());
+
+        kernel_error_eq!(&report_result, Kind::GatewayError, Audience::System,
+            "Failed to retrieve the waivers. Could not read the waiver file");
+    }
+
+    #[test]
+    fn retrieve_tailoring_error() {
+        let request = generate_valid_request();
+
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_tailoring: mock_retrieve_tailoring_error, ..collection_gateways() }, evaluation_gateways(), report_gateways());
+
+        kernel_error_eq!(&report_result, Kind::GatewayError, Audience::System,
+            "Failed to retrieve the tailoring. Could not read the tailoring file");
+    }
+
+    #[test]
+    fn retrieve_provenance_error() {
+        let request = generate_valid_request();
+
+        let report_result = evaluate_and_report(&request, collection_gateways(), EvaluationGateways { retrieve_provenance: mock_retrieve_provenance_error, ..evaluation_gateways() }, report_gateways());
+
+        kernel_error_eq!(&report_result, Kind::GatewayError, Audience::System,
+            "Failed to retrieve the provenance. Could not determine the user");
+    }
+
+    #[test]
+    fn retrieve_custody_ledger_error() {
+        let request = generate_valid_request();
+
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_custody_ledger: mock_retrieve_custody_ledger_error, ..collection_gateways() }, evaluation_gateways(), report_gateways());
+
+        kernel_error_eq!(&report_result, Kind::GatewayError, Audience::System,
+            "Failed to retrieve the custody ledger. Could not read the custody ledger");
+    }
+
     #[test]
     fn no_retrieve_procedure_definition_doc_path_error() {
         let request = generate_valid_request();
 
-        let report_result = evaluate_and_report(
-            &request,
-            mock_retrieve_directory_path_procedure_definition_doc_error,
-            mock_retrieve_procedure_definition,
-            mock_evaluate_evidence,
-            mock_sig_algo,
-            mock_file_data_gw,
-            mock_persist_report_gw);
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_path: mock_retrieve_directory_path_procedure_definition_doc_error, ..collection_gateways() }, evaluation_gateways(), report_gateways());
 
         // Make sure the error starts with the proper message
         kernel_error_starts_with!(&report_result, Kind::GatewayError, Audience::System,
@@ -64,14 +235,7 @@ mod usecase {
     fn no_retrieve_home_path_error() {
         let request = generate_valid_request();
 
-        let report_result = evaluate_and_report(
-            &request,
-            mock_retrieve_directory_path_home_error,
-            mock_retrieve_procedure_definition,
-            mock_evaluate_evidence,
-            mock_sig_algo,
-            mock_file_data_gw,
-            mock_persist_report_gw);
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_path: mock_retrieve_directory_path_home_error, ..collection_gateways() }, evaluation_gateways(), report_gateways());
 
         // Make sure the error starts with the proper message
         kernel_error_starts_with!(&report_result, Kind::GatewayError, Audience::System,
@@ -87,14 +251,7 @@ mod usecase {
     fn could_not_get_procedure_def_error() {
         let request = generate_valid_request();
 
-        let report_result = evaluate_and_report(
-            &request,
-            mock_retrieve_directory_path,
-            mock_retrieve_procedure_definition_error,
-            mock_evaluate_evidence,
-            mock_sig_algo,
-            mock_file_data_gw,
-            mock_persist_report_gw);
+        let report_result = evaluate_and_report(&request, CollectionGateways { retrieve_definition: mock_retrieve_procedure_definition_error, ..collection_gateways() }, evaluation_gateways(), report_gateways());
 
         // Make sure the error starts with the proper message
         kernel_error_starts_with!(&report_result, Kind::GatewayError, Audience::System,
@@ -109,14 +266,7 @@ mod usecase {
     fn evaluate_evidence_gateway_error() {
         let request = generate_valid_request();
 
-        let report_result = evaluate_and_report(
-            &request,
-            mock_retrieve_directory_path,
-            mock_retrieve_procedure_definition,
-            mock_evaluate_evidence_error,
-            mock_sig_algo,
-            mock_file_data_gw,
-            mock_persist_report_gw);
+        let report_result = evaluate_and_report(&request, collection_gateways(), EvaluationGateways { evaluate_evidence: mock_evaluate_evidence_error, ..evaluation_gateways() }, report_gateways());
 
         // Make sure the error starts with the proper message
         kernel_error_starts_with!(&report_result, Kind::GatewayError, Audience::System,
@@ -131,14 +281,7 @@ mod usecase {
     fn sig_algo_error() {
         let request = generate_valid_request();
 
-        let report_result = evaluate_and_report(
-            &request,
-            mock_retrieve_directory_path,
-            mock_retrieve_procedure_definition,
-            mock_evaluate_evidence,
-            mock_sig_algo_error,
-            mock_file_data_gw,
-            mock_persist_report_gw);
+        let report_result = evaluate_and_report(&request, collection_gateways(), evaluation_gateways(), ReportGateways { signature_algorithm: mock_sig_algo_error, ..report_gateways() });
 
         // Make sure the error starts with the proper message
         kernel_error_starts_with!(&report_result, Kind::ProcessingFailure, Audience::System,
@@ -153,14 +296,7 @@ mod usecase {
     fn file_data_gateway_error() {
         let request = generate_valid_request();
 
-        let report_result = evaluate_and_report(
-            &request,
-            mock_retrieve_directory_path,
-            mock_retrieve_procedure_definition,
-            mock_evaluate_evidence,
-            mock_sig_algo,
-            mock_file_data_gw_error,
-            mock_persist_report_gw);
+        let report_result = evaluate_and_report(&request, collection_gateways(), evaluation_gateways(), ReportGateways { file_data_gateway: mock_file_data_gw_error, ..report_gateways() });
 
         // Make sure the error starts with the proper message
         kernel_error_starts_with!(&report_result, Kind::ProcessingFailure, Audience::System,
@@ -175,14 +311,7 @@ mod usecase {
     fn persist_report_gateway_error( ) {
         let request = generate_valid_request();
 
-        let report_result = evaluate_and_report(
-            &request,
-            mock_retrieve_directory_path,
-            mock_retrieve_procedure_definition,
-            mock_evaluate_evidence,
-            mock_sig_algo,
-            mock_file_data_gw,
-            mock_persist_report_gw_error);
+        let report_result = evaluate_and_report(&request, collection_gateways(), evaluation_gateways(), ReportGateways { persist_report: mock_persist_report_gw_error, ..report_gateways() });
 
         // Make sure the error starts with the proper message
         kernel_error_starts_with!(&report_result, Kind::GatewayError, Audience::System,
@@ -193,6 +322,34 @@ mod usecase {
             "Could not persist the assurance report");
     }
 
+    fn collection_gateways() -> CollectionGateways {
+        CollectionGateways {
+            retrieve_path: mock_retrieve_directory_path,
+            resolve_path: mock_resolve_directory_path,
+            retrieve_definition: mock_retrieve_procedure_definition,
+            retrieve_waivers: mock_retrieve_waivers,
+            retrieve_tailoring: mock_retrieve_tailoring,
+            retrieve_custody_ledger: mock_retrieve_custody_ledger
+        }
+    }
+
+    fn evaluation_gateways() -> EvaluationGateways {
+        EvaluationGateways {
+            evaluate_evidence: mock_evaluate_evidence,
+            retrieve_attestation: mock_retrieve_attestation,
+            retrieve_provenance: mock_retrieve_provenance
+        }
+    }
+
+    fn report_gateways() -> ReportGateways {
+        ReportGateways {
+            signature_algorithm: mock_sig_algo,
+            file_data_gateway: mock_file_data_gw,
+            select_files: mock_select_files,
+            persist_report: mock_persist_report_gw
+        }
+    }
+
    fn mock_retrieve_directory_path(_dir_key: &str) -> Result<String, Error> {
        if _dir_key == "home" {
            return Ok(String::from("/User/procedure-root"))
@@ -202,6 +359,13 @@ mod usecase {
 
     }
 
+    fn mock_resolve_directory_path(home_directory: &str, dir_key: &str) -> Result<String, Error> {
+        match dir_key {
+            "home" => Ok(home_directory.to_string()),
+            _ => Ok(format!("{}/assurance_procedure.yaml", home_directory))
+        }
+    }
+
     fn mock_retrieve_directory_path_home_error(dir_key: &str) -> Result<String, Error> {
         if dir_key == "assurance-procedure-file" {
             return Ok(String::from("the/directory/path"))
@@ -220,10 +384,68 @@ mod usecase {
         Ok(generate_procedure_definition())
     }
 
+    fn mock_retrieve_dependent_procedure_definition(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+        let action1 = Action::builder().name("action-1").short_description("action-1 short").long_description("action-1 long").test_file_path("the/action-1/test/file.py").evidence_file_path("the/action-1/evidence/file.txt").try_build()?;
+        let action2 = Action::builder().name("action-2").short_description("action-2 short").long_description("action-2 long").test_file_path("the/action-2/test/file.py").evidence_file_path("the/action-2/evidence/file.txt").add_dependency("action-1").try_build()?;
+        let activity = Activity::new("procedure-1", "Short Desc", "Long Desc")?.add(action2).add(action1);
+
+        AssuranceProcedure::builder()
+            .api_version("1.4.0")
+            .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+            .add_activity(&activity)
+            .try_build()
+    }
+
+    fn mock_retrieve_attestation_procedure_definition(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+        let action1 = Action::builder().name("action-1").short_description("action-1 short").long_description("action-1 long").test_file_path("the/action-1/test/file.py").evidence_file_path("the/action-1/evidence/file.txt").try_build()?;
+        let activity = Activity::new("procedure-1", "Short Desc", "Long Desc")?.add(action1).add(generate_attestation_action());
+
+        AssuranceProcedure::builder()
+            .api_version("1.6.0")
+            .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+            .add_activity(&activity)
+            .try_build()
+    }
+
+    /// Returns the `outcome` for every evidence and test file in the request.
+    fn evaluation_results_for(files: &EvaluationFiles, outcome: &str) -> EvaluationResults {
+        let mut results = EvaluationResults::default();
+        for (evidence_file, test_files) in files.list() {
+            for test_file in test_files {
+                results = results.add_result(evidence_file, test_file, TestResult::try_from(outcome, "The test result").unwrap());
+            }
+        }
+        results
+    }
+
     fn mock_retrieve_procedure_definition_error(_file_path: &str) -> Result<AssuranceProcedure, Error> {
         Err(Error::for_system(Kind::GatewayError, "Could not get procedure definition".to_string()))
     }
 
+    fn mock_retrieve_waivers(_file_path: &str) -> Result<Waivers, Error> {
+        Ok(Waivers::default())
+    }
+
+    fn mock_retrieve_waivers_error(_file_path: &str) -> Result<Waivers, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Could not read the waiver file".to_string()))
+    }
+
+    fn mock_retrieve_tailoring(_home_directory: &str) -> Result<Tailoring, Error> {
+        Ok(Tailoring::default())
+    }
+
+    fn mock_retrieve_tailoring_error(_home_directory: &str) -> Result<Tailoring, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Could not read the tailoring file".to_string()))
+    }
+
+    fn mock_retrieve_provenance() -> Result<Provenance, Error> {
+        Provenance::try_new("1.0.0", "nape-eval 0.3.1", "linux", "x86_64", "build-01", "jane")
+    }
+
+    fn mock_retrieve_provenance_error() -> Result<Provenance, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Could not determine the user".to_string()))
+    }
+
     fn mock_evaluate_evidence(_files: &EvaluationFiles) -> Result<EvaluationResults, Error> {
         Ok(generate_evaluation_results())
     }
@@ -232,6 +454,10 @@ mod usecase {
         Err(Error::for_system(Kind::GatewayError, "Could not evaluate evidence files".to_string()))
     }
 
+    fn mock_retrieve_attestation(_file_path: &str) -> Result<Option<Attestation>, Error> {
+        Ok(None)
+    }
+
     fn mock_sig_algo_error(_file_data: &Vec<u8>) -> Result<Signature, Error> {
         Err(Error::for_system(Kind::InvalidInput, "Signature Algorithm Error".to_string()))
     }
@@ -240,6 +466,10 @@ mod usecase {
         Err(Error::for_system(Kind::GatewayError, "File Data Gateway Error".to_string()))
     }
 
+    fn mock_select_files(_file_path: &str) -> Result<SelectedFiles, Error> {
+        Ok(SelectedFiles::File)
+    }
+
     fn mock_persist_report_gw(_report: &dyn AssuranceReport, _report_directory: &str) -> Result<FilePath, Error> {
         Ok(FilePath::try_from("the/report/file.txt").unwrap())
     }
@@ -300,20 +530,491 @@ mod assurance_report_builder {
         assert_eq!(first_action.name().value, "action-1");
         assert_eq!(first_action.outcome(), &Outcome::PASS);
         assert_eq!(first_action.reason().value, "The test passed");
-        assert_eq!(first_action.test_file().file().as_str(), "the/action-1/test/file.py");
-        assert_eq!(first_action.test_file().signature().to_string(), "the-signature");
-        assert_eq!(first_action.evidence_file().file().as_str(), "the/action-1/evidence/file.txt");
-        assert_eq!(first_action.evidence_file().signature().to_string(), "the-signature");
+        assert_eq!(first_action.test_file().unwrap().file().as_str(), "the/action-1/test/file.py");
+        assert_eq!(first_action.test_file().unwrap().signature().to_string(), "the-signature");
+        assert_eq!(first_action.evidence_file().unwrap().file().as_str(), "the/action-1/evidence/file.txt");
+        assert_eq!(first_action.evidence_file().unwrap().signature().to_string(), "the-signature");
 
         let second_action = first_activity.actions.get(1).unwrap();
         assert_eq!(second_action.name().value, "action-2");
         assert_eq!(second_action.outcome(), &Outcome::PASS);
         assert_eq!(second_action.reason().value, "The test passed");
-        assert_eq!(second_action.test_file().file().as_str(), "the/action-2/test/file.py");
-        assert_eq!(second_action.test_file().signature().to_string(), "the-signature");
-        assert_eq!(second_action.evidence_file().file().as_str(), "the/action-2/evidence/file.txt");
-        assert_eq!(second_action.evidence_file().signature().to_string(), "the-signature");
+        assert_eq!(second_action.test_file().unwrap().file().as_str(), "the/action-2/test/file.py");
+        assert_eq!(second_action.test_file().unwrap().signature().to_string(), "the-signature");
+        assert_eq!(second_action.evidence_file().unwrap().file().as_str(), "the/action-2/evidence/file.txt");
+        assert_eq!(second_action.evidence_file().unwrap().signature().to_string(), "the-signature");
+
+    }
+
+    #[test]
+    fn success_with_severity_and_outcome_policy() {
+
+        let request = EvaluateEvidence::builder()
+            .subject_id("123456789")
+            .subject_nrn("nrn:sourcecode::example")
+            .procedure_repository("https://github.com/nape-central")
+            .procedure_directory("some/directory/location")
+            .outcome_policy("critical,high")
+            .try_build().unwrap();
+
+        let action1 = Action::builder().name("action-1").short_description("action-1 short").long_description("action-1 long").test_file_path("the/action-1/test/file.py").evidence_file_path("the/action-1/evidence/file.txt").severity("low").try_build().unwrap();
+        let action2 = Action::builder().name("action-2").short_description("action-2 short").long_description("action-2 long").test_file_path("the/action-2/test/file.py").evidence_file_path("the/action-2/evidence/file.txt").severity("critical").try_build().unwrap();
+        let activity = Activity::new("procedure-1", "Short Desc", "Long Desc").unwrap().add(action1).add(action2);
+        let procedure_definition = AssuranceProcedure::builder()
+            .api_version("1.1.0")
+            .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+            .add_activity(&activity)
+            .try_build().unwrap();
+
+        let evaluation_results = EvaluationResults::default()
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-1/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-1/test/file.py").unwrap(),
+                TestResult::try_from("fail", "The test failed").unwrap())
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-2/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-2/test/file.py").unwrap(),
+                TestResult::try_from("pass", "The test passed").unwrap());
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+
+        assert_eq!(report.summary().fail, 1);
+        assert_eq!(report.summary().failures_by_severity.low, 1);
+        assert_eq!(report.summary().outcome_policy, OutcomePolicy::FailOn(vec![Severity::CRITICAL, Severity::HIGH]));
+        assert_eq!(report.summary().outcome, Outcome::PASS);
+
+        let actions = &report.activities().list().get(0).unwrap().actions;
+        assert_eq!(actions.get(0).unwrap().severity(), Some(&Severity::LOW));
+        assert_eq!(actions.get(1).unwrap().severity(), Some(&Severity::CRITICAL));
+    }
+
+    #[test]
+    fn success_with_findings_and_observations() {
+
+        let request = generate_valid_request();
+        let procedure_definition = generate_procedure_definition();
+        let finding = Finding::try_new("The container runs as root.", "critical").unwrap()
+            .with_location(&EvidenceLocation::try_new("manifests/deploy.yaml").unwrap().with_lines(&LineRange::try_new(12, 14).unwrap()));
+        let mut observations = Observations::default();
+        observations.add("containers", "3").unwrap();
+        let evaluation_results = EvaluationResults::default()
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-1/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-1/test/file.py").unwrap(),
+                TestResult::try_from("fail", "The test failed").unwrap().with_finding(&finding).with_observations(&observations))
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-2/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-2/test/file.py").unwrap(),
+                TestResult::try_from("pass", "The test passed").unwrap());
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+
+        let actions = &report.activities().list().get(0).unwrap().actions;
+        assert_eq!(actions.get(0).unwrap().findings(), &vec![finding]);
+        assert_eq!(actions.get(0).unwrap().observations().get("containers"), Some("3".to_string()));
+        assert!(actions.get(1).unwrap().findings().is_empty());
+        assert!(actions.get(1).unwrap().observations().is_empty());
+    }
+
+    #[test]
+    fn success_with_waivers() {
+
+        let request = generate_valid_request();
+        let procedure_definition = generate_procedure_definition();
+        let evaluation_results = EvaluationResults::default()
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-1/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-1/test/file.py").unwrap(),
+                TestResult::try_from("fail", "The test failed").unwrap())
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-2/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-2/test/file.py").unwrap(),
+                TestResult::try_from("fail", "The test failed").unwrap());
+        let waivers = Waivers::default()
+            .try_add(Waiver::try_new("action-1", "A compensating control is in place.", "Jane Doe", "2024-06-30").unwrap()).unwrap()
+            .try_add(Waiver::try_new("action-2", "A compensating control is in place.", "John Doe", "2024-05-31").unwrap()).unwrap();
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_waivers(&waivers)
+            .with_evaluation_date(&Date::try_from("2024-06-01").unwrap())
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+
+        assert_eq!(report.summary().waived, 1);
+        assert_eq!(report.summary().fail, 1);
+        assert_eq!(report.summary().outcome, Outcome::FAIL);
+
+        let actions = &report.activities().list().get(0).unwrap().actions;
+        assert_eq!(actions.get(0).unwrap().outcome(), &Outcome::WAIVED);
+        assert_eq!(actions.get(0).unwrap().waiver().unwrap().approver.value, "Jane Doe");
+        assert_eq!(actions.get(1).unwrap().outcome(), &Outcome::FAIL);
+        assert!(actions.get(1).unwrap().waiver().is_none());
+
+        assert_eq!(report.additional_info().count(), 1);
+        assert_eq!(report.additional_info().list()[0].value, "The waiver for the action 'action-2', approved by John Doe, expired on 2024-05-31 and was not applied.");
+    }
+
+    #[test]
+    fn success_with_waiver_for_unknown_action() {
+
+        let request = generate_valid_request();
+        let procedure_definition = generate_procedure_definition();
+        let evaluation_results = EvaluationResults::default()
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-1/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-1/test/file.py").unwrap(),
+                TestResult::try_from("pass", "The test passed").unwrap())
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-2/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-2/test/file.py").unwrap(),
+                TestResult::try_from("pass", "The test passed").unwrap());
+        let waivers = Waivers::default()
+            .try_add(Waiver::try_new("action-3", "A compensating control is in place.", "Jane Doe", "2024-06-30").unwrap()).unwrap();
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_waivers(&waivers)
+            .with_evaluation_date(&Date::try_from("2024-06-01").unwrap())
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+
+        assert_eq!(report.summary().waived, 0);
+        assert_eq!(report.summary().outcome, Outcome::PASS);
+        assert_eq!(report.additional_info().count(), 1);
+        assert_eq!(report.additional_info().list()[0].value, "The waiver for the action 'action-3', approved by Jane Doe, does not match an action in the procedure and was not applied.");
+    }
+
+    #[test]
+    fn success_with_tailoring() {
+
+        let request = generate_valid_request();
+        let procedure_definition = generate_procedure_definition();
+        let evaluation_results = EvaluationResults::default()
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-1/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-1/test/file.py").unwrap(),
+                TestResult::try_from("pass", "The test passed").unwrap())
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-2/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-2/test/file.py").unwrap(),
+                TestResult::new(&Outcome::NOT_APPLICABLE, &Description::try_from("The subject does not store personal data.").unwrap()));
+        let tailoring = Tailoring::default()
+            .try_add(Exclusion::try_new("action-2", "The subject does not store personal data.").unwrap()).unwrap();
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_tailoring(&tailoring)
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+
+        assert_eq!(report.summary().action_count, 2);
+        assert_eq!(report.summary().actions_run, 1);
+        assert_eq!(report.summary().not_applicable, 1);
+        assert_eq!(report.summary().outcome, Outcome::PASS);
+
+        let excluded_action = report.activities().list().get(0).unwrap().actions.get(1).unwrap();
+        assert_eq!(excluded_action.outcome(), &Outcome::NOT_APPLICABLE);
+        assert_eq!(excluded_action.reason().value, "The subject does not store personal data.");
+        assert_eq!(excluded_action.test_file().unwrap().file().as_str(), "the/action-2/test/file.py");
+        assert!(excluded_action.evidence_file().is_none());
+        assert_eq!(excluded_action.exclusion().unwrap().action.value, "action-2");
+    }
+
+    #[test]
+    fn not_applicable_without_tailoring_error() {
+
+        let request = generate_valid_request();
+        let procedure_definition = generate_procedure_definition();
+        let evaluation_results = EvaluationResults::default()
+            .add_result(
+                &FilePath::try_from("/User/procedure-root/the/action-1/evidence/file.txt").unwrap(),
+                &FilePath::try_from("/User/procedure-root/the/action-1/test/file.py").unwrap(),
+                TestResult::new(&Outcome::NOT_APPLICABLE, &Description::try_from("The test decided it does not apply.").unwrap()));
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        kernel_error_eq!(report_result, Kind::InvalidInput, Audience::System,
+            "The action 'action-1' is not applicable, although it is not excluded by the tailoring.");
+    }
+
+    #[test]
+    fn success_with_provenance() {
+
+        let mut metadata: Vec<(String, String)> = Vec::new();
+        metadata.push(("utc-start".to_string(), "1719326666123".to_string()));
+        let request = EvaluateEvidence::builder()
+            .subject_id("123456789")
+            .subject_nrn("nrn:sourcecode::example")
+            .procedure_repository("https://github.com/nape-central")
+            .procedure_directory("some/directory/location")
+            .metadata(&metadata)
+            .try_build().unwrap();
+        let procedure_definition = generate_procedure_definition();
+        let evaluation_results = generate_evaluation_results();
+        let provenance = Provenance::try_new("1.0.0", "nape-eval 0.3.1", "linux", "x86_64", "build-01", "jane").unwrap();
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_provenance(&provenance)
+            .with_generation_time(&StartTime::from(1719330266000))
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+
+        assert_eq!(report.metadata().get("utc-start").unwrap(), "1719326666123");
+        assert_eq!(report.metadata().get("utc-generated").unwrap(), "1719330266000");
+        assert_eq!(report.metadata().get("nape-version").unwrap(), "1.0.0");
+        assert_eq!(report.metadata().get("evaluator-version").unwrap(), "nape-eval 0.3.1");
+        assert_eq!(report.metadata().get("os").unwrap(), "linux");
+        assert_eq!(report.metadata().get("arch").unwrap(), "x86_64");
+        assert_eq!(report.metadata().get("hostname").unwrap(), "build-01");
+        assert_eq!(report.metadata().get("user").unwrap(), "jane");
+
+        assert_eq!(report.additional_info().count(), 2);
+        assert_eq!(report.additional_info().list()[0].value, "The evidence collection started at 2024-06-25T14:44:26Z.");
+        assert_eq!(report.additional_info().list()[1].value, "The report was generated at 2024-06-25T15:44:26Z by nape 1.0.0 using the evaluator 'nape-eval 0.3.1' on the host 'build-01' (linux/x86_64) by the user 'jane'.");
+    }
+
+    #[test]
+    fn success_with_evidence_directory() {
+
+        let request = generate_valid_request();
+        let procedure_definition = generate_procedure_definition();
+        let evaluation_results = generate_evaluation_results();
+
+        let select_files = |file_path: &str| -> Result<SelectedFiles, Error> {
+            match file_path {
+                "/User/procedure-root/the/action-1/evidence/file.txt" => Ok(SelectedFiles::Files(vec![
+                    SelectedFile { source: "/User/procedure-root/the/action-1/evidence/file.txt/app/deploy.yaml".to_string(), relative_path: "app/deploy.yaml".to_string() },
+                    SelectedFile { source: "/User/procedure-root/the/action-1/evidence/file.txt/service.yaml".to_string(), relative_path: "service.yaml".to_string() }])),
+                _ => Ok(SelectedFiles::File)
+            }
+        };
+        let file_data = |file_path: &str| -> Result<Vec<u8>, Error> { Ok(file_path.as_bytes().to_vec()) };
+        let sig_algo = |data: &Vec<u8>| -> Result<Signature, Error> { Signature::try_new(SignatureType::SHA256, &format!("length{}", data.len())) };
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_signature_algorithm(sig_algo)
+            .with_file_data_gateway(file_data)
+            .with_select_files_gateway(select_files)
+            .try_build();
+
+        let report = is_ok!(report_result);
+        let actions = &report.activities().list().get(0).unwrap().actions;
+
+        let directory_action = actions.get(0).unwrap();
+        assert_eq!(directory_action.evidence_file().unwrap().file().as_str(), "the/action-1/evidence/file.txt");
+        assert_eq!(directory_action.evidence_files().len(), 2);
+        assert_eq!(directory_action.evidence_files()[0].file().as_str(), "the/action-1/evidence/file.txt/app/deploy.yaml");
+        assert_eq!(directory_action.evidence_files()[0].signature().structure_signature(), "SHA256[length67]");
+        assert_eq!(directory_action.evidence_files()[1].file().as_str(), "the/action-1/evidence/file.txt/service.yaml");
+        assert_eq!(directory_action.evidence_files()[1].signature().structure_signature(), "SHA256[length64]");
+        // The directory is signed with the list of "<relative path> <signature>\n" for each file.
+        assert_eq!(directory_action.evidence_file().unwrap().signature().structure_signature(), "SHA256[length63]");
+
+        let file_action = actions.get(1).unwrap();
+        assert!(file_action.evidence_files().is_empty());
+    }
+
+    #[test]
+    fn success_with_custody_ledger() {
+
+        let request = generate_valid_request();
+        let procedure_definition = generate_procedure_definition();
+        let evaluation_results = generate_evaluation_results();
+        let custody_entry = |target: &str, signature: &str| CustodyEntry {
+            action: Name::try_from("action-1").unwrap(),
+            source: "./reports/file.txt".to_string(),
+            target: target.to_string(),
+            signature: Signature::try_new(SignatureType::SHA256, signature).unwrap(),
+            collected: StartTime::from(1719326666000),
+            user: "jane".to_string(),
+        };
+        // The most recent entry for action-1 has a different signature, and action-2 was never collected.
+        let custody_ledger = vec![
+            custody_entry("the/action-1/evidence/file.txt", "the-signature"),
+            custody_entry("the/action-1/evidence/file.txt", "the-original-signature")];
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_custody_ledger(&custody_ledger)
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+        assert_eq!(report.additional_info().count(), 2);
+        assert_eq!(report.additional_info().list()[0].value, "The evidence 'the/action-1/evidence/file.txt' for the action 'action-1' was changed after it was collected from './reports/file.txt' by 'jane' at 2024-06-25T14:44:26Z. Its signature was SHA256[the-original-signature] when it was collected and is SHA256[the-signature] now.");
+        assert_eq!(report.additional_info().list()[1].value, "The evidence 'the/action-2/evidence/file.txt' for the action 'action-2' has no entry in the custody ledger.");
+    }
+
+    #[test]
+    fn success_with_custody_ledger_unchanged() {
+
+        let request = generate_valid_request();
+        let procedure_definition = generate_procedure_definition();
+        let evaluation_results = generate_evaluation_results();
+        let custody_ledger: Vec<CustodyEntry> = ["the/action-1/evidence/file.txt", "the/action-2/evidence/file.txt"].iter()
+            .map(|target| CustodyEntry {
+                action: Name::try_from("action").unwrap(),
+                source: "./reports/file.txt".to_string(),
+                target: target.to_string(),
+                signature: Signature::try_new(SignatureType::SHA256, "the-signature").unwrap(),
+                collected: StartTime::from(1719326666000),
+                user: "jane".to_string(),
+            }).collect();
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_custody_ledger(&custody_ledger)
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+        assert_eq!(report.additional_info().count(), 0);
+    }
+
+    #[test]
+    fn success_with_inputs() {
+
+        let request = generate_valid_request();
+        let action = Action::builder().name("action-1").short_description("action-1 short").long_description("action-1 long").test_file_path("the/action-1/test/file.py").evidence_file_path("the/action-1/evidence/file.txt").add_input("runtime", "the/action-1/evidence/runtime.json").try_build().unwrap();
+        let activity = Activity::new("procedure-1", "Short Desc", "Long Desc").unwrap().add(action);
+        let procedure_definition = AssuranceProcedure::builder()
+            .api_version("1.5.0")
+            .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+            .add_activity(&activity)
+            .try_build().unwrap();
+        let evaluation_results = EvaluationResults::default().add_result(
+            &FilePath::from("/User/procedure-root/the/action-1/evidence/file.txt"),
+            &FilePath::from("/User/procedure-root/the/action-1/test/file.py"),
+            TestResult::try_from("pass", "The test passed").unwrap());
+        // Only the primary evidence was collected, so the input is reported as missing from the ledger.
+        let custody_ledger = vec![CustodyEntry {
+            action: Name::try_from("action-1").unwrap(),
+            source: "./reports/file.txt".to_string(),
+            target: "the/action-1/evidence/file.txt".to_string(),
+            signature: Signature::try_new(SignatureType::SHA256, "the-signature").unwrap(),
+            collected: StartTime::from(1719326666000),
+            user: "jane".to_string(),
+        }];
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_custody_ledger(&custody_ledger)
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
+
+        let report = is_ok!(report_result);
+        let action = &report.activities().list()[0].actions[0];
+        assert_eq!(action.inputs().len(), 1);
+        assert_eq!(action.inputs()[0].name.value, "runtime");
+        assert_eq!(action.inputs()[0].evidence_file.file().as_str(), "the/action-1/evidence/runtime.json");
+        assert_eq!(action.inputs()[0].evidence_file.signature().to_string(), "the-signature");
+        assert_eq!(report.additional_info().count(), 1);
+        assert_eq!(report.additional_info().list()[0].value, "The evidence 'the/action-1/evidence/runtime.json' for the action 'action-1' has no entry in the custody ledger.");
+    }
+
+    #[test]
+    fn success_with_attestation() {
+
+        let request = generate_valid_request();
+        let activity = Activity::new("procedure-1", "Short Desc", "Long Desc").unwrap().add(generate_attestation_action());
+        let procedure_definition = AssuranceProcedure::builder()
+            .api_version("1.6.0")
+            .procedure_info("nrn:sourcecode::example", "A Short Desc.", "This is an example procedure")
+            .add_activity(&activity)
+            .try_build().unwrap();
+        let statement_path = FilePath::from("/User/procedure-root/the/access-review/attestation.yaml");
+        let evaluation_results = EvaluationResults::default().add_result(&statement_path, &statement_path, TestResult::try_from("fail", "Two accounts were not reviewed.").unwrap());
+        let attestation = generate_attestation()
+            .with_supporting_file(&SignedFile::new("the/access-review/minutes.pdf", &Signature::try_new(SignatureType::SHA256, "when-attested").unwrap()).unwrap());
+        let attestations = vec![attestation];
+        let waivers = Waivers::default().try_add(Waiver::try_new("access-review", "Reviewed next quarter", "bob", "2999-12-31").unwrap()).unwrap();
+
+        let report_result = AssuranceReportBuilder::new()
+            .with_home_dir(&FilePath::from("/User/procedure-root"))
+            .with_request(&request)
+            .with_definition(&procedure_definition)
+            .with_results(&evaluation_results)
+            .with_attestations(&attestations)
+            .with_waivers(&waivers)
+            .with_signature_algorithm(mock_sig_algo)
+            .with_file_data_gateway(mock_file_data_gw)
+            .try_build();
 
+        let report = is_ok!(report_result);
+        let action = &report.activities().list()[0].actions[0];
+        assert_eq!(action.outcome(), &Outcome::WAIVED);
+        assert_eq!(action.test_file(), None);
+        let attestation = action.attestation().unwrap();
+        assert_eq!(attestation.outcome, Outcome::PASS);
+        assert_eq!(attestation.supporting_files[0].file().as_str(), "the/access-review/minutes.pdf");
+        assert_eq!(attestation.supporting_files[0].signature().to_string(), "the-signature");
     }
 
     #[test]
@@ -467,6 +1168,14 @@ fn generate_procedure_definition() -> AssuranceProcedure {
         .try_build().unwrap()
 }
 
+fn generate_attestation_action() -> Action {
+    Action::builder().name("access-review").short_description("access-review short").long_description("access-review long").evidence_file_path("the/access-review/attestation.yaml").attestation().try_build().unwrap()
+}
+
+fn generate_attestation() -> Attestation {
+    Attestation::try_new("access-review", "pass", "All accounts were reviewed.", "jane", &StartTime::from(1719326666000)).unwrap()
+}
+
 fn generate_evaluation_results() -> EvaluationResults {
     let results = EvaluationResults::default();
 
@@ -483,6 +1192,14 @@ fn generate_evaluation_results() -> EvaluationResults {
     results
 }
 
+fn mock_retrieve_custody_ledger(_home_directory: &str) -> Result<Vec<CustodyEntry>, Error> {
+    Ok(Vec::new())
+}
+
+fn mock_retrieve_custody_ledger_error(_home_directory: &str) -> Result<Vec<CustodyEntry>, Error> {
+    Err(Error::for_system(Kind::GatewayError, "Could not read the custody ledger".to_string()))
+}
+
 fn mock_sig_algo(_file_data: &Vec<u8>) -> Result<Signature, Error> {
     Signature::try_new(SignatureType::SHA256, "the-signature")
 }
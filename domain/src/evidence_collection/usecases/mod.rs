@@ -1,5 +1,9 @@
 
 
+pub mod attest_action;
+pub mod bundle_collection;
+pub mod capture_evidence;
 pub mod collect_evidence;
 pub mod evaluate_evidence;
+pub mod publish_collection;
 pub mod start_collection;
\ No newline at end of file
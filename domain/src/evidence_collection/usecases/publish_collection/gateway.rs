@@ -0,0 +1,21 @@
+use nape_kernel::error::Error;
+use crate::evidence_collection::usecases::publish_collection::gateway_boundary::CollectionPackage;
+
+/// # Overview
+///
+/// The [`PublishCollectionGateway`] publishes the files of a finished collection to an evidence repository.
+///
+/// # Arguments
+///
+/// * `package` - The [`CollectionPackage`] to publish.
+/// * `location` - The location of the evidence repository, such as a git repository URL, a directory, or a bucket URL.
+///
+/// # Returns
+///
+/// A [`Result`] of either the location the collection was published to, or an [`Error`].
+///
+/// # Design Decision
+///
+/// * Each file is published to the `destination` of the package followed by its relative path, so the layout of the published collection matches its home directory.
+///
+pub type PublishCollectionGateway = fn(package: &CollectionPackage, location: &str) -> Result<String, Error>;
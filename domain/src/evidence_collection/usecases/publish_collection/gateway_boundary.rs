@@ -0,0 +1,37 @@
+use nape_kernel::gateways::file_selection::SelectedFile;
+
+/// The [`PublishTarget`] is the evidence repository a finished collection is published to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PublishTarget {
+    /// A git repository, such as 'https://github.com/example/evidence.git', which the collection is committed and pushed to.
+    Git { repository: String },
+    /// A directory, such as a mounted network share, which the collection is copied to.
+    Directory { path: String },
+    /// A bucket in an S3 compatible store, as a path-style URL such as 'https://s3.us-east-1.amazonaws.com/evidence', which the collection is uploaded to.
+    S3 { bucket_url: String },
+}
+
+impl PublishTarget {
+
+    /// The location of the evidence repository, which is the repository URL, directory path, or bucket URL.
+    pub fn location(&self) -> &str {
+        match self {
+            PublishTarget::Git { repository } => repository,
+            PublishTarget::Directory { path } => path,
+            PublishTarget::S3 { bucket_url } => bucket_url,
+        }
+    }
+}
+
+/// The [`CollectionPackage`] is the set of files from a finished collection which are published to the evidence repository.
+///
+/// * `destination` - The path in the evidence repository the files are published to, which is the encoded subject NRN followed by the start time of the collection.
+/// * `files` - The files to publish, where each [`SelectedFile::relative_path`] is relative to the collection home directory, and to the `destination`.
+/// * `message` - A description of the collection, used as the commit message for a git repository.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CollectionPackage {
+    pub destination: String,
+    pub files: Vec<SelectedFile>,
+    pub message: String,
+}
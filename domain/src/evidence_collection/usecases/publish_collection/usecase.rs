@@ -0,0 +1,116 @@
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::gateways::directory_list::RetrieveDirectoryPath;
+use nape_kernel::gateways::file_selection::{SelectedFiles, SelectFilesGateway};
+use nape_kernel::values::nrn::filepath_codec::encode_as_directory_name;
+use nape_kernel::values::nrn::nrn::NRN;
+use crate::evidence_collection::usecases::publish_collection::gateway::PublishCollectionGateway;
+use crate::evidence_collection::usecases::publish_collection::gateway_boundary::{CollectionPackage, PublishTarget};
+
+/// The name of the assurance report, without its extension, which must be in the home directory before a collection can be published.
+const REPORT_FILE_STEM: &str = "assurance_report.";
+
+/// The directory in the home directory which holds temporary files, and is never published.
+const TEMP_DIRECTORY: &str = "temp/";
+
+/// The [`PublishCollectionRequest`] is the request to publish a finished collection to an evidence repository.
+///
+/// * `subject_nrn` - The NRN of the subject the collection was started for.
+/// * `target` - The [`PublishTarget`] to publish the collection to.
+/// * `organisation_nids` - The organisation-defined NIDs which the subject NRN may use.
+///
+#[derive(Clone, Debug)]
+pub struct PublishCollectionRequest<'a> {
+    pub subject_nrn: &'a str,
+    pub target: PublishTarget,
+    pub organisation_nids: &'a [String],
+}
+
+/// The [`PublishedCollection`] is the result of publishing a collection.
+///
+/// * `location` - Where the collection was published to, such as the commit in the git repository, or the directory.
+/// * `file_count` - The number of files published.
+///
+#[derive(Clone, Debug)]
+pub struct PublishedCollection {
+    pub location: String,
+    pub file_count: usize,
+}
+
+/// `UCPublishCollection` is a function pointer type that represents the use case for publishing a finished collection.
+///
+/// # Parameters
+///
+/// * `request`: The [`PublishCollectionRequest`] which describes the collection and where to publish it.
+///
+/// # Returns
+///
+/// This function returns a [`Result`] that contains the [`PublishedCollection`], or an [`Error`] if the collection could not be published.
+pub type UCPublishCollection = fn(request: &PublishCollectionRequest) -> Result<PublishedCollection, Error>;
+
+
+/// # Overview
+///
+/// The `publish_collection` use case publishes the assurance report, the evidence, and the snapshot of the assurance procedure in the home directory of a collection to an evidence repository.  The files are published to a path made of the subject NRN, encoded as a directory name, followed by the start time of the collection, such as 'nrn_sourcecode_example/1719326666123'.
+///
+/// The collection must be reported with `collect report` before it is published, and the temporary files in the home directory are never published.
+///
+/// # Arguments
+///
+/// * `request` - The [`PublishCollectionRequest`].
+/// * `retrieve_directory` - An implementation of the [`RetrieveDirectoryPath`] gateway.
+/// * `select_files` - An implementation of the [`SelectFilesGateway`] gateway, used to select every file in the home directory.
+/// * `publish` - An implementation of the [`PublishCollectionGateway`] gateway for the [`PublishTarget`].
+///
+/// # Design Decision
+///
+/// * The start time of the collection is the name of its home directory, which `collect start` creates as the encoded subject NRN followed by the start time.
+///
+pub fn publish_collection(
+    request: &PublishCollectionRequest,
+    retrieve_directory: RetrieveDirectoryPath,
+    select_files: SelectFilesGateway,
+    publish: PublishCollectionGateway) -> Result<PublishedCollection, Error> {
+
+    let subject_nrn = NRN::new(request.subject_nrn)
+        .map_err(|error| Error::for_system(Kind::InvalidInput,
+                                           format!("There is an issue with the subject NRN '{}'. {}", request.subject_nrn, error.message)))?;
+    subject_nrn.nid.check_allowed(request.organisation_nids)
+        .map_err(|error| Error::for_user(Kind::InvalidInput,
+                                         format!("There is an issue with the subject NRN '{}'. {}", request.subject_nrn, error.message)))?;
+
+    let home_directory = retrieve_directory("home")
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue retrieving the home directory path. {}", error.message)))?;
+
+    let start_time = home_directory.trim_end_matches('/').rsplit('/').next().unwrap_or_default();
+    let destination = format!("{}/{}", encode_as_directory_name(&subject_nrn), start_time);
+
+    let files = match select_files(&home_directory) {
+        Ok(SelectedFiles::Files(files)) => files,
+        Ok(SelectedFiles::File) => return Err(Error::for_system(Kind::InvalidInput,
+                                                                format!("The home directory '{}' is not a directory.", home_directory))),
+        Err(error) => return Err(Error::for_system(Kind::GatewayError,
+                                                   format!("There was an issue selecting the files in the home directory '{}'. {}", home_directory, error.message)))
+    };
+
+    let files: Vec<_> = files.into_iter()
+        .filter(|file| !file.relative_path.starts_with(TEMP_DIRECTORY))
+        .collect();
+
+    if !files.iter().any(|file| file.relative_path.starts_with(REPORT_FILE_STEM)) {
+        return Err(Error::for_user(Kind::InvalidInput,
+                                   String::from("The collection does not have an assurance report. Please run 'nape collect report' before publishing the collection.")));
+    }
+
+    let package = CollectionPackage {
+        message: format!("Publish the assurance collection '{}' for the subject '{}'.", start_time, subject_nrn.value),
+        destination,
+        files,
+    };
+
+    let location = publish(&package, request.target.location())
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("There was an issue publishing the collection to '{}'. {}", request.target.location(), error.message)))?;
+
+    Ok(PublishedCollection { location, file_count: package.files.len() })
+}
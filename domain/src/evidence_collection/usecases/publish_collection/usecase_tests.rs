@@ -0,0 +1,146 @@
+use nape_kernel::error::{Audience, Error, Kind};
+use nape_kernel::gateways::file_selection::{SelectedFile, SelectedFiles};
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::evidence_collection::usecases::publish_collection::gateway_boundary::{CollectionPackage, PublishTarget};
+use crate::evidence_collection::usecases::publish_collection::usecase::{publish_collection, PublishCollectionRequest};
+
+/*** Happy Path Tests ***/
+
+#[test]
+fn publish_success() {
+    let request = git_request();
+
+    let publish_assertion = |package: &CollectionPackage, location: &str| -> Result<String, Error> {
+        assert_eq!(location, "https://github.com/example/evidence.git");
+        assert_eq!(package.destination, "nrn_sourcecode_nape_project_-_nape-cli/1719326666123");
+        assert_eq!(package.message, "Publish the assurance collection '1719326666123' for the subject 'nrn:sourcecode:nape:project/nape-cli'.");
+        let relative_paths: Vec<&str> = package.files.iter().map(|file| file.relative_path.as_str()).collect();
+        assert_eq!(relative_paths, vec!["activity/peer-review.rego", "assurance_procedure.yaml", "assurance_report.yaml", "evidence/peer-review/review.json"]);
+        Ok("https://github.com/example/evidence.git@abc123".to_string())
+    };
+
+    let result = publish_collection(&request, retrieve_directory_success, select_files_success, publish_assertion);
+
+    let published = is_ok!(result);
+    assert_eq!(published.location, "https://github.com/example/evidence.git@abc123");
+    assert_eq!(published.file_count, 4);
+}
+
+#[test]
+fn publish_directory_target_success() {
+    let request = PublishCollectionRequest { subject_nrn: "nrn:sourcecode:nape:project/nape-cli", target: PublishTarget::Directory { path: "/mnt/evidence".to_string() }, organisation_nids: &[] };
+
+    let publish_assertion = |_package: &CollectionPackage, location: &str| -> Result<String, Error> {
+        assert_eq!(location, "/mnt/evidence");
+        Ok(String::new())
+    };
+
+    let result = publish_collection(&request, retrieve_directory_success, select_files_success, publish_assertion);
+
+    is_ok!(result);
+}
+
+/*** Sad Path Tests ***/
+
+#[test]
+fn publish_without_report_error() {
+    let request = git_request();
+    let select_files_without_report = |_file_path: &str| -> Result<SelectedFiles, Error> {
+        Ok(SelectedFiles::Files(vec![selected_file("evidence/peer-review/review.json")]))
+    };
+
+    let result = publish_collection(&request, retrieve_directory_success, select_files_without_report, publish_gateway_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "The collection does not have an assurance report. Please run 'nape collect report' before publishing the collection.");
+}
+
+#[test]
+fn publish_invalid_subject_error() {
+    let request = PublishCollectionRequest { subject_nrn: "not-an-nrn", target: PublishTarget::Directory { path: "/mnt/evidence".to_string() }, organisation_nids: &[] };
+
+    let result = publish_collection(&request, retrieve_directory_success, select_files_success, publish_gateway_success);
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::System, "There is an issue with the subject NRN 'not-an-nrn'. ");
+}
+
+#[test]
+fn publish_unregistered_nid_error() {
+    let request = PublishCollectionRequest { subject_nrn: "nrn:bank-product:nape:payments", ..git_request() };
+
+    let result = publish_collection(&request, retrieve_directory_success, select_files_success, publish_gateway_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "There is an issue with the subject NRN 'nrn:bank-product:nape:payments'. 'bank-product' is not a valid NID. Must be one of: [procedure, sourcecode, service, image, cloudaccount, dataset, pipeline].");
+}
+
+#[test]
+fn publish_retrieve_directory_gateway_error() {
+    let retrieve_directory_error = |_directory_name: &str| -> Result<String, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some RetrieveDirectoryPath Gateway Error".to_string()))
+    };
+
+    let result = publish_collection(&git_request(), retrieve_directory_error, select_files_success, publish_gateway_success);
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue retrieving the home directory path. Some RetrieveDirectoryPath Gateway Error");
+}
+
+#[test]
+fn publish_select_files_gateway_error() {
+    let select_files_error = |_file_path: &str| -> Result<SelectedFiles, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some SelectFiles Gateway Error".to_string()))
+    };
+
+    let result = publish_collection(&git_request(), retrieve_directory_success, select_files_error, publish_gateway_success);
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue selecting the files in the home directory 'nrn_sourcecode_nape_project_-_nape-cli/1719326666123'. Some SelectFiles Gateway Error");
+}
+
+#[test]
+fn publish_gateway_error() {
+    let publish_error = |_package: &CollectionPackage, _location: &str| -> Result<String, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some PublishCollection Gateway Error".to_string()))
+    };
+
+    let result = publish_collection(&git_request(), retrieve_directory_success, select_files_success, publish_error);
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue publishing the collection to 'https://github.com/example/evidence.git'. Some PublishCollection Gateway Error");
+}
+
+/*** Testing Utilities & Mocks ***/
+
+fn git_request() -> PublishCollectionRequest<'static> {
+    PublishCollectionRequest {
+        subject_nrn: "nrn:sourcecode:nape:project/nape-cli",
+        target: PublishTarget::Git { repository: "https://github.com/example/evidence.git".to_string() },
+        organisation_nids: &[],
+    }
+}
+
+fn selected_file(relative_path: &str) -> SelectedFile {
+    SelectedFile {
+        source: format!("nrn_sourcecode_nape_project_-_nape-cli/1719326666123/{}", relative_path),
+        relative_path: relative_path.to_string(),
+    }
+}
+
+fn retrieve_directory_success(_directory_name: &str) -> Result<String, Error> {
+    Ok("nrn_sourcecode_nape_project_-_nape-cli/1719326666123".to_string())
+}
+
+fn select_files_success(_file_path: &str) -> Result<SelectedFiles, Error> {
+    Ok(SelectedFiles::Files(vec![
+        selected_file("activity/peer-review.rego"),
+        selected_file("assurance_procedure.yaml"),
+        selected_file("assurance_report.yaml"),
+        selected_file("evidence/peer-review/review.json"),
+        selected_file("temp/clone/HEAD"),
+    ]))
+}
+
+fn publish_gateway_success(_package: &CollectionPackage, _location: &str) -> Result<String, Error> {
+    Ok(String::new())
+}
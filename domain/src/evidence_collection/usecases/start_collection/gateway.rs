@@ -2,6 +2,9 @@ use nape_kernel::error::{Error};
 use nape_kernel::values::directory::directory_list::DirectoryList;
 use nape_kernel::values::specification::file_path::FilePath;
 use nape_kernel::values::specification::repository_link::RepositoryLink;
+use nape_kernel::values::specification::assurance_procedure::include::Source;
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
 
 /// The [`ProcedureRetrievalGateway`] is the function signature for an implementation which downloads all the procedure files.
 ///
@@ -55,3 +58,47 @@ pub type FileMoveGateway = fn(source: &str, target: &str) -> Result<FilePath, Er
 /// * `source` - The source location of the file or directory to be deleted.
 pub type FileDeleteGateway = fn(source: &str) -> Result<(), Error>;
 
+/// The [`IncludeRetrievalGateway`] is the function signature for an implementation which downloads all the files of a procedure that activities are included from.
+///
+/// # Arguments
+///
+/// * `source` - The [`Source`] of the included procedure, i.e. the repository, directory, and optional revision.
+/// * `download_dir` - The directory where the included procedure files will be downloaded to.
+///
+/// # Returns
+///
+/// A [`DirectoryList`] with the same keys as the [`ProcedureRetrievalGateway`], or an [`Error`] for the [`Audience::System`] of the [`Kind::GatewayError`].
+///
+pub type IncludeRetrievalGateway = fn(source: &Source, download_dir: &str) -> Result<DirectoryList, Error>;
+
+/// The [`ReadProcedureGateway`] is a function that reads the [`AssuranceProcedure`] from the procedure definition document.
+///
+/// # Arguments
+///
+/// * `file_path` - The path to the procedure definition document.
+pub type ReadProcedureGateway = fn(file_path: &str) -> Result<AssuranceProcedure, Error>;
+
+/// The [`PersistProcedureGateway`] is a function that writes the [`AssuranceProcedure`] to the procedure definition document, replacing its content.
+///
+/// # Arguments
+///
+/// * `procedure` - The [`AssuranceProcedure`] to write.
+/// * `file_path` - The path to the procedure definition document.
+pub type PersistProcedureGateway = fn(procedure: &AssuranceProcedure, file_path: &str) -> Result<(), Error>;
+
+/// The [`PersistTailoringGateway`] is a function that writes the [`Tailoring`] of a collection into its home directory, so it can be applied when the evidence is evaluated.
+///
+/// # Arguments
+///
+/// * `tailoring` - The [`Tailoring`] to write.
+/// * `home_directory` - The home directory of the evidence collection.
+pub type PersistTailoringGateway = fn(tailoring: &Tailoring, home_directory: &str) -> Result<(), Error>;
+
+/// The [`ProcedureGateways`] groups the gateways used to resolve the `include` section of a procedure and to record its tailoring when a collection starts.
+#[derive(Clone, Copy)]
+pub struct ProcedureGateways {
+    pub retrieve_include: IncludeRetrievalGateway,
+    pub read_procedure: ReadProcedureGateway,
+    pub persist_procedure: PersistProcedureGateway,
+    pub persist_tailoring: PersistTailoringGateway,
+}
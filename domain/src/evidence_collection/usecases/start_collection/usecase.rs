@@ -1,7 +1,10 @@
 use nape_kernel::error::{Error, Kind};
 use nape_kernel::values::directory::directory_list::DirectoryList;
+use nape_kernel::values::specification::assurance_procedure::include::Include;
+use nape_kernel::values::specification::assurance_procedure::parameters::Parameters;
 use nape_kernel::values::specification::repository_link::RepositoryLink;
-use crate::evidence_collection::usecases::start_collection::gateway::{DirectoryCreationGateway, FileDeleteGateway, FileMoveGateway, ProcedureRetrievalGateway};
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use crate::evidence_collection::usecases::start_collection::gateway::{DirectoryCreationGateway, FileDeleteGateway, FileMoveGateway, ProcedureGateways, ProcedureRetrievalGateway};
 use crate::evidence_collection::usecases::start_collection::usecase_boundary::request::StartProcedure;
 use crate::evidence_collection::usecases::start_collection::usecase_boundary::response::ProcedureStarted;
 
@@ -12,6 +15,12 @@ pub type UCStartCollectionProcedure = fn(request: StartProcedure) -> Result<Proc
 ///
 /// # Design Considerations
 /// This usecase_configuration invokes the riskiest actions first by getting all the necessary data required to compile all documents and establish the structure for capturing evidence and other aspects of the business procedure evidence collection procedure.  Once all the files and directory structures have been created, then they are persisted via the [`DirectoryCreationGateway`] and  [`ReportCreationGateway`].  This allows for a clean rollback of actions if either one of the gateways fail such that there isn't partially persisted data.
+///
+/// When the procedure has `${name}` placeholders, they are resolved with the [`Parameters`] from the metadata and subject of the request, and the resolved procedure is written back over the procedure definition document.  A placeholder which cannot be resolved stops the collection before anything is evaluated.
+///
+/// When the request has a [`Tailoring`], the excluded actions are checked against the procedure, including any included activities.  Actions whose `when` condition, or the condition of their activity, is not met by the [`Parameters`] are excluded as well, and the tailoring is written into the collection home so it can be applied when the evidence is evaluated.
+///
+/// When the procedure has an `include` section, the selected activities of each included procedure are merged into the procedure and their test files are copied into the collection home.  The merged procedure is then written back over the procedure definition document without the `include` section, so the rest of the collection only sees a single procedure.
 
 // MAKE NOTES OF THE FOLLOWING - DESIGN QUESTION - Should this usecase_configuration care about persisting the directory list, or should it simply return it? What is the harm starting out with it persisting the directory structure?  What benfiit, if any, do I get if I retunr the DirList then have some wrapper function persist the directory strucutre?  One argument is that this is the single place that manages the complete initiation process, and that process requires the persistnace of directory strucutre.  LESSON LEARNED FROM THIS - I need to treat the usecase as if it does not have any infralogic because if.when I go to use these for either the agent, or a server, I generate a coupleoing for infra state mgt to the current state management and that may not work as, for esxampl the server, could be stateless, and the state for the agent may be stored differently than in a file. It's best just to inject those concersnas the stucts instead of the funcitons assuming this is infra state data.
 
@@ -23,7 +32,8 @@ pub fn start_collection(
     create_directories: DirectoryCreationGateway,
     retrieve_procedure: ProcedureRetrievalGateway,
     move_file: FileMoveGateway,
-    delete_file: FileDeleteGateway) -> Result<ProcedureStarted, Error> {
+    delete_file: FileDeleteGateway,
+    procedure_gateways: ProcedureGateways) -> Result<ProcedureStarted, Error> {
 
     let created_directory_list = create_directories(&directory_list)
         .map_err(|error| Error::for_system(Kind::GatewayError,
@@ -57,6 +67,21 @@ pub fn start_collection(
         Error::for_system(Kind::GatewayError,
                           format!("We could not start the collection procedure. Could not move the downloaded activity test directory '{}' to '{}': {}", downloaded_activity_dir, activity_dir, error.message)))?;
 
+    let procedure = resolve_includes(procedure_definition_doc_path.as_str(), &home_dir, &temp_dir, &request.organisation_nids, procedure_gateways, create_directories, move_file)?;
+    let parameters = Parameters::from(&request.metadata, &request.subject);
+    let procedure = resolve_parameters(&parameters, &procedure, procedure_definition_doc_path.as_str(), procedure_gateways)?;
+
+    request.tailoring.check_actions(&procedure.activities)
+        .map_err(|error| Error::for_user(error.kind,
+                                         format!("We could not start the collection procedure. {}", error.message)))?;
+
+    let tailoring = request.tailoring.clone().with_conditions(&procedure.activities, &parameters);
+    if !tailoring.is_empty() {
+        (procedure_gateways.persist_tailoring)(&tailoring, &home_dir)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("We could not start the collection procedure. Could not write the tailoring to '{}': {}", home_dir, error.message)))?;
+    }
+
     delete_file(&temp_dir).map_err(|error|
         Error::for_system(Kind::GatewayError,
                           format!("Could not delete the 'temp' directory: {}", error.message)))?;
@@ -104,4 +129,103 @@ fn download_files_from_repo(request: &StartProcedure, retrieve_procedure: Proced
     Ok((procedure_doc_source, activity_source))
 }
 
+/// Merges the included activities into the procedure, and returns the merged procedure.  The NRN of the procedure, and of each included procedure, must use an NID which is built into NAPE or is one of the `organisation_nids`.
+fn resolve_includes(procedure_doc: &str, home_dir: &str, temp_dir: &str, organisation_nids: &[String], gateways: ProcedureGateways, create_directories: DirectoryCreationGateway, move_file: FileMoveGateway) -> Result<AssuranceProcedure, Error> {
+
+    let procedure = (gateways.read_procedure)(procedure_doc)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("We could not start the collection procedure. Could not read the procedure document '{}': {}", procedure_doc, error.message)))?;
+
+    procedure.procedure.nrn.nid.check_allowed(organisation_nids)
+        .map_err(|error| Error::for_user(error.kind,
+                                         format!("We could not start the collection procedure. There is an issue with the procedure NRN '{}'. {}", procedure.procedure.nrn, error.message)))?;
+
+    if procedure.includes.is_empty() {
+        return Ok(procedure)
+    }
+
+    let mut merged_procedure = procedure.clone();
+    for (index, include) in procedure.includes.iter().enumerate() {
+        let download_dir = format!("{}/include-{}", temp_dir, index);
+        let included_procedure = download_included_procedure(include, &download_dir, gateways)?;
+        included_procedure.procedure.nrn.nid.check_allowed(organisation_nids)
+            .map_err(|error| Error::for_user(error.kind,
+                                             format!("We could not start the collection procedure. There is an issue with the NRN '{}' of the included procedure '{}'. {}", included_procedure.procedure.nrn, include.source, error.message)))?;
+
+        merged_procedure = merged_procedure.include(include, &included_procedure)
+            .map_err(|error| Error::for_user(error.kind,
+                                             format!("We could not start the collection procedure. {}", error.message)))?;
+
+        copy_included_test_files(include, &included_procedure, &download_dir, home_dir, create_directories, move_file)?;
+    }
+
+    let merged_procedure = merged_procedure.without_includes();
+    merged_procedure.activities.dependency_levels()
+        .map_err(|error| Error::for_user(error.kind,
+                                         format!("We could not start the collection procedure. {}", error.message)))?;
+    (gateways.persist_procedure)(&merged_procedure, procedure_doc)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("We could not start the collection procedure. Could not write the procedure document with the included activities to '{}': {}", procedure_doc, error.message)))?;
+
+    Ok(merged_procedure)
+}
+
+/// Replaces the placeholders in the procedure with the parameters of the request, and returns the resolved procedure.
+fn resolve_parameters(parameters: &Parameters, procedure: &AssuranceProcedure, procedure_doc: &str, gateways: ProcedureGateways) -> Result<AssuranceProcedure, Error> {
+
+    let resolved_procedure = procedure.resolve_parameters(parameters)
+        .map_err(|error| Error::for_user(error.kind,
+                                         format!("We could not start the collection procedure. {}", error.message)))?;
+
+    if &resolved_procedure == procedure {
+        return Ok(resolved_procedure)
+    }
+
+    (gateways.persist_procedure)(&resolved_procedure, procedure_doc)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("We could not start the collection procedure. Could not write the procedure document with the resolved parameters to '{}': {}", procedure_doc, error.message)))?;
+
+    Ok(resolved_procedure)
+}
+
+fn download_included_procedure(include: &Include, download_dir: &str, gateways: ProcedureGateways) -> Result<AssuranceProcedure, Error> {
 
+    let downloaded_files = (gateways.retrieve_include)(&include.source, download_dir)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("We could not start the collection procedure. Could not download the included procedure '{}': {}", include.source, error.message)))?;
+
+    let procedure_doc = downloaded_files.try_get("assurance-procedure-file")
+        .ok_or_else(|| Error::for_user(Kind::NotFound,
+                                       format!("We could not start the collection procedure. We could not find the procedure definition document of the included procedure '{}'.", include.source)))?;
+
+    (gateways.read_procedure)(&procedure_doc)
+        .map_err(|error| Error::for_system(Kind::GatewayError,
+                                           format!("We could not start the collection procedure. Could not read the included procedure document '{}': {}", procedure_doc, error.message)))
+}
+
+fn copy_included_test_files(include: &Include, included_procedure: &AssuranceProcedure, download_dir: &str, home_dir: &str, create_directories: DirectoryCreationGateway, move_file: FileMoveGateway) -> Result<(), Error> {
+
+    let included_tests = included_procedure.activities.list.iter()
+        .filter(|activity| include.activities.contains(&activity.name))
+        .flat_map(|activity| activity.actions.iter())
+        .filter_map(|action| action.test.as_ref());
+
+    for test in included_tests {
+        let test_file = test.as_str().trim_start_matches("./");
+        let source = format!("{}/{}", download_dir, test_file);
+        let target = match test_file.rsplit_once('/') {
+            Some((test_directory, _)) => format!("{}/{}", home_dir, test_directory),
+            None => home_dir.to_string()
+        };
+
+        let target_directory = DirectoryList::default().try_add("included-test", &target)?;
+        create_directories(&target_directory)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("We could not start the collection procedure. Could not create the directory '{}' for the included test file: {}", target, error.message)))?;
+
+        move_file(&source, &target)
+            .map_err(|error| Error::for_system(Kind::GatewayError,
+                                               format!("We could not start the collection procedure. Could not copy the included test file '{}' to '{}': {}", source, target, error.message)))?;
+    }
+    Ok(())
+}
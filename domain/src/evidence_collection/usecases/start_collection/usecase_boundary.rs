@@ -3,13 +3,14 @@ pub mod request {
     use nape_kernel::error;
     use nape_kernel::error::Error;
     use nape_kernel::values::specification::api_version::APIVersion;
+    use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
     use nape_kernel::values::specification::kind::Kind;
     use nape_kernel::values::specification::metadata::MetaData;
     use nape_kernel::values::specification::procedure::Procedure;
     use nape_kernel::values::specification::subject::Subject;
     use nape_kernel::values::time::start_time::StartTime;
 
-    /// The [`StartProcedure`] defines the beginning of an evidence collection procedure for a business procedure.
+    /// The [`StartProcedure`] defines the beginning of an evidence collection procedure for a business procedure.  The `organisation_nids` are the organisation-defined NIDs which the NRNs of the subject, the procedure, and any included procedure may use.
     #[derive(Clone, Debug)]
     pub struct StartProcedure {
         pub start_time: StartTime,
@@ -18,6 +19,8 @@ pub mod request {
         pub metadata: MetaData,
         pub subject: Subject,
         pub procedure: Procedure,
+        pub tailoring: Tailoring,
+        pub organisation_nids: Vec<String>,
     }
 
     #[derive(Clone, Debug, Default)]
@@ -28,6 +31,8 @@ pub mod request {
         procedure_repository: Option<String>,
         procedure_directory: Option<String>,
         metadata: Vec<(String, String)>,
+        organisation_nids: Vec<String>,
+        tailoring: Tailoring,
         start_time: Option<u128>,
         start_now: bool
     }
@@ -48,6 +53,12 @@ pub mod request {
             self
         }
 
+        /// The organisation-defined NIDs which the Subject NRN, and the NRNs of the procedure and its included procedures, may use, in addition to those built into NAPE.
+        pub fn organisation_nids(mut self, organisation_nids: &[String]) ->  Self {
+            self.organisation_nids = organisation_nids.to_vec();
+            self
+        }
+
         pub fn procedure_repository(mut self, repo_link: &str) ->  Self {
             self.procedure_repository = Some(repo_link.to_string());
             self
@@ -58,6 +69,12 @@ pub mod request {
             self
         }
 
+        /// The [`Tailoring`] of the actions which do not apply to the subject.  When not provided, no actions are excluded.
+        pub fn tailoring(mut self, tailoring: &Tailoring) -> Self {
+            self.tailoring = tailoring.clone();
+            self
+        }
+
         pub fn add_metadata(mut self, key: &str, value: &str) ->Self {
             self.metadata.push((key.to_string(), value.to_string() ) );
             self
@@ -87,7 +104,10 @@ pub mod request {
             let procedure = self.validate_procedure()?;
             let metadata = self.validate_metadata(&start_time)?;
 
-            Ok(StartProcedure { start_time, api_version, kind, metadata, subject, procedure })
+            let tailoring = self.tailoring.clone();
+            let organisation_nids = self.organisation_nids.clone();
+
+            Ok(StartProcedure { start_time, api_version, kind, metadata, subject, procedure, tailoring, organisation_nids })
         }
 
         fn validate_start_time(&self) -> Result<StartTime, Error> {
@@ -110,8 +130,11 @@ pub mod request {
                 .ok_or(self.custom_error("The Subject NRN is required, although it was not provided."))?;
             let subject_id = self.subject_id.as_ref()
                 .ok_or(self.custom_error("The Subject ID is required, although it was not provided."))?;
-            Subject::try_new(nrn, subject_id).map_err(|e|
-                self.custom_error(&format!("There is an issue with your Subject data. '{}'", e.message.as_str())))
+            let subject = Subject::try_new(nrn, subject_id).map_err(|e|
+                self.custom_error(&format!("There is an issue with your Subject data. '{}'", e.message.as_str())))?;
+            subject.nrn.nid.check_allowed(&self.organisation_nids).map_err(|e|
+                self.custom_error(&format!("There is an issue with your Subject data. '{}'", e.message.as_str())))?;
+            Ok(subject)
         }
 
         fn validate_procedure(&self) -> Result<Procedure, Error> {
@@ -218,6 +218,56 @@ mod request_tests {
         assert!(error.message.starts_with("There is an issue with your Start Procedure request. There is an issue with your Subject data. "));
     }
 
+    #[test]
+    fn builder_success_organisation_nid() {
+        let builder = request::StartProcedureBuilder::default()
+            .start_now()
+            .api_version("1.0.0")
+            .subject_nrn("nrn:bank-product:nape/savings")
+            .subject_id("1234")
+            .organisation_nids(&["bank-product".to_string()])
+            .procedure_repository("https://example.com")
+            .procedure_directory("some/dir/location");
+
+        let start_procedure = is_ok!(builder.try_build());
+        assert_eq!(start_procedure.subject.nrn, NRN::new("nrn:bank-product:nape/savings").unwrap());
+    }
+
+    #[test]
+    fn builder_success_tailoring() {
+        let tailoring = specification::assurance_report::tailoring::Tailoring::default()
+            .try_add(specification::assurance_report::exclusion::Exclusion::try_new("action-1", "Not applicable.").unwrap()).unwrap();
+        let builder = request::StartProcedureBuilder::default()
+            .start_now()
+            .api_version("1.0.0")
+            .subject_nrn("nrn:sourcecode:example")
+            .subject_id("1234")
+            .procedure_repository("https://example.com")
+            .procedure_directory("some/dir/location")
+            .tailoring(&tailoring);
+
+        let start_procedure = is_ok!(builder.try_build());
+        assert_eq!(start_procedure.tailoring, tailoring);
+    }
+
+    #[test]
+    fn test_start_procedure_builder_nid_not_allowed() {
+        let builder = request::StartProcedureBuilder::default()
+            .start_now()
+            .api_version("1.0.0")
+            .subject_nrn("nrn:bank-product:nape/savings")
+            .subject_id("1234")
+            .procedure_repository("https://example.com")
+            .procedure_directory("some/dir/location");
+
+        let result = builder.try_build();
+        assert!(result.is_err());
+        let error = result.err().unwrap();
+        assert_eq!(error.kind, error::Kind::InvalidInput);
+        assert_eq!(error.message, "There is an issue with your Start Procedure request. There is an issue with your Subject data. \
+                                   ''bank-product' is not a valid NID. Must be one of: [procedure, sourcecode, service, image, cloudaccount, dataset, pipeline].'");
+    }
+
     #[test]
     fn test_start_procedure_builder_invalid_procedure() {
         let builder = request::StartProcedureBuilder::default()
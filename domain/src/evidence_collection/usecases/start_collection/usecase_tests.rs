@@ -1,8 +1,16 @@
 use nape_kernel::error::{Audience, Error, Kind};
 use nape_kernel::values::directory::directory_list::DirectoryList;
 use nape_kernel::values::specification::file_path::FilePath;
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+use nape_kernel::values::specification::assurance_procedure::condition::Condition;
+use nape_kernel::values::specification::assurance_procedure::include::{Include, Source};
+use nape_kernel::values::specification::assurance_report::exclusion::Exclusion;
+use nape_kernel::values::specification::assurance_report::tailoring::Tailoring;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::{AssuranceProcedure, AssuranceProcedureBuilder};
 use nape_kernel::values::specification::repository_link::RepositoryLink;
-use nape_testing_assertions::kernel_error_eq;
+use nape_testing_assertions::{kernel_error_eq, kernel_error_starts_with};
+use crate::evidence_collection::usecases::start_collection::gateway::ProcedureGateways;
 use crate::evidence_collection::usecases::start_collection::usecase::{start_collection};
 use crate::evidence_collection::usecases::start_collection::usecase_boundary::request::{StartProcedureBuilder, StartProcedure};
 
@@ -16,7 +24,8 @@ fn start_collection_success() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_success,
         file_move_gateway_success,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
@@ -40,7 +49,8 @@ fn start_collection_error_missing_home_dir() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_success,
         file_move_gateway_success,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     kernel_error_eq!(&result,
@@ -65,7 +75,8 @@ fn start_collection_error_missing_activity_dir() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_success,
         file_move_gateway_success,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     kernel_error_eq!(&result,
@@ -91,7 +102,8 @@ fn start_collection_error_missing_temp_dir() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_success,
         file_move_gateway_success,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     kernel_error_eq!(&result,
@@ -118,7 +130,8 @@ fn start_collection_error_move_file_missing_procedure_doc() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_missing_procedure_doc,
         file_move_gateway_success,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     assert!(result.is_err(), "{}", format!("An error was expected although none was returned: {:?}", result.err()));
@@ -141,7 +154,8 @@ fn start_collection_error_move_file_missing_activity_dir() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_missing_activity_dir,
         file_move_gateway_success,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     assert!(result.is_err(), "{}", format!("An error was expected although none was returned: {:?}", result.err()));
@@ -166,6 +180,7 @@ fn start_collection_error_procedure_retrieval_gateway_error() {
         procedure_retrieval_gateway_error,
         file_move_gateway_success,
         file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     kernel_error_eq!(result,
@@ -186,6 +201,7 @@ fn start_collection_error_directory_creation_gateway_error() {
         procedure_retrieval_gateway_success,
         file_move_gateway_success,
         file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     kernel_error_eq!(result,
@@ -206,6 +222,7 @@ fn start_collection_error_file_delete_gateway_error() {
         procedure_retrieval_gateway_success,
         file_move_gateway_success,
         file_delete_gateway_error,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     assert!(result.is_err(), "{}", format!("An error was expected although none was returned: {:?}", result.err()));
@@ -231,7 +248,8 @@ fn start_collection_error_file_move_procedure_doc() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_success,
         file_move_gateway_error_for_procedure_doc,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     assert!(result.is_err(), "{}", format!("An error was expected although none was returned: {:?}", result.err()));
@@ -252,7 +270,8 @@ fn start_collection_error_file_move_activity_dir() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_success,
         file_move_gateway_error_for_activity_dir,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     assert!(result.is_err(), "{}", format!("An error was expected although none was returned: {:?}", result.err()));
@@ -276,7 +295,8 @@ fn success_activity_moved() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_success,
         file_move_gateway_assert_correct_move_targets,
-        file_delete_gateway_success
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
@@ -293,11 +313,322 @@ fn temp_dir_deleted_success() {
         directory_creation_gateway_success,
         procedure_retrieval_gateway_success,
         file_move_gateway_success,
-        file_delete_gateway_assert_temp_dir_deleted
+        file_delete_gateway_assert_temp_dir_deleted,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
+
+}
+
+/* Include Tests */
+
+#[test]
+fn include_success() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_assert_included_test_copied,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_include, persist_procedure: persist_procedure_gateway_assert_included, persist_tailoring: persist_tailoring_gateway_success }
     );
 
     assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
+}
+
+#[test]
+fn include_error_retrieval() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_error, read_procedure: read_procedure_gateway_with_include, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
 
+    kernel_error_eq!(&result,
+        Kind::GatewayError,
+        Audience::System,
+        "We could not start the collection procedure. Could not download the included procedure 'https://example.com/procedures.git//shared@v1.0.0': Include Retrieval Gateway Failure"
+    );
+}
+
+#[test]
+fn include_error_activity_collision() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_colliding_include, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    kernel_error_eq!(&result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not start the collection procedure. We could not include the activities from 'https://example.com/procedures.git//shared@v1.0.0'. The activity 'branch-protection' already exists in the procedure. Activity names must be unique."
+    );
+}
+
+#[test]
+fn include_error_unknown_dependency() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_unknown_dependency, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    kernel_error_eq!(&result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not start the collection procedure. The dependencies of the actions are not valid. The action 'build-check' depends on 'protected-branch', which is not an action of the procedure."
+    );
+}
+
+/* Organisation NID Tests */
+
+#[test]
+fn procedure_organisation_nid_success() {
+    let request = generate_organisation_request(&["bank-product".to_string()]);
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_organisation_nid, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
+}
+
+#[test]
+fn procedure_error_nid_not_allowed() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_organisation_nid, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    kernel_error_starts_with!(&result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not start the collection procedure. There is an issue with the procedure NRN 'nrn:bank-product:example'. 'bank-product' is not a valid NID."
+    );
+}
+
+#[test]
+fn include_error_nid_not_allowed() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_organisation_nid_include, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    kernel_error_starts_with!(&result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not start the collection procedure. There is an issue with the NRN 'nrn:bank-product:shared' of the included procedure 'https://example.com/procedures.git//shared@v1.0.0'. 'bank-product' is not a valid NID."
+    );
+}
+
+/* Tailoring Tests */
+
+#[test]
+fn tailoring_success() {
+    let request = generate_tailored_request("protected-main");
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_include, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_assert_home }
+    );
+
+    assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
+}
+
+#[test]
+fn tailoring_error_unknown_action() {
+    let request = generate_tailored_request("unknown-action");
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    kernel_error_eq!(&result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not start the collection procedure. The excluded action 'unknown-action' is not an action of the procedure. Please check the action names in the tailoring file."
+    );
+}
+
+#[test]
+fn tailoring_error_persist() {
+    let request = generate_tailored_request("build-check");
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_without_includes, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_error }
+    );
+
+    kernel_error_eq!(&result,
+        Kind::GatewayError,
+        Audience::System,
+        "We could not start the collection procedure. Could not write the tailoring to 'nrn_sourcecode_example/1714646108364': Persist Tailoring Gateway Failure"
+    );
+}
+
+/* Parameter Tests */
+
+#[test]
+fn parameters_success() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_parameters, persist_procedure: persist_procedure_gateway_assert_resolved, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
+}
+
+#[test]
+fn parameters_error_unresolved() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_unknown_parameter, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    kernel_error_eq!(&result,
+        Kind::InvalidInput,
+        Audience::User,
+        "We could not start the collection procedure. We could not resolve the parameters of the action 'build-check'. There is an issue with a procedure parameter: The placeholder '${meta.environment}' in './evidence/${meta.environment}/build-check.json' could not be resolved. The available parameters are: [meta.utc-start, subject.nrn, subject.nid, subject.nss, subject.id]."
+    );
+}
+
+#[test]
+fn parameters_error_persist() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_parameters, persist_procedure: persist_procedure_gateway_error, persist_tailoring: persist_tailoring_gateway_success }
+    );
+
+    kernel_error_eq!(&result,
+        Kind::GatewayError,
+        Audience::System,
+        "We could not start the collection procedure. Could not write the procedure document with the resolved parameters to 'some/path/to/moved_file/success.txt': Persist Procedure Gateway Failure"
+    );
+}
+
+/* Condition Tests */
+
+#[test]
+fn conditions_not_met_success() {
+    let request = generate_valid_request();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_conditions, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_assert_conditions }
+    );
+
+    assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
+}
+
+#[test]
+fn conditions_met_success() {
+    let request = StartProcedureBuilder::default()
+        .start_at(1714646108364)
+        .api_version("1.0.0")
+        .subject_nrn("nrn:sourcecode:example")
+        .subject_id("123456789")
+        .procedure_repository("https://example.com")
+        .procedure_directory("some/location")
+        .add_metadata("pci", "true")
+        .try_build()
+        .unwrap();
+    let directory_list = generate_valid_directory_list();
+    let result = start_collection(
+        &request,
+        &directory_list,
+        directory_creation_gateway_success,
+        procedure_retrieval_gateway_success,
+        file_move_gateway_success,
+        file_delete_gateway_success,
+        ProcedureGateways { retrieve_include: include_retrieval_gateway_success, read_procedure: read_procedure_gateway_with_conditions, persist_procedure: persist_procedure_gateway_success, persist_tailoring: persist_tailoring_gateway_unused }
+    );
+
+    assert!(result.is_ok(), "{}", format!("An error was returned when one was not expected: {:?}", result.err()));
 }
 
 /***
@@ -317,6 +648,36 @@ fn generate_valid_request() -> StartProcedure {
         .unwrap()
 }
 
+/// Assumes canned start time of 1714646108364
+fn generate_tailored_request(excluded_action: &str) -> StartProcedure {
+    let tailoring = Tailoring::default()
+        .try_add(Exclusion::try_new(excluded_action, "The subject does not apply.").unwrap()).unwrap();
+    StartProcedureBuilder::default()
+        .start_at(1714646108364)
+        .api_version("1.0.0")
+        .subject_nrn("nrn:sourcecode:example")
+        .subject_id("123456789")
+        .procedure_repository("https://example.com")
+        .procedure_directory("some/location")
+        .tailoring(&tailoring)
+        .try_build()
+        .unwrap()
+}
+
+/// Assumes canned start time of 1714646108364
+fn generate_organisation_request(organisation_nids: &[String]) -> StartProcedure {
+    StartProcedureBuilder::default()
+        .start_at(1714646108364)
+        .api_version("1.0.0")
+        .subject_nrn("nrn:sourcecode:example")
+        .subject_id("123456789")
+        .procedure_repository("https://example.com")
+        .procedure_directory("some/location")
+        .organisation_nids(organisation_nids)
+        .try_build()
+        .unwrap()
+}
+
 /// Assumes canned start time of 1714646108364
 fn generate_valid_directory_list() -> DirectoryList {
     let directories: Vec<(String, String)> = [
@@ -408,3 +769,199 @@ fn file_delete_gateway_assert_temp_dir_deleted(source: &str) -> Result<(), Error
     Ok(())
 }
 
+
+
+/* Include Mocks */
+
+const INCLUDED_PROCEDURE_DOC: &str = "/some/path/to/include/assurance_procedure.yaml";
+
+fn include_retrieval_gateway_success(_source: &Source, download_dir: &str) -> Result<DirectoryList, Error> {
+    assert_eq!(download_dir, "nrn_sourcecode_example/1714646108364/temp/include-0");
+    Ok(DirectoryList::default()
+        .try_add("assurance-procedure-file", INCLUDED_PROCEDURE_DOC)?
+        .try_add("activity-dir", ACTIVITY_DIR)?)
+}
+
+fn include_retrieval_gateway_error(_source: &Source, _download_dir: &str) -> Result<DirectoryList, Error> {
+    Err(Error::for_system(Kind::GatewayError, "Include Retrieval Gateway Failure".to_string()))
+}
+
+fn read_procedure_gateway_without_includes(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+    Ok(generate_procedure("nrn:procedure:example", "build", "build-check").try_build()?)
+}
+
+fn read_procedure_gateway_with_include(file_path: &str) -> Result<AssuranceProcedure, Error> {
+    if file_path == INCLUDED_PROCEDURE_DOC {
+        return generate_procedure("nrn:procedure:shared", "branch-protection", "protected-main").try_build();
+    }
+    generate_procedure("nrn:procedure:example", "build", "build-check").add_include(&generate_include()).try_build()
+}
+
+fn read_procedure_gateway_with_organisation_nid(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+    generate_procedure("nrn:bank-product:example", "build", "build-check").try_build()
+}
+
+fn read_procedure_gateway_with_organisation_nid_include(file_path: &str) -> Result<AssuranceProcedure, Error> {
+    if file_path == INCLUDED_PROCEDURE_DOC {
+        return generate_procedure("nrn:bank-product:shared", "branch-protection", "protected-main").try_build();
+    }
+    generate_procedure("nrn:procedure:example", "build", "build-check").add_include(&generate_include()).try_build()
+}
+
+fn read_procedure_gateway_with_colliding_include(file_path: &str) -> Result<AssuranceProcedure, Error> {
+    if file_path == INCLUDED_PROCEDURE_DOC {
+        return generate_procedure("nrn:procedure:shared", "branch-protection", "protected-main").try_build();
+    }
+    generate_procedure("nrn:procedure:example", "branch-protection", "build-check").add_include(&generate_include()).try_build()
+}
+
+fn read_procedure_gateway_with_unknown_dependency(file_path: &str) -> Result<AssuranceProcedure, Error> {
+    if file_path == INCLUDED_PROCEDURE_DOC {
+        return generate_procedure("nrn:procedure:shared", "branch-protection", "protected-main").try_build();
+    }
+    let action = Action::builder()
+        .name("build-check")
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path("./activity/build/build-check.py")
+        .evidence_file_path("./evidence/build/build-check.json")
+        .add_dependency("protected-branch")
+        .try_build()?;
+    AssuranceProcedure::builder()
+        .api_version("1.4.0")
+        .procedure_info("nrn:procedure:example", "Short Desc", "Long Desc")
+        .add_activity(&Activity::new("build", "Short Desc", "Long Desc")?.add(action))
+        .add_include(&generate_include())
+        .try_build()
+}
+
+fn persist_procedure_gateway_success(_procedure: &AssuranceProcedure, _file_path: &str) -> Result<(), Error> {
+    Ok(())
+}
+
+fn persist_procedure_gateway_assert_included(procedure: &AssuranceProcedure, _file_path: &str) -> Result<(), Error> {
+    assert!(procedure.includes.is_empty());
+    assert_eq!(procedure.activities.count(), 2);
+    assert_eq!(procedure.activities.list[1].name.value, "branch-protection");
+    assert_eq!(procedure.activities.list[1].source, Some(generate_include().source));
+    Ok(())
+}
+
+/* Parameter Mocks */
+
+fn read_procedure_gateway_with_parameters(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+    let action = Action::builder()
+        .name("build-check")
+        .short_description("Check build ${subject.id}")
+        .long_description("Long Desc")
+        .test_file_path("./activity/build/build-check.py")
+        .evidence_file_path("./evidence/${subject.id}/build-check.json")
+        .try_build()?;
+
+    AssuranceProcedure::builder()
+        .api_version("1.2.0")
+        .procedure_info("nrn:procedure:example", "Short Desc", "Long Desc")
+        .add_activity(&Activity::new("build", "Short Desc", "Long Desc")?.add(action))
+        .try_build()
+}
+
+fn read_procedure_gateway_with_unknown_parameter(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+    let action = Action::builder()
+        .name("build-check")
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path("./activity/build/build-check.py")
+        .evidence_file_path("./evidence/${meta.environment}/build-check.json")
+        .try_build()?;
+
+    AssuranceProcedure::builder()
+        .api_version("1.2.0")
+        .procedure_info("nrn:procedure:example", "Short Desc", "Long Desc")
+        .add_activity(&Activity::new("build", "Short Desc", "Long Desc")?.add(action))
+        .try_build()
+}
+
+fn persist_procedure_gateway_assert_resolved(procedure: &AssuranceProcedure, _file_path: &str) -> Result<(), Error> {
+    let action = procedure.activities.find_action("build-check").unwrap();
+    assert_eq!(action.short.value, "Check build 123456789");
+    assert_eq!(action.evidence.as_str(), "./evidence/123456789/build-check.json");
+    Ok(())
+}
+
+fn persist_procedure_gateway_error(_procedure: &AssuranceProcedure, _file_path: &str) -> Result<(), Error> {
+    Err(Error::for_system(Kind::GatewayError, "Persist Procedure Gateway Failure".to_string()))
+}
+
+/* Condition Mocks */
+
+fn read_procedure_gateway_with_conditions(_file_path: &str) -> Result<AssuranceProcedure, Error> {
+    let action = Action::builder()
+        .name("card-data-encrypted")
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path("./activity/pci/card-data-encrypted.py")
+        .evidence_file_path("./evidence/pci/card-data-encrypted.json")
+        .try_build()?;
+    let pci = Activity::new("pci", "Short Desc", "Long Desc")?
+        .add(action)
+        .when(&Condition::try_from("meta.pci == true")?);
+
+    generate_procedure("nrn:procedure:example", "build", "build-check")
+        .add_activity(&pci)
+        .try_build()
+}
+
+fn persist_tailoring_gateway_assert_conditions(tailoring: &Tailoring, _home_directory: &str) -> Result<(), Error> {
+    assert_eq!(tailoring.list().len(), 1);
+    assert_eq!(tailoring.list()[0].action.value, "card-data-encrypted");
+    assert_eq!(tailoring.list()[0].justification.value, "The condition 'meta.pci == true' is not met.");
+    Ok(())
+}
+
+fn persist_tailoring_gateway_unused(_tailoring: &Tailoring, _home_directory: &str) -> Result<(), Error> {
+    panic!("The tailoring must not be written when no actions are excluded.")
+}
+
+/* Tailoring Mocks */
+
+fn persist_tailoring_gateway_success(_tailoring: &Tailoring, _home_directory: &str) -> Result<(), Error> {
+    Ok(())
+}
+
+fn persist_tailoring_gateway_error(_tailoring: &Tailoring, _home_directory: &str) -> Result<(), Error> {
+    Err(Error::for_system(Kind::GatewayError, "Persist Tailoring Gateway Failure".to_string()))
+}
+
+fn persist_tailoring_gateway_assert_home(tailoring: &Tailoring, home_directory: &str) -> Result<(), Error> {
+    assert_eq!(home_directory, "nrn_sourcecode_example/1714646108364");
+    assert_eq!(tailoring.list()[0].action.value, "protected-main");
+    Ok(())
+}
+
+fn file_move_gateway_assert_included_test_copied(source: &str, target: &str) -> Result<FilePath, Error> {
+    if source.contains("include-0") {
+        assert_eq!(source, "nrn_sourcecode_example/1714646108364/temp/include-0/activity/branch-protection/protected-main.py");
+        assert_eq!(target, "nrn_sourcecode_example/1714646108364/activity/branch-protection");
+    }
+    Ok(FilePath::from("some/path/to/moved_file/success.txt"))
+}
+
+fn generate_include() -> Include {
+    let source = Source::try_new("https://example.com/procedures.git", "shared", Some("v1.0.0")).unwrap();
+    Include::try_new(&source, &["branch-protection".to_string()]).unwrap()
+}
+
+fn generate_procedure(nrn: &str, activity_name: &str, action_name: &str) -> AssuranceProcedureBuilder {
+    let action = Action::builder()
+        .name(action_name)
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path(&format!("./activity/{}/{}.py", activity_name, action_name))
+        .evidence_file_path(&format!("./evidence/{}/{}.json", activity_name, action_name))
+        .try_build().unwrap();
+
+    AssuranceProcedure::builder()
+        .api_version("1.2.0")
+        .procedure_info(nrn, "Short Desc", "Long Desc")
+        .add_activity(&Activity::new(activity_name, "Short Desc", "Long Desc").unwrap().add(action))
+}
@@ -1 +1,2 @@
-pub mod evidence_collection;
\ No newline at end of file
+pub mod evidence_collection;
+pub mod procedure_management;
@@ -0,0 +1,31 @@
+use nape_kernel::error::Error;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use crate::procedure_management::usecases::list_procedures::gateway_boundary::ProcedureFile;
+
+/// # Overview
+///
+/// The [`FindProcedureFilesGateway`] walks a procedure repository and returns every assurance procedure file within it.
+///
+/// # Arguments
+///
+/// * `repository` - The procedure repository, such as a git repository URL or a local directory.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`ProcedureFile`]s in the repository, or an [`Error`].
+///
+pub type FindProcedureFilesGateway = fn(repository: &str) -> Result<Vec<ProcedureFile>, Error>;
+
+/// # Overview
+///
+/// The [`ParseProcedureGateway`] reads the content of an assurance procedure file into an [`AssuranceProcedure`].
+///
+/// # Arguments
+///
+/// * `content` - The content of the assurance procedure file.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`AssuranceProcedure`], or an [`Error`] if the content is not a valid assurance procedure.
+///
+pub type ParseProcedureGateway = fn(content: &str) -> Result<AssuranceProcedure, Error>;
@@ -0,0 +1,13 @@
+/// The name of the file which defines a procedure.  Every directory which contains this file is a procedure directory.
+pub const PROCEDURE_FILE_NAME: &str = "assurance_procedure.yaml";
+
+/// The [`ProcedureFile`] is an assurance procedure file found in a procedure repository.
+///
+/// * `directory` - The procedure directory, relative to the root of the repository, which is the `--procedure-directory` for `collect start`.
+/// * `content` - The content of the assurance procedure file.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProcedureFile {
+    pub directory: String,
+    pub content: String,
+}
@@ -0,0 +1,111 @@
+use nape_kernel::error::{Error, Kind};
+use crate::procedure_management::usecases::list_procedures::gateway::{FindProcedureFilesGateway, ParseProcedureGateway};
+
+/// The [`ListProceduresRequest`] is the request to discover the procedures in a procedure repository.
+///
+/// * `repository` - The procedure repository, such as a git repository URL or a local directory.
+/// * `organisation_nids` - The organisation-defined NIDs which the NRN of a procedure may use.
+///
+#[derive(Clone, Debug)]
+pub struct ListProceduresRequest<'a> {
+    pub repository: &'a str,
+    pub organisation_nids: &'a [String],
+}
+
+/// The [`ProcedureSummary`] describes a procedure found in a procedure repository.
+///
+/// * `directory` - The procedure directory, relative to the root of the repository.
+/// * `nrn` - The NRN of the procedure.
+/// * `short_description` - The short description of the procedure.
+/// * `activity_count` - The number of activities in the procedure.
+/// * `action_count` - The number of actions across all the activities of the procedure.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ProcedureSummary {
+    pub directory: String,
+    pub nrn: String,
+    pub short_description: String,
+    pub activity_count: usize,
+    pub action_count: usize,
+}
+
+/// The [`InvalidProcedure`] is an assurance procedure file found in a procedure repository which could not be read.
+///
+/// * `directory` - The procedure directory, relative to the root of the repository.
+/// * `reason` - Why the assurance procedure file could not be read.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct InvalidProcedure {
+    pub directory: String,
+    pub reason: String,
+}
+
+/// The [`ListedProcedures`] is the result of discovering the procedures in a procedure repository, ordered by their directory.
+///
+/// * `procedures` - The [`ProcedureSummary`] of each procedure.
+/// * `invalid` - The [`InvalidProcedure`]s, which are reported rather than stopping the discovery.
+///
+#[derive(Clone, Debug, Default)]
+pub struct ListedProcedures {
+    pub procedures: Vec<ProcedureSummary>,
+    pub invalid: Vec<InvalidProcedure>,
+}
+
+/// `UCListProcedures` is a function pointer type that represents the use case for discovering the procedures in a procedure repository.
+///
+/// # Parameters
+///
+/// * `request`: The [`ListProceduresRequest`] which identifies the procedure repository.
+///
+/// # Returns
+///
+/// This function returns a [`Result`] that contains the [`ListedProcedures`], or an [`Error`] if the repository could not be read.
+pub type UCListProcedures = fn(request: &ListProceduresRequest) -> Result<ListedProcedures, Error>;
+
+/// # Overview
+///
+/// The `list_procedures` use case finds every directory in a procedure repository which contains an assurance procedure file, and summarises the procedure it defines, so the `--procedure-directory` for `collect start` does not have to be known in advance.
+///
+/// # Arguments
+///
+/// * `request` - The [`ListProceduresRequest`].
+/// * `find_procedure_files` - An implementation of the [`FindProcedureFilesGateway`] gateway for the repository.
+/// * `parse_procedure` - An implementation of the [`ParseProcedureGateway`] gateway.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`ListedProcedures`], or an [`Error`].
+///
+/// # Errors
+///
+/// * An [`Error`] is returned if the repository could not be read.  An assurance procedure file which can not be parsed, or whose NRN uses an NID which is neither built into NAPE nor one of the `organisation_nids`, is reported as an [`InvalidProcedure`] instead.
+///
+pub fn list_procedures(request: &ListProceduresRequest,
+                       find_procedure_files: FindProcedureFilesGateway,
+                       parse_procedure: ParseProcedureGateway) -> Result<ListedProcedures, Error> {
+
+    let mut procedure_files = find_procedure_files(request.repository)
+        .map_err(|e| Error::for_system(Kind::GatewayError,
+                                       format!("There was an issue finding the procedures in the repository '{}'. {}", request.repository, e.message)))?;
+    procedure_files.sort_by(|a, b| a.directory.cmp(&b.directory));
+
+    let mut listed = ListedProcedures::default();
+    for procedure_file in procedure_files {
+        let parsed = parse_procedure(&procedure_file.content)
+            .and_then(|procedure| procedure.procedure.nrn.nid.check_allowed(request.organisation_nids).map(|_| procedure));
+        match parsed {
+            Ok(procedure) => listed.procedures.push(ProcedureSummary {
+                directory: procedure_file.directory,
+                nrn: procedure.procedure.nrn.to_string(),
+                short_description: procedure.procedure.short.value.clone(),
+                activity_count: procedure.activities.count(),
+                action_count: procedure.activities.action_count(),
+            }),
+            Err(e) => listed.invalid.push(InvalidProcedure {
+                directory: procedure_file.directory,
+                reason: e.message,
+            }),
+        }
+    }
+    Ok(listed)
+}
@@ -0,0 +1,110 @@
+use nape_kernel::error::{Audience, Error, Kind};
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::procedure_management::usecases::list_procedures::gateway_boundary::ProcedureFile;
+use crate::procedure_management::usecases::list_procedures::usecase::{list_procedures, InvalidProcedure, ListProceduresRequest, ProcedureSummary};
+
+/*** Happy Path Tests ***/
+
+#[test]
+fn list_success() {
+    let result = list_procedures(&request(), find_procedure_files_success, parse_nrn_as_procedure);
+
+    let listed = is_ok!(result);
+    assert_eq!(listed.procedures, vec![
+        ProcedureSummary {
+            directory: "rust-ci/sourcecode-integration".to_string(),
+            nrn: "nrn:procedure:nape:rust-ci/sourcecode-integration".to_string(),
+            short_description: "A procedure for testing".to_string(),
+            activity_count: 1,
+            action_count: 2,
+        },
+        ProcedureSummary {
+            directory: "service/change-approval".to_string(),
+            nrn: "nrn:procedure:nape:service/change-approval".to_string(),
+            short_description: "A procedure for testing".to_string(),
+            activity_count: 1,
+            action_count: 2,
+        },
+    ]);
+    assert_eq!(listed.invalid, vec![
+        InvalidProcedure { directory: "drafts".to_string(), reason: "Some ParseProcedure Gateway Error".to_string() },
+    ]);
+}
+
+/*** Sad Path Tests ***/
+
+#[test]
+fn list_unregistered_nid_invalid() {
+    let find_custom_nids = |_repository: &str| -> Result<Vec<ProcedureFile>, Error> {
+        Ok(vec![
+            procedure_file("bank", "nrn:bank-product:nape:accounts"),
+            procedure_file("payments", "nrn:payments-product:nape:cards"),
+        ])
+    };
+    let organisation_nids = vec!["payments-product".to_string()];
+
+    let result = list_procedures(&ListProceduresRequest { organisation_nids: &organisation_nids, ..request() }, find_custom_nids, parse_nrn_as_procedure);
+
+    let listed = is_ok!(result);
+    let directories: Vec<&str> = listed.procedures.iter().map(|procedure| procedure.directory.as_str()).collect();
+    assert_eq!(directories, vec!["payments"]);
+    assert_eq!(listed.invalid, vec![
+        InvalidProcedure { directory: "bank".to_string(), reason: "'bank-product' is not a valid NID. Must be one of: [procedure, sourcecode, service, image, cloudaccount, dataset, pipeline, payments-product].".to_string() },
+    ]);
+}
+
+#[test]
+fn find_procedure_files_gateway_error() {
+    let find_error = |_repository: &str| -> Result<Vec<ProcedureFile>, Error> {
+        Err(Error::for_system(Kind::GatewayError, "Some FindProcedureFiles Gateway Error".to_string()))
+    };
+
+    let result = list_procedures(&request(), find_error, parse_nrn_as_procedure);
+
+    kernel_error_eq!(result, Kind::GatewayError, Audience::System,
+        "There was an issue finding the procedures in the repository 'https://github.com/nape/procedures.git'. Some FindProcedureFiles Gateway Error");
+}
+
+/*** Testing Utilities & Mocks ***/
+
+fn request() -> ListProceduresRequest<'static> {
+    ListProceduresRequest { repository: "https://github.com/nape/procedures.git", organisation_nids: &[] }
+}
+
+fn procedure_file(directory: &str, content: &str) -> ProcedureFile {
+    ProcedureFile { directory: directory.to_string(), content: content.to_string() }
+}
+
+fn find_procedure_files_success(_repository: &str) -> Result<Vec<ProcedureFile>, Error> {
+    Ok(vec![
+        procedure_file("service/change-approval", "nrn:procedure:nape:service/change-approval"),
+        procedure_file("drafts", "invalid"),
+        procedure_file("rust-ci/sourcecode-integration", "nrn:procedure:nape:rust-ci/sourcecode-integration"),
+    ])
+}
+
+/// Reads the content of the procedure file as the NRN of a procedure with one activity and two actions.
+fn parse_nrn_as_procedure(content: &str) -> Result<AssuranceProcedure, Error> {
+    if content == "invalid" {
+        return Err(Error::for_system(Kind::ProcessingFailure, "Some ParseProcedure Gateway Error".to_string()));
+    }
+    let action = |name: &str| Action::builder()
+        .name(name)
+        .short_description("An action for testing")
+        .long_description("An action for testing the listing of procedures")
+        .test_file_path(&format!("{}.rego", name))
+        .evidence_file_path(&format!("{}.json", name))
+        .try_build()
+        .unwrap();
+    let activity = Activity::new("peer-review", "An activity for testing", "An activity for testing the listing of procedures").unwrap()
+        .add(action("at-least-one-reviewer"))
+        .add(action("no-self-approval"));
+    AssuranceProcedure::builder()
+        .api_version("1.0.0")
+        .procedure_info(content, "A procedure for testing", "A procedure for testing the listing of procedures")
+        .add_activity(&activity)
+        .try_build()
+}
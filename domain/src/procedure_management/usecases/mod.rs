@@ -0,0 +1,2 @@
+pub mod list_procedures;
+pub mod scaffold_procedure;
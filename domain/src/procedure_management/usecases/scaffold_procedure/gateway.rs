@@ -0,0 +1,36 @@
+use nape_kernel::error::Error;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use crate::procedure_management::usecases::scaffold_procedure::gateway_boundary::ScaffoldFile;
+
+/// # Overview
+///
+/// The [`SerializeProcedureGateway`] writes an [`AssuranceProcedure`] as the content of an assurance procedure file.
+///
+/// # Arguments
+///
+/// * `procedure` - The [`AssuranceProcedure`] to serialize.
+///
+/// # Returns
+///
+/// A [`Result`] of either the content of the assurance procedure file, or an [`Error`].
+///
+pub type SerializeProcedureGateway = fn(procedure: &AssuranceProcedure) -> Result<String, Error>;
+
+/// # Overview
+///
+/// The [`WriteScaffoldGateway`] writes the files generated for a new procedure to the procedure directory.
+///
+/// # Arguments
+///
+/// * `directory` - The procedure directory, which is created when it does not exist.
+/// * `files` - The [`ScaffoldFile`]s to write, relative to the `directory`.
+///
+/// # Returns
+///
+/// A [`Result`] of either the paths of the files written, or an [`Error`].
+///
+/// # Design Decision
+///
+/// * An existing file is never overwritten, so an [`Error`] is returned before anything is written when one of the files already exists.
+///
+pub type WriteScaffoldGateway = fn(directory: &str, files: &[ScaffoldFile]) -> Result<Vec<String>, Error>;
@@ -0,0 +1,10 @@
+/// The [`ScaffoldFile`] is a file generated for a new procedure.
+///
+/// * `path` - The path of the file, relative to the procedure directory.
+/// * `content` - The content of the file.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ScaffoldFile {
+    pub path: String,
+    pub content: String,
+}
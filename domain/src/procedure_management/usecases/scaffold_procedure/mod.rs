@@ -0,0 +1,4 @@
+pub mod gateway;
+pub mod gateway_boundary;
+pub mod usecase;
+#[cfg(test)] mod usecase_tests;
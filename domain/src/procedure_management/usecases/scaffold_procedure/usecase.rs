@@ -0,0 +1,189 @@
+use std::collections::HashSet;
+use nape_kernel::error::{Error, Kind};
+use nape_kernel::values::nrn::nrn::{NapeNID, NRN};
+use nape_kernel::values::specification::assurance_procedure::action::Action;
+use nape_kernel::values::specification::assurance_procedure::activity::Activity;
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use crate::procedure_management::usecases::list_procedures::gateway::ParseProcedureGateway;
+use crate::procedure_management::usecases::list_procedures::gateway_boundary::PROCEDURE_FILE_NAME;
+use crate::procedure_management::usecases::scaffold_procedure::gateway::{SerializeProcedureGateway, WriteScaffoldGateway};
+use crate::procedure_management::usecases::scaffold_procedure::gateway_boundary::ScaffoldFile;
+
+/// The schema version new procedures are written with.
+const SCAFFOLD_API_VERSION: &str = "1.1.0";
+
+/// The [`ActivityOutline`] names an activity of a new procedure and its actions.
+///
+/// * `name` - The name of the activity.
+/// * `actions` - The names of the actions of the activity.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ActivityOutline {
+    pub name: String,
+    pub actions: Vec<String>,
+}
+
+/// The [`ScaffoldProcedureRequest`] is the request to generate a new procedure.
+///
+/// * `nrn` - The NRN of the procedure, which must use the 'procedure' NID.
+/// * `short_description` - The short description of the procedure.  A placeholder is used when it is not provided.
+/// * `activities` - The [`ActivityOutline`] of each activity.
+/// * `directory` - The procedure directory to write to.  The last NSS of the NRN is used when it is not provided.
+///
+#[derive(Clone, Debug)]
+pub struct ScaffoldProcedureRequest<'a> {
+    pub nrn: &'a str,
+    pub short_description: Option<&'a str>,
+    pub activities: Vec<ActivityOutline>,
+    pub directory: Option<&'a str>,
+}
+
+/// The [`ScaffoldedProcedure`] is the result of generating a new procedure.
+///
+/// * `directory` - The procedure directory which was written to.
+/// * `files` - The paths of the files written.
+///
+#[derive(Clone, Debug)]
+pub struct ScaffoldedProcedure {
+    pub directory: String,
+    pub files: Vec<String>,
+}
+
+/// `UCScaffoldProcedure` is a function pointer type that represents the use case for generating a new procedure.
+///
+/// # Parameters
+///
+/// * `request`: The [`ScaffoldProcedureRequest`] which outlines the procedure.
+///
+/// # Returns
+///
+/// This function returns a [`Result`] that contains the [`ScaffoldedProcedure`], or an [`Error`] if the procedure could not be generated.
+pub type UCScaffoldProcedure = fn(request: &ScaffoldProcedureRequest) -> Result<ScaffoldedProcedure, Error>;
+
+/// # Overview
+///
+/// The `scaffold_procedure` use case generates a new procedure from an outline of its activities and actions.  The assurance procedure file is built with the [`AssuranceProcedure`] builder, and a placeholder test is generated for each action at the path of its `test`, such as './activity/peer-review/at-least-two-reviewers.py'.  The `evidence` of each action is './evidence/<activity>/<action>.json'.
+///
+/// The assurance procedure file is read back with the [`ParseProcedureGateway`] before anything is written, so the new procedure is always valid.
+///
+/// # Arguments
+///
+/// * `request` - The [`ScaffoldProcedureRequest`].
+/// * `serialize_procedure` - An implementation of the [`SerializeProcedureGateway`] gateway.
+/// * `parse_procedure` - An implementation of the [`ParseProcedureGateway`] gateway.
+/// * `write_scaffold` - An implementation of the [`WriteScaffoldGateway`] gateway.
+///
+/// # Returns
+///
+/// A [`Result`] of either the [`ScaffoldedProcedure`], or an [`Error`].
+///
+/// # Errors
+///
+/// * An [`Error`] for [`Audience::User`] with [`Kind::InvalidInput`] is returned if the NRN does not use the 'procedure' NID, there are no activities, an activity has no actions, an action is named more than once, or a name is not valid.
+/// * An [`Error`] is returned if one of the gateways fails.
+///
+pub fn scaffold_procedure(request: &ScaffoldProcedureRequest,
+                          serialize_procedure: SerializeProcedureGateway,
+                          parse_procedure: ParseProcedureGateway,
+                          write_scaffold: WriteScaffoldGateway) -> Result<ScaffoldedProcedure, Error> {
+
+    let nrn = NRN::new(request.nrn)
+        .map_err(|e| user_error(&e.message))?;
+    if nrn.nid != NapeNID::Procedure {
+        return Err(user_error(&format!("The NRN '{}' must use the 'procedure' NID, such as 'nrn:procedure:{}'.", request.nrn, nss_value(&nrn))));
+    }
+
+    let procedure = build_procedure(request)?;
+
+    let procedure_content = serialize_procedure(&procedure)
+        .map_err(|e| Error::for_system(Kind::GatewayError,
+                                       format!("There was an issue writing the procedure '{}'. {}", request.nrn, e.message)))?;
+    parse_procedure(&procedure_content)
+        .map_err(|e| Error::for_system(Kind::ProcessingFailure,
+                                       format!("The generated procedure '{}' is not valid. {}", request.nrn, e.message)))?;
+
+    let mut files = vec![ScaffoldFile { path: PROCEDURE_FILE_NAME.to_string(), content: procedure_content }];
+    for activity in &procedure.activities.list {
+        for action in &activity.actions {
+            if let Some(test) = &action.test {
+                files.push(ScaffoldFile {
+                    path: test.as_str().trim_start_matches("./").to_string(),
+                    content: placeholder_test(&activity.name.value, &action.name.value, action.evidence.as_str()),
+                });
+            }
+        }
+    }
+
+    let directory = match request.directory {
+        Some(directory) => directory.to_string(),
+        None => default_directory(&nrn),
+    };
+    let written = write_scaffold(&directory, &files)
+        .map_err(|e| Error::for_system(Kind::GatewayError,
+                                       format!("There was an issue writing the procedure to '{}'. {}", directory, e.message)))?;
+
+    Ok(ScaffoldedProcedure { directory, files: written })
+}
+
+fn build_procedure(request: &ScaffoldProcedureRequest) -> Result<AssuranceProcedure, Error> {
+
+    if request.activities.is_empty() {
+        return Err(user_error("At least one activity is required."));
+    }
+
+    let short_description = request.short_description.unwrap_or("Describe the procedure.");
+    let mut builder = AssuranceProcedure::builder()
+        .api_version(SCAFFOLD_API_VERSION)
+        .procedure_info(request.nrn, short_description, "Describe the purpose of the procedure, and what it assures.");
+
+    let mut action_names = HashSet::new();
+    for outline in &request.activities {
+        if outline.actions.is_empty() {
+            return Err(user_error(&format!("The activity '{}' must have at least one action.", outline.name)));
+        }
+        let mut activity = Activity::new(&outline.name, &format!("Describe the {} activity.", outline.name), "Describe what the activity verifies.")
+            .map_err(|e| user_error(&e.message))?;
+        for action_name in &outline.actions {
+            if !action_names.insert(action_name.to_lowercase()) {
+                return Err(user_error(&format!("The action '{}' is named more than once. Each action must have a unique name.", action_name)));
+            }
+            let action = Action::builder()
+                .name(action_name)
+                .short_description(&format!("Describe the {} action.", action_name))
+                .long_description("Describe what the test of the action checks in the evidence.")
+                .test_file_path(&format!("./activity/{}/{}.py", activity.name.value, action_name.to_lowercase()))
+                .evidence_file_path(&format!("./evidence/{}/{}.json", activity.name.value, action_name.to_lowercase()))
+                .try_build()
+                .map_err(|e| user_error(&e.message))?;
+            activity = activity.add(action);
+        }
+        builder = builder.add_activity(&activity);
+    }
+
+    builder.try_build().map_err(|e| user_error(&e.message))
+}
+
+fn placeholder_test(activity_name: &str, action_name: &str, evidence: &str) -> String {
+    format!("# The test for the action '{}' of the activity '{}'.\n\
+             # It is evaluated by 'nape-eval' against the evidence collected to '{}'.\n\
+             # Replace this placeholder with the checks the action requires.\n",
+            action_name, activity_name, evidence)
+}
+
+/// The last part of the last NSS of the NRN, such as 'sourcecode-integration' for 'nrn:procedure:nape:rust-ci/sourcecode-integration'.
+fn default_directory(nrn: &NRN) -> String {
+    nrn.nss.iter().rev()
+        .filter_map(|nss| nss.value.rsplit('/').find(|part| !part.is_empty()))
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn nss_value(nrn: &NRN) -> String {
+    nrn.nss.iter().map(|nss| nss.value.as_str()).collect::<Vec<&str>>().join(":")
+}
+
+fn user_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput,
+                    format!("We could not create the procedure. {}", message))
+}
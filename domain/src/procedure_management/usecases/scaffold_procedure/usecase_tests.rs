@@ -0,0 +1,157 @@
+use nape_kernel::error::{Audience, Error, Kind};
+use nape_kernel::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::procedure_management::usecases::scaffold_procedure::gateway_boundary::ScaffoldFile;
+use crate::procedure_management::usecases::scaffold_procedure::usecase::{scaffold_procedure, ActivityOutline, ScaffoldProcedureRequest};
+
+/*** Happy Path Tests ***/
+
+#[test]
+fn scaffold_success() {
+    let serialize_assertion = |procedure: &AssuranceProcedure| -> Result<String, Error> {
+        assert_eq!(procedure.api_version.as_string(), "1.1.0");
+        assert_eq!(procedure.procedure.short.value, "Rust CI Assurance Procedure");
+        assert_eq!(procedure.activities.count(), 2);
+        assert_eq!(procedure.activities.action_count(), 3);
+        let action = procedure.activities.find_action("requester-not-a-reviewer").unwrap();
+        assert_eq!(action.test.as_ref().unwrap().as_str(), "./activity/peer-review/requester-not-a-reviewer.py");
+        assert_eq!(action.evidence.as_str(), "./evidence/peer-review/requester-not-a-reviewer.json");
+        Ok(procedure.procedure.nrn.to_string())
+    };
+    let write_assertion = |directory: &str, files: &[ScaffoldFile]| -> Result<Vec<String>, Error> {
+        assert_eq!(directory, "sourcecode-integration");
+        let paths: Vec<&str> = files.iter().map(|file| file.path.as_str()).collect();
+        assert_eq!(paths, vec![
+            "assurance_procedure.yaml",
+            "activity/peer-review/at-least-two-reviewers.py",
+            "activity/peer-review/requester-not-a-reviewer.py",
+            "activity/build/unit-tests.py",
+        ]);
+        assert_eq!(files[0].content, "nrn:procedure:nape:rust-ci/sourcecode-integration");
+        assert!(files[3].content.contains("against the evidence collected to './evidence/build/unit-tests.json'"));
+        Ok(files.iter().map(|file| format!("{}/{}", directory, file.path)).collect())
+    };
+
+    let result = scaffold_procedure(&request(None), serialize_assertion, parse_procedure_success, write_assertion);
+
+    let scaffolded = is_ok!(result);
+    assert_eq!(scaffolded.directory, "sourcecode-integration");
+    assert_eq!(scaffolded.files.len(), 4);
+}
+
+#[test]
+fn scaffold_to_directory_success() {
+    let write_assertion = |directory: &str, files: &[ScaffoldFile]| -> Result<Vec<String>, Error> {
+        assert_eq!(directory, "procedures/rust-ci");
+        Ok(files.iter().map(|file| file.path.clone()).collect())
+    };
+
+    let result = scaffold_procedure(&request(Some("procedures/rust-ci")), serialize_nrn, parse_procedure_success, write_assertion);
+
+    is_ok!(result);
+}
+
+/*** Sad Path Tests ***/
+
+#[test]
+fn scaffold_wrong_nid_error() {
+    let mut request = request(None);
+    request.nrn = "nrn:sourcecode:nape:rust-ci";
+
+    let result = scaffold_procedure(&request, serialize_nrn, parse_procedure_success, write_scaffold_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "We could not create the procedure. The NRN 'nrn:sourcecode:nape:rust-ci' must use the 'procedure' NID, such as 'nrn:procedure:nape:rust-ci'.");
+}
+
+#[test]
+fn scaffold_organisation_nid_error() {
+    let mut request = request(None);
+    request.nrn = "nrn:bank-product:nape:rust-ci";
+
+    let result = scaffold_procedure(&request, serialize_nrn, parse_procedure_success, write_scaffold_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "We could not create the procedure. The NRN 'nrn:bank-product:nape:rust-ci' must use the 'procedure' NID, such as 'nrn:procedure:nape:rust-ci'.");
+}
+
+#[test]
+fn scaffold_duplicate_action_error() {
+    let mut request = request(None);
+    request.activities.push(outline("deploy", &["unit-tests"]));
+
+    let result = scaffold_procedure(&request, serialize_nrn, parse_procedure_success, write_scaffold_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "We could not create the procedure. The action 'unit-tests' is named more than once. Each action must have a unique name.");
+}
+
+#[test]
+fn scaffold_activity_without_actions_error() {
+    let mut request = request(None);
+    request.activities.push(outline("deploy", &[]));
+
+    let result = scaffold_procedure(&request, serialize_nrn, parse_procedure_success, write_scaffold_success);
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User,
+        "We could not create the procedure. The activity 'deploy' must have at least one action.");
+}
+
+#[test]
+fn scaffold_invalid_generated_procedure_error() {
+    let parse_error = |_content: &str| -> Result<AssuranceProcedure, Error> {
+        Err(Error::for_system(Kind::ProcessingFailure, "Some ParseProcedure Gateway Error".to_string()))
+    };
+    let write_never_called = |_directory: &str, _files: &[ScaffoldFile]| -> Result<Vec<String>, Error> {
+        panic!("Nothing is written when the generated procedure is not valid.");
+    };
+
+    let result = scaffold_procedure(&request(None), serialize_nrn, parse_error, write_never_called);
+
+    kernel_error_eq!(result, Kind::ProcessingFailure, Audience::System,
+        "The generated procedure 'nrn:procedure:nape:rust-ci/sourcecode-integration' is not valid. Some ParseProcedure Gateway Error");
+}
+
+#[test]
+fn scaffold_write_gateway_error() {
+    let write_error = |_directory: &str, _files: &[ScaffoldFile]| -> Result<Vec<String>, Error> {
+        Err(Error::for_user(Kind::InvalidInput, "Some WriteScaffold Gateway Error".to_string()))
+    };
+
+    let result = scaffold_procedure(&request(None), serialize_nrn, parse_procedure_success, write_error);
+
+    kernel_error_starts_with!(result, Kind::GatewayError, Audience::System, "There was an issue writing the procedure to 'sourcecode-integration'. ");
+}
+
+/*** Testing Utilities & Mocks ***/
+
+fn outline(name: &str, actions: &[&str]) -> ActivityOutline {
+    ActivityOutline { name: name.to_string(), actions: actions.iter().map(|action| action.to_string()).collect() }
+}
+
+fn request(directory: Option<&str>) -> ScaffoldProcedureRequest<'_> {
+    ScaffoldProcedureRequest {
+        nrn: "nrn:procedure:nape:rust-ci/sourcecode-integration",
+        short_description: Some("Rust CI Assurance Procedure"),
+        activities: vec![
+            outline("peer-review", &["at-least-two-reviewers", "requester-not-a-reviewer"]),
+            outline("build", &["unit-tests"]),
+        ],
+        directory,
+    }
+}
+
+fn serialize_nrn(procedure: &AssuranceProcedure) -> Result<String, Error> {
+    Ok(procedure.procedure.nrn.to_string())
+}
+
+fn parse_procedure_success(content: &str) -> Result<AssuranceProcedure, Error> {
+    AssuranceProcedure::builder()
+        .api_version("1.1.0")
+        .procedure_info(content, "A procedure for testing", "A procedure for testing the scaffolding of procedures")
+        .try_build()
+}
+
+fn write_scaffold_success(_directory: &str, files: &[ScaffoldFile]) -> Result<Vec<String>, Error> {
+    Ok(files.iter().map(|file| file.path.clone()).collect())
+}
@@ -1,6 +1,8 @@
 
 pub mod os_home_directory;
+pub mod pattern;
 pub mod signature_algorithm;
 
+#[cfg(test)] mod pattern_tests;
 #[cfg(test)] mod signature_algorithm_tests;
 
@@ -0,0 +1,60 @@
+use regex::bytes::Regex;
+use crate::error::{Error, Kind};
+
+/// # Overview
+///
+/// A [`Pattern`] is a regular expression used to find text, such as secrets, within evidence.
+///
+/// # Syntax
+///
+/// The syntax is the syntax of the `regex` crate, e.g. character classes such as `[a-z0-9_-]` and `\d`, groups `( )` and `(?: )`, lazy quantifiers such as `*?`, and flags such as a leading `(?i)` for a case-insensitive pattern.
+///
+/// # Design Decision
+///
+/// * The pattern runs in time linear to the size of the evidence, as evidence can be large and the patterns are supplied by users.
+/// * The pattern is matched against bytes rather than text, so evidence which is not valid UTF-8, such as a binary file with an embedded key, is also searched.
+///
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    regex: Regex,
+}
+
+/// A [`PatternMatch`] is where a [`Pattern`] matched the data, as byte offsets.
+///
+/// * `start` - The offset of the start of the match.
+/// * `end` - The offset of the end of the match.
+/// * `group` - The offsets of the first capture group, when the pattern has one and it took part in the match.
+///
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PatternMatch {
+    pub start: usize,
+    pub end: usize,
+    pub group: Option<(usize, usize)>,
+}
+
+impl Pattern {
+
+    /// Creates a [`Pattern`] from the regular expression, or returns an [`Error`] for the [`Audience::User`] if the expression is not valid.
+    pub fn try_new(pattern: &str) -> Result<Pattern, Error> {
+        let regex = Regex::new(pattern)
+            .map_err(|error| Error::for_user(Kind::InvalidInput,
+                                             format!("The pattern '{}' is not valid. {}", pattern, error)))?;
+        Ok(Pattern { regex })
+    }
+
+    /// The regular expression the [`Pattern`] was created from.
+    pub fn as_str(&self) -> &str {
+        self.regex.as_str()
+    }
+
+    /// Finds every non-overlapping match of the [`Pattern`] in the data, from left to right.
+    pub fn find_all(&self, data: &[u8]) -> Vec<PatternMatch> {
+        self.regex.captures_iter(data)
+            .filter_map(|captures| {
+                let found = captures.get(0)?;
+                let group = captures.get(1).map(|group| (group.start(), group.end()));
+                Some(PatternMatch { start: found.start(), end: found.end(), group })
+            })
+            .collect()
+    }
+}
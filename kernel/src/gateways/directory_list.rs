@@ -1,4 +1,7 @@
 use crate::error::Error;
 
 /// Retrieve the directory path based on a given directory key.
-pub type RetrieveDirectoryPath = fn(directory_key: &str) -> Result<String, Error>;
\ No newline at end of file
+pub type RetrieveDirectoryPath = fn(directory_key: &str) -> Result<String, Error>;
+
+/// Resolve the directory path for a given directory key within the home directory of a collection, such as an archived collection, rather than the collection recorded in the application state.
+pub type ResolveDirectoryPath = fn(home_directory: &str, directory_key: &str) -> Result<String, Error>;
@@ -0,0 +1,35 @@
+use crate::error::Error;
+
+/// The [`SelectedFile`] is a file selected by a directory or glob pattern.
+///
+/// * `source` - The path to the file.
+/// * `relative_path` - The path of the file relative to the directory, or the base directory of the glob pattern, which selected it.  The path is always separated by '/'.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SelectedFile {
+    pub source: String,
+    pub relative_path: String,
+}
+
+/// The [`SelectedFiles`] are the files a file path refers to.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SelectedFiles {
+    /// The file path refers to a single file.
+    File,
+    /// The file path is a directory or a glob pattern, which selected the files in the list.  The list is sorted by the relative path and may be empty.
+    Files(Vec<SelectedFile>),
+}
+
+/// # Overview
+///
+/// A [`SelectFilesGateway`] gateway that determines which files a file path refers to, where the file path may be a single file, a directory whose files are selected recursively, or a glob pattern.
+///
+/// # Arguments
+///
+/// * `file_path` - The file path, directory, or glob pattern.
+///
+/// # Returns
+///
+/// A `Result<SelectedFiles, Error>` containing the [`SelectedFiles`], or an [`Error`] if the file path does not exist.
+///
+pub type SelectFilesGateway = fn(file_path: &str) -> Result<SelectedFiles, Error>;
@@ -1,4 +1,3 @@
 pub mod directory_list;
 pub mod file_data_gateway;
-
-
+pub mod file_selection;
@@ -2,7 +2,7 @@ use crate::error::{Error, Kind};
 use crate::values::nrn::nrn::NRN;
 
 pub fn encode_as_directory_name(nrn: &NRN) -> String {
-    nrn.value
+    nrn.canonical()
         .replace(":", "_")
         .replace("/", "_-_")
 }
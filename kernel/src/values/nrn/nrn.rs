@@ -1,27 +1,43 @@
 use std::fmt::{Display};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
 use std::string::ToString;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use crate::error::{Error, Kind};
 
 pub const NRN_SCHEME: &str = "nrn";
 
-/// The [`ALLOWED_NRN_NID_LIST`] contains the list of[`NID`]s that are allowed for an [`NRN`] and their human-readable string representations.
+/// The [`ALLOWED_NRN_NID_LIST`] contains the list of[`NID`]s that are allowed for an [`NRN`] and their human-readable string representations. Organisations may allow additional [`NID`]s, which are represented as [`NapeNID::Custom`].
 pub const ALLOWED_NRN_NID_LIST: &'static [(NapeNID, &'static str)] = &[
     (NapeNID::Procedure, "procedure"),
-    (NapeNID::SourceCode, "sourcecode"), ];
+    (NapeNID::SourceCode, "sourcecode"),
+    (NapeNID::Service, "service"),
+    (NapeNID::Image, "image"),
+    (NapeNID::CloudAccount, "cloudaccount"),
+    (NapeNID::Dataset, "dataset"),
+    (NapeNID::Pipeline, "pipeline"), ];
 
-/// An NAPE Resource Name (NRN) is a specific version of a Universal Resource Name (URN) that identifies a resource within the NAPE ecosystem. It is in the format `nrn:<nid>:<nss>`. The [`NRN`] struct contains the following fields:
+/// An NAPE Resource Name (NRN) is a specific version of a Universal Resource Name (URN) that identifies a resource within the NAPE ecosystem. It is in the format `nrn:<nid>:<nss>[?+<r-component>][?=<q-component>][#<fragment>]`, following RFC 8141. The [`NRN`] struct contains the following fields:
 ///
-/// * `value` - The full [`NRN`] string
+/// * `value` - The full [`NRN`] string, as it was provided
 /// * `scheme` - The scheme of the [`NRN`]. This should always be `nrn`
 /// * `nid` - The Namespace Identifier [`NID`] of the [`NRN`]
 /// * `nss` - The Namespace String [`NSS`] of the [`NRN`]
+/// * `r_component` - The optional r-component, which holds parameters for the resolution of the resource
+/// * `q_component` - The optional q-component, which holds parameters for the resource itself
+/// * `fragment` - The optional fragment, which identifies a part of the resource
 ///
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+/// Two [`NRN`]s are equal, and hash the same, when their [`NRN::canonical`] names are equal. As in RFC 8141, the scheme and [`NID`] are case-insensitive, and the r-component, q-component, and fragment are not part of the comparison.
+///
+#[derive(Clone, Debug)]
 pub struct NRN {
      pub value: String,
      pub scheme: String,
      pub nid: NapeNID,
      pub nss: Vec<NSS>,
+     pub r_component: Option<String>,
+     pub q_component: Option<String>,
+     pub fragment: Option<String>,
 }
 
 impl NRN {
@@ -40,23 +56,60 @@ impl NRN {
     ///  * the input is empty,
     /// * does not start with the correct scheme,
     /// * contains an invalid [`NID`],
-    /// * the [`NID`] provided is not an [`NapeNID`] within the [`ALLOWED_NRN_NID_LIST`], or
-    /// * does not contain at least one [`NSS`].
+    /// * does not contain at least one [`NSS`], or
+    /// * contains an empty or invalid r-component, q-component, or fragment.
+    ///
+    /// An [`NID`] which is not within the [`ALLOWED_NRN_NID_LIST`] becomes a [`NapeNID::Custom`]. Use [`NapeNID::check_allowed`] to confirm it is one of the organisation's [`NID`]s.
     ///
     pub fn new(nrn: &str) -> Result<NRN, Error> {
 
         Self::check_nrn_is_not_empty(nrn)?;
-        Self::check_nrn_starts_with_correct_scheme(nrn)?;
-        let _nid = Self::check_for_valid_nid_and_extract(nrn)?;
-        let _nss = Self::check_for_valid_nss_and_extract(nrn)?;
+        let (assigned_name, r_component, q_component, fragment) = Self::split_components(nrn)?;
+        Self::check_nrn_starts_with_correct_scheme(assigned_name)?;
+        let _nid = Self::check_for_valid_nid_and_extract(assigned_name)?;
+        let _nss = Self::check_for_valid_nss_and_extract(assigned_name)?;
 
         Ok(NRN {
             value: String::from(nrn),
             scheme: String::from(NRN_SCHEME),
             nid: _nid,
-            nss: _nss
+            nss: _nss,
+            r_component,
+            q_component,
+            fragment,
         })
     }
+
+    /// The canonical name of the [`NRN`], which is used to compare [`NRN`]s. The scheme and [`NID`] are lower case, percent-encoded characters in the [`NSS`] use upper case hexadecimal digits, and the r-component, q-component, and fragment are left out.
+    pub fn canonical(&self) -> String {
+        let nss: Vec<String> = self.nss.iter().map(|nss| normalise_percent_encoding(&nss.value)).collect();
+        format!("{}:{}:{}", self.scheme, self.nid, nss.join(":"))
+    }
+
+    /// Splits the NRN into its assigned name and optional r-component, q-component, and fragment.
+    fn split_components(nrn: &str) -> Result<NRNComponents<'_>, Error> {
+        let (without_fragment, fragment) = match nrn.split_once('#') {
+            Some((name, fragment)) => (name, Some(Self::check_component(nrn, "fragment", fragment)?)),
+            None => (nrn, None),
+        };
+        let (without_q_component, q_component) = match without_fragment.split_once("?=") {
+            Some((name, q_component)) => (name, Some(Self::check_component(nrn, "q-component", q_component)?)),
+            None => (without_fragment, None),
+        };
+        let (assigned_name, r_component) = match without_q_component.split_once("?+") {
+            Some((name, r_component)) => (name, Some(Self::check_component(nrn, "r-component", r_component)?)),
+            None => (without_q_component, None),
+        };
+        Ok((assigned_name, r_component, q_component, fragment))
+    }
+    fn check_component(nrn: &str, component_name: &str, component: &str) -> Result<String, Error> {
+        if component.is_empty() {
+            return Err(Error::for_user(Kind::InvalidInput, format!("The supplied NRN '{}' has an empty {}.", nrn, component_name)));
+        }
+        let validated = NSS::new(component).map_err(|e|
+            Error::for_user(Kind::InvalidInput, format!("The supplied NRN '{}' has an invalid {}. {}", nrn, component_name, e.message)))?;
+        Ok(validated.value)
+    }
     fn check_nrn_is_not_empty(nrn: &str) -> Result<(), Error> {
         if nrn.is_empty() {
             return Err(Error::for_user(Kind::InvalidInput, "You provided an empty NRN value. An NRN must be in the format 'nrn:<nid>:<nss>'".to_string()));
@@ -64,16 +117,19 @@ impl NRN {
         Ok(())
     }
     fn check_nrn_starts_with_correct_scheme(nrn: &str) -> Result<(), Error> {
-        let scheme = nrn.split(":").next().unwrap();
-        if scheme != NRN_SCHEME {
+        let scheme = nrn.split(":").next()
+            .ok_or_else(|| Error::for_user(Kind::InvalidInput, format!("The supplied NRN '{}' does not have a scheme. An NRN must be in the format 'nrn:<nid>:<nss>'", nrn)))?;
+        if !scheme.eq_ignore_ascii_case(NRN_SCHEME) {
             return Err(Error::for_user(Kind::InvalidInput, format!("You provided '{}' as an NRN and the scheme '{}'  is not valid. Must be '{}'", nrn, scheme, NRN_SCHEME)));
         }
         Ok(())
     }
     fn check_for_valid_nid_and_extract(nrn: &str) -> Result<NapeNID, Error> {
-        let extracted_nid = nrn.split(":").nth(1).unwrap();
-        let nid = NID::new(extracted_nid)?;
-        NapeNID::new(nid)
+        let extracted_nid = nrn.split(":").nth(1)
+            .ok_or_else(|| Error::for_user(Kind::InvalidInput, format!("The supplied NRN '{}' does not have an NID. An NRN must be in the format 'nrn:<nid>:<nss>'", nrn)))?
+            .to_ascii_lowercase();
+        let nid = NID::new(&extracted_nid)?;
+        Ok(NapeNID::new(nid))
     }
     fn check_for_valid_nss_and_extract(nrn: &str) -> Result<Vec<NSS>, Error>  {
 
@@ -93,30 +149,110 @@ impl NRN {
     }
 
 }
+/// The assigned name of an [`NRN`], followed by its optional r-component, q-component, and fragment.
+type NRNComponents<'a> = (&'a str, Option<String>, Option<String>, Option<String>);
+
+impl PartialEq for NRN {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical() == other.canonical()
+    }
+}
+impl Eq for NRN {}
+impl Hash for NRN {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical().hash(state);
+    }
+}
+impl FromStr for NRN {
+    type Err = Error;
+
+    fn from_str(nrn: &str) -> Result<Self, Self::Err> {
+        NRN::new(nrn)
+    }
+}
+/// Displays the [`NRN::canonical`] name followed by any r-component, q-component, and fragment.
+impl Display for NRN {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.canonical())?;
+        if let Some(r_component) = &self.r_component { write!(f, "?+{}", r_component)?; }
+        if let Some(q_component) = &self.q_component { write!(f, "?={}", q_component)?; }
+        if let Some(fragment) = &self.fragment { write!(f, "#{}", fragment)?; }
+        Ok(())
+    }
+}
+impl Serialize for NRN {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+impl<'de> Deserialize<'de> for NRN {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let nrn = String::deserialize(deserializer)?;
+        NRN::new(&nrn).map_err(|e| serde::de::Error::custom(e.message))
+    }
+}
+
+/// Upper cases the hexadecimal digits of the percent-encoded characters, as RFC 3986 considers them equivalent.
+fn normalise_percent_encoding(value: &str) -> String {
+    let mut normalised = String::with_capacity(value.len());
+    let mut characters = value.chars();
+    while let Some(character) = characters.next() {
+        normalised.push(character);
+        if character == '%' {
+            characters.by_ref().take(2).for_each(|digit| normalised.push(digit.to_ascii_uppercase()));
+        }
+    }
+    normalised
+}
 
 /// An [`NapeNID`] is an enumeration of the different types of resources that can be identified within an [`NRN`] namespace.  The [`ALLOWED_NRN_NID_LIST`] contains the list of [`NID`]s that are allowed.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum NapeNID {
     Procedure,
-    SourceCode
+    SourceCode,
+    Service,
+    Image,
+    CloudAccount,
+    Dataset,
+    Pipeline,
+    /// An organisation-defined [`NID`], which is not within the [`ALLOWED_NRN_NID_LIST`].
+    Custom(String)
 }
 impl NapeNID {
 
-    pub fn new(nid: NID) -> Result<NapeNID, Error> {
+    /// Create the [`NapeNID`] for the [`NID`]. An [`NID`] which is not within the [`ALLOWED_NRN_NID_LIST`] becomes a [`NapeNID::Custom`].
+    pub fn new(nid: NID) -> NapeNID {
         let nid_value = nid.value.as_str();
         for (nid_enum, human_readable_nid) in ALLOWED_NRN_NID_LIST {
-            if nid_value == *human_readable_nid { return Ok(nid_enum.clone()); }
+            if nid_value == *human_readable_nid { return nid_enum.clone(); }
         }
-        let list_of_valid_nid_inputs: Vec<&str> = ALLOWED_NRN_NID_LIST.iter().map(|(_, nid_str)| *nid_str).collect();
+        NapeNID::Custom(nid.value)
+    }
+
+    /// Check the [`NapeNID`] is allowed. The [`NapeNID`]s within the [`ALLOWED_NRN_NID_LIST`] are always allowed, and a [`NapeNID::Custom`] is allowed when it is one of the `organisation_nids`.
+    ///
+    /// An [`Error`]  of [`Kind::InvalidInput`] is returned if the [`NapeNID`] is not allowed.
+    ///
+    pub fn check_allowed(&self, organisation_nids: &[String]) -> Result<(), Error> {
+        let custom_nid = match self {
+            NapeNID::Custom(custom_nid) => custom_nid,
+            _ => return Ok(()),
+        };
+        if organisation_nids.iter().any(|nid| nid.eq_ignore_ascii_case(custom_nid)) { return Ok(()); }
+
+        let list_of_valid_nid_inputs: Vec<&str> = ALLOWED_NRN_NID_LIST.iter().map(|(_, nid_str)| *nid_str)
+            .chain(organisation_nids.iter().map(|nid_str| nid_str.as_str()))
+            .collect();
         Err(Error::for_user(
             Kind::InvalidInput,
-            format!("'{}' is not a valid NID. Must be one of: [{}].", nid_value, list_of_valid_nid_inputs.join(", "))
+            format!("'{}' is not a valid NID. Must be one of: [{}].", custom_nid, list_of_valid_nid_inputs.join(", "))
         ) )
     }
 
 }
 impl Display for NapeNID {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let NapeNID::Custom(custom_nid) = self { return write!(f, "{}", custom_nid); }
         Ok(for (nid, nid_str) in ALLOWED_NRN_NID_LIST {
             if self == nid { return write!(f, "{}", nid_str); }
         })
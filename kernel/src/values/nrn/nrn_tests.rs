@@ -1,4 +1,6 @@
 
+use std::collections::HashSet;
+use std::str::FromStr;
 use crate::error::{Audience, Kind};
 use crate::values::nrn::nrn::{NRN, NapeNID, NID, NSS};
 
@@ -34,13 +36,28 @@ fn nrn_from_str_error_wrong_scheme() {
     assert_eq!(result.err().unwrap().message, "You provided 'urn:sourecode:nape::project/nape-cli' as an NRN and the scheme 'urn'  is not valid. Must be 'nrn'");
 }
 #[test]
-fn nrn_from_str_error_wrong_nid() {
-    let result = NRN::new("nrn:somewrongnid:nape::project/nape-cli");
+fn nrn_from_str_custom_nid() {
+    let result = NRN::new("nrn:somecustomnid:nape::project/nape-cli");
+
+    assert!(result.is_ok());
+    assert_eq!(result.unwrap().nid, NapeNID::Custom("somecustomnid".to_string()));
+}
+#[test]
+fn nrn_from_str_common_nids() {
+    assert_eq!(NRN::new("nrn:service:nape:payments-api").unwrap().nid, NapeNID::Service);
+    assert_eq!(NRN::new("nrn:image:nape:registry/nape-cli").unwrap().nid, NapeNID::Image);
+    assert_eq!(NRN::new("nrn:cloudaccount:aws:123456789012").unwrap().nid, NapeNID::CloudAccount);
+    assert_eq!(NRN::new("nrn:dataset:nape:customers").unwrap().nid, NapeNID::Dataset);
+    assert_eq!(NRN::new("nrn:pipeline:nape:release").unwrap().nid, NapeNID::Pipeline);
+}
+#[test]
+fn nrn_from_str_error_invalid_nid() {
+    let result = NRN::new("nrn:some_wrong_nid:nape::project/nape-cli");
 
     assert!(result.is_err());
     let error = result.err().unwrap();
     assert_eq!(error.kind, Kind::InvalidInput);
-    assert_eq!(error.message, "'somewrongnid' is not a valid NID. Must be one of: [procedure, sourcecode].");
+    assert_eq!(error.message, "NID 'some_wrong_nid' contains invalid character '_' at position 4.  An NID can only contains alphanumeric characters or a dash '-'.");
 }
 #[test]
 fn nrn_from_str_error_missing_nss() {
@@ -59,6 +76,109 @@ fn nrn_from_str_error_invalid_nss() {
     assert_eq!(error.message, "Input 'invalid nss' contains whitespace character ' ' at position 7");
 }
 
+#[test]
+fn nrn_from_str_components_success() {
+    let result = NRN::new("nrn:sourcecode:nape:project/nape-cli?+branch=main?=format=yaml#section-2");
+
+    assert!(result.is_ok());
+    let nrn = result.unwrap();
+    assert_eq!(nrn.nss.len(), 2);
+    assert_eq!(nrn.nss[1], NSS::new("project/nape-cli").unwrap());
+    assert_eq!(nrn.r_component, Some("branch=main".to_string()));
+    assert_eq!(nrn.q_component, Some("format=yaml".to_string()));
+    assert_eq!(nrn.fragment, Some("section-2".to_string()));
+}
+#[test]
+fn nrn_from_str_fragment_only_success() {
+    let nrn = NRN::new("nrn:procedure:nape:rust-ci#build").unwrap();
+
+    assert_eq!(nrn.r_component, None);
+    assert_eq!(nrn.q_component, None);
+    assert_eq!(nrn.fragment, Some("build".to_string()));
+    assert_eq!(nrn.nss[1], NSS::new("rust-ci").unwrap());
+}
+#[test]
+fn nrn_from_str_error_empty_component() {
+    let result = NRN::new("nrn:procedure:nape:rust-ci?=");
+
+    assert!(result.is_err());
+    let error = result.err().unwrap();
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "The supplied NRN 'nrn:procedure:nape:rust-ci?=' has an empty q-component.");
+}
+#[test]
+fn nrn_from_str_error_missing_nid_before_fragment() {
+    let result = NRN::new("nrn#frag");
+
+    assert!(result.is_err());
+    let error = result.err().unwrap();
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.audience, Audience::User);
+    assert_eq!(error.message, "The supplied NRN 'nrn' does not have an NID. An NRN must be in the format 'nrn:<nid>:<nss>'");
+}
+#[test]
+fn nrn_from_str_error_missing_nid_before_r_component() {
+    let result = NRN::new("nrn?+q");
+
+    assert!(result.is_err());
+    let error = result.err().unwrap();
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "The supplied NRN 'nrn' does not have an NID. An NRN must be in the format 'nrn:<nid>:<nss>'");
+}
+#[test]
+fn nrn_from_str_error_fragment_only() {
+    let result = NRN::new("#frag");
+
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().kind, Kind::InvalidInput);
+}
+#[test]
+fn nrn_from_str_error_invalid_component() {
+    let result = NRN::new("nrn:procedure:nape:rust-ci#a b");
+
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().message, "The supplied NRN 'nrn:procedure:nape:rust-ci#a b' has an invalid fragment. Input 'a b' contains whitespace character ' ' at position 1");
+}
+#[test]
+fn nrn_canonical_equality() {
+    let lower_case = NRN::new("nrn:procedure:nape:rust-ci%2fbuild").unwrap();
+    let upper_case = NRN::new("NRN:Procedure:nape:rust-ci%2Fbuild?=format=yaml#build").unwrap();
+
+    assert_eq!(upper_case.nid, NapeNID::Procedure);
+    assert_eq!(upper_case.canonical(), "nrn:procedure:nape:rust-ci%2Fbuild");
+    assert_eq!(lower_case, upper_case);
+    assert_ne!(lower_case, NRN::new("nrn:procedure:Nape:rust-ci%2Fbuild").unwrap());
+
+    let keys: HashSet<NRN> = vec![lower_case, upper_case].into_iter().collect();
+    assert_eq!(keys.len(), 1);
+}
+#[test]
+fn nrn_from_str_trait_and_display() {
+    let nrn = NRN::from_str("NRN:SourceCode:nape:project/nape-cli?+branch=main#readme").unwrap();
+
+    assert_eq!(nrn.value, "NRN:SourceCode:nape:project/nape-cli?+branch=main#readme");
+    assert_eq!(nrn.to_string(), "nrn:sourcecode:nape:project/nape-cli?+branch=main#readme");
+    assert_eq!("nrn:sourcecode:nape:project/nape-cli".parse::<NRN>().unwrap(), nrn);
+}
+#[test]
+fn nrn_serde_round_trip() {
+    let nrn = NRN::new("nrn:Procedure:nape:rust-ci?=format=yaml").unwrap();
+
+    let serialized = serde_json::to_string(&nrn).unwrap();
+    assert_eq!(serialized, "\"nrn:procedure:nape:rust-ci?=format=yaml\"");
+
+    let deserialized: NRN = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, nrn);
+    assert_eq!(deserialized.q_component, Some("format=yaml".to_string()));
+}
+#[test]
+fn nrn_serde_invalid_error() {
+    let result = serde_json::from_str::<NRN>("\"urn:procedure:nape\"");
+
+    assert!(result.is_err());
+    assert!(result.err().unwrap().to_string().starts_with("You provided 'urn:procedure:nape' as an NRN and the scheme 'urn'  is not valid."));
+}
+
 
 /** NAPE NID Tests ***/
 
@@ -75,16 +195,32 @@ fn nid_sourcecode_display() {
     assert_eq!(result.to_string(), "procedure");
 }
 
+#[test]
+fn nid_custom_display() {
+    let result = format!("{}", NapeNID::Custom("bank-product".to_string()));
+    assert_eq!(result, "bank-product");
+}
+
 #[test]
 fn nid_does_not_exist() {
     let nid = NID::new("does-not-exist").unwrap();
     let does_not_exist = NapeNID::new(nid);
 
-    assert!(does_not_exist.is_err());
-    let dne_error = does_not_exist.err().unwrap();
+    assert_eq!(does_not_exist, NapeNID::Custom("does-not-exist".to_string()));
+    let not_allowed = does_not_exist.check_allowed(&["bank-product".to_string()]);
+
+    assert!(not_allowed.is_err());
+    let dne_error = not_allowed.err().unwrap();
     assert_eq!(dne_error.kind, Kind::InvalidInput);
     assert_eq!(dne_error.audience, Audience::User);
-    assert_eq!(dne_error.message, "'does-not-exist' is not a valid NID. Must be one of: [procedure, sourcecode].");
+    assert_eq!(dne_error.message, "'does-not-exist' is not a valid NID. Must be one of: [procedure, sourcecode, service, image, cloudaccount, dataset, pipeline, bank-product].");
+}
+#[test]
+fn nid_allowed() {
+    let organisation_nids = vec!["bank-product".to_string()];
+
+    assert!(NapeNID::Custom("bank-product".to_string()).check_allowed(&organisation_nids).is_ok());
+    assert!(NapeNID::Service.check_allowed(&[]).is_ok());
 }
 #[test]
 fn verify_nid_exists()  {
@@ -92,13 +228,8 @@ fn verify_nid_exists()  {
     let sourcecode_nid = NapeNID::new(NID::new("sourcecode").unwrap());
     let procedure_nid = NapeNID::new(NID::new("procedure").unwrap());
 
-
-    assert!(sourcecode_nid.is_ok());
-    assert_eq!(sourcecode_nid.unwrap(), NapeNID::SourceCode);
-    ("procedure");
-
-    assert!(procedure_nid.is_ok());
-    assert_eq!(procedure_nid.unwrap(), NapeNID::Procedure);
+    assert_eq!(sourcecode_nid, NapeNID::SourceCode);
+    assert_eq!(procedure_nid, NapeNID::Procedure);
 
 }
 
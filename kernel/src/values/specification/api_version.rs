@@ -1,7 +1,7 @@
 use crate::error::{Error, Kind};
 
 /// [`APIVersion`] represents the version of the NAPE API versioning primarily for the NAPE Specifications and follows the [Semantic Versioning 2.0.0 specification](https://github.com/semver/semver)
-#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, PartialOrd, Ord)]
 pub struct APIVersion {
     pub major: u8,
     pub minor: u8,
@@ -1,22 +1,54 @@
 use crate::error::{Error, Kind};
+use crate::values::specification::assurance_procedure::condition::Condition;
+use crate::values::specification::assurance_procedure::evidence_input::{EvidenceInput, PRIMARY_EVIDENCE_INPUT};
 use crate::values::specification::description::Description;
 use crate::values::specification::file_path::FilePath;
 use crate::values::specification::name::Name;
+use crate::values::specification::severity::Severity;
 use crate::values::specification::short_description::ShortDescription;
 
+/// The [`Action`] is a control action of an assurance procedure.  The evidence of an action is evaluated by its `test`, unless it is an attestation action, which has no test.  The outcome of an attestation action is attested by a person, and its `evidence` is the path of their attestation statement.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Action {
     pub name: Name,
     pub short: ShortDescription,
     pub description: Description,
-    pub test: FilePath,
+    pub test: Option<FilePath>,
     pub evidence: FilePath,
+    pub severity: Option<Severity>,
+    pub tags: Vec<Name>,
+    pub owner: Option<ShortDescription>,
+    pub when: Option<Condition>,
+    pub depends_on: Vec<Name>,
+    pub inputs: Vec<EvidenceInput>,
 }
 
 impl Action {
     pub fn builder() -> ActionBuilder {
         ActionBuilder::new()
     }
+
+    /// Returns `true` if the action is an attestation action, whose outcome is attested by a person rather than the result of a test.
+    pub fn is_attestation(&self) -> bool {
+        self.test.is_none()
+    }
+
+    /// Returns the evidence path of the input with the `name`, where the primary input is named `evidence`.
+    pub fn input_evidence(&self, name: &str) -> Option<&FilePath> {
+        if name == PRIMARY_EVIDENCE_INPUT {
+            return Some(&self.evidence);
+        }
+        self.inputs.iter()
+            .find(|input| input.name.value == name)
+            .map(|input| &input.evidence)
+    }
+
+    /// Returns the names of all evidence inputs, starting with the primary input.
+    pub fn input_names(&self) -> Vec<&str> {
+        let mut names = vec![PRIMARY_EVIDENCE_INPUT];
+        names.extend(self.inputs.iter().map(|input| input.name.value.as_str()));
+        names
+    }
 }
 
 pub struct ActionBuilder {
@@ -25,6 +57,13 @@ pub struct ActionBuilder {
     description: Option<String>,
     test: Option<String>,
     evidence: Option<String>,
+    severity: Option<String>,
+    tags: Vec<String>,
+    owner: Option<String>,
+    when: Option<String>,
+    depends_on: Vec<String>,
+    inputs: Vec<(String, String)>,
+    attestation: bool,
 }
 
 impl ActionBuilder {
@@ -35,6 +74,13 @@ impl ActionBuilder {
             description: None,
             test: None,
             evidence: None,
+            severity: None,
+            tags: Vec::new(),
+            owner: None,
+            when: None,
+            depends_on: Vec::new(),
+            inputs: Vec::new(),
+            attestation: false,
         }
     }
 
@@ -63,6 +109,42 @@ impl ActionBuilder {
         self
     }
 
+    pub fn severity(mut self, severity: &str) -> ActionBuilder {
+        self.severity = Some(severity.to_string());
+        self
+    }
+
+    pub fn add_tag(mut self, tag: &str) -> ActionBuilder {
+        self.tags.push(tag.to_string());
+        self
+    }
+
+    pub fn owner(mut self, owner: &str) -> ActionBuilder {
+        self.owner = Some(owner.to_string());
+        self
+    }
+
+    pub fn when(mut self, condition: &str) -> ActionBuilder {
+        self.when = Some(condition.to_string());
+        self
+    }
+
+    pub fn add_dependency(mut self, action_name: &str) -> ActionBuilder {
+        self.depends_on.push(action_name.to_string());
+        self
+    }
+
+    pub fn add_input(mut self, name: &str, evidence: &str) -> ActionBuilder {
+        self.inputs.push((name.to_string(), evidence.to_string()));
+        self
+    }
+
+    /// Make the action an attestation action, which has no test file or evidence inputs.
+    pub fn attestation(mut self) -> ActionBuilder {
+        self.attestation = true;
+        self
+    }
+
     pub fn try_build(self) -> Result<Action, Error> {
 
         let valid_name = self.build_name()?;
@@ -70,13 +152,25 @@ impl ActionBuilder {
         let valid_description =self.build_long_description()?;
         let valid_test = self.build_test_file_path()?;
         let valid_evidence = self.build_evidence_file_path()?;
+        let valid_severity = self.build_severity()?;
+        let valid_tags = self.build_tags()?;
+        let valid_owner = self.build_owner()?;
+        let valid_when = self.build_when()?;
+        let valid_depends_on = self.build_depends_on()?;
+        let valid_inputs = self.build_inputs()?;
 
         Ok(Action  {
             name: valid_name,
             short: valid_short,
             description: valid_description,
             test: valid_test,
-            evidence: valid_evidence
+            evidence: valid_evidence,
+            severity: valid_severity,
+            tags: valid_tags,
+            owner: valid_owner,
+            when: valid_when,
+            depends_on: valid_depends_on,
+            inputs: valid_inputs
         })
     }
 
@@ -101,10 +195,17 @@ impl ActionBuilder {
             .map_err(|error| custom_error(&format!("There is an issue with the long description '{}'. {}", description, error.message)))
     }
 
-    fn build_test_file_path(&self) -> Result<FilePath, Error> {
+    fn build_test_file_path(&self) -> Result<Option<FilePath>, Error> {
+        if self.attestation {
+            return match self.test.as_ref() {
+                Some(test) => Err(custom_error(&format!("An attestation action does not have a test, but the test file path '{}' was provided.", test))),
+                None => Ok(None)
+            };
+        }
         let test = self.test.as_ref()
             .ok_or(custom_error("The test file path is required, but was not provided."))?;
         FilePath::try_from(test)
+            .map(Some)
             .map_err(|error| custom_error(&format!("There is an issue with the test file path '{}'. {}", test, error.message)))
     }
 
@@ -115,6 +216,73 @@ impl ActionBuilder {
             .map_err(|error| custom_error(&format!("There is an issue with the evidence file path '{}'. {}", evidence, error.message)))
     }
 
+    fn build_severity(&self) -> Result<Option<Severity>, Error> {
+        match self.severity.as_ref() {
+            Some(severity) => Severity::try_from(severity)
+                .map(Some)
+                .map_err(|error| custom_error(&format!("There is an issue with the severity '{}'. {}", severity, error.message))),
+            None => Ok(None)
+        }
+    }
+
+    fn build_tags(&self) -> Result<Vec<Name>, Error> {
+        let mut tags: Vec<Name> = Vec::new();
+        for tag in &self.tags {
+            let valid_tag = Name::try_from(tag)
+                .map_err(|error| custom_error(&format!("There is an issue with the tag '{}'. {}", tag, error.message)))?;
+            if !tags.contains(&valid_tag) {
+                tags.push(valid_tag);
+            }
+        }
+        Ok(tags)
+    }
+
+    fn build_owner(&self) -> Result<Option<ShortDescription>, Error> {
+        match self.owner.as_ref() {
+            Some(owner) => ShortDescription::try_from(owner)
+                .map(Some)
+                .map_err(|error| custom_error(&format!("There is an issue with the owner '{}'. {}", owner, error.message))),
+            None => Ok(None)
+        }
+    }
+
+    fn build_when(&self) -> Result<Option<Condition>, Error> {
+        match self.when.as_ref() {
+            Some(condition) => Condition::try_from(condition)
+                .map(Some)
+                .map_err(|error| custom_error(&error.message)),
+            None => Ok(None)
+        }
+    }
+
+    fn build_depends_on(&self) -> Result<Vec<Name>, Error> {
+        let mut depends_on: Vec<Name> = Vec::new();
+        for dependency in &self.depends_on {
+            let valid_dependency = Name::try_from(dependency)
+                .map_err(|error| custom_error(&format!("There is an issue with the dependency '{}'. {}", dependency, error.message)))?;
+            if !depends_on.contains(&valid_dependency) {
+                depends_on.push(valid_dependency);
+            }
+        }
+        Ok(depends_on)
+    }
+
+    fn build_inputs(&self) -> Result<Vec<EvidenceInput>, Error> {
+        if self.attestation && !self.inputs.is_empty() {
+            return Err(custom_error("An attestation action does not have a test, so it cannot have evidence inputs."));
+        }
+        let mut inputs: Vec<EvidenceInput> = Vec::new();
+        for (name, evidence) in &self.inputs {
+            let valid_input = EvidenceInput::try_new(name, evidence)
+                .map_err(|error| custom_error(&error.message))?;
+            if inputs.iter().any(|input| input.name == valid_input.name) {
+                return Err(custom_error(&format!("The evidence input '{}' is declared more than once.", name)));
+            }
+            inputs.push(valid_input);
+        }
+        Ok(inputs)
+    }
+
 }
 
 fn custom_error(message: &str) -> Error {
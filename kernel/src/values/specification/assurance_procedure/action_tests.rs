@@ -6,6 +6,8 @@ use crate::values::specification::description::Description;
 use crate::values::specification::file_path::FilePath;
 use crate::values::specification::name::Name;
 use crate::values::specification::assurance_procedure::action::Action;
+use crate::values::specification::assurance_procedure::condition::Condition;
+use crate::values::specification::severity::Severity;
 use crate::values::specification::short_description::ShortDescription;
 
 #[test]
@@ -25,7 +27,8 @@ fn new_success() {
     assert_eq!(result.name, Name::try_from("name").unwrap());
     assert_eq!(result.short, ShortDescription::try_from("short").unwrap());
     assert_eq!(result.description, Description::try_from("long").unwrap());
-    assert_eq!(result.test, FilePath::try_from("test").unwrap());
+    assert_eq!(result.test, Some(FilePath::try_from("test").unwrap()));
+    assert!(!result.is_attestation());
     assert_eq!(result.evidence, FilePath::try_from("evidence").unwrap());
 }
 
@@ -151,4 +154,198 @@ fn bad_evidence_file_path_error() {
         .try_build();
 
     kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. There is an issue with the evidence file path ''. ");
-}
\ No newline at end of file
+}
+#[test]
+fn optional_fields_default_success() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .try_build();
+
+    let result = is_ok!(result);
+    assert_eq!(result.severity, None);
+    assert!(result.tags.is_empty());
+    assert_eq!(result.owner, None);
+    assert_eq!(result.when, None);
+    assert!(result.depends_on.is_empty());
+    assert!(result.inputs.is_empty());
+    assert_eq!(result.input_names(), vec!["evidence"]);
+}
+
+#[test]
+fn optional_fields_success() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .severity("High")
+        .add_tag("security")
+        .add_tag("peer-review")
+        .add_tag("security")
+        .owner("platform-team@example.com")
+        .when("meta.environment == prod")
+        .add_dependency("ci-config-valid")
+        .add_dependency("ci-config-valid")
+        .try_build();
+
+    let result = is_ok!(result);
+    assert_eq!(result.severity, Some(Severity::HIGH));
+    assert_eq!(result.tags, vec![Name::try_from("security").unwrap(), Name::try_from("peer-review").unwrap()]);
+    assert_eq!(result.owner, Some(ShortDescription::try_from("platform-team@example.com").unwrap()));
+    assert_eq!(result.when, Some(Condition::try_from("meta.environment == prod").unwrap()));
+    assert_eq!(result.depends_on, vec![Name::try_from("ci-config-valid").unwrap()]);
+}
+
+#[test]
+fn bad_severity_error() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .severity("urgent")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. There is an issue with the severity 'urgent'. ");
+}
+
+#[test]
+fn bad_tag_error() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .add_tag("not a tag")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. There is an issue with the tag 'not a tag'. ");
+}
+
+#[test]
+fn bad_owner_error() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .owner("")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. There is an issue with the owner ''. ");
+}
+
+#[test]
+fn bad_when_error() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .when("environment == prod")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. There is an issue with the condition 'environment == prod'. ");
+}
+
+#[test]
+fn bad_dependency_error() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .add_dependency("not an action")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. There is an issue with the dependency 'not an action'. ");
+}
+
+#[test]
+fn inputs_success() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence/ci/config.yaml")
+        .add_input("runtime", "evidence/ci/runtime.json")
+        .try_build();
+
+    let result = is_ok!(result);
+    assert_eq!(result.inputs.len(), 1);
+    assert_eq!(result.input_names(), vec!["evidence", "runtime"]);
+    assert_eq!(result.input_evidence("evidence").unwrap().as_str(), "evidence/ci/config.yaml");
+    assert_eq!(result.input_evidence("runtime").unwrap().as_str(), "evidence/ci/runtime.json");
+    assert_eq!(result.input_evidence("unknown"), None);
+}
+
+#[test]
+fn duplicate_input_error() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .add_input("runtime", "evidence/ci/runtime.json")
+        .add_input("runtime", "evidence/ci/runtime-2.json")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. The evidence input 'runtime' is declared more than once.");
+}
+
+#[test]
+fn bad_input_error() {
+    let result = Action::builder().name("action-name")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence")
+        .add_input("evidence", "evidence/ci/runtime.json")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. There is an issue with the evidence input 'evidence'. ");
+}
+
+#[test]
+fn attestation_success() {
+    let result = Action::builder().name("access-review")
+        .short_description("short")
+        .long_description("long")
+        .evidence_file_path("evidence/access-review/attestation.yaml")
+        .attestation()
+        .try_build();
+
+    let action = is_ok!(result);
+    assert!(action.is_attestation());
+    assert_eq!(action.test, None);
+    assert_eq!(action.evidence.as_str(), "evidence/access-review/attestation.yaml");
+}
+
+#[test]
+fn attestation_with_test_error() {
+    let result = Action::builder().name("access-review")
+        .short_description("short")
+        .long_description("long")
+        .test_file_path("test")
+        .evidence_file_path("evidence/access-review/attestation.yaml")
+        .attestation()
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. An attestation action does not have a test, but the test file path 'test' was provided.");
+}
+
+#[test]
+fn attestation_with_input_error() {
+    let result = Action::builder().name("access-review")
+        .short_description("short")
+        .long_description("long")
+        .evidence_file_path("evidence/access-review/attestation.yaml")
+        .add_input("runtime", "evidence/ci/runtime.json")
+        .attestation()
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Action for an Assurance Procedure could not be created. An attestation action does not have a test, so it cannot have evidence inputs.");
+}
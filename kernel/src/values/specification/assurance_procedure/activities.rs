@@ -105,6 +105,45 @@ impl Activities {
         }
     }
 
+    /// Finds the [`Action`] with the `name` across all activities.
+    pub fn find_action(&self, name: &str) -> Option<&Action> {
+        self.list.iter()
+            .flat_map(|activity| activity.actions.iter())
+            .find(|action| action.name.value == name)
+    }
+
+    /// # Overview
+    ///
+    /// Groups all [`Action`]s into levels by their `depends_on`, so that an action only depends on actions of an earlier level.  The actions of the first level do not depend on any action, and the actions of each level keep the order of the procedure.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for [`Audience::User`] of [`Kind::InvalidInput`] if an action depends on an action which does not exist, or the dependencies form a cycle.
+    ///
+    pub fn dependency_levels(&self) -> Result<Vec<Vec<&Action>>, Error> {
+        let mut remaining: Vec<&Action> = self.list.iter()
+            .flat_map(|activity| activity.actions.iter())
+            .collect();
+
+        for action in &remaining {
+            if let Some(unknown) = action.depends_on.iter().find(|dependency| self.find_action(&dependency.value).is_none()) {
+                return Err(dependency_error(&format!("The action '{}' depends on '{}', which is not an action of the procedure.", action.name.value, unknown.value)));
+            }
+        }
+
+        let mut levels: Vec<Vec<&Action>> = Vec::new();
+        while !remaining.is_empty() {
+            let (level, blocked): (Vec<&Action>, Vec<&Action>) = remaining.iter()
+                .partition(|action| action.depends_on.iter().all(|dependency| !remaining.iter().any(|other| &other.name == dependency)));
+            if level.is_empty() {
+                return Err(dependency_error(&format!("The dependencies form a cycle: {}.", find_cycle(&blocked))));
+            }
+            levels.push(level);
+            remaining = blocked;
+        }
+        Ok(levels)
+    }
+
     /// A count of all [`Action`]s across all activities.
     pub fn action_count(&self) -> usize {
         self.list.iter()
@@ -117,4 +156,25 @@ impl Activities {
         self.list.len()
     }
 
-}
\ No newline at end of file
+}
+
+/// Follows the dependencies of the `blocked` actions, which all depend on another blocked action, until an action is repeated.
+fn find_cycle(blocked: &[&Action]) -> String {
+    let mut path: Vec<&Action> = vec![blocked[0]];
+    loop {
+        let current = path[path.len() - 1];
+        let next = blocked.iter()
+            .find(|other| current.depends_on.contains(&other.name))
+            .expect("A blocked action always depends on another blocked action.");
+        if let Some(start) = path.iter().position(|action| action.name == next.name) {
+            let mut names: Vec<&str> = path[start..].iter().map(|action| action.name.value.as_str()).collect();
+            names.push(next.name.value.as_str());
+            return names.join(" -> ");
+        }
+        path.push(next);
+    }
+}
+
+fn dependency_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("The dependencies of the actions are not valid. {}", message))
+}
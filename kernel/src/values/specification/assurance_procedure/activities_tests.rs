@@ -159,4 +159,71 @@ fn add_action_without_existing_activity_error() {
     assert_eq!(err.message, "Activity 'activity-1' does not exist. The activity must exist before you can add an action to it.  Please add a activity with the name you provided, a short description, and a long description.");
 }
 
+#[test]
+fn find_action_success() {
+    let action_1 = Action::builder().name("action-1").short_description("Short Desc").long_description("Long Desc")
+        .test_file_path("test_file").evidence_file_path("evidence/action-1").try_build().unwrap();
+    let action_2 = Action::builder().name("action-2").short_description("Short Desc").long_description("Long Desc")
+        .test_file_path("test_file").evidence_file_path("evidence/action-2").try_build().unwrap();
+    let activities = Activities::default()
+        .add("activity-1", "Short Desc", "Long Desc").unwrap()
+        .add("activity-2", "Short Desc", "Long Desc").unwrap()
+        .add_activity("activity-1", &action_1).unwrap()
+        .add_activity("activity-2", &action_2).unwrap();
+
+    assert_eq!(activities.find_action("action-2").unwrap().evidence.as_str(), "evidence/action-2");
+    assert!(activities.find_action("action-3").is_none());
+}
+
+#[test]
+fn dependency_levels_success() {
+    let activities = dependent_activities(&[("action-1", &[]), ("action-2", &["action-3"]), ("action-3", &["action-1"]), ("action-4", &[])]);
+
+    let levels = activities.dependency_levels().unwrap();
+    let names: Vec<Vec<&str>> = levels.iter()
+        .map(|level| level.iter().map(|action| action.name.value.as_str()).collect())
+        .collect();
+    assert_eq!(names, vec![vec!["action-1", "action-4"], vec!["action-3"], vec!["action-2"]]);
+}
+
+#[test]
+fn dependency_levels_unknown_action_error() {
+    let activities = dependent_activities(&[("action-1", &["action-9"])]);
+
+    let err = activities.dependency_levels().unwrap_err();
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(err.message, "The dependencies of the actions are not valid. The action 'action-1' depends on 'action-9', which is not an action of the procedure.");
+}
 
+#[test]
+fn dependency_levels_cycle_error() {
+    let activities = dependent_activities(&[("action-1", &[]), ("action-2", &["action-1", "action-4"]), ("action-3", &["action-2"]), ("action-4", &["action-3"])]);
+
+    let err = activities.dependency_levels().unwrap_err();
+    assert_eq!(err.kind, Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(err.message, "The dependencies of the actions are not valid. The dependencies form a cycle: action-2 -> action-4 -> action-3 -> action-2.");
+}
+
+#[test]
+fn dependency_levels_self_error() {
+    let activities = dependent_activities(&[("action-1", &["action-1"])]);
+
+    let err = activities.dependency_levels().unwrap_err();
+    assert_eq!(err.message, "The dependencies of the actions are not valid. The dependencies form a cycle: action-1 -> action-1.");
+}
+
+fn dependent_activities(actions: &[(&str, &[&str])]) -> Activities {
+    let mut activities = Activities::default()
+        .add("activity-1", "Short Desc", "Long Desc").unwrap();
+    for (name, depends_on) in actions {
+        let mut builder = Action::builder().name(name).short_description("Short Desc").long_description("Long Desc")
+            .test_file_path(&format!("test/{}", name)).evidence_file_path(&format!("evidence/{}", name));
+        for dependency in depends_on.iter() {
+            builder = builder.add_dependency(dependency);
+        }
+        activities = activities.add_activity("activity-1", &builder.try_build().unwrap()).unwrap();
+    }
+    activities
+}
@@ -1,6 +1,8 @@
 use crate::error::{Error, Kind};
 use crate::values::specification::description::Description;
 use crate::values::specification::file_path::FilePath;
+use crate::values::specification::assurance_procedure::condition::Condition;
+use crate::values::specification::assurance_procedure::include::Source;
 use crate::values::specification::name::Name;
 use crate::values::specification::assurance_procedure::action::Action;
 use crate::values::specification::short_description::ShortDescription;
@@ -11,7 +13,9 @@ pub struct Activity {
     pub short: ShortDescription,
     pub description: Description,
     pub expected_evidence: Vec<FilePath>,
-    pub actions: Vec<Action>
+    pub actions: Vec<Action>,
+    pub source: Option<Source>,
+    pub when: Option<Condition>
 }
 
 impl Activity {
@@ -45,7 +49,9 @@ impl Activity {
             short: valid_short,
             description: valid_long,
             expected_evidence: Vec::new(),
-            actions: Vec::new()
+            actions: Vec::new(),
+            source: None,
+            when: None
         })
     }
 
@@ -60,7 +66,9 @@ impl Activity {
             short: self.short,
             description: self.description,
             expected_evidence: self.expected_evidence,
-            actions: new_actions
+            actions: new_actions,
+            source: self.source,
+            when: self.when
         }
     }
 
@@ -77,10 +85,22 @@ impl Activity {
             short: self.short,
             description: self.description,
             expected_evidence: new_expected_evidence,
-            actions: self.actions
+            actions: self.actions,
+            source: self.source,
+            when: self.when
         })
     }
 
+    /// Records the [`Source`] procedure the activity was included from, and returns a new instance of the activity
+    pub fn included_from(self, source: &Source) -> Activity {
+        Activity { source: Some(source.clone()), ..self }
+    }
+
+    /// Records the [`Condition`] which decides if the activity applies to the subject, and returns a new instance of the activity
+    pub fn when(self, condition: &Condition) -> Activity {
+        Activity { when: Some(condition.clone()), ..self }
+    }
+
     /// Returns the number of actions in the activity
     pub fn action_count(&self) -> usize {
         self.actions.len()
@@ -2,9 +2,18 @@
 use crate::error::{Audience, Kind};
 use crate::values::specification::assurance_procedure::action::Action;
 use crate::values::specification::assurance_procedure::activity::Activity;
+use crate::values::specification::assurance_procedure::condition::Condition;
 
 /** Happy path tests **/
 
+#[test]
+fn when_success() {
+    let condition = Condition::try_from("meta.pci == true").unwrap();
+    let activity = Activity::new("pci", "Short Desc", "Long Desc").unwrap().when(&condition);
+
+    assert_eq!(activity.when, Some(condition));
+}
+
 #[test]
 fn new_success() {
     let result = Activity::new("procedure-1", "Short Desc", "Long Desc");
@@ -35,7 +44,7 @@ fn add_activity_success() {
     assert_eq!(updated_activity.actions[0].name.value, "action-1");
     assert_eq!(updated_activity.actions[0].short.value, "Short Desc");
     assert_eq!(updated_activity.actions[0].description.value, "Long Desc");
-    assert_eq!(updated_activity.actions[0].test.as_str(), "test_file_path");
+    assert_eq!(updated_activity.actions[0].test.as_ref().unwrap().as_str(), "test_file_path");
     assert_eq!(updated_activity.actions[0].evidence.as_str(), "evidence_file_path");
 
 }
@@ -0,0 +1,113 @@
+use std::fmt::Display;
+use crate::error::{Error, Kind};
+use crate::values::specification::assurance_procedure::parameters::Parameters;
+
+/// The parameter names of the subject a [`Condition`] can compare, in addition to the `meta.<key>` parameters.
+const SUBJECT_PARAMETERS: &[&str] = &["subject.nrn", "subject.nid", "subject.nss", "subject.id"];
+
+/// A single `name == value` or `name != value` comparison of a [`Condition`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Comparison {
+    name: String,
+    value: String,
+    equal: bool,
+}
+
+impl Comparison {
+
+    fn is_met(&self, parameters: &Parameters) -> bool {
+        let matches = parameters.get(&self.name) == Some(self.value.as_str());
+        matches == self.equal
+    }
+
+}
+
+/// # Overview
+///
+/// The [`Condition`] is the `when` expression of an activity or action, which decides if it applies to the subject of a collection.  It compares the [`Parameters`] of the collection with a value, e.g. `meta.pci == 'true'`.
+///
+/// * A comparison is either `name == value`, or `name != value`.  The value may be quoted with `'` or `"`.
+/// * Comparisons can be combined with `&&` and `||`, where `&&` binds tighter than `||`.
+/// * The name is either `meta.<key>`, or one of `subject.nrn`, `subject.nid`, `subject.nss`, or `subject.id`.
+///
+/// A metadata key which was not given to the collection never equals a value.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Condition {
+    expression: String,
+    any_of: Vec<Vec<Comparison>>,
+}
+
+impl Condition {
+
+    /// Parses the `expression` of a [`Condition`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the expression is empty, a comparison does not have the form `name == value` or `name != value`, or the name is not a known parameter.
+    ///
+    pub fn try_from(expression: &str) -> Result<Condition, Error> {
+        let expression = expression.trim();
+        if expression.is_empty() {
+            return Err(custom_error(expression, "The condition is required, although a value was not provided."));
+        }
+
+        let mut any_of = Vec::new();
+        for conjunction in expression.split("||") {
+            let mut all_of = Vec::new();
+            for comparison in conjunction.split("&&") {
+                all_of.push(parse_comparison(expression, comparison)?);
+            }
+            any_of.push(all_of);
+        }
+
+        Ok(Condition { expression: expression.to_string(), any_of })
+    }
+
+    /// Returns `true` if the [`Condition`] is met by the [`Parameters`] of the collection.
+    pub fn is_met(&self, parameters: &Parameters) -> bool {
+        self.any_of.iter()
+            .any(|all_of| all_of.iter().all(|comparison| comparison.is_met(parameters)))
+    }
+
+    /// Returns the expression of the [`Condition`].
+    pub fn as_str(&self) -> &str {
+        &self.expression
+    }
+
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.expression)
+    }
+}
+
+fn parse_comparison(expression: &str, comparison: &str) -> Result<Comparison, Error> {
+    let (name, value, equal) = match (comparison.split_once("=="), comparison.split_once("!=")) {
+        (Some((name, value)), None) => (name, value, true),
+        (None, Some((name, value))) => (name, value, false),
+        _ => return Err(custom_error(expression, &format!("The comparison '{}' must have the form 'name == value' or 'name != value'.", comparison.trim())))
+    };
+
+    let name = name.trim();
+    if !name.starts_with("meta.") && !SUBJECT_PARAMETERS.contains(&name) {
+        return Err(custom_error(expression, &format!("The name '{}' is not known. It must be 'meta.<key>', or one of: [{}].", name, SUBJECT_PARAMETERS.join(", "))));
+    }
+
+    let value = value.trim();
+    let value = strip_quotes(value, '\'').or_else(|| strip_quotes(value, '"')).unwrap_or(value);
+    if value.is_empty() || value.contains(['=', '!']) {
+        return Err(custom_error(expression, &format!("The comparison '{}' must have the form 'name == value' or 'name != value'.", comparison.trim())));
+    }
+
+    Ok(Comparison { name: name.to_string(), value: value.to_string(), equal })
+}
+
+fn strip_quotes(value: &str, quote: char) -> Option<&str> {
+    value.strip_prefix(quote).and_then(|value| value.strip_suffix(quote))
+}
+
+fn custom_error(expression: &str, message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("There is an issue with the condition '{}'. {}", expression, message))
+}
@@ -0,0 +1,78 @@
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_procedure::condition::Condition;
+use crate::values::specification::assurance_procedure::parameters::Parameters;
+use crate::values::specification::metadata::MetaData;
+use crate::values::specification::subject::Subject;
+
+#[test]
+fn try_from_success() {
+    let condition = Condition::try_from(" meta.pci == 'true' ").unwrap();
+
+    assert_eq!(condition.as_str(), "meta.pci == 'true'");
+    assert_eq!(condition.to_string(), "meta.pci == 'true'");
+}
+
+#[test]
+fn is_met_equal_success() {
+    assert!(Condition::try_from("meta.pci == 'true'").unwrap().is_met(&parameters()));
+    assert!(Condition::try_from("subject.nid == sourcecode").unwrap().is_met(&parameters()));
+    assert!(!Condition::try_from("meta.environment == \"staging\"").unwrap().is_met(&parameters()));
+}
+
+#[test]
+fn is_met_not_equal_success() {
+    assert!(Condition::try_from("meta.environment != staging").unwrap().is_met(&parameters()));
+    assert!(!Condition::try_from("meta.pci != true").unwrap().is_met(&parameters()));
+}
+
+#[test]
+fn is_met_missing_metadata() {
+    assert!(!Condition::try_from("meta.region == eu").unwrap().is_met(&parameters()));
+    assert!(Condition::try_from("meta.region != eu").unwrap().is_met(&parameters()));
+}
+
+#[test]
+fn is_met_and_or_success() {
+    assert!(Condition::try_from("meta.pci == true && meta.environment == prod").unwrap().is_met(&parameters()));
+    assert!(!Condition::try_from("meta.pci == true && meta.environment == staging").unwrap().is_met(&parameters()));
+    assert!(Condition::try_from("meta.environment == staging || subject.id == 1234").unwrap().is_met(&parameters()));
+    assert!(Condition::try_from("meta.environment == staging && meta.pci == true || subject.nss == 'nape:nape-cli'").unwrap().is_met(&parameters()));
+}
+
+#[test]
+fn try_from_error_empty() {
+    let error = Condition::try_from(" ").unwrap_err();
+
+    assert_eq!(error.audience, Audience::User);
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "There is an issue with the condition ''. The condition is required, although a value was not provided.");
+}
+
+#[test]
+fn try_from_error_missing_operator() {
+    let error = Condition::try_from("meta.pci = true").unwrap_err();
+
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "There is an issue with the condition 'meta.pci = true'. The comparison 'meta.pci = true' must have the form 'name == value' or 'name != value'.");
+}
+
+#[test]
+fn try_from_error_missing_value() {
+    let error = Condition::try_from("meta.pci == ''").unwrap_err();
+
+    assert_eq!(error.message, "There is an issue with the condition 'meta.pci == '''. The comparison 'meta.pci == ''' must have the form 'name == value' or 'name != value'.");
+}
+
+#[test]
+fn try_from_error_unknown_name() {
+    let error = Condition::try_from("pci == true").unwrap_err();
+
+    assert_eq!(error.message, "There is an issue with the condition 'pci == true'. The name 'pci' is not known. It must be 'meta.<key>', or one of: [subject.nrn, subject.nid, subject.nss, subject.id].");
+}
+
+fn parameters() -> Parameters {
+    let mut metadata = MetaData::default();
+    metadata.add("pci", "true").unwrap();
+    metadata.add("environment", "prod").unwrap();
+    Parameters::from(&metadata, &Subject::try_new("nrn:sourcecode:nape:nape-cli", "1234").unwrap())
+}
@@ -0,0 +1,46 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::file_path::FilePath;
+use crate::values::specification::name::Name;
+
+/// The name of the primary evidence input of an action, which is its `evidence` file.
+pub const PRIMARY_EVIDENCE_INPUT: &str = "evidence";
+
+/// # Overview
+///
+/// The [`EvidenceInput`] is a named evidence file of an action, in addition to its primary `evidence` file, e.g. a runtime dump which is evaluated together with a configuration file.  Each input is passed to the test of the action by its name.
+///
+/// # Attributes
+///
+/// * `name` - The name the test refers to the input by.
+/// * `evidence` - The path of the evidence file, directory, or glob pattern, relative to the home directory.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EvidenceInput {
+    pub name: Name,
+    pub evidence: FilePath,
+}
+
+impl EvidenceInput {
+
+    /// Attempts to create a new [`EvidenceInput`].
+    ///
+    /// # Returns
+    ///
+    /// An [`EvidenceInput`], or an [`Error`] for [`Audience::User`] of [`Kind::InvalidInput`] if the name or evidence path is not valid, or the name is the reserved name of the primary evidence input.
+    ///
+    pub fn try_new(name: &str, evidence: &str) -> Result<EvidenceInput, Error> {
+        let valid_name = Name::try_from(name)
+            .map_err(|e| custom_error(name, &e.message))?;
+        if valid_name.value == PRIMARY_EVIDENCE_INPUT {
+            return Err(custom_error(name, &format!("The name '{}' is reserved for the primary evidence file of the action.", PRIMARY_EVIDENCE_INPUT)));
+        }
+        let valid_evidence = FilePath::try_from(evidence)
+            .map_err(|e| custom_error(name, &e.message))?;
+        Ok(EvidenceInput { name: valid_name, evidence: valid_evidence })
+    }
+
+}
+
+fn custom_error(name: &str, message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("There is an issue with the evidence input '{}'. {}", name, message))
+}
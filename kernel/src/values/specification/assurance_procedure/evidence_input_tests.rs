@@ -0,0 +1,33 @@
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_procedure::evidence_input::EvidenceInput;
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+
+#[test]
+fn try_new_success() {
+    let result = EvidenceInput::try_new("runtime", "./evidence/ci/runtime.json");
+
+    let input = is_ok!(result);
+    assert_eq!(input.name.value, "runtime");
+    assert_eq!(input.evidence.as_str(), "./evidence/ci/runtime.json");
+}
+
+#[test]
+fn try_new_reserved_name_error() {
+    let result = EvidenceInput::try_new("evidence", "./evidence/ci/runtime.json");
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "There is an issue with the evidence input 'evidence'. The name 'evidence' is reserved for the primary evidence file of the action.");
+}
+
+#[test]
+fn try_new_invalid_name_error() {
+    let result = EvidenceInput::try_new("runtime dump", "./evidence/ci/runtime.json");
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "There is an issue with the evidence input 'runtime dump'. ");
+}
+
+#[test]
+fn try_new_empty_evidence_error() {
+    let result = EvidenceInput::try_new("runtime", "");
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "There is an issue with the evidence input 'runtime'. ");
+}
@@ -0,0 +1,98 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::name::Name;
+use crate::values::specification::procedure::Procedure;
+
+/// # Overview
+///
+/// The [`Source`] is the location of another Assurance Procedure, which activities are included from.
+///
+/// # Attributes
+///
+/// * `repository` - The repository link where the included procedure is stored.
+/// * `directory` - The directory within the repository where the included procedure is located.
+/// * `revision` - An optional revision, e.g. a tag, branch, or commit, of the repository.  When it is not provided, the default branch is used.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Source {
+    pub repository: String,
+    pub directory: String,
+    pub revision: Option<String>,
+}
+
+impl Source {
+
+    /// Attempts to create a new [`Source`].
+    ///
+    /// # Returns
+    ///
+    /// A [`Source`], or an [`Error`] for [`Audience::User`] of [`Kind::InvalidInput`] if the repository, directory, or revision is not valid.
+    ///
+    pub fn try_new(repository: &str, directory: &str, revision: Option<&str>) -> Result<Source, Error> {
+        let location = Procedure::try_new(repository, directory)
+            .map_err(|e| custom_error(&e.message))?;
+        let valid_revision = match revision {
+            Some(revision) => Some(validate_revision(revision)?),
+            None => None
+        };
+        Ok(Source { repository: location.repository, directory: location.directory, revision: valid_revision })
+    }
+
+}
+
+impl std::fmt::Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.revision {
+            Some(revision) => write!(f, "{}//{}@{}", self.repository, self.directory, revision),
+            None => write!(f, "{}//{}", self.repository, self.directory)
+        }
+    }
+}
+
+/// # Overview
+///
+/// The [`Include`] is an entry in the `include` section of an Assurance Procedure.  It selects the activities to pull in from the procedure at the [`Source`].
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Include {
+    pub source: Source,
+    pub activities: Vec<Name>,
+}
+
+impl Include {
+
+    /// Attempts to create a new [`Include`].
+    ///
+    /// # Returns
+    ///
+    /// An [`Include`], or an [`Error`] for [`Audience::User`] of [`Kind::InvalidInput`] if no activities are selected, an activity name is invalid, or an activity is selected more than once.
+    ///
+    pub fn try_new(source: &Source, activities: &[String]) -> Result<Include, Error> {
+        if activities.is_empty() {
+            return Err(custom_error(&format!("The include for '{}' does not select any activities. Please list the activities to include.", source)));
+        }
+
+        let mut valid_activities: Vec<Name> = Vec::new();
+        for activity in activities {
+            let name = Name::try_from(activity)
+                .map_err(|e| custom_error(&format!("The activity name '{}' is invalid. {}", activity, e.message)))?;
+            if valid_activities.contains(&name) {
+                return Err(custom_error(&format!("The activity '{}' is selected more than once for '{}'.", activity, source)));
+            }
+            valid_activities.push(name);
+        }
+
+        Ok(Include { source: source.clone(), activities: valid_activities })
+    }
+
+}
+
+fn validate_revision(revision: &str) -> Result<String, Error> {
+    if revision.is_empty() || revision.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(custom_error(&format!("The revision '{}' is invalid. It must not be empty or contain whitespace.", revision)));
+    }
+    Ok(revision.to_string())
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("There is an issue with the procedure include: {}", message))
+}
@@ -0,0 +1,67 @@
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_procedure::include::{Include, Source};
+
+#[test]
+fn source_new_success() {
+    let source = Source::try_new("https://github.com/example/procedures.git", "shared/branch-protection", Some("v1.2.0")).unwrap();
+
+    assert_eq!(source.repository, "https://github.com/example/procedures.git");
+    assert_eq!(source.directory, "shared/branch-protection");
+    assert_eq!(source.revision, Some("v1.2.0".to_string()));
+    assert_eq!(source.to_string(), "https://github.com/example/procedures.git//shared/branch-protection@v1.2.0");
+}
+
+#[test]
+fn source_new_without_revision() {
+    let source = Source::try_new("https://github.com/example/procedures.git", "shared", None).unwrap();
+
+    assert_eq!(source.revision, None);
+    assert_eq!(source.to_string(), "https://github.com/example/procedures.git//shared");
+}
+
+#[test]
+fn source_new_error_invalid_revision() {
+    let result = Source::try_new("https://github.com/example/procedures.git", "shared", Some("v1 2"));
+
+    let error = result.unwrap_err();
+    assert_eq!(error.audience, Audience::User);
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "There is an issue with the procedure include: The revision 'v1 2' is invalid. It must not be empty or contain whitespace.");
+}
+
+#[test]
+fn source_new_error_invalid_directory() {
+    let result = Source::try_new("https://github.com/example/procedures.git", "/shared", None);
+
+    let error = result.unwrap_err();
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert!(error.message.starts_with("There is an issue with the procedure include: "));
+}
+
+#[test]
+fn include_new_success() {
+    let source = Source::try_new("https://github.com/example/procedures.git", "shared", None).unwrap();
+    let include = Include::try_new(&source, &["branch-protection".to_string(), "dependency-scanning".to_string()]).unwrap();
+
+    assert_eq!(include.source, source);
+    assert_eq!(include.activities.len(), 2);
+    assert_eq!(include.activities[1].value, "dependency-scanning");
+}
+
+#[test]
+fn include_new_error_no_activities() {
+    let source = Source::try_new("https://github.com/example/procedures.git", "shared", None).unwrap();
+    let error = Include::try_new(&source, &[]).unwrap_err();
+
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "There is an issue with the procedure include: The include for 'https://github.com/example/procedures.git//shared' does not select any activities. Please list the activities to include.");
+}
+
+#[test]
+fn include_new_error_duplicate_activity() {
+    let source = Source::try_new("https://github.com/example/procedures.git", "shared", None).unwrap();
+    let error = Include::try_new(&source, &["branch-protection".to_string(), "branch-protection".to_string()]).unwrap_err();
+
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "There is an issue with the procedure include: The activity 'branch-protection' is selected more than once for 'https://github.com/example/procedures.git//shared'.");
+}
@@ -3,6 +3,10 @@ pub mod artifact;
 pub mod artifacts;
 pub mod activity;
 pub mod activities;
+pub mod condition;
+pub mod evidence_input;
+pub mod include;
+pub mod parameters;
 pub mod procedure;
 
 
@@ -11,4 +15,8 @@ pub mod procedure;
 #[cfg(test)] mod artifacts_tests;
 #[cfg(test)] mod activity_tests;
 #[cfg(test)] mod activities_tests;
+#[cfg(test)] mod condition_tests;
+#[cfg(test)] mod evidence_input_tests;
+#[cfg(test)] mod include_tests;
+#[cfg(test)] mod parameters_tests;
 #[cfg(test)] mod procedure_tests;
\ No newline at end of file
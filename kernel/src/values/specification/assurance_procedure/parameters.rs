@@ -0,0 +1,92 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::metadata::MetaData;
+use crate::values::specification::subject::Subject;
+
+/// The opening of a placeholder in a procedure, e.g. `${meta.environment}`.
+const PLACEHOLDER_START: &str = "${";
+
+/// The closing of a placeholder in a procedure.
+const PLACEHOLDER_END: &str = "}";
+
+/// # Overview
+///
+/// The [`Parameters`] are the values a procedure can refer to with a `${name}` placeholder.  They are built from the [`MetaData`] and [`Subject`] of a collection:
+///
+/// * `meta.<key>` - The value of each metadata key, e.g. `${meta.environment}`.
+/// * `subject.nrn` - The NRN of the subject.
+/// * `subject.nid` - The namespace identifier of the subject NRN, e.g. `sourcecode`.
+/// * `subject.nss` - The namespace specific string of the subject NRN, e.g. `nape:nape-cli`.
+/// * `subject.id` - The unique identifier of the subject.
+///
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Parameters {
+    list: Vec<(String, String)>,
+}
+
+impl Parameters {
+
+    /// Creates the [`Parameters`] for the [`MetaData`] and [`Subject`] of a collection.
+    pub fn from(metadata: &MetaData, subject: &Subject) -> Parameters {
+        let mut list: Vec<(String, String)> = metadata.data.iter()
+            .map(|(key, value)| (format!("meta.{}", key.value), value.value.clone()))
+            .collect();
+        list.push(("subject.nrn".to_string(), subject.nrn.to_string()));
+        list.push(("subject.nid".to_string(), subject.nrn.nid.to_string()));
+        list.push(("subject.nss".to_string(), subject.nrn.nss.iter().map(|nss| nss.value.as_str()).collect::<Vec<&str>>().join(":")));
+        list.push(("subject.id".to_string(), subject.id.value.clone()));
+        Parameters { list }
+    }
+
+    /// Returns the value of the parameter with the `name`, if it exists.
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.list.iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the name and value of each parameter.
+    pub fn list(&self) -> &Vec<(String, String)> {
+        &self.list
+    }
+
+    /// # Overview
+    ///
+    /// Replaces every `${name}` placeholder in the `value` with the value of the parameter.
+    ///
+    /// # Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if:
+    ///
+    /// * a placeholder refers to a parameter which does not exist, or
+    /// * a placeholder is not closed.
+    ///
+    pub fn resolve(&self, value: &str) -> Result<String, Error> {
+        let mut resolved = String::new();
+        let mut remaining = value;
+
+        while let Some(start) = remaining.find(PLACEHOLDER_START) {
+            resolved.push_str(&remaining[..start]);
+            let after_start = &remaining[start + PLACEHOLDER_START.len()..];
+            let end = after_start.find(PLACEHOLDER_END)
+                .ok_or_else(|| custom_error(&format!("The placeholder in '{}' is not closed. A placeholder must have the form '${{name}}'.", value)))?;
+            let name = after_start[..end].trim();
+            let parameter = self.get(name)
+                .ok_or_else(|| custom_error(&format!("The placeholder '${{{}}}' in '{}' could not be resolved. {}", name, value, self.available())))?;
+            resolved.push_str(parameter);
+            remaining = &after_start[end + PLACEHOLDER_END.len()..];
+        }
+        resolved.push_str(remaining);
+
+        Ok(resolved)
+    }
+
+    fn available(&self) -> String {
+        let names: Vec<&str> = self.list.iter().map(|(key, _)| key.as_str()).collect();
+        format!("The available parameters are: [{}].", names.join(", "))
+    }
+
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("There is an issue with a procedure parameter: {}", message))
+}
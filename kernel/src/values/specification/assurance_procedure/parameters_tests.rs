@@ -0,0 +1,56 @@
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_procedure::parameters::Parameters;
+use crate::values::specification::metadata::MetaData;
+use crate::values::specification::subject::Subject;
+
+#[test]
+fn from_success() {
+    let parameters = parameters();
+
+    assert_eq!(parameters.get("meta.environment"), Some("prod"));
+    assert_eq!(parameters.get("subject.nrn"), Some("nrn:sourcecode:nape:nape-cli"));
+    assert_eq!(parameters.get("subject.nid"), Some("sourcecode"));
+    assert_eq!(parameters.get("subject.nss"), Some("nape:nape-cli"));
+    assert_eq!(parameters.get("subject.id"), Some("1234"));
+    assert_eq!(parameters.get("environment"), None);
+    assert_eq!(parameters.list().len(), 5);
+}
+
+#[test]
+fn resolve_success() {
+    let result = parameters().resolve("./evidence/${meta.environment}/${subject.id}.json");
+
+    assert_eq!(result.unwrap(), "./evidence/prod/1234.json");
+}
+
+#[test]
+fn resolve_success_without_placeholders() {
+    let result = parameters().resolve("./evidence/build.json");
+
+    assert_eq!(result.unwrap(), "./evidence/build.json");
+}
+
+#[test]
+fn resolve_error_unknown_parameter() {
+    let result = parameters().resolve("./evidence/${meta.region}.json");
+
+    let error = result.unwrap_err();
+    assert_eq!(error.audience, Audience::User);
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "There is an issue with a procedure parameter: The placeholder '${meta.region}' in './evidence/${meta.region}.json' could not be resolved. The available parameters are: [meta.environment, subject.nrn, subject.nid, subject.nss, subject.id].");
+}
+
+#[test]
+fn resolve_error_not_closed() {
+    let result = parameters().resolve("./evidence/${meta.environment.json");
+
+    let error = result.unwrap_err();
+    assert_eq!(error.kind, Kind::InvalidInput);
+    assert_eq!(error.message, "There is an issue with a procedure parameter: The placeholder in './evidence/${meta.environment.json' is not closed. A placeholder must have the form '${name}'.");
+}
+
+fn parameters() -> Parameters {
+    let mut metadata = MetaData::default();
+    metadata.add("environment", "prod").unwrap();
+    Parameters::from(&metadata, &Subject::try_new("nrn:sourcecode:nape:nape-cli", "1234").unwrap())
+}
@@ -1,17 +1,32 @@
 use crate::algorithms::signature_algorithm::Signature;
 use crate::error::{Error, Kind};
+use crate::values::specification::assurance_report::attestation::Attestation;
+use crate::values::specification::assurance_report::exclusion::Exclusion;
+use crate::values::specification::assurance_report::finding::Finding;
+use crate::values::specification::assurance_report::observations::Observations;
 use crate::values::specification::assurance_report::signed_file::SignedFile;
+use crate::values::specification::assurance_report::signed_input::SignedInput;
+use crate::values::specification::assurance_report::waiver::Waiver;
 use crate::values::specification::description::Description;
 use crate::values::specification::name::Name;
 use crate::values::specification::outcome::Outcome;
+use crate::values::specification::severity::Severity;
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Action {
     name: Name,
     outcome: Outcome,
     reason: Description,
-    test_file: SignedFile,
-    evidence_file: SignedFile
+    test_file: Option<SignedFile>,
+    evidence_file: Option<SignedFile>,
+    evidence_files: Vec<SignedFile>,
+    inputs: Vec<SignedInput>,
+    severity: Option<Severity>,
+    waiver: Option<Waiver>,
+    exclusion: Option<Exclusion>,
+    attestation: Option<Attestation>,
+    findings: Vec<Finding>,
+    observations: Observations
 }
 
 impl Action {
@@ -38,14 +53,54 @@ impl Action {
         &self.reason
     }
 
-    /// Return a reference to the test file (a [`SignedFile`] struct) of the action.
-    pub fn test_file(&self) -> &SignedFile {
-        &self.test_file
+    /// Return a reference to the test file (a [`SignedFile`] struct) of the action.  There is no test file for an attestation action, whose outcome is attested by a person rather than the result of a test.  The test file is always present for other actions.
+    pub fn test_file(&self) -> Option<&SignedFile> {
+        self.test_file.as_ref()
     }
 
-    /// Return a reference to the evidence file (a [`SignedFile`] struct) of the action.
-    pub fn evidence_file(&self) -> &SignedFile {
-        &self.evidence_file
+    /// Return a reference to the evidence file (a [`SignedFile`] struct) of the action.  There is no evidence file for an action with the [`Outcome::NOT_APPLICABLE`] outcome, because it was not collected, or the [`Outcome::SKIPPED`] outcome, because it was not evaluated.
+    pub fn evidence_file(&self) -> Option<&SignedFile> {
+        self.evidence_file.as_ref()
+    }
+
+    /// Return the individually signed files within the evidence, when the evidence is a directory or glob pattern rather than a single file.  The list is empty for a single evidence file.
+    pub fn evidence_files(&self) -> &Vec<SignedFile> {
+        &self.evidence_files
+    }
+
+    /// Return the signed evidence of each named evidence input, in addition to the primary evidence file.  The list is empty when the action has no additional inputs, or its evidence was not collected.
+    pub fn inputs(&self) -> &Vec<SignedInput> {
+        &self.inputs
+    }
+
+    /// Return a reference to the [`Severity`] of the action, if the procedure assigned one.
+    pub fn severity(&self) -> Option<&Severity> {
+        self.severity.as_ref()
+    }
+
+    /// Return a reference to the [`Waiver`] which was applied to the action, if there is one.
+    pub fn waiver(&self) -> Option<&Waiver> {
+        self.waiver.as_ref()
+    }
+
+    /// Return a reference to the [`Exclusion`] which tailored the action out of the collection, if there is one.
+    pub fn exclusion(&self) -> Option<&Exclusion> {
+        self.exclusion.as_ref()
+    }
+
+    /// Return a reference to the [`Attestation`] of the action, if its outcome was attested by a person.  The evidence file of an attested action is its signed attestation statement.
+    pub fn attestation(&self) -> Option<&Attestation> {
+        self.attestation.as_ref()
+    }
+
+    /// Return the [`Finding`]s the test reported in the evidence of the action.  The list is empty when the test did not report any findings.
+    pub fn findings(&self) -> &Vec<Finding> {
+        &self.findings
+    }
+
+    /// Return the [`Observations`] the test measured in the evidence of the action.
+    pub fn observations(&self) -> &Observations {
+        &self.observations
     }
 
 }
@@ -65,7 +120,16 @@ pub struct ActionBuilder {
     test_file_signature_str: Option<String>,
     signed_evidence: Option<SignedFile>, // note - if this is not provided, then the evidence_file_path and evidence_file_signature must be provided
     evidence_file_path_str: Option<String>,
-    evidence_file_signature_str: Option<String>
+    evidence_file_signature_str: Option<String>,
+    evidence_files: Vec<SignedFile>, // note - only provided when the evidence is a directory or glob pattern
+    inputs: Vec<SignedInput>, // note - only provided when the action has named evidence inputs
+    severity: Option<Severity>, // note - the severity is optional, if neither is provided the action has no severity
+    severity_str: Option<String>,
+    waiver: Option<Waiver>, // note - the waiver is optional, and is only set when a waiver was applied to the action
+    exclusion: Option<Exclusion>, // note - the exclusion is optional, and is only set when the action was tailored out of the collection
+    attestation: Option<Attestation>, // note - the attestation is optional, and is only set when the outcome of the action was attested
+    findings: Vec<Finding>, // note - only provided when the test reported findings
+    observations: Observations // note - only provided when the test reported observations
 }
 
 ///
@@ -90,7 +154,16 @@ impl ActionBuilder {
             test_file_signature_str: None,
             signed_evidence: None,
             evidence_file_path_str: None,
-            evidence_file_signature_str: None
+            evidence_file_signature_str: None,
+            evidence_files: Vec::new(),
+            inputs: Vec::new(),
+            severity: None,
+            severity_str: None,
+            waiver: None,
+            exclusion: None,
+            attestation: None,
+            findings: Vec::new(),
+            observations: Observations::default()
         }
     }
 
@@ -180,20 +253,82 @@ impl ActionBuilder {
         self
     }
 
+    /// Add an individually signed file within the evidence, when the evidence is a directory or glob pattern.
+    pub fn add_evidence_file(mut self, evidence_file: &SignedFile) -> Self {
+        self.evidence_files.push(evidence_file.clone());
+        self
+    }
+
+    /// Add the signed evidence of a named evidence input of the action.
+    pub fn add_input(mut self, input: &SignedInput) -> Self {
+        self.inputs.push(input.clone());
+        self
+    }
+
+    /// Use an existing ['Severity'] instance to set the severity of the action.
+    pub fn use_severity(mut self, severity: &Severity) -> Self {
+        self.severity = Some(severity.clone());
+        self
+    }
+
+    /// Use a string to set the severity of the action.
+    pub fn severity(mut self, severity: &str) -> Self {
+        self.severity_str = Some(severity.to_string());
+        self
+    }
+
+    /// Use an existing ['Waiver'] instance to record the waiver which was applied to the action.
+    pub fn use_waiver(mut self, waiver: &Waiver) -> Self {
+        self.waiver = Some(waiver.clone());
+        self
+    }
+
+    /// Use an existing ['Exclusion'] instance to record that the action was tailored out of the collection.
+    pub fn use_exclusion(mut self, exclusion: &Exclusion) -> Self {
+        self.exclusion = Some(exclusion.clone());
+        self
+    }
+
+    /// Use an existing ['Attestation'] instance to record the statement of the person who attested the outcome of the action.  An attested action has no test file.
+    pub fn use_attestation(mut self, attestation: &Attestation) -> Self {
+        self.attestation = Some(attestation.clone());
+        self
+    }
+
+    /// Add a [`Finding`] the test reported in the evidence of the action.
+    pub fn add_finding(mut self, finding: &Finding) -> Self {
+        self.findings.push(finding.clone());
+        self
+    }
+
+    /// Use an existing ['Observations'] instance to record the values the test measured in the evidence of the action.
+    pub fn use_observations(mut self, observations: &Observations) -> Self {
+        self.observations = observations.clone();
+        self
+    }
 
     pub fn try_build(self) -> Result<Action, Error> {
         let valid_name = self.validate_name()?;
         let valid_outcome = self.validate_outcome()?;
         let valid_reason = self.validate_reason()?;
-        let valid_test = self.validate_signed_test_file()?;
-        let valid_evidence = self.validate_signed_evidence_file()?;
+        let valid_test = self.validate_signed_test_file(&valid_outcome)?;
+        let valid_evidence = self.validate_signed_evidence_file(&valid_outcome)?;
+        let valid_severity = self.validate_severity()?;
 
         Ok(Action {
             name: valid_name,
             outcome: valid_outcome,
             reason: valid_reason,
             test_file: valid_test,
-            evidence_file: valid_evidence
+            evidence_file: valid_evidence,
+            evidence_files: self.evidence_files,
+            inputs: self.inputs,
+            severity: valid_severity,
+            waiver: self.waiver,
+            exclusion: self.exclusion,
+            attestation: self.attestation,
+            findings: self.findings,
+            observations: self.observations
         })
     }
 
@@ -223,6 +358,19 @@ impl ActionBuilder {
         }
     }
 
+     fn validate_severity(&self) -> Result<Option<Severity>, Error> {
+        match &self.severity {
+            Some(severity) => Ok(Some(severity.clone())),
+            None => match &self.severity_str {
+                Some(severity_str) => {
+                    Severity::try_from(severity_str).map(Some).map_err(|e| Error::for_user(Kind::InvalidInput,
+                                                                                 format!("There is an issue with the severity '{}'. {}", severity_str, e.message)))
+                }
+                None => Ok(None)
+            }
+        }
+    }
+
      fn validate_reason(&self) -> Result<Description, Error> {
         match &self.reason {
             Some(reason) => Ok(reason.clone()),
@@ -236,11 +384,17 @@ impl ActionBuilder {
         }
     }
 
-     fn validate_signed_test_file(&self) -> Result<SignedFile, Error> {
+     fn validate_signed_test_file(&self, outcome: &Outcome) -> Result<Option<SignedFile>, Error> {
         match &self.signed_test {
-            Some(test_file) => Ok(test_file.clone()),
+            Some(test_file) => Ok(Some(test_file.clone())),
             None => {
 
+                // An attested action has no test file, and neither does an attestation action which does not apply to the subject, or was skipped, unless it is provided.
+                let attestation_action = self.attestation.is_some() || outcome == &Outcome::NOT_APPLICABLE || outcome == &Outcome::SKIPPED;
+                if attestation_action && self.test_file_path_str.is_none() && self.test_file_signature_str.is_none() {
+                    return Ok(None);
+                }
+
                 let file_path = match &self.test_file_path_str {
                     Some(file_path) => file_path,
                     None => return Err(Error::for_user(Kind::InvalidInput, "Please provide a test file path for the action.".to_string()))
@@ -252,17 +406,22 @@ impl ActionBuilder {
 
                 let valid_signature = Signature::try_from(file_signature).map_err(|e| Error::for_user(Kind::InvalidInput,
                                                            format!("There is an issue with the test file signature '{}'. {}", file_signature, e.message)))?;
-                SignedFile::new(file_path, &valid_signature).map_err(|e| Error::for_user(Kind::InvalidInput,
+                SignedFile::new(file_path, &valid_signature).map(Some).map_err(|e| Error::for_user(Kind::InvalidInput,
                                                                          format!("There is an issue with the test file path '{}'. {}", file_path, e.message)))
             }
         }
     }
 
-     fn validate_signed_evidence_file(&self) -> Result<SignedFile, Error> {
+     fn validate_signed_evidence_file(&self, outcome: &Outcome) -> Result<Option<SignedFile>, Error> {
         match &self.signed_evidence {
-            Some(evidence_file) => Ok(evidence_file.clone()),
+            Some(evidence_file) => Ok(Some(evidence_file.clone())),
             None => {
 
+                // An action which does not apply to the subject, or was skipped, has no evidence, unless it is provided.
+                if (outcome == &Outcome::NOT_APPLICABLE || outcome == &Outcome::SKIPPED) && self.evidence_file_path_str.is_none() && self.evidence_file_signature_str.is_none() {
+                    return Ok(None);
+                }
+
                 let file_path = match &self.evidence_file_path_str {
                     Some(file_path) => file_path,
                     None => return Err(Error::for_user(Kind::InvalidInput, "Please provide an evidence file path for the action.".to_string()))
@@ -274,7 +433,7 @@ impl ActionBuilder {
 
                 let valid_signature = Signature::try_from(file_signature).map_err(|e| Error::for_user(Kind::InvalidInput,
                                                            format!("There is an issue with the evidence file signature '{}'. {}", file_signature, e.message)))?;
-                SignedFile::new(file_path, &valid_signature).map_err(|e| Error::for_user(Kind::InvalidInput,
+                SignedFile::new(file_path, &valid_signature).map(Some).map_err(|e| Error::for_user(Kind::InvalidInput,
                                                                          format!("There is an issue with the evidence file path '{}'. {}", file_path, e.message)))
 
             }
@@ -3,10 +3,18 @@ use crate::algorithms::signature_algorithm::Signature;
 use crate::algorithms::signature_algorithm::SignatureType::SHA256;
 use crate::error::{Audience, Kind};
 use crate::values::specification::assurance_report::action::Action;
+use crate::values::specification::assurance_report::attestation::Attestation;
+use crate::values::specification::assurance_report::exclusion::Exclusion;
+use crate::values::specification::assurance_report::finding::{EvidenceLocation, Finding, LineRange};
+use crate::values::specification::assurance_report::observations::Observations;
 use crate::values::specification::outcome::Outcome;
 use crate::values::specification::assurance_report::signed_file::SignedFile;
+use crate::values::specification::assurance_report::signed_input::SignedInput;
 use crate::values::specification::description::Description;
 use crate::values::specification::name::Name;
+use crate::values::specification::severity::Severity;
+use crate::values::time::start_time::StartTime;
+use crate::values::specification::assurance_report::waiver::Waiver;
 
 
 #[test]
@@ -27,8 +35,8 @@ fn builder_use_only_success() {
     assert_eq!(action.name(), &Name::try_from("action-name").unwrap());
     assert_eq!(action.outcome(), &Outcome::PASS);
     assert_eq!(action.reason(), &Description::try_from("action reason").unwrap());
-    assert_eq!(action.test_file(), &test_file);
-    assert_eq!(action.evidence_file(), &evidence_file);
+    assert_eq!(action.test_file(), Some(&test_file));
+    assert_eq!(action.evidence_file(), Some(&evidence_file));
 }
 
 #[test]
@@ -53,8 +61,8 @@ fn builder_str_only_success() {
     let evidence_file_sig = Signature::try_new(SHA256, "evidencesignature").unwrap();
     let evidence_file = SignedFile::new("./some-evidence/file.txt", &evidence_file_sig).unwrap();
 
-    assert_eq!(action.test_file(), &test_file);
-    assert_eq!(action.evidence_file(), &evidence_file);
+    assert_eq!(action.test_file(), Some(&test_file));
+    assert_eq!(action.evidence_file(), Some(&evidence_file));
 }
 
 #[test]
@@ -82,8 +90,8 @@ fn builder_ensure_use_overrides_str_success() {
     assert_eq!(action.name(), &Name::try_from("action-name").unwrap());
     assert_eq!(action.outcome(), &Outcome::PASS);
     assert_eq!(action.reason(), &Description::try_from("action reason").unwrap());
-    assert_eq!(action.test_file(), &test_file);
-    assert_eq!(action.evidence_file(), &evidence_file);
+    assert_eq!(action.test_file(), Some(&test_file));
+    assert_eq!(action.evidence_file(), Some(&evidence_file));
 
 }
 
@@ -202,4 +210,213 @@ fn builder_invalid_evidence_file_signature_error() {
         .try_build();
 
     kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "There is an issue with the evidence file signature 'BILL[evidencesignature]'. ");
-}
\ No newline at end of file
+}
+#[test]
+fn builder_severity_success() {
+
+    let without_severity = Action::builder()
+        .name("action-name")
+        .outcome("pass")
+        .reason("action reason")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .evidence_file_path("./some-evidence/file.txt")
+        .evidence_file_signature("SHA256[evidencesignature]")
+        .try_build().unwrap();
+
+    let with_severity = Action::builder()
+        .name("action-name")
+        .outcome("pass")
+        .reason("action reason")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .evidence_file_path("./some-evidence/file.txt")
+        .evidence_file_signature("SHA256[evidencesignature]")
+        .severity("low")
+        .use_severity(&Severity::CRITICAL)
+        .try_build().unwrap();
+
+    assert_eq!(without_severity.severity(), None);
+    assert_eq!(with_severity.severity(), Some(&Severity::CRITICAL));
+}
+
+#[test]
+fn builder_invalid_severity_error() {
+
+    let result = Action::builder()
+        .name("action-name")
+        .outcome("pass")
+        .reason("action reason")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .evidence_file_path("./some-evidence/file.txt")
+        .evidence_file_signature("SHA256[evidencesignature]")
+        .severity("urgent")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "There is an issue with the severity 'urgent'. ");
+}
+
+#[test]
+fn builder_waiver_success() {
+
+    let waiver = Waiver::try_new("action-name", "A compensating control is in place.", "Jane Doe", "2024-06-30").unwrap();
+
+    let action = Action::builder()
+        .name("action-name")
+        .outcome("waived")
+        .reason("action reason")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .evidence_file_path("./some-evidence/file.txt")
+        .evidence_file_signature("SHA256[evidencesignature]")
+        .use_waiver(&waiver)
+        .try_build().unwrap();
+
+    assert_eq!(action.outcome(), &Outcome::WAIVED);
+    assert_eq!(action.waiver(), Some(&waiver));
+}
+
+#[test]
+fn builder_evidence_files_success() {
+
+    let manifest = SignedFile::new("./some-evidence/manifests/deploy.yaml", &Signature::try_new(SHA256, "deploysignature").unwrap()).unwrap();
+    let service = SignedFile::new("./some-evidence/manifests/service.yaml", &Signature::try_new(SHA256, "servicesignature").unwrap()).unwrap();
+
+    let action = Action::builder()
+        .name("action-name")
+        .outcome("pass")
+        .reason("action reason")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .evidence_file_path("./some-evidence/manifests")
+        .evidence_file_signature("SHA256[directorysignature]")
+        .add_evidence_file(&manifest)
+        .add_evidence_file(&service)
+        .try_build().unwrap();
+
+    assert_eq!(action.evidence_files(), &vec![manifest, service]);
+}
+
+#[test]
+fn builder_inputs_success() {
+
+    let runtime = SignedFile::new("./some-evidence/runtime.json", &Signature::try_new(SHA256, "runtimesignature").unwrap()).unwrap();
+    let input = SignedInput::new(&Name::try_from("runtime").unwrap(), &runtime, &[]);
+
+    let action = Action::builder()
+        .name("action-name")
+        .outcome("pass")
+        .reason("action reason")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .evidence_file_path("./some-evidence/config.yaml")
+        .evidence_file_signature("SHA256[configsignature]")
+        .add_input(&input)
+        .try_build().unwrap();
+
+    assert_eq!(action.inputs(), &vec![input]);
+    assert_eq!(action.inputs()[0].evidence_file.file().as_str(), "./some-evidence/runtime.json");
+}
+
+#[test]
+fn builder_findings_and_observations_success() {
+
+    let finding = Finding::try_new("The container runs as root.", "critical").unwrap()
+        .with_location(&EvidenceLocation::try_new("manifests/deploy.yaml").unwrap().with_lines(&LineRange::try_new(12, 14).unwrap()));
+    let mut observations = Observations::default();
+    observations.add("containers", "3").unwrap();
+
+    let action = Action::builder()
+        .name("action-name")
+        .outcome("fail")
+        .reason("action reason")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .evidence_file_path("./some-evidence/manifests")
+        .evidence_file_signature("SHA256[directorysignature]")
+        .add_finding(&finding)
+        .use_observations(&observations)
+        .try_build().unwrap();
+
+    assert_eq!(action.findings(), &vec![finding]);
+    assert_eq!(action.observations().get("containers"), Some("3".to_string()));
+}
+
+#[test]
+fn builder_not_applicable_without_evidence_success() {
+
+    let exclusion = Exclusion::try_new("action-name", "The subject has no database.").unwrap();
+
+    let action = Action::builder()
+        .name("action-name")
+        .use_outcome(&Outcome::NOT_APPLICABLE)
+        .use_reason(&exclusion.justification)
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .use_exclusion(&exclusion)
+        .try_build().unwrap();
+
+    assert_eq!(action.outcome(), &Outcome::NOT_APPLICABLE);
+    assert_eq!(action.evidence_file(), None);
+    assert_eq!(action.exclusion(), Some(&exclusion));
+}
+
+#[test]
+fn builder_skipped_without_evidence_success() {
+
+    let action = Action::builder()
+        .name("action-name")
+        .outcome("skipped")
+        .reason("The action 'action-name' depends on 'other-action', which did not pass.")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .try_build().unwrap();
+
+    assert_eq!(action.outcome(), &Outcome::SKIPPED);
+    assert_eq!(action.evidence_file(), None);
+}
+
+#[test]
+fn builder_missing_evidence_error() {
+    let result = Action::builder()
+        .name("action-name")
+        .outcome("pass")
+        .reason("action reason")
+        .test_file_path("./some-test/file.txt")
+        .test_file_signature("SHA256[testsignature]")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "Please provide an evidence file path for the action.");
+}
+
+#[test]
+fn builder_attestation_without_test_success() {
+    let attestation = Attestation::try_new("action-name", "pass", "All accounts were reviewed.", "Jane Doe", &StartTime::from(1719326666000)).unwrap();
+
+    let action = Action::builder()
+        .name("action-name")
+        .use_outcome(&attestation.outcome)
+        .use_reason(&attestation.rationale)
+        .evidence_file_path("./evidence/action-name/attestation.yaml")
+        .evidence_file_signature("SHA256[statementsignature]")
+        .use_attestation(&attestation)
+        .try_build().unwrap();
+
+    assert_eq!(action.test_file(), None);
+    assert_eq!(action.attestation(), Some(&attestation));
+    assert_eq!(action.evidence_file().unwrap().file().as_str(), "./evidence/action-name/attestation.yaml");
+}
+
+#[test]
+fn builder_missing_test_error() {
+    let result = Action::builder()
+        .name("action-name")
+        .outcome("pass")
+        .reason("action reason")
+        .evidence_file_path("./some-evidence/file.txt")
+        .evidence_file_signature("SHA256[evidencesignature]")
+        .try_build();
+
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "Please provide a test file path for the action.");
+}
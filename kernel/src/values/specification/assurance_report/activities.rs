@@ -1,6 +1,7 @@
 use crate::error::{Error, Kind};
 use crate::values::specification::assurance_report::action::Action;
 use crate::values::specification::assurance_report::activity::Activity;
+use crate::values::specification::assurance_procedure::include::Source;
 
 /// The [`Activities`] struct is a collection of [`Activity`] that can be used to represent and manage a list of activities.
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
@@ -55,7 +56,8 @@ impl Activities {
 ///
 pub struct Builder {
     activities: Vec<Activity>,
-    actions: Vec<(String, Action)>
+    actions: Vec<(String, Action)>,
+    sources: Vec<(String, Source)>
 }
 
 impl Builder {
@@ -63,7 +65,7 @@ impl Builder {
     /// Create a new instance of the [`Builder`] struct to build an ['Activities'] struct.
     ///
     pub fn new() -> Self {
-        Builder { activities: Vec::new(), actions: Vec::new() }
+        Builder { activities: Vec::new(), actions: Vec::new(), sources: Vec::new() }
     }
 
     /// Add an [`Activity`] with a list of existing [`Action`] into the list of activities.
@@ -107,6 +109,22 @@ impl Builder {
         self
     }
 
+    /// Record the [`Source`] procedure an [`Activity`] was included from.
+    ///
+    /// # Arguments
+    ///
+    /// * `activity_name` - A string slice that holds the name of the included activity.
+    /// * `source` - A reference to the [`Source`] the activity was included from.
+    ///
+    /// # Returns
+    ///
+    /// * A mutable reference to the [`Builder`] struct.
+    ///
+    pub fn use_source(&mut self, activity_name: &str, source: &Source) -> &mut Self {
+        self.sources.push((String::from(activity_name), source.clone()));
+        self
+    }
+
     /// Build an instance of the [`Activities`] struct from the list of activities and actions.
     ///
     /// # Returns
@@ -115,7 +133,12 @@ impl Builder {
     ///
     pub fn try_build(&mut self) -> Result<Activities, Error> {
         self.create_activity_from_action_input()?;
-        let valid_activities = self.validate_activities()?;
+        let mut valid_activities = self.validate_activities()?;
+        for (activity_name, source) in &self.sources {
+            if let Some(activity) = valid_activities.iter_mut().find(|activity| activity.name.value == *activity_name) {
+                activity.source = Some(source.clone());
+            }
+        }
         Ok(Activities { list: valid_activities })
     }
 
@@ -136,6 +159,7 @@ impl Builder {
                 let mut builder = Activity::builder();
                 let activity_name = activity.name().clone();
                 let activity_builder = builder.use_name(&activity_name);
+                if let Some(source) = existing_activity.source.as_ref().or(activity.source.as_ref()) { activity_builder.use_source(source); }
 
                 for existing_action in &existing_activity.actions { activity_builder.add(existing_action); } // Add the existing actions to the new merged activity
                 for action in &activity.actions { activity_builder.add(action); } // Add the new actions from duplicative activity
@@ -9,6 +9,7 @@ use crate::values::specification::assurance_report::activity::Activity;
 use crate::values::specification::assurance_report::activities::Activities;
 use crate::values::specification::assurance_report::signed_file::SignedFile;
 use crate::values::specification::name::Name;
+use crate::values::specification::assurance_procedure::include::Source;
 
 
 /** Positive Tests **/
@@ -184,6 +185,23 @@ fn add_action_error_invalid_activity_name() {
 }
 
 
+#[test]
+fn use_source_for_included_activity() {
+    let source = Source::try_new("https://github.com/example/procedures.git", "shared", Some("v1")).unwrap();
+
+    let activities = Activities::builder()
+        .add_action("activity-1", &generate_testing_action("action-1"))
+        .add_action("included-activity", &generate_testing_action("action-2"))
+        .add_action("included-activity", &generate_testing_action("action-3"))
+        .use_source("included-activity", &source)
+        .try_build().unwrap();
+
+    assert_eq!(activities.count(), 2);
+    assert_eq!(activities.list()[0].source(), None);
+    assert_eq!(activities.list()[1].source(), Some(&source));
+    assert_eq!(activities.list()[1].count(), 2);
+}
+
 fn generate_testing_action(name: &str) -> Action {  Action::builder()
         .name(name)
         .outcome("pass")
@@ -1,12 +1,14 @@
 use std::collections::HashSet;
 use crate::error::{Error, Kind};
 use crate::values::specification::assurance_report::action::Action;
+use crate::values::specification::assurance_procedure::include::Source;
 use crate::values::specification::name::Name;
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Activity {
     pub name: Name,
-    pub actions: Vec<Action>
+    pub actions: Vec<Action>,
+    pub source: Option<Source>
 }
 
 impl Activity {
@@ -19,6 +21,11 @@ impl Activity {
         &self.actions
     }
 
+    /// The procedure the activity was included from, if it was included.
+    pub fn source(&self) -> Option<&Source> {
+        self.source.as_ref()
+    }
+
     pub fn builder() -> Builder {
         Builder::new()
     }
@@ -34,12 +41,13 @@ impl Activity {
 pub struct Builder {
     name: Option<Name>,
     name_str: Option<String>,
-    actions: Vec<Action>
+    actions: Vec<Action>,
+    source: Option<Source>
 }
 
 impl Builder {
     pub fn new() -> Self {
-        Self { name: None, name_str: None, actions: Vec::new() }
+        Self { name: None, name_str: None, actions: Vec::new(), source: None }
     }
 
    pub fn use_name(&mut self, name: &Name) -> &mut Self {
@@ -57,10 +65,15 @@ impl Builder {
         self
     }
 
+    pub fn use_source(&mut self, source: &Source) -> &mut Self {
+        self.source = Some(source.clone());
+        self
+    }
+
     pub fn try_build(&self) -> Result<Activity, Error> {
         let valid_name = self.validate_name()?;
         self.validate_actions(&valid_name)?;
-        Ok(Activity { name: valid_name, actions: self.actions.clone() })
+        Ok(Activity { name: valid_name, actions: self.actions.clone(), source: self.source.clone() })
     }
 
     fn validate_name(&self) -> Result<Name, Error> {
@@ -0,0 +1,72 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::assurance_report::signed_file::SignedFile;
+use crate::values::specification::description::Description;
+use crate::values::specification::name::Name;
+use crate::values::specification::outcome::Outcome;
+use crate::values::specification::short_description::ShortDescription;
+use crate::values::time::start_time::StartTime;
+
+/// The [`ATTESTED`] marker is recorded in the report in place of the test file signature of an action whose outcome was attested by a person.
+pub const ATTESTED: &str = "ATTESTED";
+
+/// The [`Outcome`]s a person can attest to.
+const ATTESTABLE_OUTCOMES: &[Outcome] = &[Outcome::PASS, Outcome::FAIL, Outcome::INCONCLUSIVE];
+
+/// # Overview
+///
+/// The [`Attestation`] is the statement of a person that an attestation action, which has no automated test, was performed.  The outcome of the action is the attested [`Outcome`], rather than the result of a test.
+///
+/// # Attributes
+///
+/// * `action` - The [`Name`] of the action which was attested.
+/// * `outcome` - The attested [`Outcome`], which is one of pass, fail, or inconclusive.
+/// * `rationale` - Why the person attested the outcome.
+/// * `attested_by` - Who attested the action.
+/// * `attested_at` - When the action was attested.
+/// * `supporting_files` - The signed files which support the statement, relative to the collection's home directory.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Attestation {
+    pub action: Name,
+    pub outcome: Outcome,
+    pub rationale: Description,
+    pub attested_by: ShortDescription,
+    pub attested_at: StartTime,
+    pub supporting_files: Vec<SignedFile>,
+}
+
+impl Attestation {
+
+    /// Create a new [`Attestation`] without any supporting files.
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if any of the values are invalid, or the outcome cannot be attested.
+    ///
+    pub fn try_new(action: &str, outcome: &str, rationale: &str, attested_by: &str, attested_at: &StartTime) -> Result<Attestation, Error> {
+        let action = Name::try_from(action)
+            .map_err(|e| custom_error(&format!("There is an issue with the action name '{}'. {}", action, e.message)))?;
+        let outcome = Outcome::try_from(outcome)
+            .ok()
+            .filter(|outcome| ATTESTABLE_OUTCOMES.contains(outcome))
+            .ok_or_else(|| custom_error(&format!("The outcome '{}' cannot be attested for the action '{}'. Must be one of: [{}].",
+                                                 outcome, action.value, ATTESTABLE_OUTCOMES.iter().map(|outcome| outcome.to_string()).collect::<Vec<String>>().join(", "))))?;
+        let rationale = Description::try_from(rationale)
+            .map_err(|e| custom_error(&format!("There is an issue with the rationale for the action '{}'. {}", action.value, e.message)))?;
+        let attested_by = ShortDescription::try_from(attested_by)
+            .map_err(|e| custom_error(&format!("There is an issue with who attested the action '{}'. {}", action.value, e.message)))?;
+
+        Ok(Attestation { action, outcome, rationale, attested_by, attested_at: attested_at.clone(), supporting_files: Vec::new() })
+    }
+
+    /// Returns the [`Attestation`] with the signed supporting file added.
+    pub fn with_supporting_file(mut self, supporting_file: &SignedFile) -> Attestation {
+        self.supporting_files.push(supporting_file.clone());
+        self
+    }
+
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("The Attestation could not be created. {}", message))
+}
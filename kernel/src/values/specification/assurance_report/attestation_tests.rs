@@ -0,0 +1,53 @@
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::algorithms::signature_algorithm::{Signature, SignatureType};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_report::attestation::Attestation;
+use crate::values::specification::assurance_report::signed_file::SignedFile;
+use crate::values::specification::outcome::Outcome;
+use crate::values::time::start_time::StartTime;
+
+#[test]
+fn try_new_success() {
+    let attestation = is_ok!(Attestation::try_new("access-review", "pass", "All accounts were reviewed by the team leads.", "Jane Doe", &StartTime::from(1719326666000)));
+
+    assert_eq!(attestation.action.value, "access-review");
+    assert_eq!(attestation.outcome, Outcome::PASS);
+    assert_eq!(attestation.rationale.value, "All accounts were reviewed by the team leads.");
+    assert_eq!(attestation.attested_by.value, "Jane Doe");
+    assert_eq!(attestation.attested_at, StartTime::from(1719326666000));
+    assert!(attestation.supporting_files.is_empty());
+}
+
+#[test]
+fn with_supporting_file_success() {
+    let supporting_file = SignedFile::new("evidence/access-review/review.csv", &Signature::try_new(SignatureType::SHA256, "reviewsig").unwrap()).unwrap();
+
+    let attestation = Attestation::try_new("access-review", "fail", "Two accounts were not reviewed.", "Jane Doe", &StartTime::from(1719326666000)).unwrap()
+        .with_supporting_file(&supporting_file);
+
+    assert_eq!(attestation.supporting_files, vec![supporting_file]);
+}
+
+#[test]
+fn try_new_bad_action_error() {
+    let result = Attestation::try_new("access review", "pass", "All accounts were reviewed.", "Jane Doe", &StartTime::from(1719326666000));
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Attestation could not be created. There is an issue with the action name 'access review'. ");
+}
+
+#[test]
+fn try_new_outcome_cannot_be_attested_error() {
+    let result = Attestation::try_new("access-review", "waived", "All accounts were reviewed.", "Jane Doe", &StartTime::from(1719326666000));
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The Attestation could not be created. The outcome 'waived' cannot be attested for the action 'access-review'. Must be one of: [pass, fail, inconclusive].");
+}
+
+#[test]
+fn try_new_bad_rationale_error() {
+    let result = Attestation::try_new("access-review", "pass", "", "Jane Doe", &StartTime::from(1719326666000));
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Attestation could not be created. There is an issue with the rationale for the action 'access-review'. ");
+}
+
+#[test]
+fn try_new_bad_attested_by_error() {
+    let result = Attestation::try_new("access-review", "pass", "All accounts were reviewed.", "", &StartTime::from(1719326666000));
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Attestation could not be created. There is an issue with who attested the action 'access-review'. ");
+}
@@ -0,0 +1,58 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::assurance_procedure::condition::Condition;
+use crate::values::specification::description::Description;
+use crate::values::specification::name::Name;
+
+/// # Overview
+///
+/// The [`Exclusion`] tailors an action out of a collection because it does not apply to the subject.  An excluded action is not evaluated, and it is reported with the [`Outcome::NOT_APPLICABLE`] outcome and the justification.
+///
+/// # Attributes
+///
+/// * `action` - The [`Name`] of the action which does not apply.
+/// * `justification` - The reason the action does not apply to the subject.
+/// * `condition` - The `when` [`Condition`] of the procedure which was not met, if the action was excluded by a condition rather than a tailoring file.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Exclusion {
+    pub action: Name,
+    pub justification: Description,
+    pub condition: Option<Condition>,
+}
+
+impl Exclusion {
+
+    /// Create a new [`Exclusion`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the action name or justification is invalid.
+    ///
+    pub fn try_new(action: &str, justification: &str) -> Result<Exclusion, Error> {
+        let action = Name::try_from(action)
+            .map_err(|e| custom_error(&format!("There is an issue with the action name '{}'. {}", action, e.message)))?;
+        let justification = Description::try_from(justification)
+            .map_err(|e| custom_error(&format!("There is an issue with the justification for the action '{}'. {}", action.value, e.message)))?;
+
+        Ok(Exclusion { action, justification, condition: None })
+    }
+
+    /// Create a new [`Exclusion`] for an action whose `when` [`Condition`] is not met by the subject.
+    pub fn for_condition(action: &Name, condition: &Condition) -> Exclusion {
+        Exclusion {
+            action: action.clone(),
+            justification: Description { value: format!("The condition '{}' is not met.", condition) },
+            condition: Some(condition.clone())
+        }
+    }
+
+    /// Records the [`Condition`] which excluded the action, and returns a new instance of the [`Exclusion`].
+    pub fn with_condition(self, condition: &Condition) -> Exclusion {
+        Exclusion { condition: Some(condition.clone()), ..self }
+    }
+
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("The Exclusion could not be created. {}", message))
+}
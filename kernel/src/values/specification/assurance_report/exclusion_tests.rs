@@ -0,0 +1,37 @@
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_procedure::condition::Condition;
+use crate::values::specification::assurance_report::exclusion::Exclusion;
+use crate::values::specification::name::Name;
+
+#[test]
+fn try_new_success() {
+    let exclusion = is_ok!(Exclusion::try_new("action-1", "The subject does not store personal data."));
+
+    assert_eq!(exclusion.action.value, "action-1");
+    assert_eq!(exclusion.justification.value, "The subject does not store personal data.");
+    assert_eq!(exclusion.condition, None);
+}
+
+#[test]
+fn for_condition_success() {
+    let condition = Condition::try_from("meta.pci == true").unwrap();
+
+    let exclusion = Exclusion::for_condition(&Name::try_from("action-1").unwrap(), &condition);
+
+    assert_eq!(exclusion.action.value, "action-1");
+    assert_eq!(exclusion.justification.value, "The condition 'meta.pci == true' is not met.");
+    assert_eq!(exclusion.condition, Some(condition));
+}
+
+#[test]
+fn try_new_bad_action_error() {
+    let result = Exclusion::try_new("action 1", "The subject does not store personal data.");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Exclusion could not be created. There is an issue with the action name 'action 1'. ");
+}
+
+#[test]
+fn try_new_bad_justification_error() {
+    let result = Exclusion::try_new("action-1", "");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Exclusion could not be created. There is an issue with the justification for the action 'action-1'. ");
+}
@@ -0,0 +1,106 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::description::Description;
+use crate::values::specification::file_path::FilePath;
+use crate::values::specification::severity::Severity;
+
+/// # Overview
+///
+/// The [`Finding`] is a single issue a test found in the evidence of an action.  A test can report many findings, in addition to the outcome and reason of its evaluation.
+///
+/// # Attributes
+///
+/// * `message` - What the test found.
+/// * `severity` - The [`Severity`] of the finding.
+/// * `location` - Where in the evidence the finding was found, if the test reported it.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Finding {
+    pub message: Description,
+    pub severity: Severity,
+    pub location: Option<EvidenceLocation>,
+}
+
+/// The [`EvidenceLocation`] is the file within the evidence, and optionally the range of lines within the file, where a [`Finding`] was found.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct EvidenceLocation {
+    pub file: FilePath,
+    pub lines: Option<LineRange>,
+}
+
+/// The [`LineRange`] is the first and last line, counted from one, of a [`Finding`] within a file of the evidence.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LineRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+impl Finding {
+
+    /// Create a new [`Finding`] without a location.
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the message or severity is invalid.
+    ///
+    pub fn try_new(message: &str, severity: &str) -> Result<Finding, Error> {
+        let message = Description::try_from(message)
+            .map_err(|e| custom_error(&format!("There is an issue with the message '{}'. {}", message, e.message)))?;
+        let severity = Severity::try_from(severity)
+            .map_err(|e| custom_error(&format!("There is an issue with the severity of the finding '{}'. {}", message.value, e.message)))?;
+
+        Ok(Finding { message, severity, location: None })
+    }
+
+    /// Records where in the evidence the finding was found, and returns a new instance of the [`Finding`].
+    pub fn with_location(self, location: &EvidenceLocation) -> Finding {
+        Finding { location: Some(location.clone()), ..self }
+    }
+
+}
+
+impl EvidenceLocation {
+
+    /// Create a new [`EvidenceLocation`] for a file within the evidence, without a range of lines.
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the file path is empty.
+    ///
+    pub fn try_new(file: &str) -> Result<EvidenceLocation, Error> {
+        let file = FilePath::try_from(file)
+            .map_err(|e| custom_error(&format!("There is an issue with the evidence path. {}", e.message)))?;
+
+        Ok(EvidenceLocation { file, lines: None })
+    }
+
+    /// Records the range of lines within the file, and returns a new instance of the [`EvidenceLocation`].
+    pub fn with_lines(self, lines: &LineRange) -> EvidenceLocation {
+        EvidenceLocation { lines: Some(lines.clone()), ..self }
+    }
+
+}
+
+impl LineRange {
+
+    /// Create a new [`LineRange`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the first line is zero, or the last line is before the first line.
+    ///
+    pub fn try_new(start: u32, end: u32) -> Result<LineRange, Error> {
+        if start == 0 {
+            return Err(custom_error("The first line of the range must be at least 1, as lines are counted from one."));
+        }
+        if end < start {
+            return Err(custom_error(&format!("The last line '{}' of the range is before the first line '{}'.", end, start)));
+        }
+
+        Ok(LineRange { start, end })
+    }
+
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("The Finding could not be created. {}", message))
+}
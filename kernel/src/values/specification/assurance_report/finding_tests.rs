@@ -0,0 +1,63 @@
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_report::finding::{EvidenceLocation, Finding, LineRange};
+use crate::values::specification::severity::Severity;
+
+#[test]
+fn try_new_success() {
+    let finding = is_ok!(Finding::try_new("The change has only one reviewer.", "high"));
+
+    assert_eq!(finding.message.value, "The change has only one reviewer.");
+    assert_eq!(finding.severity, Severity::HIGH);
+    assert_eq!(finding.location, None);
+}
+
+#[test]
+fn with_location_success() {
+    let location = EvidenceLocation::try_new("manifests/deployment.yaml").unwrap()
+        .with_lines(&LineRange::try_new(12, 14).unwrap());
+
+    let finding = Finding::try_new("The container runs as root.", "critical").unwrap()
+        .with_location(&location);
+
+    let location = finding.location.unwrap();
+    assert_eq!(location.file.as_str(), "manifests/deployment.yaml");
+    assert_eq!(location.lines, Some(LineRange { start: 12, end: 14 }));
+}
+
+#[test]
+fn line_range_single_line_success() {
+    let lines = is_ok!(LineRange::try_new(7, 7));
+
+    assert_eq!(lines, LineRange { start: 7, end: 7 });
+}
+
+#[test]
+fn try_new_bad_message_error() {
+    let result = Finding::try_new("", "high");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Finding could not be created. There is an issue with the message ''. ");
+}
+
+#[test]
+fn try_new_bad_severity_error() {
+    let result = Finding::try_new("The change has only one reviewer.", "info");
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The Finding could not be created. There is an issue with the severity of the finding 'The change has only one reviewer.'. 'info' is not a valid Severity. Must be one of: [critical, high, medium, low].");
+}
+
+#[test]
+fn evidence_location_empty_file_error() {
+    let result = EvidenceLocation::try_new(" ");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Finding could not be created. There is an issue with the evidence path. ");
+}
+
+#[test]
+fn line_range_zero_start_error() {
+    let result = LineRange::try_new(0, 3);
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The Finding could not be created. The first line of the range must be at least 1, as lines are counted from one.");
+}
+
+#[test]
+fn line_range_end_before_start_error() {
+    let result = LineRange::try_new(9, 4);
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The Finding could not be created. The last line '4' of the range is before the first line '9'.");
+}
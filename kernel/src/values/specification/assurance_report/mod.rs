@@ -3,8 +3,18 @@ pub mod additional_information;
 
 pub mod activity;
 pub mod activities;
+pub mod attestation;
+pub mod exclusion;
+pub mod finding;
+pub mod observations;
+pub mod outcome_policy;
+pub mod provenance;
 pub mod signed_file;
+pub mod signed_input;
 pub mod summary;
+pub mod tailoring;
+pub mod waiver;
+pub mod waivers;
 
 
 #[cfg(test)] mod action_tests;
@@ -12,5 +22,14 @@ pub mod summary;
 
 #[cfg(test)] mod activity_tests;
 #[cfg(test)] mod activities_tests;
+#[cfg(test)] mod attestation_tests;
+#[cfg(test)] mod exclusion_tests;
+#[cfg(test)] mod finding_tests;
+#[cfg(test)] mod observations_tests;
+#[cfg(test)] mod outcome_policy_tests;
+#[cfg(test)] mod provenance_tests;
 #[cfg(test)] mod signed_file_tests;
 #[cfg(test)] mod summary_tests;
+#[cfg(test)] mod tailoring_tests;
+#[cfg(test)] mod waiver_tests;
+#[cfg(test)] mod waivers_tests;
@@ -0,0 +1,47 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::description::Description;
+use crate::values::specification::name::Name;
+
+/// The [`Observations`] struct is a key-value store of the values a test measured in the evidence of an action, such as the number of reviewers of a change, in the order the test reported them.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Observations {
+    pub data: Vec<(Name, Description)>,
+}
+
+impl Observations {
+
+    /// Add an observed key-value pair to the [`Observations`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the key or value is invalid, or the key was already observed.
+    ///
+    pub fn add(&mut self, key: &str, value: &str) -> Result<(), Error> {
+        let valid_key = Name::try_from(key)
+            .map_err(|e| custom_error(&format!("There is an issue with the observation key '{}'. {}", key, e.message)))?;
+        let valid_value = Description::try_from(value)
+            .map_err(|e| custom_error(&format!("There is an issue with the value of the observation '{}'. {}", valid_key.value, e.message)))?;
+        if self.get(&valid_key.value).is_some() {
+            return Err(custom_error(&format!("The observation '{}' was reported more than once.", valid_key.value)));
+        }
+        self.data.push((valid_key, valid_value));
+        Ok(())
+    }
+
+    /// Get an observed value based on the key.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.data.iter()
+            .find(|(k, _)| k.value == key)
+            .map(|(_, v)| v.value.clone())
+    }
+
+    /// Returns `true` if nothing was observed.
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("The Observations could not be recorded. {}", message))
+}
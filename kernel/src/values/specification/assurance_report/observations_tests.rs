@@ -0,0 +1,41 @@
+use nape_testing_assertions::{is_ok, kernel_error_eq, kernel_error_starts_with};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_report::observations::Observations;
+
+#[test]
+fn add_success() {
+    let mut observations = Observations::default();
+
+    is_ok!(observations.add("reviewers", "2"));
+    is_ok!(observations.add("tls-version", "1.3"));
+
+    assert_eq!(observations.get("reviewers"), Some("2".to_string()));
+    assert_eq!(observations.get("tls-version"), Some("1.3".to_string()));
+    assert_eq!(observations.get("approvers"), None);
+    assert_eq!(observations.data.iter().map(|(key, _)| key.value.as_str()).collect::<Vec<&str>>(), vec!["reviewers", "tls-version"]);
+}
+
+#[test]
+fn is_empty_success() {
+    let mut observations = Observations::default();
+    assert!(observations.is_empty());
+
+    observations.add("reviewers", "2").unwrap();
+    assert!(!observations.is_empty());
+}
+
+#[test]
+fn add_bad_key_error() {
+    let mut observations = Observations::default();
+    let result = observations.add("tls_version", "1.3");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Observations could not be recorded. There is an issue with the observation key 'tls_version'. ");
+}
+
+#[test]
+fn add_duplicate_key_error() {
+    let mut observations = Observations::default();
+    observations.add("reviewers", "2").unwrap();
+
+    let result = observations.add("Reviewers", "3");
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The Observations could not be recorded. The observation 'reviewers' was reported more than once.");
+}
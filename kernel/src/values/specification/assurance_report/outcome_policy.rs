@@ -0,0 +1,86 @@
+use std::fmt::Display;
+use crate::error::{Error, Kind};
+use crate::values::specification::severity::Severity;
+
+/// The [`ANY_FAILURE`] is the string representation of the [`OutcomePolicy::AnyFailure`] policy.
+const ANY_FAILURE: &str = "any";
+
+/// The [`SEVERITY_ORDER`] is used to keep the severities of an [`OutcomePolicy::FailOn`] policy in a consistent order, from the most to the least severe.
+const SEVERITY_ORDER: [Severity; 4] = [Severity::CRITICAL, Severity::HIGH, Severity::MEDIUM, Severity::LOW];
+
+/// # Overview
+///
+/// The [`OutcomePolicy`] determines which failed actions cause the overall outcome of an Assurance Report to be a FAIL.
+///
+/// * [`OutcomePolicy::AnyFailure`] - any failed action fails the report.  This is the default.
+/// * [`OutcomePolicy::FailOn`] - only failed actions with one of the listed severities fail the report.
+///
+/// # Design Decision
+///
+/// * A failed action without a severity always fails the report.  A procedure author who did not rate an action has not signalled that it is safe to ignore.
+///
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum OutcomePolicy {
+    #[default]
+    AnyFailure,
+    FailOn(Vec<Severity>),
+}
+
+impl OutcomePolicy {
+
+    /// Create an [`OutcomePolicy`] from a string.
+    ///
+    /// # Arguments
+    ///
+    /// * `policy` - Either 'any', or a comma separated list of severities such as 'critical,high'.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] of either the [`OutcomePolicy`] or an [`Error`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the policy is empty, or contains a value which is not a valid [`Severity`].
+    ///
+    pub fn try_from(policy: &str) -> Result<OutcomePolicy, Error> {
+        if policy.trim().is_empty() {
+            return Err(custom_error("You have provided an empty policy. Please provide 'any' or a comma separated list of severities."));
+        }
+        if policy.trim().to_lowercase() == ANY_FAILURE {
+            return Ok(OutcomePolicy::AnyFailure);
+        }
+        let mut severities = Vec::new();
+        for value in policy.split(',') {
+            let severity = Severity::try_from(value.trim())
+                .map_err(|e| custom_error(&e.message))?;
+            severities.push(severity);
+        }
+        Ok(OutcomePolicy::FailOn(
+            SEVERITY_ORDER.iter().filter(|severity| severities.contains(severity)).cloned().collect()))
+    }
+
+    /// Returns `true` if a failed action with the given severity fails the report under this policy.
+    pub fn fails_report(&self, severity: Option<&Severity>) -> bool {
+        match (self, severity) {
+            (OutcomePolicy::AnyFailure, _) => true,
+            (OutcomePolicy::FailOn(_), None) => true,
+            (OutcomePolicy::FailOn(severities), Some(severity)) => severities.contains(severity),
+        }
+    }
+}
+
+impl Display for OutcomePolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            OutcomePolicy::AnyFailure => write!(f, "{}", ANY_FAILURE),
+            OutcomePolicy::FailOn(severities) => {
+                let values: Vec<String> = severities.iter().map(|severity| severity.to_string()).collect();
+                write!(f, "{}", values.join(","))
+            }
+        }
+    }
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("There is an issue with the outcome policy. {}", message))
+}
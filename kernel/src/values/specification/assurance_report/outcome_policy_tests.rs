@@ -0,0 +1,56 @@
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_report::outcome_policy::OutcomePolicy;
+use crate::values::specification::severity::Severity;
+
+#[test]
+fn default_is_any_failure() {
+    assert_eq!(OutcomePolicy::default(), OutcomePolicy::AnyFailure);
+}
+
+#[test]
+fn try_from_any_success() {
+    let result = OutcomePolicy::try_from("ANY");
+    assert_eq!(is_ok!(result), OutcomePolicy::AnyFailure);
+}
+
+#[test]
+fn try_from_severities_success() {
+    let result = OutcomePolicy::try_from("high, critical,high");
+    assert_eq!(is_ok!(result), OutcomePolicy::FailOn(vec![Severity::CRITICAL, Severity::HIGH]));
+}
+
+#[test]
+fn try_from_empty_error() {
+    let result = OutcomePolicy::try_from(" ");
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "There is an issue with the outcome policy. You have provided an empty policy. Please provide 'any' or a comma separated list of severities.");
+}
+
+#[test]
+fn try_from_invalid_severity_error() {
+    let result = OutcomePolicy::try_from("critical,urgent");
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "There is an issue with the outcome policy. 'urgent' is not a valid Severity. Must be one of: [critical, high, medium, low].");
+}
+
+#[test]
+fn fails_report_any_failure() {
+    let policy = OutcomePolicy::AnyFailure;
+    assert!(policy.fails_report(Some(&Severity::LOW)));
+    assert!(policy.fails_report(None));
+}
+
+#[test]
+fn fails_report_fail_on() {
+    let policy = OutcomePolicy::FailOn(vec![Severity::CRITICAL, Severity::HIGH]);
+    assert!(policy.fails_report(Some(&Severity::CRITICAL)));
+    assert!(policy.fails_report(Some(&Severity::HIGH)));
+    assert!(!policy.fails_report(Some(&Severity::MEDIUM)));
+    assert!(!policy.fails_report(Some(&Severity::LOW)));
+    assert!(policy.fails_report(None));
+}
+
+#[test]
+fn to_string_success() {
+    assert_eq!(OutcomePolicy::AnyFailure.to_string(), "any");
+    assert_eq!(OutcomePolicy::FailOn(vec![Severity::CRITICAL, Severity::HIGH]).to_string(), "critical,high");
+}
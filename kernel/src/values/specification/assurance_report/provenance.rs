@@ -0,0 +1,52 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::short_description::ShortDescription;
+
+/// # Overview
+///
+/// The [`Provenance`] describes the environment an [`AssuranceReport`] was generated in, so the report is self-describing when it is audited long after it was created.
+///
+/// # Attributes
+///
+/// * `nape_version` - The version of the `nape` CLI which generated the report.
+/// * `evaluator_version` - The version of the evaluator used to evaluate the evidence.
+/// * `os` - The operating system the report was generated on.
+/// * `arch` - The CPU architecture the report was generated on.
+/// * `hostname` - The name of the host the report was generated on.
+/// * `user` - The user who generated the report.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Provenance {
+    pub nape_version: ShortDescription,
+    pub evaluator_version: ShortDescription,
+    pub os: ShortDescription,
+    pub arch: ShortDescription,
+    pub hostname: ShortDescription,
+    pub user: ShortDescription,
+}
+
+impl Provenance {
+
+    /// Create a new [`Provenance`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if any of the values are empty or too long.
+    ///
+    pub fn try_new(nape_version: &str, evaluator_version: &str, os: &str, arch: &str, hostname: &str, user: &str) -> Result<Provenance, Error> {
+        Ok(Provenance {
+            nape_version: try_value("nape version", nape_version)?,
+            evaluator_version: try_value("evaluator version", evaluator_version)?,
+            os: try_value("operating system", os)?,
+            arch: try_value("architecture", arch)?,
+            hostname: try_value("hostname", hostname)?,
+            user: try_value("user", user)?,
+        })
+    }
+
+}
+
+fn try_value(field: &str, value: &str) -> Result<ShortDescription, Error> {
+    ShortDescription::try_from(value)
+        .map_err(|e| Error::for_user(Kind::InvalidInput,
+                                     format!("The Provenance could not be created. There is an issue with the {} '{}'. {}", field, value, e.message)))
+}
@@ -0,0 +1,27 @@
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_report::provenance::Provenance;
+
+#[test]
+fn try_new_success() {
+    let provenance = is_ok!(Provenance::try_new("1.0.0", "nape-eval 0.3.1", "linux", "x86_64", "build-01", "jane"));
+
+    assert_eq!(provenance.nape_version.value, "1.0.0");
+    assert_eq!(provenance.evaluator_version.value, "nape-eval 0.3.1");
+    assert_eq!(provenance.os.value, "linux");
+    assert_eq!(provenance.arch.value, "x86_64");
+    assert_eq!(provenance.hostname.value, "build-01");
+    assert_eq!(provenance.user.value, "jane");
+}
+
+#[test]
+fn try_new_empty_user_error() {
+    let result = Provenance::try_new("1.0.0", "nape-eval 0.3.1", "linux", "x86_64", "build-01", "");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Provenance could not be created. There is an issue with the user ''. ");
+}
+
+#[test]
+fn try_new_empty_hostname_error() {
+    let result = Provenance::try_new("1.0.0", "nape-eval 0.3.1", "linux", "x86_64", "", "jane");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Provenance could not be created. There is an issue with the hostname ''. ");
+}
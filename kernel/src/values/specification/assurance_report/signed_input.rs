@@ -0,0 +1,28 @@
+use crate::values::specification::assurance_report::signed_file::SignedFile;
+use crate::values::specification::name::Name;
+
+/// # Overview
+///
+/// The [`SignedInput`] is the signed evidence of a named evidence input of an action, in addition to its primary evidence file.
+///
+/// # Attributes
+///
+/// * `name` - The name of the evidence input in the procedure.
+/// * `evidence_file` - The signed evidence of the input.  When the evidence is a directory or glob pattern, this is the signature of the list of its files.
+/// * `evidence_files` - The individually signed files within the evidence, when it is a directory or glob pattern.  The list is empty for a single evidence file.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SignedInput {
+    pub name: Name,
+    pub evidence_file: SignedFile,
+    pub evidence_files: Vec<SignedFile>,
+}
+
+impl SignedInput {
+
+    /// Creates a new [`SignedInput`] for the signed evidence of the input with the `name`.
+    pub fn new(name: &Name, evidence_file: &SignedFile, evidence_files: &[SignedFile]) -> SignedInput {
+        SignedInput { name: name.clone(), evidence_file: evidence_file.clone(), evidence_files: evidence_files.to_vec() }
+    }
+
+}
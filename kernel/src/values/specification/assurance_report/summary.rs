@@ -1,5 +1,7 @@
 use crate::values::specification::outcome::Outcome;
 use crate::values::specification::assurance_report::activities::Activities;
+use crate::values::specification::assurance_report::outcome_policy::OutcomePolicy;
+use crate::values::specification::severity::Severity;
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
 pub struct Summary {
@@ -9,12 +11,39 @@ pub struct Summary {
     pub pass: u32,
     pub fail: u32,
     pub inconclusive: u32,
+    pub waived: u32,
+    pub not_applicable: u32,
+    pub skipped: u32,
+    pub failures_by_severity: FailuresBySeverity,
+    pub outcome_policy: OutcomePolicy,
     pub outcome: Outcome,
 }
 
+/// The [`FailuresBySeverity`] struct holds the count of failed actions for each [`Severity`].  Failed actions which were not assigned a severity are counted as `unrated`.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct FailuresBySeverity {
+    pub critical: u32,
+    pub high: u32,
+    pub medium: u32,
+    pub low: u32,
+    pub unrated: u32,
+}
+
+impl FailuresBySeverity {
+    fn count(&mut self, severity: Option<&Severity>) {
+        match severity {
+            Some(Severity::CRITICAL) => self.critical += 1,
+            Some(Severity::HIGH) => self.high += 1,
+            Some(Severity::MEDIUM) => self.medium += 1,
+            Some(Severity::LOW) => self.low += 1,
+            None => self.unrated += 1,
+        }
+    }
+}
+
 impl Summary {
 
-    /// Create a new [`Summary`] from a list of [`Activities`].
+    /// Create a new [`Summary`] from a list of [`Activities`], using the default [`OutcomePolicy`] where any failed action fails the report.
     ///
     /// # Arguments
     ///
@@ -25,26 +54,68 @@ impl Summary {
     /// * A new [`Summary`] struct.
     ///
     pub fn of(activities: &Activities) -> Summary {
+        Summary::of_with_policy(activities, &OutcomePolicy::default())
+    }
+
+    /// Create a new [`Summary`] from a list of [`Activities`], where the overall outcome is determined by the [`OutcomePolicy`].
+    ///
+    /// # Arguments
+    ///
+    /// * `activities` - A reference to a [`Activities`] struct that holds a list of activites.
+    /// * `policy` - The [`OutcomePolicy`] which determines which failed actions fail the report.
+    ///
+    /// # Returns
+    ///
+    /// * A new [`Summary`] struct.
+    ///
+    pub fn of_with_policy(activities: &Activities, policy: &OutcomePolicy) -> Summary {
+
+        let mut failures_by_severity = FailuresBySeverity::default();
+        let mut failures_under_policy = 0;
+        let mut waived = 0;
+        let mut not_applicable = 0;
+        let mut skipped = 0;
 
         let (pass, fail, inconclusive, errors) = activities.list().iter()
             .flat_map(|activity| activity.actions.iter())
-            .fold((0, 0, 0, 0), |(pass, fail, inconclusive, errors), activity| {
-                match activity.outcome() {
+            .fold((0, 0, 0, 0), |(pass, fail, inconclusive, errors), action| {
+                match action.outcome() {
                 Outcome::PASS => (pass + 1, fail, inconclusive, errors),
-                Outcome::FAIL => (pass, fail + 1, inconclusive, errors),
+                Outcome::FAIL => {
+                    failures_by_severity.count(action.severity());
+                    if policy.fails_report(action.severity()) { failures_under_policy += 1; }
+                    (pass, fail + 1, inconclusive, errors)
+                },
                 Outcome::INCONCLUSIVE => (pass, fail, inconclusive + 1, errors),
                 Outcome::ERROR => (pass, fail, inconclusive, errors +1),
+                Outcome::WAIVED => {
+                    waived += 1;
+                    (pass, fail, inconclusive, errors)
+                },
+                Outcome::NOT_APPLICABLE => {
+                    not_applicable += 1;
+                    (pass, fail, inconclusive, errors)
+                },
+                Outcome::SKIPPED => {
+                    skipped += 1;
+                    (pass, fail, inconclusive, errors)
+                },
             }
         });
 
         Summary {
             activity_count: activities.list().len() as u32,
-            action_count: pass + fail + inconclusive + errors,
-            actions_run: pass + fail + inconclusive,
+            action_count: pass + fail + inconclusive + errors + waived + not_applicable + skipped,
+            actions_run: pass + fail + inconclusive + waived,
             pass,
             fail,
             inconclusive,
-            outcome: determine_outcome(pass, fail, inconclusive, errors),
+            waived,
+            not_applicable,
+            skipped,
+            failures_by_severity,
+            outcome_policy: policy.clone(),
+            outcome: determine_outcome(pass, failures_under_policy, inconclusive, errors),
         }
     }
 
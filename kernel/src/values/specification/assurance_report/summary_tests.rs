@@ -5,7 +5,9 @@ use crate::values::specification::outcome::Outcome;
 use crate::values::specification::assurance_report::activity::Activity;
 use crate::values::specification::assurance_report::activities::Activities;
 use crate::values::specification::assurance_report::signed_file::SignedFile;
-use crate::values::specification::assurance_report::summary::Summary;
+use crate::values::specification::assurance_report::outcome_policy::OutcomePolicy;
+use crate::values::specification::assurance_report::summary::{FailuresBySeverity, Summary};
+use crate::values::specification::severity::Severity;
 
 
 #[test]
@@ -17,6 +19,11 @@ fn default_summary() {
     assert_eq!(summary.pass, 0);
     assert_eq!(summary.fail, 0);
     assert_eq!(summary.inconclusive, 0);
+    assert_eq!(summary.waived, 0);
+    assert_eq!(summary.not_applicable, 0);
+    assert_eq!(summary.skipped, 0);
+    assert_eq!(summary.failures_by_severity, FailuresBySeverity::default());
+    assert_eq!(summary.outcome_policy, OutcomePolicy::AnyFailure);
     assert_eq!(summary.outcome, Outcome::INCONCLUSIVE);
 }
 
@@ -138,6 +145,142 @@ fn summary_of_success_outcome_inconclusive_with_at_least_one_error() {
 }
 
 
+#[test]
+fn summary_of_with_policy_failures_by_severity() {
+    let activities = severity_activities_helper();
+
+    let summary = Summary::of_with_policy(&activities, &OutcomePolicy::AnyFailure);
+
+    assert_eq!(summary.fail, 4);
+    assert_eq!(summary.failures_by_severity, FailuresBySeverity { critical: 0, high: 1, medium: 1, low: 1, unrated: 1 });
+    assert_eq!(summary.outcome, Outcome::FAIL);
+}
+
+#[test]
+fn summary_of_with_policy_fail_on_critical_and_high() {
+    let activities = severity_activities_helper();
+    let policy = OutcomePolicy::FailOn(vec![Severity::CRITICAL, Severity::HIGH]);
+
+    let summary = Summary::of_with_policy(&activities, &policy);
+
+    assert_eq!(summary.outcome_policy, policy);
+    assert_eq!(summary.outcome, Outcome::FAIL);
+}
+
+#[test]
+fn summary_of_with_policy_pass_when_only_lower_severities_fail() {
+    let (test_file, evidence_file) = signed_file_helper();
+
+    let action1 = Action::builder().name("Test-Action-1").use_outcome(&Outcome::PASS).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).use_severity(&Severity::CRITICAL).try_build().unwrap();
+    let action2 = Action::builder().name("Test-Action-2").use_outcome(&Outcome::FAIL).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).use_severity(&Severity::MEDIUM).try_build().unwrap();
+    let action3 = Action::builder().name("Test-Action-3").use_outcome(&Outcome::FAIL).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).use_severity(&Severity::LOW).try_build().unwrap();
+
+    let activity = Activity::builder().name("Test-Activity")
+        .add(&action1)
+        .add(&action2)
+        .add(&action3)
+        .try_build().unwrap();
+
+    let activities = Activities::builder().add_activity(&activity).try_build().unwrap();
+
+    let summary = Summary::of_with_policy(&activities, &OutcomePolicy::FailOn(vec![Severity::CRITICAL, Severity::HIGH]));
+
+    assert_eq!(summary.fail, 2);
+    assert_eq!(summary.failures_by_severity, FailuresBySeverity { critical: 0, high: 0, medium: 1, low: 1, unrated: 0 });
+    assert_eq!(summary.outcome, Outcome::PASS);
+}
+
+#[test]
+fn summary_of_waived_actions_do_not_fail_the_report() {
+    let (test_file, evidence_file) = signed_file_helper();
+
+    let action1 = Action::builder().name("Test-Action-1").use_outcome(&Outcome::PASS).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).try_build().unwrap();
+    let action2 = Action::builder().name("Test-Action-2").use_outcome(&Outcome::WAIVED).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).use_severity(&Severity::CRITICAL).try_build().unwrap();
+
+    let activity = Activity::builder().name("Test-Activity")
+        .add(&action1)
+        .add(&action2)
+        .try_build().unwrap();
+
+    let activities = Activities::builder().add_activity(&activity).try_build().unwrap();
+
+    let summary = Summary::of(&activities);
+
+    assert_eq!(summary.action_count, 2);
+    assert_eq!(summary.actions_run, 2);
+    assert_eq!(summary.pass, 1);
+    assert_eq!(summary.fail, 0);
+    assert_eq!(summary.waived, 1);
+    assert_eq!(summary.failures_by_severity, FailuresBySeverity::default());
+    assert_eq!(summary.outcome, Outcome::PASS);
+}
+
+#[test]
+fn summary_of_not_applicable_actions_are_counted_separately() {
+    let (test_file, evidence_file) = signed_file_helper();
+
+    let action1 = Action::builder().name("Test-Action-1").use_outcome(&Outcome::PASS).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).try_build().unwrap();
+    let action2 = Action::builder().name("Test-Action-2").use_outcome(&Outcome::NOT_APPLICABLE).reason("The subject has no database.").use_test_file_signature(&test_file).try_build().unwrap();
+
+    let activity = Activity::builder().name("Test-Activity")
+        .add(&action1)
+        .add(&action2)
+        .try_build().unwrap();
+
+    let activities = Activities::builder().add_activity(&activity).try_build().unwrap();
+
+    let summary = Summary::of(&activities);
+
+    assert_eq!(summary.action_count, 2);
+    assert_eq!(summary.actions_run, 1);
+    assert_eq!(summary.pass, 1);
+    assert_eq!(summary.not_applicable, 1);
+    assert_eq!(summary.outcome, Outcome::PASS);
+}
+
+#[test]
+fn summary_of_skipped_actions_are_counted_separately() {
+    let (test_file, evidence_file) = signed_file_helper();
+
+    let action1 = Action::builder().name("Test-Action-1").use_outcome(&Outcome::FAIL).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).try_build().unwrap();
+    let action2 = Action::builder().name("Test-Action-2").use_outcome(&Outcome::SKIPPED).reason("The action 'Test-Action-2' depends on 'Test-Action-1', which did not pass.").use_test_file_signature(&test_file).try_build().unwrap();
+
+    let activity = Activity::builder().name("Test-Activity")
+        .add(&action1)
+        .add(&action2)
+        .try_build().unwrap();
+
+    let activities = Activities::builder().add_activity(&activity).try_build().unwrap();
+
+    let summary = Summary::of(&activities);
+
+    assert_eq!(summary.action_count, 2);
+    assert_eq!(summary.actions_run, 1);
+    assert_eq!(summary.fail, 1);
+    assert_eq!(summary.skipped, 1);
+    assert_eq!(summary.outcome, Outcome::FAIL);
+}
+
+fn severity_activities_helper() -> Activities {
+    let (test_file, evidence_file) = signed_file_helper();
+
+    let action1 = Action::builder().name("Test-Action-1").use_outcome(&Outcome::FAIL).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).use_severity(&Severity::HIGH).try_build().unwrap();
+    let action2 = Action::builder().name("Test-Action-2").use_outcome(&Outcome::FAIL).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).use_severity(&Severity::MEDIUM).try_build().unwrap();
+    let action3 = Action::builder().name("Test-Action-3").use_outcome(&Outcome::FAIL).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).use_severity(&Severity::LOW).try_build().unwrap();
+    let action4 = Action::builder().name("Test-Action-4").use_outcome(&Outcome::FAIL).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).try_build().unwrap();
+    let action5 = Action::builder().name("Test-Action-5").use_outcome(&Outcome::PASS).reason("some reason").use_test_file_signature(&test_file).use_evidence_file_signature(&evidence_file).use_severity(&Severity::CRITICAL).try_build().unwrap();
+
+    let activity = Activity::builder().name("Test-Activity")
+        .add(&action1)
+        .add(&action2)
+        .add(&action3)
+        .add(&action4)
+        .add(&action5)
+        .try_build().unwrap();
+
+    Activities::builder().add_activity(&activity).try_build().unwrap()
+}
+
 fn signed_file_helper() -> (SignedFile, SignedFile) {
     let test_file_sig = Signature::try_new(SHA256, "the-test-file-signature").unwrap();
     let test_file = SignedFile::new("./some-location/file.txt", &test_file_sig).unwrap();
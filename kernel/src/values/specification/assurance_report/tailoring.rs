@@ -0,0 +1,79 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::assurance_procedure::activities::Activities;
+use crate::values::specification::assurance_procedure::parameters::Parameters;
+use crate::values::specification::assurance_report::exclusion::Exclusion;
+use crate::values::specification::name::Name;
+
+/// The [`Tailoring`] struct holds the list of [`Exclusion`]s for the actions of a procedure which do not apply to the subject of a collection.  There is at most one [`Exclusion`] for each action name.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Tailoring {
+    list: Vec<Exclusion>
+}
+
+impl Tailoring {
+
+    /// Add an [`Exclusion`] to the list.
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if an [`Exclusion`] for the same action already exists.
+    ///
+    pub fn try_add(mut self, exclusion: Exclusion) -> Result<Self, Error> {
+        if self.for_action(&exclusion.action).is_some() {
+            return Err(Error::for_user(Kind::InvalidInput,
+                                       format!("The action '{}' is excluded more than once. Each action can only be excluded once.", exclusion.action.value)));
+        }
+        self.list.push(exclusion);
+        Ok(self)
+    }
+
+    /// Returns the [`Exclusion`] for the action, if the action is excluded.
+    pub fn for_action(&self, action: &Name) -> Option<&Exclusion> {
+        self.list.iter().find(|exclusion| &exclusion.action == action)
+    }
+
+    /// Returns a reference to the list of [`Exclusion`]s.
+    pub fn list(&self) -> &Vec<Exclusion> {
+        &self.list
+    }
+
+    /// Returns `true` if no actions are excluded.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// # Overview
+    ///
+    /// Excludes the actions whose `when` condition is not met by the [`Parameters`] of the collection, and returns a new instance of the [`Tailoring`].  When the condition of an activity is not met, all of its actions are excluded.  Actions which are already excluded keep their existing [`Exclusion`].
+    ///
+    pub fn with_conditions(mut self, activities: &Activities, parameters: &Parameters) -> Self {
+        for activity in &activities.list {
+            let activity_condition = activity.when.as_ref().filter(|condition| !condition.is_met(parameters));
+            for action in &activity.actions {
+                let unmet_condition = activity_condition
+                    .or_else(|| action.when.as_ref().filter(|condition| !condition.is_met(parameters)));
+                if let Some(condition) = unmet_condition {
+                    if self.for_action(&action.name).is_none() {
+                        self.list.push(Exclusion::for_condition(&action.name, condition));
+                    }
+                }
+            }
+        }
+        self
+    }
+
+    /// Checks that every excluded action is an action of the procedure.
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned for the first excluded action which is not in the [`Activities`] of the procedure.
+    ///
+    pub fn check_actions(&self, activities: &Activities) -> Result<(), Error> {
+        match self.list.iter().find(|exclusion| activities.find_action(&exclusion.action.value).is_none()) {
+            Some(exclusion) => Err(Error::for_user(Kind::InvalidInput,
+                                                   format!("The excluded action '{}' is not an action of the procedure. Please check the action names in the tailoring file.", exclusion.action.value))),
+            None => Ok(())
+        }
+    }
+
+}
@@ -0,0 +1,123 @@
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_procedure::action::Action;
+use crate::values::specification::assurance_procedure::activities::Activities;
+use crate::values::specification::assurance_procedure::activity::Activity;
+use crate::values::specification::assurance_procedure::condition::Condition;
+use crate::values::specification::assurance_procedure::parameters::Parameters;
+use crate::values::specification::metadata::MetaData;
+use crate::values::specification::subject::Subject;
+use crate::values::specification::assurance_report::exclusion::Exclusion;
+use crate::values::specification::assurance_report::tailoring::Tailoring;
+use crate::values::specification::name::Name;
+
+#[test]
+fn add_success() {
+    let tailoring = Tailoring::default()
+        .try_add(Exclusion::try_new("action-1", "Not applicable.").unwrap()).unwrap()
+        .try_add(Exclusion::try_new("action-2", "Not applicable.").unwrap());
+
+    let tailoring = is_ok!(tailoring);
+    assert!(!tailoring.is_empty());
+    assert_eq!(tailoring.list().len(), 2);
+    assert_eq!(tailoring.for_action(&Name::try_from("action-2").unwrap()).unwrap().action.value, "action-2");
+    assert!(tailoring.for_action(&Name::try_from("action-3").unwrap()).is_none());
+}
+
+#[test]
+fn add_duplicate_action_error() {
+    let result = Tailoring::default()
+        .try_add(Exclusion::try_new("action-1", "Not applicable.").unwrap()).unwrap()
+        .try_add(Exclusion::try_new("action-1", "Another reason.").unwrap());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The action 'action-1' is excluded more than once. Each action can only be excluded once.");
+}
+
+#[test]
+fn check_actions_success() {
+    let tailoring = Tailoring::default()
+        .try_add(Exclusion::try_new("action-1", "Not applicable.").unwrap()).unwrap();
+
+    is_ok!(tailoring.check_actions(&activities_helper()));
+    is_ok!(Tailoring::default().check_actions(&activities_helper()));
+}
+
+#[test]
+fn check_actions_unknown_action_error() {
+    let tailoring = Tailoring::default()
+        .try_add(Exclusion::try_new("action-9", "Not applicable.").unwrap()).unwrap();
+
+    let result = tailoring.check_actions(&activities_helper());
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "The excluded action 'action-9' is not an action of the procedure. Please check the action names in the tailoring file.");
+}
+
+#[test]
+fn with_conditions_activity_not_met() {
+    let tailoring = Tailoring::default().with_conditions(&conditional_activities(), &parameters("false"));
+
+    let pci = tailoring.for_action(&Name::try_from("card-data-encrypted").unwrap()).unwrap();
+    assert_eq!(pci.justification.value, "The condition 'meta.pci == true' is not met.");
+    assert_eq!(pci.condition, Some(Condition::try_from("meta.pci == true").unwrap()));
+    assert!(tailoring.for_action(&Name::try_from("card-data-masked").unwrap()).is_some());
+    assert!(tailoring.for_action(&Name::try_from("build-check").unwrap()).is_none());
+    assert_eq!(tailoring.list().len(), 3);
+}
+
+#[test]
+fn with_conditions_action_not_met() {
+    let tailoring = Tailoring::default().with_conditions(&conditional_activities(), &parameters("true"));
+
+    assert_eq!(tailoring.list().len(), 1);
+    let exclusion = tailoring.for_action(&Name::try_from("prod-approval").unwrap()).unwrap();
+    assert_eq!(exclusion.condition, Some(Condition::try_from("meta.environment == prod").unwrap()));
+}
+
+#[test]
+fn with_conditions_keeps_existing_exclusion() {
+    let tailoring = Tailoring::default()
+        .try_add(Exclusion::try_new("card-data-masked", "Masking is done by the gateway.").unwrap()).unwrap()
+        .with_conditions(&conditional_activities(), &parameters("false"));
+
+    let exclusion = tailoring.for_action(&Name::try_from("card-data-masked").unwrap()).unwrap();
+    assert_eq!(exclusion.justification.value, "Masking is done by the gateway.");
+    assert_eq!(exclusion.condition, None);
+    assert_eq!(tailoring.list().len(), 3);
+}
+
+fn conditional_activities() -> Activities {
+    let action = |name: &str| Action::builder().name(name)
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path(&format!("./activity/{}.py", name))
+        .evidence_file_path(&format!("./evidence/{}.json", name));
+
+    let build = Activity::new("build", "Short Desc", "Long Desc").unwrap()
+        .add(action("build-check").try_build().unwrap())
+        .add(action("prod-approval").when("meta.environment == prod").try_build().unwrap());
+    let pci = Activity::new("pci", "Short Desc", "Long Desc").unwrap()
+        .add(action("card-data-encrypted").try_build().unwrap())
+        .add(action("card-data-masked").try_build().unwrap())
+        .when(&Condition::try_from("meta.pci == true").unwrap());
+
+    Activities::default().merge(&build).merge(&pci)
+}
+
+fn parameters(pci: &str) -> Parameters {
+    let mut metadata = MetaData::default();
+    metadata.add("pci", pci).unwrap();
+    metadata.add("environment", "staging").unwrap();
+    Parameters::from(&metadata, &Subject::try_new("nrn:sourcecode:example", "1234").unwrap())
+}
+
+fn activities_helper() -> Activities {
+    let action = Action::builder().name("action-1")
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path("test_file")
+        .evidence_file_path("evidence")
+        .try_build().unwrap();
+
+    Activities::default()
+        .add("activity-1", "Short Desc", "Long Desc").unwrap()
+        .add_activity("activity-1", &action).unwrap()
+}
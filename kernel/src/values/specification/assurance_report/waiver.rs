@@ -0,0 +1,56 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::description::Description;
+use crate::values::specification::name::Name;
+use crate::values::specification::short_description::ShortDescription;
+use crate::values::time::date::Date;
+
+/// # Overview
+///
+/// The [`Waiver`] is an approved exception for an action which is known to fail.  While a [`Waiver`] has not expired, a failed action it covers is reported with the [`Outcome::WAIVED`] outcome rather than [`Outcome::FAIL`].
+///
+/// # Attributes
+///
+/// * `action` - The [`Name`] of the action the waiver applies to.
+/// * `justification` - The reason the failure is accepted.
+/// * `approver` - Who approved the waiver.
+/// * `expires` - The last [`Date`] the waiver applies.
+///
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Waiver {
+    pub action: Name,
+    pub justification: Description,
+    pub approver: ShortDescription,
+    pub expires: Date,
+}
+
+impl Waiver {
+
+    /// Create a new [`Waiver`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if any of the values are invalid.
+    ///
+    pub fn try_new(action: &str, justification: &str, approver: &str, expires: &str) -> Result<Waiver, Error> {
+        let action = Name::try_from(action)
+            .map_err(|e| custom_error(&format!("There is an issue with the action name '{}'. {}", action, e.message)))?;
+        let justification = Description::try_from(justification)
+            .map_err(|e| custom_error(&format!("There is an issue with the justification for the action '{}'. {}", action.value, e.message)))?;
+        let approver = ShortDescription::try_from(approver)
+            .map_err(|e| custom_error(&format!("There is an issue with the approver for the action '{}'. {}", action.value, e.message)))?;
+        let expires = Date::try_from(expires)
+            .map_err(|e| custom_error(&format!("There is an issue with the expiry for the action '{}'. {}", action.value, e.message)))?;
+
+        Ok(Waiver { action, justification, approver, expires })
+    }
+
+    /// Returns `true` if the waiver no longer applies on the given [`Date`].  A waiver applies up to and including its expiry date.
+    pub fn is_expired(&self, today: &Date) -> bool {
+        today > &self.expires
+    }
+
+}
+
+fn custom_error(message: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("The Waiver could not be created. {}", message))
+}
@@ -0,0 +1,47 @@
+use nape_testing_assertions::{is_ok, kernel_error_starts_with};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_report::waiver::Waiver;
+use crate::values::time::date::Date;
+
+#[test]
+fn try_new_success() {
+    let waiver = is_ok!(Waiver::try_new("action-1", "Replaced by a compensating control.", "Jane Doe", "2024-06-30"));
+
+    assert_eq!(waiver.action.value, "action-1");
+    assert_eq!(waiver.justification.value, "Replaced by a compensating control.");
+    assert_eq!(waiver.approver.value, "Jane Doe");
+    assert_eq!(waiver.expires, Date::try_from("2024-06-30").unwrap());
+}
+
+#[test]
+fn is_expired() {
+    let waiver = Waiver::try_new("action-1", "Replaced by a compensating control.", "Jane Doe", "2024-06-30").unwrap();
+
+    assert!(!waiver.is_expired(&Date::try_from("2024-06-29").unwrap()));
+    assert!(!waiver.is_expired(&Date::try_from("2024-06-30").unwrap()));
+    assert!(waiver.is_expired(&Date::try_from("2024-07-01").unwrap()));
+}
+
+#[test]
+fn try_new_bad_action_error() {
+    let result = Waiver::try_new("action 1", "Replaced by a compensating control.", "Jane Doe", "2024-06-30");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Waiver could not be created. There is an issue with the action name 'action 1'. ");
+}
+
+#[test]
+fn try_new_bad_justification_error() {
+    let result = Waiver::try_new("action-1", "", "Jane Doe", "2024-06-30");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Waiver could not be created. There is an issue with the justification for the action 'action-1'. ");
+}
+
+#[test]
+fn try_new_bad_approver_error() {
+    let result = Waiver::try_new("action-1", "Replaced by a compensating control.", "", "2024-06-30");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Waiver could not be created. There is an issue with the approver for the action 'action-1'. ");
+}
+
+#[test]
+fn try_new_bad_expiry_error() {
+    let result = Waiver::try_new("action-1", "Replaced by a compensating control.", "Jane Doe", "30/06/2024");
+    kernel_error_starts_with!(result, Kind::InvalidInput, Audience::User, "The Waiver could not be created. There is an issue with the expiry for the action 'action-1'. ");
+}
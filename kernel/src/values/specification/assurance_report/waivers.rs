@@ -0,0 +1,38 @@
+use crate::error::{Error, Kind};
+use crate::values::specification::assurance_report::waiver::Waiver;
+use crate::values::specification::name::Name;
+
+/// The [`Waivers`] struct holds the list of [`Waiver`]s which are applied to the results of an evaluation.  There is at most one [`Waiver`] for each action name.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Waivers {
+    list: Vec<Waiver>
+}
+
+impl Waivers {
+
+    /// Add a [`Waiver`] to the list, and return the new list.
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if a [`Waiver`] for the same action already exists.
+    ///
+    pub fn try_add(mut self, waiver: Waiver) -> Result<Self, Error> {
+        if self.for_action(&waiver.action).is_some() {
+            return Err(Error::for_user(Kind::InvalidInput,
+                                       format!("There is more than one waiver for the action '{}'. Each action can only have one waiver.", waiver.action.value)));
+        }
+        self.list.push(waiver);
+        Ok(self)
+    }
+
+    /// Returns the [`Waiver`] for the action, if there is one.
+    pub fn for_action(&self, action: &Name) -> Option<&Waiver> {
+        self.list.iter().find(|waiver| &waiver.action == action)
+    }
+
+    /// Returns a reference to the list of [`Waiver`]s.
+    pub fn list(&self) -> &Vec<Waiver> {
+        &self.list
+    }
+
+}
@@ -0,0 +1,26 @@
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::error::{Audience, Kind};
+use crate::values::specification::assurance_report::waiver::Waiver;
+use crate::values::specification::assurance_report::waivers::Waivers;
+use crate::values::specification::name::Name;
+
+#[test]
+fn add_success() {
+    let waivers = Waivers::default()
+        .try_add(Waiver::try_new("action-1", "Justified.", "Jane Doe", "2024-06-30").unwrap()).unwrap()
+        .try_add(Waiver::try_new("action-2", "Justified.", "Jane Doe", "2024-06-30").unwrap());
+
+    let waivers = is_ok!(waivers);
+    assert_eq!(waivers.list().len(), 2);
+    assert_eq!(waivers.for_action(&Name::try_from("action-2").unwrap()).unwrap().action.value, "action-2");
+    assert!(waivers.for_action(&Name::try_from("action-3").unwrap()).is_none());
+}
+
+#[test]
+fn add_duplicate_action_error() {
+    let result = Waivers::default()
+        .try_add(Waiver::try_new("action-1", "Justified.", "Jane Doe", "2024-06-30").unwrap()).unwrap()
+        .try_add(Waiver::try_new("action-1", "Another.", "John Doe", "2024-07-30").unwrap());
+
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "There is more than one waiver for the action 'action-1'. Each action can only have one waiver.");
+}
@@ -13,6 +13,7 @@ pub mod name;
 pub mod outcome;
 pub mod procedure;
 pub mod repository_link;
+pub mod severity;
 pub mod short_description;
 pub mod kind;
 
@@ -27,6 +28,7 @@ pub mod kind;
 #[cfg(test)] mod outcome_tests;
 #[cfg(test)] mod procedure_tests;
 #[cfg(test)] mod repository_link_tests;
+#[cfg(test)] mod severity_tests;
 #[cfg(test)] mod short_description_tests;
 #[cfg(test)] mod kind_tests;
 
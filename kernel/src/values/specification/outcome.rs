@@ -7,11 +7,24 @@ const OUTCOME_STRINGS: &'static [(Outcome, &'static str)] = &[
     (Outcome::FAIL, "fail"),
     (Outcome::INCONCLUSIVE, "inconclusive"),
     (Outcome::PASS, "pass"),
-    (Outcome::ERROR, "error") ];
+    (Outcome::ERROR, "error"),
+    (Outcome::WAIVED, "waived"),
+    (Outcome::NOT_APPLICABLE, "not_applicable"),
+    (Outcome::SKIPPED, "skipped") ];
+
+/// The [`TEST_OUTCOMES`] are the [`Outcome`]s a control action test can report.  The other outcomes are only assigned by NAPE, e.g. [`Outcome::WAIVED`] when an unexpired waiver is applied to a failed action.
+const TEST_OUTCOMES: &[Outcome] = &[Outcome::PASS, Outcome::FAIL, Outcome::INCONCLUSIVE, Outcome::ERROR];
 
 #[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum Outcome {
-    FAIL, PASS, INCONCLUSIVE, ERROR
+    FAIL, PASS, INCONCLUSIVE, ERROR,
+    /// A failed action which is covered by an unexpired waiver.
+    WAIVED,
+    /// An action which was tailored out of the collection because it does not apply to the subject.
+    #[allow(non_camel_case_types)]
+    NOT_APPLICABLE,
+    /// An action which was not evaluated because an action it depends on did not pass.
+    SKIPPED
 }
 
 impl Outcome {
@@ -28,6 +41,19 @@ impl Outcome {
             format!("'{}' is not a valid Outcome. Must be one of: [{}].", outcome_value, list_of_valid_outcome_inputs.join(", "))
         ) )
     }
+
+    /// Converts the outcome reported by a control action test, which must be one of the [`TEST_OUTCOMES`], so a test cannot waive, skip, or tailor out its own action.
+    pub fn try_from_test(outcome_value: &str) -> Result<Outcome, Error> {
+        let outcome = Outcome::try_from(outcome_value)?;
+        if TEST_OUTCOMES.contains(&outcome) {
+            return Ok(outcome);
+        }
+        let list_of_test_outcomes: Vec<String> = TEST_OUTCOMES.iter().map(|outcome| outcome.to_string()).collect();
+        Err(Error::for_user(
+            crate::error::Kind::InvalidInput,
+            format!("'{}' is not an Outcome a test can report. Must be one of: [{}].", outcome_value, list_of_test_outcomes.join(", "))
+        ) )
+    }
 }
 
 impl Display for Outcome {
@@ -16,7 +16,21 @@ fn new_outcome_enum_error_empty_value() {
 #[test]
 fn new_outcome_enum_error_invalid_input_value() {
     let outcome = Outcome::try_from("invalid");
-    assert_eq!(outcome, Err(Error::for_user(error::Kind::InvalidInput, "'invalid' is not a valid Outcome. Must be one of: [fail, inconclusive, pass, error].".to_string())));
+    assert_eq!(outcome, Err(Error::for_user(error::Kind::InvalidInput, "'invalid' is not a valid Outcome. Must be one of: [fail, inconclusive, pass, error, waived, not_applicable, skipped].".to_string())));
+}
+
+#[test]
+fn try_from_test_success() {
+    assert_eq!(Outcome::try_from_test("pass"), Ok(Outcome::PASS));
+    assert_eq!(Outcome::try_from_test("FAIL"), Ok(Outcome::FAIL));
+    assert_eq!(Outcome::try_from_test("inconclusive"), Ok(Outcome::INCONCLUSIVE));
+    assert_eq!(Outcome::try_from_test("error"), Ok(Outcome::ERROR));
+}
+
+#[test]
+fn try_from_test_waived_error() {
+    let outcome = Outcome::try_from_test("waived");
+    assert_eq!(outcome, Err(Error::for_user(error::Kind::InvalidInput, "'waived' is not an Outcome a test can report. Must be one of: [pass, fail, inconclusive, error].".to_string())));
 }
 
 #[test]
@@ -43,6 +57,12 @@ fn to_string_error() {
     assert_eq!(outcome.to_string(), "error");
 }
 
+#[test]
+fn to_string_waived() {
+    let outcome = Outcome::WAIVED;
+    assert_eq!(outcome.to_string(), "waived");
+}
+
 #[test]
 fn default_outcome() {
     let outcome = Outcome::default();
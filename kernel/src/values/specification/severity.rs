@@ -0,0 +1,41 @@
+use std::fmt::Display;
+use crate::error;
+use crate::error::Error;
+
+/// The [`SEVERITY_STRINGS`] contains the list of [`Severity`] levels and their string representations.  This is used for validation of inputs when converting from string to [`Severity`] enum, and when converting from [`Severity`] enum to string.
+const SEVERITY_STRINGS: &[(Severity, &str)] = &[
+    (Severity::CRITICAL, "critical"),
+    (Severity::HIGH, "high"),
+    (Severity::MEDIUM, "medium"),
+    (Severity::LOW, "low") ];
+
+/// The [`Severity`] represents how important an action of an Assurance Procedure is, which is used to weigh the impact of a failed action.
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub enum Severity {
+    CRITICAL, HIGH, MEDIUM, LOW
+}
+
+impl Severity {
+    pub fn try_from(severity_value: &str) -> Result<Severity, Error> {
+        if severity_value.is_empty() {
+            return Err(Error::for_user(error::Kind::InvalidInput, "You have provided an empty Severity value. Please provide a Severity value.".to_string()));
+        }
+        for (severity_enum, human_readable_severity) in SEVERITY_STRINGS {
+            if severity_value.to_lowercase() == *human_readable_severity { return Ok(severity_enum.clone()); }
+        }
+        let list_of_valid_severity_inputs: Vec<&str> = SEVERITY_STRINGS.iter().map(|(_, severity_str)| *severity_str).collect();
+        Err(Error::for_user(
+            error::Kind::InvalidInput,
+            format!("'{}' is not a valid Severity. Must be one of: [{}].", severity_value, list_of_valid_severity_inputs.join(", "))
+        ) )
+    }
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (severity_enum, severity_str) in SEVERITY_STRINGS {
+            if self == severity_enum { return write!(f, "{}", severity_str); }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,37 @@
+use crate::error;
+use crate::error::Error;
+use crate::values::specification::severity::Severity;
+
+#[test]
+fn try_from_success() {
+    assert_eq!(Severity::try_from("critical"), Ok(Severity::CRITICAL));
+    assert_eq!(Severity::try_from("high"), Ok(Severity::HIGH));
+    assert_eq!(Severity::try_from("medium"), Ok(Severity::MEDIUM));
+    assert_eq!(Severity::try_from("low"), Ok(Severity::LOW));
+}
+
+#[test]
+fn handle_uppercase_input_success() {
+    assert_eq!(Severity::try_from("CRITICAL"), Ok(Severity::CRITICAL));
+    assert_eq!(Severity::try_from("High"), Ok(Severity::HIGH));
+}
+
+#[test]
+fn try_from_error_empty_value() {
+    let result = Severity::try_from("");
+    assert_eq!(result, Err(Error::for_user(error::Kind::InvalidInput, "You have provided an empty Severity value. Please provide a Severity value.".to_string())));
+}
+
+#[test]
+fn try_from_error_invalid_input_value() {
+    let result = Severity::try_from("urgent");
+    assert_eq!(result, Err(Error::for_user(error::Kind::InvalidInput, "'urgent' is not a valid Severity. Must be one of: [critical, high, medium, low].".to_string())));
+}
+
+#[test]
+fn to_string_success() {
+    assert_eq!(Severity::CRITICAL.to_string(), "critical");
+    assert_eq!(Severity::HIGH.to_string(), "high");
+    assert_eq!(Severity::MEDIUM.to_string(), "medium");
+    assert_eq!(Severity::LOW.to_string(), "low");
+}
@@ -1,13 +1,30 @@
+use std::any::Any;
 use crate::error;
 use crate::error::{Error};
 use crate::values::specification::api_version::APIVersion;
+use crate::values::specification::assurance_procedure::action::Action;
 use crate::values::specification::assurance_procedure::artifact::Artifact;
 use crate::values::specification::assurance_procedure::artifacts::Artifacts;
 use crate::values::specification::assurance_procedure::activity::Activity;
 use crate::values::specification::assurance_procedure::activities::Activities;
+use crate::values::specification::assurance_procedure::evidence_input::EvidenceInput;
+use crate::values::specification::assurance_procedure::include::Include;
+use crate::values::specification::assurance_procedure::parameters::Parameters;
 use crate::values::specification::assurance_procedure::procedure::Procedure;
+use crate::values::specification::description::Description;
+use crate::values::specification::file_path::FilePath;
 use crate::values::specification::kind::Kind;
+use crate::values::specification::short_description::ShortDescription;
+use crate::values::specification::traits;
 
+/// # Overview
+///
+/// The [`AssuranceProcedure`] is the in-memory model of the NAPE Assurance Procedure specification.  Every supported 1.x schema (1.0.0, 1.1.0, 1.2.0, 1.3.0, 1.4.0, 1.5.0, and 1.6.0) is read into this model; fields introduced by newer schemas are optional so that older procedures are upgraded by leaving them unset.
+///
+/// The `api_version` holds the version of the schema the procedure was read from.
+///
+/// The `includes` hold the activities to pull in from other procedures.  They are resolved with [`AssuranceProcedure::include`] when a collection starts, after which the included activities are part of the `activities` and record where they came from.
+///
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct AssuranceProcedure {
     pub api_version: APIVersion,
@@ -15,6 +32,17 @@ pub struct AssuranceProcedure {
     pub procedure: Procedure,
     pub activities: Activities,
     pub artifacts: Artifacts,
+    pub includes: Vec<Include>,
+}
+
+impl traits::AssuranceProcedure for AssuranceProcedure {
+    fn api_version(&self) -> APIVersion {
+        self.api_version.clone()
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 impl AssuranceProcedure {
@@ -22,6 +50,92 @@ impl AssuranceProcedure {
         AssuranceProcedureBuilder::new()
     }
 
+    /// # Overview
+    ///
+    /// Merges the activities selected by the [`Include`] from the `included` procedure into this procedure.  Each included activity records the source of the include, and the merged procedure takes the later `apiVersion` of the two, so it can be written with the fields of either.
+    ///
+    /// # Arguments
+    ///
+    /// * `include` - The [`Include`] which selects the activities.
+    /// * `included` - The [`AssuranceProcedure`] retrieved from the source of the [`Include`].
+    ///
+    /// # Returns
+    ///
+    /// A new instance of the [`AssuranceProcedure`] with the included activities, or an [`Error`] for [`Audience::User`] of [`Kind::InvalidInput`] if:
+    ///
+    /// * a selected activity does not exist in the `included` procedure,
+    /// * an activity with the same name already exists in this procedure, or
+    /// * an included action has the same name, test file, or evidence file as an existing action.
+    ///
+    pub fn include(&self, include: &Include, included: &AssuranceProcedure) -> Result<AssuranceProcedure, Error> {
+        let mut activities = self.activities.clone();
+
+        for activity_name in &include.activities {
+            let activity = included.activities.list.iter()
+                .find(|activity| &activity.name == activity_name)
+                .ok_or_else(|| include_error(include, &format!("The activity '{}' does not exist in the procedure '{}'.", activity_name.value, included.procedure.nrn)))?;
+
+            if activities.list.iter().any(|existing| existing.name == activity.name) {
+                return Err(include_error(include, &format!("The activity '{}' already exists in the procedure. Activity names must be unique.", activity_name.value)));
+            }
+
+            for action in &activity.actions {
+                check_action_collision(include, &activities, action)?;
+            }
+
+            activities = activities.merge(&activity.clone().included_from(&include.source));
+        }
+
+        let api_version = self.api_version.clone().max(included.api_version.clone());
+        Ok(AssuranceProcedure { api_version, activities, ..self.clone() })
+    }
+
+    /// Returns a new instance of the [`AssuranceProcedure`] without any [`Include`], e.g. once they have been resolved.
+    pub fn without_includes(&self) -> AssuranceProcedure {
+        AssuranceProcedure { includes: Vec::new(), ..self.clone() }
+    }
+
+    /// # Overview
+    ///
+    /// Replaces the `${name}` placeholders in the procedure with the [`Parameters`] of a collection.  The placeholders are resolved in the short and long descriptions of the procedure, its activities and actions, and in the test, evidence, evidence input, and expected evidence file paths.
+    ///
+    /// # Returns
+    ///
+    /// A new instance of the [`AssuranceProcedure`] without placeholders, or an [`Error`] for [`Audience::User`] of [`Kind::InvalidInput`] if a placeholder could not be resolved, or the resolved value is not valid.
+    ///
+    pub fn resolve_parameters(&self, parameters: &Parameters) -> Result<AssuranceProcedure, Error> {
+        let procedure = Procedure {
+            short: resolve_short(parameters, &self.procedure.short)
+                .map_err(|e| parameter_error("the procedure information", &e))?,
+            description: resolve_description(parameters, &self.procedure.description)
+                .map_err(|e| parameter_error("the procedure information", &e))?,
+            ..self.procedure.clone()
+        };
+
+        let mut activities = Activities::default();
+        for activity in &self.activities.list {
+            let context = format!("the activity '{}'", activity.name.value);
+            let mut actions = Vec::new();
+            for action in &activity.actions {
+                actions.push(resolve_action(parameters, action)?);
+            }
+            let mut expected_evidence = Vec::new();
+            for file_path in &activity.expected_evidence {
+                expected_evidence.push(resolve_file_path(parameters, file_path)
+                    .map_err(|e| parameter_error(&context, &e))?);
+            }
+            activities.list.push(Activity {
+                short: resolve_short(parameters, &activity.short).map_err(|e| parameter_error(&context, &e))?,
+                description: resolve_description(parameters, &activity.description).map_err(|e| parameter_error(&context, &e))?,
+                expected_evidence,
+                actions,
+                ..activity.clone()
+            });
+        }
+
+        Ok(AssuranceProcedure { procedure, activities, ..self.clone() })
+    }
+
 }
 
  pub struct AssuranceProcedureBuilder {
@@ -31,7 +145,8 @@ impl AssuranceProcedure {
     procedure_short_desc: Option<String>,
     procedure_long_desc: Option<String>,
     activities: Vec<Activity>,
-    artifacts: Vec<Artifact>
+    artifacts: Vec<Artifact>,
+    includes: Vec<Include>
 }
 
 impl AssuranceProcedureBuilder {
@@ -44,7 +159,8 @@ impl AssuranceProcedureBuilder {
                 procedure_short_desc: None,
                 procedure_long_desc: None,
                 activities: Vec::new(),
-                artifacts: Vec::new()
+                artifacts: Vec::new(),
+                includes: Vec::new()
             }
     }
 
@@ -80,14 +196,20 @@ impl AssuranceProcedureBuilder {
         self
     }
 
+    pub fn add_include(mut self, include: &Include) -> Self {
+        self.includes.push(include.clone());
+        self
+    }
+
     pub fn try_build(&self) -> Result<AssuranceProcedure, Error> {
         let api_version = self.build_api_version()?;
         let kind = Kind::AssuranceProcedure;
         let procedure = self.build_procedure()?;
-        let activities = self.build_activities();
+        let activities = self.build_activities()?;
         let artifacts = self.build_artifacts()?;
+        let includes = self.includes.clone();
 
-        Ok(AssuranceProcedure { api_version, kind, procedure, activities, artifacts })
+        Ok(AssuranceProcedure { api_version, kind, procedure, activities, artifacts, includes })
 
     }
 
@@ -114,12 +236,16 @@ impl AssuranceProcedureBuilder {
 
     }
 
-    fn build_activities(&self) -> Activities {
+    /// The dependencies between the actions are checked once the procedure is complete.  A procedure with an `include` section is checked once its includes are resolved, as an action may depend on an included action.
+    fn build_activities(&self) -> Result<Activities, Error> {
         let mut activities = Activities::default();
         for procedure in &self.activities {
            activities = activities.merge(procedure)
         }
-        activities
+        if self.includes.is_empty() {
+            activities.dependency_levels().map_err(|e| custom_error(&e.message))?;
+        }
+        Ok(activities)
     }
 
     fn build_artifacts(&self) -> Result<Artifacts, Error> {
@@ -133,6 +259,62 @@ impl AssuranceProcedureBuilder {
 
 }
 
+fn check_action_collision(include: &Include, activities: &Activities, action: &Action) -> Result<(), Error> {
+    for existing in activities.list.iter().flat_map(|activity| activity.actions.iter()) {
+        if existing.name == action.name {
+            return Err(include_error(include, &format!("The action '{}' already exists in the procedure. Action names must be unique.", action.name.value)));
+        }
+        if let Some(test) = action.test.as_ref().filter(|test| existing.test.as_ref() == Some(test)) {
+            return Err(include_error(include, &format!("The test file '{}' of the action '{}' is already used by the action '{}'.", test.as_str(), action.name.value, existing.name.value)));
+        }
+        if existing.evidence == action.evidence {
+            return Err(include_error(include, &format!("The evidence file '{}' of the action '{}' is already used by the action '{}'.", action.evidence.as_str(), action.name.value, existing.name.value)));
+        }
+    }
+    Ok(())
+}
+
+fn resolve_action(parameters: &Parameters, action: &Action) -> Result<Action, Error> {
+    let context = format!("the action '{}'", action.name.value);
+    let mut inputs = Vec::new();
+    for input in &action.inputs {
+        inputs.push(EvidenceInput {
+            evidence: resolve_file_path(parameters, &input.evidence).map_err(|e| parameter_error(&context, &e))?,
+            ..input.clone()
+        });
+    }
+    Ok(Action {
+        inputs,
+        short: resolve_short(parameters, &action.short).map_err(|e| parameter_error(&context, &e))?,
+        description: resolve_description(parameters, &action.description).map_err(|e| parameter_error(&context, &e))?,
+        test: action.test.as_ref().map(|test| resolve_file_path(parameters, test)).transpose().map_err(|e| parameter_error(&context, &e))?,
+        evidence: resolve_file_path(parameters, &action.evidence).map_err(|e| parameter_error(&context, &e))?,
+        ..action.clone()
+    })
+}
+
+fn resolve_short(parameters: &Parameters, short: &ShortDescription) -> Result<ShortDescription, Error> {
+    ShortDescription::try_from(&parameters.resolve(&short.value)?)
+}
+
+fn resolve_description(parameters: &Parameters, description: &Description) -> Result<Description, Error> {
+    Description::try_from(&parameters.resolve(&description.value)?)
+}
+
+fn resolve_file_path(parameters: &Parameters, file_path: &FilePath) -> Result<FilePath, Error> {
+    FilePath::try_from(&parameters.resolve(file_path.as_str())?)
+}
+
+fn parameter_error(context: &str, error: &Error) -> Error {
+    Error::for_user(error::Kind::InvalidInput,
+                    format!("We could not resolve the parameters of {}. {}", context, error.message))
+}
+
+fn include_error(include: &Include, message: &str) -> Error {
+    Error::for_user(error::Kind::InvalidInput,
+                    format!("We could not include the activities from '{}'. {}", include.source, message))
+}
+
 fn custom_error(message: &str) -> Error {
     Error::for_user(error::Kind::InvalidInput,
                     format!("The AssuranceProcedure could not be created: {}", message))
@@ -3,7 +3,13 @@ use crate::error::{Audience};
 use crate::values::specification::api_version::APIVersion;
 use crate::values::specification::kind::Kind;
 use crate::values::specification::assurance_procedure::artifact::Artifact;
-use crate::values::specification::assurance_procedure::activity::Activity;use crate::values::specification::v1_0_0::assurance_procedure::AssuranceProcedure;
+use crate::values::specification::assurance_procedure::action::Action;
+use crate::values::specification::assurance_procedure::activity::Activity;
+use crate::values::specification::assurance_procedure::include::{Include, Source};
+use crate::values::specification::assurance_procedure::parameters::Parameters;
+use crate::values::specification::metadata::MetaData;
+use crate::values::specification::subject::Subject;
+use crate::values::specification::v1_0_0::assurance_procedure::{AssuranceProcedure, AssuranceProcedureBuilder};
 
 #[test]
 fn new_builder() {
@@ -128,3 +134,246 @@ fn builder_error_add_artifact_duplicate_artifact() {
     assert_eq!(err.audience, Audience::User);
     assert!(err.message.starts_with("The AssuranceProcedure could not be created: The artifact 'artifact-1' has an issue: "));
 }
+
+/*** Dependency Tests ***/
+#[test]
+fn builder_error_dependency_cycle() {
+    let result = procedure_with_dependency("build-check", &["build-check"]).try_build();
+
+    let err = result.unwrap_err();
+    assert_eq!(err.kind, error::Kind::InvalidInput);
+    assert_eq!(err.audience, Audience::User);
+    assert_eq!(err.message, "The AssuranceProcedure could not be created: The dependencies of the actions are not valid. The dependencies form a cycle: build-check -> build-check.");
+}
+
+#[test]
+fn builder_error_unknown_dependency() {
+    let result = procedure_with_dependency("build-check", &["protected-main"]).try_build();
+
+    let err = result.unwrap_err();
+    assert_eq!(err.message, "The AssuranceProcedure could not be created: The dependencies of the actions are not valid. The action 'build-check' depends on 'protected-main', which is not an action of the procedure.");
+}
+
+#[test]
+fn include_success_dependency_on_included_action() {
+    let include = include_for(&["branch-protection"]);
+    let procedure = procedure_with_dependency("build-check", &["protected-main"]).add_include(&include).try_build().unwrap();
+    let shared = procedure_with("nrn:procedure:example/shared", "branch-protection", "protected-main", "./activity/branch/protected.py").try_build().unwrap();
+
+    let result = procedure.include(&include, &shared).unwrap().without_includes();
+
+    let levels = result.activities.dependency_levels().unwrap();
+    assert_eq!(levels.len(), 2);
+    assert_eq!(levels[1][0].name.value, "build-check");
+}
+
+/*** Include Tests ***/
+#[test]
+fn include_success() {
+    let procedure = procedure_with("nrn:procedure:example/main", "build", "build-check", "./activity/build/check.py");
+    let shared = procedure_with("nrn:procedure:example/shared", "branch-protection", "protected-main", "./activity/branch/protected.py");
+    let include = include_for(&["branch-protection"]);
+
+    let merged = procedure.add_include(&include).try_build().unwrap();
+    assert_eq!(merged.includes.len(), 1);
+
+    let result = merged.include(&include, &shared.try_build().unwrap()).unwrap().without_includes();
+
+    assert_eq!(result.includes.len(), 0);
+    assert_eq!(result.activities.count(), 2);
+    assert_eq!(result.activities.list[0].source, None);
+    assert_eq!(result.activities.list[1].name.value, "branch-protection");
+    assert_eq!(result.activities.list[1].source, Some(include.source.clone()));
+    assert!(result.activities.find_action("protected-main").is_some());
+}
+
+#[test]
+fn include_success_attestation_actions() {
+    let attestation_procedure = |nrn: &str, activity_name: &str, action_name: &str| {
+        let action = Action::builder().name(action_name).short_description("Short Desc").long_description("Long Desc")
+            .evidence_file_path(&format!("./evidence/{}/attestation.yaml", action_name)).attestation().try_build().unwrap();
+        AssuranceProcedure::builder()
+            .api_version("1.6.0")
+            .procedure_info(nrn, "A Short Desc.", "This is an example procedure")
+            .add_activity(&Activity::new(activity_name, "Short Desc", "Long Desc").unwrap().add(action))
+    };
+    let include = include_for(&["access"]);
+    let procedure = attestation_procedure("nrn:procedure:example/main", "review", "change-review").add_include(&include).try_build().unwrap();
+    let shared = attestation_procedure("nrn:procedure:example/shared", "access", "access-review").try_build().unwrap();
+
+    let result = procedure.include(&include, &shared);
+
+    let merged = result.unwrap().without_includes();
+    assert_eq!(merged.activities.count(), 2);
+    assert!(merged.activities.find_action("access-review").unwrap().is_attestation());
+}
+
+#[test]
+fn include_success_later_api_version() {
+    let include = include_for(&["branch-protection"]);
+    let procedure = procedure_with("nrn:procedure:example/main", "build", "build-check", "./activity/build/check.py").add_include(&include).try_build().unwrap();
+    let shared = procedure_with("nrn:procedure:example/shared", "branch-protection", "protected-main", "./activity/branch/protected.py").try_build().unwrap();
+
+    let result = procedure.include(&include, &AssuranceProcedure { api_version: APIVersion::new(1, 6, 0), ..shared });
+
+    assert_eq!(result.unwrap().api_version, APIVersion::new(1, 6, 0));
+}
+
+#[test]
+fn include_error_activity_not_found() {
+    let procedure = procedure_with("nrn:procedure:example/main", "build", "build-check", "./activity/build/check.py").try_build().unwrap();
+    let shared = procedure_with("nrn:procedure:example/shared", "branch-protection", "protected-main", "./activity/branch/protected.py").try_build().unwrap();
+
+    let error = procedure.include(&include_for(&["dependency-scanning"]), &shared).unwrap_err();
+
+    assert_eq!(error.audience, Audience::User);
+    assert_eq!(error.message, "We could not include the activities from 'https://github.com/example/procedures.git//shared@v1'. The activity 'dependency-scanning' does not exist in the procedure 'nrn:procedure:example/shared'.");
+}
+
+#[test]
+fn include_error_activity_name_collision() {
+    let procedure = procedure_with("nrn:procedure:example/main", "branch-protection", "build-check", "./activity/build/check.py").try_build().unwrap();
+    let shared = procedure_with("nrn:procedure:example/shared", "branch-protection", "protected-main", "./activity/branch/protected.py").try_build().unwrap();
+
+    let error = procedure.include(&include_for(&["branch-protection"]), &shared).unwrap_err();
+
+    assert_eq!(error.kind, error::Kind::InvalidInput);
+    assert!(error.message.ends_with("The activity 'branch-protection' already exists in the procedure. Activity names must be unique."));
+}
+
+#[test]
+fn include_error_action_name_collision() {
+    let procedure = procedure_with("nrn:procedure:example/main", "build", "protected-main", "./activity/build/check.py").try_build().unwrap();
+    let shared = procedure_with("nrn:procedure:example/shared", "branch-protection", "protected-main", "./activity/branch/protected.py").try_build().unwrap();
+
+    let error = procedure.include(&include_for(&["branch-protection"]), &shared).unwrap_err();
+
+    assert!(error.message.ends_with("The action 'protected-main' already exists in the procedure. Action names must be unique."));
+}
+
+#[test]
+fn include_error_test_file_collision() {
+    let procedure = procedure_with("nrn:procedure:example/main", "build", "build-check", "./activity/check.py").try_build().unwrap();
+    let shared = procedure_with("nrn:procedure:example/shared", "branch-protection", "protected-main", "./activity/check.py").try_build().unwrap();
+
+    let error = procedure.include(&include_for(&["branch-protection"]), &shared).unwrap_err();
+
+    assert!(error.message.ends_with("The test file './activity/check.py' of the action 'protected-main' is already used by the action 'build-check'."));
+}
+
+#[test]
+fn resolve_parameters_success() {
+    let procedure = procedure_with("nrn:procedure:example/main", "build", "build-check", "./activity/${meta.environment}/check.py").try_build().unwrap();
+
+    let resolved = procedure.resolve_parameters(&parameters_for("staging")).unwrap();
+
+    let action = resolved.activities.find_action("build-check").unwrap();
+    assert_eq!(action.test.as_ref().unwrap().as_str(), "./activity/staging/check.py");
+    assert_eq!(action.evidence.as_str(), "./evidence/build-check.json");
+    assert_eq!(resolved.procedure, procedure.procedure);
+}
+
+#[test]
+fn resolve_parameters_success_inputs() {
+    let action = Action::builder()
+        .name("build-check")
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path("./activity/build/check.py")
+        .evidence_file_path("./evidence/build/config.yaml")
+        .add_input("runtime", "./evidence/build/${meta.environment}.json")
+        .try_build().unwrap();
+    let procedure = AssuranceProcedure::builder()
+        .api_version("1.5.0")
+        .procedure_info("nrn:procedure:example/main", "A Short Desc.", "This is an example procedure")
+        .add_activity(&Activity::new("build", "Short Desc", "Long Desc").unwrap().add(action))
+        .try_build().unwrap();
+
+    let resolved = procedure.resolve_parameters(&parameters_for("staging")).unwrap();
+
+    let action = resolved.activities.find_action("build-check").unwrap();
+    assert_eq!(action.input_evidence("runtime").unwrap().as_str(), "./evidence/build/staging.json");
+}
+
+#[test]
+fn resolve_parameters_success_descriptions() {
+    let action = Action::builder()
+        .name("build-check")
+        .short_description("Check ${subject.id}")
+        .long_description("Checks the build in ${meta.environment}")
+        .test_file_path("./activity/check.py")
+        .evidence_file_path("./evidence/${meta.environment}.json")
+        .try_build().unwrap();
+    let activity = Activity::new("build", "Build for ${meta.environment}", "Long Desc").unwrap().add(action);
+    let procedure = AssuranceProcedure::builder()
+        .api_version("1.2.0")
+        .procedure_info("nrn:procedure:example/main", "Release to ${meta.environment}", "This is an example procedure")
+        .add_activity(&activity)
+        .try_build().unwrap();
+
+    let resolved = procedure.resolve_parameters(&parameters_for("prod")).unwrap();
+
+    let action = resolved.activities.find_action("build-check").unwrap();
+    assert_eq!(resolved.procedure.short.value, "Release to prod");
+    assert_eq!(resolved.activities.list[0].short.value, "Build for prod");
+    assert_eq!(action.short.value, "Check 1234");
+    assert_eq!(action.description.value, "Checks the build in prod");
+    assert_eq!(action.evidence.as_str(), "./evidence/prod.json");
+}
+
+#[test]
+fn resolve_parameters_error_unresolved() {
+    let procedure = procedure_with("nrn:procedure:example/main", "build", "build-check", "./activity/${meta.region}/check.py").try_build().unwrap();
+
+    let error = procedure.resolve_parameters(&parameters_for("staging")).unwrap_err();
+
+    assert_eq!(error.kind, error::Kind::InvalidInput);
+    assert_eq!(error.audience, Audience::User);
+    assert!(error.message.starts_with("We could not resolve the parameters of the action 'build-check'. There is an issue with a procedure parameter: The placeholder '${meta.region}' in './activity/${meta.region}/check.py' could not be resolved."));
+}
+
+fn parameters_for(environment: &str) -> Parameters {
+    let mut metadata = MetaData::default();
+    metadata.add("environment", environment).unwrap();
+    Parameters::from(&metadata, &Subject::try_new("nrn:sourcecode:example", "1234").unwrap())
+}
+
+fn procedure_with(nrn: &str, activity_name: &str, action_name: &str, test: &str) -> AssuranceProcedureBuilder {
+    let action = Action::builder()
+        .name(action_name)
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path(test)
+        .evidence_file_path(&format!("./evidence/{}.json", action_name))
+        .try_build().unwrap();
+    let activity = Activity::new(activity_name, "Short Desc", "Long Desc").unwrap().add(action);
+
+    AssuranceProcedure::builder()
+        .api_version("1.2.0")
+        .procedure_info(nrn, "A Short Desc.", "This is an example procedure")
+        .add_activity(&activity)
+}
+
+fn procedure_with_dependency(action_name: &str, depends_on: &[&str]) -> AssuranceProcedureBuilder {
+    let mut builder = Action::builder()
+        .name(action_name)
+        .short_description("Short Desc")
+        .long_description("Long Desc")
+        .test_file_path(&format!("./activity/build/{}.py", action_name))
+        .evidence_file_path(&format!("./evidence/{}.json", action_name));
+    for dependency in depends_on {
+        builder = builder.add_dependency(dependency);
+    }
+    let activity = Activity::new("build", "Short Desc", "Long Desc").unwrap().add(builder.try_build().unwrap());
+
+    AssuranceProcedure::builder()
+        .api_version("1.4.0")
+        .procedure_info("nrn:procedure:example/main", "A Short Desc.", "This is an example procedure")
+        .add_activity(&activity)
+}
+
+fn include_for(activities: &[&str]) -> Include {
+    let source = Source::try_new("https://github.com/example/procedures.git", "shared", Some("v1")).unwrap();
+    let names: Vec<String> = activities.iter().map(|name| name.to_string()).collect();
+    Include::try_new(&source, &names).unwrap()
+}
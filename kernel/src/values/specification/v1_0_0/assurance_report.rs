@@ -6,6 +6,7 @@ use crate::values::specification::assurance_report::action::Action;
 use crate::values::specification::assurance_report::additional_information::AdditionalInformation;
 use crate::values::specification::assurance_report::activity::Activity;
 use crate::values::specification::assurance_report::activities::Activities;
+use crate::values::specification::assurance_report::outcome_policy::OutcomePolicy;
 use crate::values::specification::assurance_report::summary::Summary;
 use crate::values::specification::metadata::MetaData;
 use crate::values::specification::procedure::Procedure;
@@ -85,6 +86,7 @@ pub struct Builder {
     activities: Option<Activities>,
     summary: Option<Summary>,
     additional_info: Option<AdditionalInformation>,
+    outcome_policy: Option<OutcomePolicy>,
     metadata_vec: Vec<(String, String)>,
     subject_nrn_str: Option<String>,
     subject_id_str: Option<String>,
@@ -93,6 +95,7 @@ pub struct Builder {
     activities_vec: Vec<Activity>,
     actions_vec: Vec<(String, Action)>,
     additional_info_vec: Vec<String>,
+    outcome_policy_str: Option<String>,
 }
 
 
@@ -112,7 +115,9 @@ impl Builder {
             activities_vec: Vec::new(),
             actions_vec: Vec::new(),
             additional_info: None,
-            additional_info_vec: Vec::new()
+            additional_info_vec: Vec::new(),
+            outcome_policy: None,
+            outcome_policy_str: None
         }
     }
 
@@ -141,6 +146,11 @@ impl Builder {
         self
     }
 
+    pub fn use_outcome_policy(mut self, outcome_policy: &OutcomePolicy) -> Self {
+        self.outcome_policy = Some(outcome_policy.clone());
+        self
+    }
+
     pub fn add_metadata(mut self, key: &str, value: &str) ->Self {
         self.metadata_vec.push((key.to_string(), value.to_string() ) );
         self
@@ -197,6 +207,11 @@ impl Builder {
         self
     }
 
+    pub fn outcome_policy(mut self, policy: &str) -> Self {
+        self.outcome_policy_str = Some(policy.to_string());
+        self
+    }
+
 
     pub fn try_build(self) -> Result<AssuranceReportV1, Error> {
 
@@ -204,7 +219,8 @@ impl Builder {
         let subject = self.build_subject()?;
         let procedure = self.build_procedure()?;
         let activities = self.build_activities()?;
-        let summary = Summary::of(&activities);
+        let outcome_policy = self.build_outcome_policy()?;
+        let summary = Summary::of_with_policy(&activities, &outcome_policy);
         let additional_info = self.build_additional_information()?;
 
         Ok(AssuranceReportV1 { metadata, subject, procedure, summary, activities, additional_info })
@@ -270,6 +286,17 @@ impl Builder {
         }
     }
 
+    fn build_outcome_policy(&self) -> Result<OutcomePolicy, Error> {
+        match &self.outcome_policy {
+            Some(outcome_policy) => Ok(outcome_policy.clone()),
+            None => match &self.outcome_policy_str {
+                Some(policy) => OutcomePolicy::try_from(policy)
+                    .map_err(|e| customer_error(e.message.as_str())),
+                None => Ok(OutcomePolicy::default())
+            }
+        }
+    }
+
     fn build_additional_information(&self) -> Result<AdditionalInformation, Error> {
         match &self.additional_info {
             Some(additional_info) => return Ok(additional_info.clone()),
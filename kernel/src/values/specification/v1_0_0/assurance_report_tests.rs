@@ -10,11 +10,13 @@ use crate::values::specification::assurance_report::activities::Activities;
 use crate::values::specification::outcome::Outcome;
 use crate::values::specification::assurance_report::activity::Activity;
 use crate::values::specification::assurance_report::additional_information::AdditionalInformation;
+use crate::values::specification::assurance_report::outcome_policy::OutcomePolicy;
 use crate::values::specification::assurance_report::signed_file::SignedFile;
 use crate::values::specification::description::Description;
 use crate::values::specification::metadata::MetaData;
 use crate::values::specification::name::Name;
 use crate::values::specification::procedure::Procedure;
+use crate::values::specification::severity::Severity;
 use crate::values::specification::subject::Subject;
 use crate::values::specification::traits::AssuranceReport;
 
@@ -502,6 +504,48 @@ fn builder_additional_information_empty_string_error() {
     kernel_error_starts_with!(report_result, Kind::InvalidInput, Audience::User, "The AssuranceReport could not be created. There is an issue adding your Additional Information to the report. ");
 }
 
+#[test]
+fn builder_outcome_policy_success() {
+    let policy_from_str = Builder::new()
+        .subject_nrn("nrn:procedure:example")
+        .subject_id("somesubjectid")
+        .procedure_repository("https://some-location.com")
+        .procedure_directory("some/location")
+        .add_action("activity", &testing_acttion())
+        .outcome_policy("critical,high")
+        .try_build();
+
+    let report = is_ok!(policy_from_str);
+    assert_eq!(report.summary().outcome_policy, OutcomePolicy::FailOn(vec![Severity::CRITICAL, Severity::HIGH]));
+
+    let use_overrides_str = Builder::new()
+        .subject_nrn("nrn:procedure:example")
+        .subject_id("somesubjectid")
+        .procedure_repository("https://some-location.com")
+        .procedure_directory("some/location")
+        .add_action("activity", &testing_acttion())
+        .outcome_policy("critical")
+        .use_outcome_policy(&OutcomePolicy::AnyFailure)
+        .try_build();
+
+    let report = is_ok!(use_overrides_str);
+    assert_eq!(report.summary().outcome_policy, OutcomePolicy::AnyFailure);
+}
+
+#[test]
+fn builder_bad_outcome_policy_error() {
+    let report_result = Builder::new()
+        .subject_nrn("nrn:procedure:example")
+        .subject_id("somesubjectid")
+        .procedure_repository("https://some-location.com")
+        .procedure_directory("some/location")
+        .add_action("activity", &testing_acttion())
+        .outcome_policy("urgent")
+        .try_build();
+
+    kernel_error_starts_with!(report_result, Kind::InvalidInput, Audience::User, "The AssuranceReport could not be created. There is an issue with the outcome policy. ");
+}
+
 /** Testing Helpers */
 
 fn testing_acttion() -> Action {
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::error::{Error, Kind};
+
+/// The number of milliseconds in a day.
+const MILLISECONDS_PER_DAY: u128 = 86_400_000;
+
+/// The [`Date`] struct represents a calendar date in UTC, in the ISO 8601 format of 'YYYY-MM-DD'.
+///
+/// # Design Decision
+///
+/// * The fields are ordered year, month, day so the derived ordering compares dates chronologically.
+///
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    pub year: u16,
+    pub month: u8,
+    pub day: u8,
+}
+
+impl Date {
+
+    /// # Overview
+    /// Creates a new [`Date`] for the current day in UTC.
+    pub fn today() -> Self {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)
+            .expect("Time went backwards");
+        Date::from_epoch_millis(now.as_millis())
+    }
+
+    /// # Overview
+    /// Creates a new [`Date`] from the number of milliseconds since the UNIX epoch, such as the value of a [`StartTime`].
+    ///
+    /// # Arguments
+    ///
+    /// * `millis` - The number of milliseconds since 1970-01-01 UTC.
+    ///
+    pub fn from_epoch_millis(millis: u128) -> Self {
+        let (year, month, day) = civil_from_days((millis / MILLISECONDS_PER_DAY) as i64);
+        Date { year: year as u16, month: month as u8, day: day as u8 }
+    }
+
+    /// # Overview
+    /// Creates a new [`Date`] from a string in the format 'YYYY-MM-DD'.
+    ///
+    /// # Returns
+    ///
+    /// A [`Result`] of either the [`Date`] or an [`Error`].
+    ///
+    /// ## Errors
+    ///
+    /// An [`Error`] of [`Kind::InvalidInput`] for [`Audience::User`] is returned if the value is not in the format 'YYYY-MM-DD', or is not a valid calendar date.
+    ///
+    pub fn try_from(value: &str) -> Result<Self, Error> {
+        let parts: Vec<&str> = value.trim().split('-').collect();
+        if parts.len() != 3 || parts[0].len() != 4 || parts[1].len() != 2 || parts[2].len() != 2 {
+            return Err(invalid_date_error(value));
+        }
+        let year = parts[0].parse::<u16>().map_err(|_| invalid_date_error(value))?;
+        let month = parts[1].parse::<u8>().map_err(|_| invalid_date_error(value))?;
+        let day = parts[2].parse::<u8>().map_err(|_| invalid_date_error(value))?;
+
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) {
+            return Err(invalid_date_error(value));
+        }
+        Ok(Date { year, month, day })
+    }
+
+}
+
+impl Display for Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if (year.is_multiple_of(4) && !year.is_multiple_of(100)) || year.is_multiple_of(400) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Converts the number of days since 1970-01-01 to a (year, month, day) civil date, see <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+fn invalid_date_error(value: &str) -> Error {
+    Error::for_user(Kind::InvalidInput, format!("'{}' is not a valid date. A date must be in the format 'YYYY-MM-DD'.", value))
+}
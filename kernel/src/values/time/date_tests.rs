@@ -0,0 +1,38 @@
+use nape_testing_assertions::{is_ok, kernel_error_eq};
+use crate::error::{Audience, Kind};
+use crate::values::time::date::Date;
+
+#[test]
+fn try_from_success() {
+    let date = is_ok!(Date::try_from("2024-02-29"));
+    assert_eq!(date, Date { year: 2024, month: 2, day: 29 });
+}
+
+#[test]
+fn try_from_invalid_format_error() {
+    let result = Date::try_from("2024/01/01");
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "'2024/01/01' is not a valid date. A date must be in the format 'YYYY-MM-DD'.");
+}
+
+#[test]
+fn try_from_invalid_day_error() {
+    let result = Date::try_from("2023-02-29");
+    kernel_error_eq!(result, Kind::InvalidInput, Audience::User, "'2023-02-29' is not a valid date. A date must be in the format 'YYYY-MM-DD'.");
+}
+
+#[test]
+fn from_epoch_millis_success() {
+    assert_eq!(Date::from_epoch_millis(0), Date { year: 1970, month: 1, day: 1 });
+    assert_eq!(Date::from_epoch_millis(1_709_208_000_000), Date { year: 2024, month: 2, day: 29 });
+}
+
+#[test]
+fn ordering_is_chronological() {
+    assert!(Date::try_from("2024-01-31").unwrap() < Date::try_from("2024-02-01").unwrap());
+    assert!(Date::try_from("2025-01-01").unwrap() > Date::try_from("2024-12-31").unwrap());
+}
+
+#[test]
+fn to_string_success() {
+    assert_eq!(Date { year: 2024, month: 3, day: 5 }.to_string(), "2024-03-05");
+}
@@ -1,2 +1,4 @@
+pub mod date;
 pub mod start_time;
-#[cfg(test)] mod start_time_tests;
\ No newline at end of file
+#[cfg(test)] mod date_tests;
+#[cfg(test)] mod start_time_tests;
@@ -1,5 +1,9 @@
 use std::time::{SystemTime, UNIX_EPOCH};
 use crate::error::{Error, Kind};
+use crate::values::time::date::Date;
+
+/// The number of milliseconds in a day.
+const MILLISECONDS_PER_DAY: u128 = 86_400_000;
 
 #[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct StartTime {
@@ -60,6 +64,18 @@ impl StartTime {
         self.time.to_string()
     }
 
+    /// # Overview
+    /// Converts the [`StartTime`] struct to a human readable UTC timestamp in the ISO 8601 format of 'YYYY-MM-DDTHH:MM:SSZ'.
+    ///
+    pub fn to_utc_string(&self) -> String {
+        let seconds_of_day = (self.time % MILLISECONDS_PER_DAY) / 1000;
+        format!("{}T{:02}:{:02}:{:02}Z",
+                Date::from_epoch_millis(self.time),
+                seconds_of_day / 3600,
+                (seconds_of_day % 3600) / 60,
+                seconds_of_day % 60)
+    }
+
 }
 
 fn get_current_time_in_milliseconds() -> u128 {
@@ -26,6 +26,12 @@ fn to_string_success() {
     assert_eq!(start_time.to_string(), "1000");
 }
 
+#[test]
+fn to_utc_string_success() {
+    assert_eq!(StartTime::from(0).to_utc_string(), "1970-01-01T00:00:00Z");
+    assert_eq!(StartTime::from(1719326666123).to_utc_string(), "2024-06-25T14:44:26Z");
+}
+
 #[test]
 fn try_from_zero_error() {
     let result = StartTime::try_from(0);